@@ -0,0 +1,96 @@
+//! Compares the default, [`HashMap`](std::collections::HashMap)-backed [`CooldownState`]
+//! against [`CooldownState::dense`], for a 10-ability enum across 10 000 entities.
+//!
+//! Run with `cargo bench --bench cooldowns`.
+
+use bevy::reflect::Reflect;
+use bevy::utils::Duration;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use leafwing_abilities::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+enum Ability {
+    Fireball,
+    Frostbolt,
+    Blink,
+    Shield,
+    Heal,
+    Dash,
+    Taunt,
+    Stun,
+    Summon,
+    Ultimate,
+}
+
+const ABILITIES: [Ability; 10] = [
+    Ability::Fireball,
+    Ability::Frostbolt,
+    Ability::Blink,
+    Ability::Shield,
+    Ability::Heal,
+    Ability::Dash,
+    Ability::Taunt,
+    Ability::Stun,
+    Ability::Summon,
+    Ability::Ultimate,
+];
+
+const ENTITIES: usize = 10_000;
+
+/// Creates the cooldowns of every entity, with every ability on cooldown.
+fn cooldowns(empty: CooldownState<Ability>) -> Vec<CooldownState<Ability>> {
+    let mut cooldowns = empty;
+    for (i, ability) in ABILITIES.into_iter().enumerate() {
+        cooldowns.set(ability, Cooldown::from_secs(1. + i as f32));
+    }
+    cooldowns.trigger_all(None);
+
+    vec![cooldowns; ENTITIES]
+}
+
+fn layouts() -> [(&'static str, fn() -> CooldownState<Ability>); 2] {
+    [
+        ("sparse", CooldownState::default),
+        ("dense", CooldownState::dense),
+    ]
+}
+
+fn tick(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tick");
+    for (name, empty) in layouts() {
+        let cooldowns = cooldowns(empty());
+        group.bench_function(name, |b| {
+            b.iter_batched_ref(
+                || cooldowns.clone(),
+                |cooldowns| {
+                    for cooldown_state in cooldowns.iter_mut() {
+                        black_box(cooldown_state.tick(Duration::from_millis(16), None));
+                    }
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+fn ready(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ready");
+    for (name, empty) in layouts() {
+        let cooldowns = cooldowns(empty());
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for cooldown_state in cooldowns.iter() {
+                    for ability in ABILITIES.iter() {
+                        let _ = black_box(cooldown_state.ready(ability));
+                    }
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, tick, ready);
+criterion_main!(benches);
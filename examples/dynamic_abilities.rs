@@ -0,0 +1,81 @@
+//! Demonstrates how to use abilities that are only known at runtime, such as those added by mods.
+//!
+//! Press 1 or 2 to use the two modded abilities, and watch the console for the results.
+use bevy::prelude::*;
+use leafwing_abilities::dynamic_ability::{AbilityDefinition, AbilityRegistry, DynamicAbility};
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_input_manager::prelude::*;
+
+/// In a real game, this would be read from the files of each installed mod.
+const MODDED_ABILITIES: &str = r#"[
+    (name: "Frost Nova", cooldown: Some(5.0), cost: Some(20.0)),
+    (name: "Shadow Step", charges: Some(2), cost: Some(5.0)),
+]"#;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(InputManagerPlugin::<DynamicAbility>::default())
+        .add_plugins(AbilityPlugin::<DynamicAbility>::default())
+        .insert_resource(load_modded_abilities())
+        .add_systems(Startup, spawn_player)
+        .add_systems(Update, use_abilities)
+        .run();
+}
+
+fn load_modded_abilities() -> AbilityRegistry {
+    let definitions: Vec<AbilityDefinition> =
+        ron::from_str(MODDED_ABILITIES).expect("Modded abilities should be valid RON");
+
+    let mut registry = AbilityRegistry::default();
+    registry
+        .register_all(definitions)
+        .expect("Modded abilities should not conflict");
+    registry
+}
+
+#[derive(Component)]
+struct Player;
+
+fn spawn_player(mut commands: Commands, registry: Res<AbilityRegistry>) {
+    // Bind each ability to a number key, in the order they were registered
+    let keys = [KeyCode::Digit1, KeyCode::Digit2];
+    let input_map = InputMap::new(
+        registry
+            .iter()
+            .zip(keys)
+            .map(|((ability, _definition), key)| (ability, key)),
+    );
+
+    commands.spawn((
+        Player,
+        InputManagerBundle {
+            action_state: ActionState::default(),
+            input_map,
+        },
+        registry.abilities_bundle(),
+        PoolBundle {
+            pool: ManaPool::new(Mana(100.), Mana(100.), Mana(10.)),
+            ability_costs: registry.ability_costs::<ManaPool>(),
+        },
+    ));
+}
+
+fn use_abilities(
+    mut query: Query<AbilityState<DynamicAbility, ManaPool>, With<Player>>,
+    registry: Res<AbilityRegistry>,
+) {
+    let mut ability_state = query.single_mut();
+
+    for (ability, definition) in registry.iter() {
+        if !ability_state.action_state.just_pressed(&ability) {
+            continue;
+        }
+
+        match ability_state.trigger(&ability) {
+            Ok(()) => info!("Used {}!", definition.name),
+            Err(error) => info!("Could not use {}: {error}", definition.name),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Demonstrates how to display the ability status of several local players,
+//! as you might in a split-screen game.
+//!
+//! Each player has their own corner of the screen, and their own keys:
+//! player 1 uses Q / W, player 2 uses O / P, player 3 uses Z / X and player 4 uses N / M.
+//! The HUD of each player only reads the `AbilityHudSummary` resource.
+use bevy::prelude::*;
+use leafwing_abilities::hud_summary::{AbilityHudSummary, PlayerId};
+use leafwing_abilities::plugin::AbilitySystem;
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_abilities::systems::regenerate_resource_pool;
+use leafwing_input_manager::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(InputManagerPlugin::<Spell>::default())
+        .add_plugins(AbilityPlugin::<Spell>::default().with_hud_summary::<ManaPool>())
+        .add_systems(Startup, (spawn_camera, spawn_players, spawn_huds))
+        .add_systems(Update, (cast_spells, regenerate_resource_pool::<ManaPool>))
+        .add_systems(
+            PostUpdate,
+            update_huds.after(AbilitySystem::UpdateHudSummary),
+        )
+        .run();
+}
+
+#[derive(Actionlike, Reflect, Abilitylike, Clone, Copy, PartialEq, Debug, Hash, Eq)]
+enum Spell {
+    Zap,
+    Meteor,
+}
+
+const KEYS: [(KeyCode, KeyCode); 4] = [
+    (KeyCode::KeyQ, KeyCode::KeyW),
+    (KeyCode::KeyO, KeyCode::KeyP),
+    (KeyCode::KeyZ, KeyCode::KeyX),
+    (KeyCode::KeyN, KeyCode::KeyM),
+];
+
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn(Camera2d);
+}
+
+fn spawn_players(mut commands: Commands) {
+    for (id, (zap_key, meteor_key)) in KEYS.into_iter().enumerate() {
+        commands.spawn((
+            PlayerId(id as u32),
+            InputManagerBundle {
+                action_state: ActionState::default(),
+                input_map: InputMap::new([(Spell::Zap, zap_key), (Spell::Meteor, meteor_key)]),
+            },
+            AbilitiesBundle {
+                cooldowns: CooldownState::new([
+                    (Spell::Zap, Cooldown::from_secs(0.5)),
+                    (Spell::Meteor, Cooldown::from_secs(8.)),
+                ]),
+                ..default()
+            },
+            PoolBundle {
+                pool: ManaPool::new(Mana(100.), Mana(100.), Mana(5.)),
+                ability_costs: AbilityCosts::new([
+                    (Spell::Zap, Mana(5.)),
+                    (Spell::Meteor, Mana(60.)),
+                ]),
+            },
+        ));
+    }
+}
+
+/// Marks the text that displays the HUD of a player.
+#[derive(Component)]
+struct Hud(PlayerId);
+
+fn spawn_huds(mut commands: Commands) {
+    // One HUD in each corner of the screen, mimicking a four-way split
+    let corners = [
+        (Val::Px(10.), Val::Auto, Val::Px(10.), Val::Auto),
+        (Val::Auto, Val::Px(10.), Val::Px(10.), Val::Auto),
+        (Val::Px(10.), Val::Auto, Val::Auto, Val::Px(10.)),
+        (Val::Auto, Val::Px(10.), Val::Auto, Val::Px(10.)),
+    ];
+
+    for (id, (left, right, top, bottom)) in corners.into_iter().enumerate() {
+        commands.spawn((
+            Text::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                left,
+                right,
+                top,
+                bottom,
+                ..default()
+            },
+            Hud(PlayerId(id as u32)),
+        ));
+    }
+}
+
+fn cast_spells(mut query: Query<AbilityState<Spell, ManaPool>>) {
+    for mut ability_state in query.iter_mut() {
+        for spell in [Spell::Zap, Spell::Meteor] {
+            let _ = ability_state.trigger_if_just_pressed(&spell);
+        }
+    }
+}
+
+fn update_huds(summary: Res<AbilityHudSummary<Spell>>, mut query: Query<(&mut Text, &Hud)>) {
+    for (mut text, hud) in query.iter_mut() {
+        let Some(snapshots) = summary.get(hud.0) else {
+            continue;
+        };
+
+        let mut lines = vec![format!("Player {}", hud.0 .0 + 1)];
+        for snapshot in snapshots {
+            let status = match snapshot.ready {
+                Ok(()) => "ready".to_string(),
+                Err(CannotUseAbility::OnCooldown) => format!(
+                    "{:.0}% cooling down",
+                    snapshot.cooldown_remaining.unwrap_or_default() * 100.
+                ),
+                Err(error) => error.to_string(),
+            };
+            lines.push(format!("{:?}: {status}", snapshot.action));
+        }
+
+        **text = lines.join("\n");
+    }
+}
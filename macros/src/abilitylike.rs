@@ -1,8 +1,8 @@
 use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use proc_macro_crate::{crate_name, FoundCrate};
-use quote::quote;
-use syn::{DeriveInput, Ident};
+use quote::{quote, quote_spanned};
+use syn::{Data, DeriveInput, Fields, Ident, Lit, Meta, MetaNameValue, NestedMeta, Variant};
 
 /// This approach and implementation is inspired by the `strum` crate,
 /// Copyright (c) 2019 Peter Glotfelty
@@ -33,7 +33,323 @@ pub(crate) fn abilitylike_inner(ast: &DeriveInput) -> TokenStream {
         quote!(leafwing_abilities)
     };
 
+    let variant_attributes = match &ast.data {
+        Data::Enum(data_enum) => {
+            match parse_variant_attributes(data_enum.variants.iter(), &crate_path) {
+                Ok(variant_attributes) => variant_attributes,
+                Err(error) => return error.to_compile_error(),
+            }
+        }
+        _ => Vec::new(),
+    };
+
+    let defaults = if variant_attributes.is_empty() {
+        TokenStream::new()
+    } else {
+        default_functions(ast, &variant_attributes, &crate_path)
+    };
+
     quote! {
         impl #impl_generics #crate_path::Abilitylike for #enum_name #type_generics #where_clause {}
+
+        #defaults
+    }
+}
+
+/// The values set by an `#[ability(...)]` attribute on a single unit variant.
+#[derive(Default)]
+struct AbilityAttributes {
+    cooldown_secs: Option<f32>,
+    charges: Option<u8>,
+    replenish: Option<(Span, TokenStream)>,
+    cooldown_strategy: Option<(Span, TokenStream)>,
+    cost: Option<f32>,
+}
+
+/// Parses the `#[ability(...)]` attributes of each variant, skipping variants without one.
+fn parse_variant_attributes<'a>(
+    variants: impl Iterator<Item = &'a Variant>,
+    crate_path: &TokenStream,
+) -> syn::Result<Vec<(Ident, AbilityAttributes)>> {
+    let mut parsed = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |error: syn::Error| match errors.as_mut() {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
+    };
+
+    for variant in variants {
+        let mut attributes = AbilityAttributes::default();
+        let mut found = false;
+
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("ability"))
+        {
+            found = true;
+
+            if !matches!(variant.fields, Fields::Unit) {
+                push_error(syn::Error::new_spanned(
+                    attr,
+                    "`#[ability(...)]` can only be used on unit variants",
+                ));
+                continue;
+            }
+
+            let list = match attr.parse_meta() {
+                Ok(Meta::List(list)) => list,
+                Ok(meta) => {
+                    push_error(syn::Error::new_spanned(
+                        meta,
+                        "expected `#[ability(key = value, ...)]`",
+                    ));
+                    continue;
+                }
+                Err(error) => {
+                    push_error(error);
+                    continue;
+                }
+            };
+
+            for nested in list.nested.iter() {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(name_value)) => {
+                        if let Err(error) =
+                            parse_name_value(name_value, &mut attributes, crate_path)
+                        {
+                            push_error(error);
+                        }
+                    }
+                    other => push_error(syn::Error::new_spanned(other, "expected `key = value`")),
+                }
+            }
+
+            if attributes.charges.is_none() {
+                if let Some((span, _)) = attributes
+                    .replenish
+                    .as_ref()
+                    .or(attributes.cooldown_strategy.as_ref())
+                {
+                    push_error(syn::Error::new(
+                        *span,
+                        "`replenish` and `cooldown_strategy` require `charges` to be set",
+                    ));
+                }
+            }
+        }
+
+        if found {
+            parsed.push((variant.ident.clone(), attributes));
+        }
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(parsed),
+    }
+}
+
+/// Parses a single `key = value` pair of an `#[ability(...)]` attribute into `attributes`.
+fn parse_name_value(
+    name_value: &MetaNameValue,
+    attributes: &mut AbilityAttributes,
+    crate_path: &TokenStream,
+) -> syn::Result<()> {
+    let lit = &name_value.lit;
+    let Some(key) = name_value.path.get_ident() else {
+        return Err(syn::Error::new_spanned(&name_value.path, "expected a key"));
+    };
+
+    let duplicate = match key.to_string().as_str() {
+        "cooldown_secs" => attributes
+            .cooldown_secs
+            .replace(parse_non_negative(lit, "cooldown_secs")?)
+            .is_some(),
+        "cost" => attributes
+            .cost
+            .replace(parse_non_negative(lit, "cost")?)
+            .is_some(),
+        "charges" => {
+            let Lit::Int(int) = lit else {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "`charges` must be an integer between 0 and 255",
+                ));
+            };
+            attributes.charges.replace(int.base10_parse()?).is_some()
+        }
+        "replenish" => {
+            let strategy = match parse_str(lit, "replenish")?.as_str() {
+                "one_at_a_time" => quote!(OneAtATime),
+                "all_at_once" => quote!(AllAtOnce),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "unknown `replenish` strategy: expected \"one_at_a_time\" or \"all_at_once\"",
+                    ))
+                }
+            };
+            attributes
+                .replenish
+                .replace((
+                    lit.span(),
+                    quote_spanned!(lit.span()=> #crate_path::charges::ReplenishStrategy::#strategy),
+                ))
+                .is_some()
+        }
+        "cooldown_strategy" => {
+            let strategy = match parse_str(lit, "cooldown_strategy")?.as_str() {
+                "ignore" => quote!(Ignore),
+                "constantly_refresh" => quote!(ConstantlyRefresh),
+                "refresh_when_empty" => quote!(RefreshWhenEmpty),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "unknown `cooldown_strategy`: expected \"ignore\", \"constantly_refresh\" or \"refresh_when_empty\"",
+                    ))
+                }
+            };
+            attributes
+                .cooldown_strategy
+                .replace((
+                    lit.span(),
+                    quote_spanned!(lit.span()=> #crate_path::charges::CooldownStrategy::#strategy),
+                ))
+                .is_some()
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                key,
+                "unknown key: expected one of `cooldown_secs`, `charges`, `replenish`, `cooldown_strategy` or `cost`",
+            ))
+        }
+    };
+
+    if duplicate {
+        return Err(syn::Error::new_spanned(key, format!("duplicate `{key}`")));
+    }
+
+    Ok(())
+}
+
+/// Parses an integer or float literal as a finite, non-negative `f32`.
+fn parse_non_negative(lit: &Lit, key: &str) -> syn::Result<f32> {
+    let value: f32 = match lit {
+        Lit::Float(float) => float.base10_parse()?,
+        Lit::Int(int) => int.base10_parse()?,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!("`{key}` must be a number"),
+            ))
+        }
+    };
+
+    if !value.is_finite() || value < 0.0 {
+        return Err(syn::Error::new_spanned(
+            lit,
+            format!("`{key}` must be finite and non-negative"),
+        ));
+    }
+
+    Ok(value)
+}
+
+/// Parses a string literal.
+fn parse_str(lit: &Lit, key: &str) -> syn::Result<String> {
+    match lit {
+        Lit::Str(string) => Ok(string.value()),
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            format!("`{key}` must be a string"),
+        )),
+    }
+}
+
+/// Generates the `default_*` inherent functions from the parsed variant attributes.
+fn default_functions(
+    ast: &DeriveInput,
+    variant_attributes: &[(Ident, AbilityAttributes)],
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let enum_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let cooldowns: Vec<TokenStream> = variant_attributes
+        .iter()
+        .filter_map(|(variant, attributes)| {
+            let secs = attributes.cooldown_secs?;
+            Some(quote!((Self::#variant, #crate_path::cooldown::Cooldown::from_secs(#secs))))
+        })
+        .collect();
+    let n_cooldowns = cooldowns.len();
+
+    let charges: Vec<TokenStream> = variant_attributes
+        .iter()
+        .filter_map(|(variant, attributes)| {
+            let max = attributes.charges?;
+            let replenish = attributes.replenish.clone().map(|(_, tokens)| tokens).unwrap_or_else(
+                || quote!(<#crate_path::charges::ReplenishStrategy as ::core::default::Default>::default()),
+            );
+            let cooldown_strategy = attributes.cooldown_strategy.clone().map(|(_, tokens)| tokens).unwrap_or_else(
+                || quote!(<#crate_path::charges::CooldownStrategy as ::core::default::Default>::default()),
+            );
+            Some(quote!((Self::#variant, #crate_path::charges::Charges::new(#max, #replenish, #cooldown_strategy))))
+        })
+        .collect();
+    let n_charges = charges.len();
+
+    let costs: Vec<TokenStream> = variant_attributes
+        .iter()
+        .filter_map(|(variant, attributes)| {
+            let cost = attributes.cost?;
+            Some(quote!((Self::#variant, <P::Quantity as ::core::convert::From<f32>>::from(#cost))))
+        })
+        .collect();
+    let n_costs = costs.len();
+
+    quote! {
+        impl #impl_generics #enum_name #type_generics #where_clause {
+            /// The `CooldownState` declared by the `cooldown_secs` of each variant's `#[ability(...)]` attribute.
+            ///
+            /// Variants without a `cooldown_secs` have no cooldown.
+            #[must_use]
+            pub fn default_cooldowns() -> #crate_path::cooldown::CooldownState<Self> {
+                let pairs: [(Self, #crate_path::cooldown::Cooldown); #n_cooldowns] = [#(#cooldowns),*];
+                #crate_path::cooldown::CooldownState::new(pairs)
+            }
+
+            /// The `ChargeState` declared by the `charges` of each variant's `#[ability(...)]` attribute.
+            ///
+            /// Variants without `charges` have no charges.
+            #[must_use]
+            pub fn default_charges() -> #crate_path::charges::ChargeState<Self> {
+                let pairs: [(Self, #crate_path::charges::Charges); #n_charges] = [#(#charges),*];
+                #crate_path::charges::ChargeState::new(pairs)
+            }
+
+            /// The `AbilityCosts` declared by the `cost` of each variant's `#[ability(...)]` attribute.
+            ///
+            /// Variants without a `cost` have no explicit cost.
+            #[must_use]
+            pub fn default_costs<P: #crate_path::pool::Pool>() -> #crate_path::pool::AbilityCosts<Self, P>
+            where
+                P::Quantity: ::core::convert::From<f32>,
+            {
+                let pairs: [(Self, P::Quantity); #n_costs] = [#(#costs),*];
+                #crate_path::pool::AbilityCosts::new(pairs)
+            }
+
+            /// An `AbilitiesBundle` containing the [`default_cooldowns`](Self::default_cooldowns)
+            /// and [`default_charges`](Self::default_charges).
+            #[must_use]
+            pub fn default_abilities_bundle() -> #crate_path::AbilitiesBundle<Self> {
+                #crate_path::AbilitiesBundle {
+                    cooldowns: Self::default_cooldowns(),
+                    charges: Self::default_charges(),
+                }
+            }
+        }
     }
 }
@@ -9,7 +9,21 @@ mod abilitylike;
 use proc_macro::TokenStream;
 use syn::DeriveInput;
 
-#[proc_macro_derive(Abilitylike)]
+/// Derives the `Abilitylike` trait.
+///
+/// Unit variants of enums may be annotated with `#[ability(...)]` to declare their defaults,
+/// using any of the following keys:
+///
+/// - `cooldown_secs`: the max time of the ability's cooldown, in seconds
+/// - `charges`: the max number of charges
+/// - `replenish`: how the charges are replenished, either `"one_at_a_time"` or `"all_at_once"`
+/// - `cooldown_strategy`: how the charges interact with the cooldown, one of `"ignore"`, `"constantly_refresh"` or `"refresh_when_empty"`
+/// - `cost`: the flat cost of the ability, for any pool whose quantity can be created from an `f32`
+///
+/// If any variant is annotated, the inherent functions `default_cooldowns()`, `default_charges()`,
+/// `default_costs::<P>()` and `default_abilities_bundle()` are generated from these attributes.
+/// Variants without a given key are simply omitted from the corresponding map.
+#[proc_macro_derive(Abilitylike, attributes(ability))]
 pub fn abilitylike(input: TokenStream) -> TokenStream {
     let ast = syn::parse_macro_input!(input as DeriveInput);
 
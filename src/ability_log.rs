@@ -0,0 +1,293 @@
+//! An opt-in record of which abilities were triggered, and when.
+//!
+//! This is intended for replays, kill-cams and debugging tools that need an authoritative timeline of ability usage.
+//! Enable it with [`AbilityPlugin::with_logging`](crate::plugin::AbilityPlugin::with_logging).
+//! Abilities triggered through [`AbilityState`](crate::AbilityState) are then recorded automatically.
+//! For abilities triggered in other ways, such as by calling [`Abilitylike::trigger`] directly,
+//! send an [`AbilityTriggered`] event from your gameplay systems whenever an ability is attempted.
+
+use crate::{Abilitylike, CannotUseAbility};
+
+use bevy::ecs::prelude::{Component, Entity, Event, Resource};
+use bevy::utils::Duration;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::VecDeque;
+
+/// An event that records an attempt to trigger the ability `action`.
+///
+/// Send this after calling [`Abilitylike::trigger`] or one of the [`AbilityState`](crate::AbilityState) helpers,
+/// passing in the returned result as the `outcome`.
+/// These events are read by [`log_ability_triggers`](crate::systems::log_ability_triggers) to populate the [`AbilityLog`].
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct AbilityTriggered<A: Abilitylike> {
+    /// The entity that attempted to use the ability, or [`None`] if the abilities are stored in a resource.
+    pub entity: Option<Entity>,
+    /// The ability that was attempted.
+    pub action: A,
+    /// Whether or not the ability could be used.
+    pub outcome: Result<(), CannotUseAbility>,
+}
+
+/// The attempts to trigger abilities of type `A` made through [`AbilityState`](crate::AbilityState) on this entity,
+/// which have not yet been moved into the [`AbilityLog`].
+///
+/// When logging is enabled with [`AbilityPlugin::with_logging`](crate::plugin::AbilityPlugin::with_logging),
+/// this component is added to every entity with a [`CooldownState`](crate::cooldown::CooldownState),
+/// and drained each frame by [`log_ability_triggers`](crate::systems::log_ability_triggers).
+/// Without it, [`AbilityState`](crate::AbilityState) records nothing.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct AbilityAttempts<A: Abilitylike> {
+    attempts: Vec<(A, Result<(), CannotUseAbility>)>,
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Abilitylike> Default for AbilityAttempts<A> {
+    fn default() -> Self {
+        AbilityAttempts {
+            attempts: Vec::new(),
+        }
+    }
+}
+
+impl<A: Abilitylike> AbilityAttempts<A> {
+    /// Records an attempt to trigger `action`, which had the provided `outcome`.
+    #[inline]
+    pub fn record(&mut self, action: A, outcome: Result<(), CannotUseAbility>) {
+        self.attempts.push((action, outcome));
+    }
+
+    /// Returns an iterator over the recorded attempts, from oldest to newest.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &(A, Result<(), CannotUseAbility>)> {
+        self.attempts.iter()
+    }
+
+    /// Removes and returns all recorded attempts, from oldest to newest.
+    #[inline]
+    pub fn drain(&mut self) -> impl Iterator<Item = (A, Result<(), CannotUseAbility>)> + '_ {
+        self.attempts.drain(..)
+    }
+}
+
+/// A single recorded ability attempt in an [`AbilityLog`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AbilityLogEntry<A: Abilitylike> {
+    /// The time since app startup at which the ability was attempted.
+    pub time: Duration,
+    /// The entity that attempted to use the ability, or [`None`] if the abilities are stored in a resource.
+    pub entity: Option<Entity>,
+    /// The ability that was attempted.
+    pub action: A,
+    /// Whether or not the ability could be used.
+    pub outcome: Result<(), CannotUseAbility>,
+}
+
+/// A ring buffer of the most recent ability attempts for abilities of type `A`.
+///
+/// Once the buffer is full, the oldest entries are discarded to make room for new ones.
+/// Insert this resource yourself before adding the [`AbilityPlugin`](crate::plugin::AbilityPlugin) to customize its capacity.
+///
+/// When deserializing, only the newest entries that fit within the capacity are kept.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize)]
+pub struct AbilityLog<A: Abilitylike> {
+    entries: VecDeque<AbilityLogEntry<A>>,
+    capacity: usize,
+}
+
+impl<A: Abilitylike> Default for AbilityLog<A> {
+    /// Creates an empty log with a capacity of [`AbilityLog::DEFAULT_CAPACITY`].
+    fn default() -> Self {
+        AbilityLog::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl<'de, A: Abilitylike + Deserialize<'de>> Deserialize<'de> for AbilityLog<A> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// The serialized form of [`AbilityLog`], before it has been trimmed to its capacity.
+        #[derive(Deserialize)]
+        struct UntrimmedAbilityLog<A: Abilitylike> {
+            entries: VecDeque<AbilityLogEntry<A>>,
+            capacity: usize,
+        }
+
+        let untrimmed = UntrimmedAbilityLog::<A>::deserialize(deserializer)?;
+        let mut log = AbilityLog {
+            entries: untrimmed.entries,
+            capacity: untrimmed.capacity,
+        };
+        log.trim();
+        Ok(log)
+    }
+}
+
+impl<A: Abilitylike> AbilityLog<A> {
+    /// The number of entries stored by [`AbilityLog::default`].
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Creates an empty log that stores at most `capacity` entries.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        AbilityLog {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// The maximum number of entries that can be stored.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of entries currently stored.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Are there no entries stored?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Records a new entry, evicting the oldest entry if the log is full.
+    pub fn record(&mut self, entry: AbilityLogEntry<A>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.entries.push_back(entry);
+        self.trim();
+    }
+
+    /// Evicts the oldest entries until the log fits within its capacity.
+    fn trim(&mut self) {
+        let excess = self.entries.len().saturating_sub(self.capacity);
+        self.entries.drain(..excess);
+    }
+
+    /// Removes all entries from the log.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns an iterator over the stored entries, from oldest to newest.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &AbilityLogEntry<A>> {
+        self.entries.iter()
+    }
+
+    /// Returns an iterator over the stored entries recorded at or after `time`, from oldest to newest.
+    #[inline]
+    pub fn iter_since(&self, time: Duration) -> impl Iterator<Item = &AbilityLogEntry<A>> {
+        self.entries.iter().filter(move |entry| entry.time >= time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_abilities;
+    use crate::Abilitylike;
+    use bevy::reflect::Reflect;
+    use leafwing_input_manager::Actionlike;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(
+        Actionlike,
+        Abilitylike,
+        Reflect,
+        Clone,
+        Copy,
+        Hash,
+        PartialEq,
+        Eq,
+        Debug,
+        Serialize,
+        Deserialize,
+    )]
+    enum TestAction {
+        Jump,
+        Dash,
+    }
+
+    fn entry(secs: u64, action: TestAction) -> AbilityLogEntry<TestAction> {
+        AbilityLogEntry {
+            time: Duration::from_secs(secs),
+            entity: None,
+            action,
+            outcome: Ok(()),
+        }
+    }
+
+    #[test]
+    fn full_log_evicts_oldest_entries() {
+        let mut log = AbilityLog::with_capacity(2);
+        log.record(entry(1, TestAction::Jump));
+        log.record(entry(2, TestAction::Dash));
+        log.record(entry(3, TestAction::Jump));
+
+        assert_eq!(log.len(), 2);
+        let times: Vec<Duration> = log.iter().map(|entry| entry.time).collect();
+        assert_eq!(times, vec![Duration::from_secs(2), Duration::from_secs(3)]);
+    }
+
+    #[test]
+    fn iter_since_filters_old_entries() {
+        let mut log = AbilityLog::default();
+        log.record(entry(1, TestAction::Jump));
+        log.record(entry(2, TestAction::Dash));
+        log.record(entry(3, TestAction::Jump));
+
+        let actions: Vec<TestAction> = log
+            .iter_since(Duration::from_secs(2))
+            .map(|entry| entry.action)
+            .collect();
+        assert_eq!(actions, vec![TestAction::Dash, TestAction::Jump]);
+    }
+
+    #[test]
+    fn log_round_trips_through_serde() {
+        let mut log = AbilityLog::with_capacity(4);
+        log.record(entry(1, TestAction::Jump));
+        log.record(AbilityLogEntry {
+            outcome: Err(CannotUseAbility::OnCooldown),
+            ..entry(2, TestAction::Dash)
+        });
+
+        let serialized = serde_json::to_string(&log).unwrap();
+        let deserialized: AbilityLog<TestAction> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(log, deserialized);
+    }
+
+    #[test]
+    fn deserialized_log_is_trimmed_to_capacity() {
+        let mut log = AbilityLog::with_capacity(3);
+        log.record(entry(1, TestAction::Jump));
+        log.record(entry(2, TestAction::Dash));
+        log.record(entry(3, TestAction::Jump));
+
+        let serialized = serde_json::to_string(&log)
+            .unwrap()
+            .replace("\"capacity\":3", "\"capacity\":2");
+        let mut deserialized: AbilityLog<TestAction> = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.len(), 2);
+        assert_eq!(
+            deserialized.iter().next().unwrap().time,
+            Duration::from_secs(2)
+        );
+
+        deserialized.record(entry(4, TestAction::Dash));
+        assert_eq!(deserialized.len(), 2);
+        assert_eq!(
+            deserialized.iter().next().unwrap().time,
+            Duration::from_secs(3)
+        );
+    }
+}
@@ -2,10 +2,15 @@
 #![allow(missing_docs)]
 
 use crate::{
+    ability_log::AbilityAttempts,
     charges::ChargeState,
     cooldown::CooldownState,
-    pool::{AbilityCosts, MaxPoolLessThanMin, Pool},
-    Abilitylike, CannotUseAbility,
+    disabled::DisabledAbilities,
+    pool::{
+        modified_cost, pool_can_pay, pool_shortfall, scale_cost, AbilityCost, AbilityCosts,
+        CostModifiers, CostPayments, MaxPoolLessThanMin, Pool, PoolShortfall,
+    },
+    ready_at_scale, trigger_at_scale, AbilityScale, Abilitylike, CannotUseAbility,
 };
 // Required due to poor macro hygiene in `WorldQuery` macro
 // Tracked in https://github.com/bevyengine/bevy/issues/6593
@@ -31,16 +36,8 @@ use leafwing_input_manager::action_state::ActionState;
 ///
 /// ## Multiple resource pools
 ///
-/// When working with abilities that require multiple resource pools, there are two options:
-///
-/// 1. Create a new [`Pool`] type that contains all of the possible resource pools.
-/// 2. Pass in [`NullPool`] and handle the resource costs manually in your ability implementations.
-///
-/// The first solution is reliable and type-safe, but limits you to a fixed collection of resource pools
-/// and can be wasteful and confusing, as the majority of abilities or characters will only use a single resource pool.
-///
-/// The second solution is more flexible, but requires you to handle the resource costs manually.
-/// Make sure to check if the resource cost can be paid before calling [`Abilitylike::trigger`]!
+/// When working with abilities that cost resources from two pools, use [`MultiPoolAbilityState`] instead.
+/// For more pools, call [`Abilitylike::trigger_multi`] directly.
 #[derive(QueryData)]
 #[query_data(mutable)]
 pub struct AbilityState<A: Abilitylike, P: Pool + Component = NullPool> {
@@ -54,25 +51,373 @@ pub struct AbilityState<A: Abilitylike, P: Pool + Component = NullPool> {
     pub pool: Option<&'static mut P>,
     /// The [`AbilityCosts`] of each ability, in terms of [`P::Quantity`](Pool::Quantity)
     pub ability_costs: Option<&'static mut AbilityCosts<A, P>>,
+    /// The [`CostModifiers`] that adjust the [`AbilityCosts`] of each ability
+    pub cost_modifiers: Option<&'static mut CostModifiers<A, P>>,
+    /// The [`CostPayments`] that record what was paid for each ability, inserted alongside the [`AbilityCosts`]
+    pub cost_payments: Option<&'static mut CostPayments<A, P>>,
+    /// The [`DisabledAbilities`] that cannot currently be used
+    pub disabled: Option<&'static DisabledAbilities<A>>,
+    /// The [`AbilityAttempts`] that record each ability triggered, if logging is enabled
+    pub attempts: Option<&'static mut AbilityAttempts<A>>,
+}
+
+/// A variant of [`AbilityState`] for abilities whose costs are paid from two resource pools, `P1` and `P2`.
+///
+/// Costs are checked and paid atomically, using [`Abilitylike::ready_multi`] and [`Abilitylike::trigger_multi`]:
+/// if either pool cannot pay, nothing is spent from the other.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct MultiPoolAbilityState<A: Abilitylike, P1: Pool + Component, P2: Pool + Component> {
+    /// The [`ActionState`] of the abilities of this entity of type `A`
+    pub action_state: &'static ActionState<A>,
+    /// The [`ChargeState`] associated with each action of type `A` for this entity
+    pub charges: &'static mut ChargeState<A>,
+    /// The [`CooldownState`] associated with each action of type `A` for this entity
+    pub cooldowns: &'static mut CooldownState<A>,
+    /// The first [`Pool`] of resources, of type `P1`
+    pub pool_1: Option<&'static mut P1>,
+    /// The second [`Pool`] of resources, of type `P2`
+    pub pool_2: Option<&'static mut P2>,
+    /// The [`AbilityCosts`] of each ability, in terms of [`P1::Quantity`](Pool::Quantity)
+    pub ability_costs_1: Option<&'static AbilityCosts<A, P1>>,
+    /// The [`AbilityCosts`] of each ability, in terms of [`P2::Quantity`](Pool::Quantity)
+    pub ability_costs_2: Option<&'static AbilityCosts<A, P2>>,
+    /// The [`DisabledAbilities`] that cannot currently be used
+    pub disabled: Option<&'static DisabledAbilities<A>>,
+    /// The [`AbilityAttempts`] that record each ability triggered, if logging is enabled
+    pub attempts: Option<&'static mut AbilityAttempts<A>>,
+}
+
+impl<A: Abilitylike, P1: Pool + Component, P2: Pool + Component>
+    MultiPoolAbilityStateItem<'_, A, P1, P2>
+{
+    /// Is this ability ready?
+    ///
+    /// Returns [`CannotUseAbility::Disabled`] if the action is disabled by [`DisabledAbilities`].
+    /// Otherwise, calls [`Abilitylike::ready_multi`] on the specified action.
+    #[inline]
+    pub fn ready(&self, action: &A) -> Result<(), CannotUseAbility> {
+        if let Some(disabled) = self.disabled {
+            disabled.enabled(action)?;
+        }
+
+        action.ready_multi(
+            &*self.charges,
+            &*self.cooldowns,
+            (self.pool_1.as_deref(), self.pool_2.as_deref()),
+            (self.ability_costs_1, self.ability_costs_2),
+        )
+    }
+
+    /// Is this ability both ready and just pressed?
+    ///
+    /// The error value for "this ability is not pressed" will be prioritized over "this ability is not ready".
+    #[inline]
+    pub fn ready_and_just_pressed(&self, action: &A) -> Result<(), CannotUseAbility> {
+        if self.action_state.just_pressed(action) {
+            self.ready(action)
+        } else {
+            Err(CannotUseAbility::NotPressed)
+        }
+    }
+
+    /// Triggers this ability, paying its costs from both pools.
+    ///
+    /// Returns [`CannotUseAbility::Disabled`] without spending anything if the action is disabled by [`DisabledAbilities`].
+    /// Otherwise, calls [`Abilitylike::trigger_multi`] on the specified action.
+    /// The attempt is recorded in the [`AbilityAttempts`], if present.
+    #[inline]
+    pub fn trigger(&mut self, action: &A) -> Result<(), CannotUseAbility> {
+        let outcome = match self.disabled {
+            Some(disabled) => disabled.enabled(action),
+            None => Ok(()),
+        }
+        .and_then(|()| {
+            action.trigger_multi(
+                &mut *self.charges,
+                &mut *self.cooldowns,
+                (self.pool_1.as_deref_mut(), self.pool_2.as_deref_mut()),
+                (self.ability_costs_1, self.ability_costs_2),
+            )
+        });
+
+        if let Some(attempts) = self.attempts.as_deref_mut() {
+            attempts.record(action.clone(), outcome);
+        }
+        outcome
+    }
+
+    /// Reverses the effects of triggering this ability, leaving the global cooldown consumed.
+    ///
+    /// Calls [`Abilitylike::refund_multi`] on the specified action.
+    #[inline]
+    pub fn refund(&mut self, action: &A) {
+        action.refund_multi(
+            &mut *self.charges,
+            &mut *self.cooldowns,
+            (self.pool_1.as_deref_mut(), self.pool_2.as_deref_mut()),
+            (self.ability_costs_1, self.ability_costs_2),
+        )
+    }
+
+    /// Triggers this ability, if the action was just pressed.
+    ///
+    /// Calls [`Abilitylike::trigger_multi`] on the specified action.
+    #[inline]
+    pub fn trigger_if_just_pressed(&mut self, action: &A) -> Result<(), CannotUseAbility> {
+        if self.action_state.just_pressed(action) {
+            self.trigger(action)
+        } else {
+            Err(CannotUseAbility::NotPressed)
+        }
+    }
+}
+
+impl<A: Abilitylike, P1: Pool + Component, P2: Pool + Component>
+    MultiPoolAbilityStateReadOnlyItem<'_, A, P1, P2>
+{
+    /// Is this ability ready?
+    ///
+    /// Returns [`CannotUseAbility::Disabled`] if the action is disabled by [`DisabledAbilities`].
+    /// Otherwise, calls [`Abilitylike::ready_multi`] on the specified action.
+    #[inline]
+    pub fn ready(&self, action: &A) -> Result<(), CannotUseAbility> {
+        if let Some(disabled) = self.disabled {
+            disabled.enabled(action)?;
+        }
+
+        action.ready_multi(
+            self.charges,
+            self.cooldowns,
+            (self.pool_1, self.pool_2),
+            (self.ability_costs_1, self.ability_costs_2),
+        )
+    }
+
+    /// Is this ability both ready and just pressed?
+    ///
+    /// The error value for "this ability is not pressed" will be prioritized over "this ability is not ready".
+    #[inline]
+    pub fn ready_and_just_pressed(&self, action: &A) -> Result<(), CannotUseAbility> {
+        if self.action_state.just_pressed(action) {
+            self.ready(action)
+        } else {
+            Err(CannotUseAbility::NotPressed)
+        }
+    }
+}
+
+/// Controls how [`AbilityStateItem::trigger_all_just_pressed`] and [`AutoTrigger`] resolve multiple abilities pressed on the same frame.
+///
+/// This is analogous to leafwing-input-manager's `ClashStrategy`:
+/// when several abilities are pressed at once, the order in which they are processed determines
+/// which one gets to spend shared resources, so that order should be explicit and deterministic.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum AbilityClashPolicy<A: Abilitylike> {
+    /// Abilities are attempted in the order their variants are declared, and only the first one that succeeds is used.
+    ///
+    /// This order is defined by [`Abilitylike::index`]:
+    /// by default, declaration order is read via reflection, and actions that are not enums are attempted last.
+    /// [`DynamicAbility`](crate::dynamic_ability::DynamicAbility) actions are attempted in registration order.
+    #[default]
+    ActionlikeIndexOrder,
+    /// Abilities are attempted in the order of this list, and only the first one that succeeds is used.
+    ///
+    /// Pressed abilities that are not in the list are attempted afterwards, in declaration order.
+    PriorityList(Vec<A>),
+    /// Every pressed ability is attempted, in declaration order, regardless of whether the others succeeded.
+    AllIndependently,
+}
+
+impl<A: Abilitylike> AbilityClashPolicy<A> {
+    /// Sorts `actions` into the order in which they should be attempted under this policy.
+    fn sort(&self, actions: &mut [A]) {
+        actions.sort_by_key(Abilitylike::index);
+
+        if let AbilityClashPolicy::PriorityList(priorities) = self {
+            actions.sort_by_key(|action| {
+                priorities
+                    .iter()
+                    .position(|prioritized| prioritized == action)
+                    .unwrap_or(usize::MAX)
+            });
+        }
+    }
+
+    /// Should we keep attempting abilities once one has succeeded?
+    fn continue_after_success(&self) -> bool {
+        matches!(self, AbilityClashPolicy::AllIndependently)
+    }
+}
+
+/// Automatically triggers the abilities of type `A` that were just pressed on this entity,
+/// resolving abilities pressed on the same frame according to its [`AbilityClashPolicy`].
+///
+/// Entities with this component are handled by [`auto_trigger_abilities`](crate::systems::auto_trigger_abilities),
+/// which is added by [`AbilityPlugin::with_auto_trigger`](crate::plugin::AbilityPlugin::with_auto_trigger).
+/// Gameplay systems can then read which abilities were used this frame with [`AutoTrigger::triggered`],
+/// rather than triggering the abilities themselves.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct AutoTrigger<A: Abilitylike> {
+    /// How abilities pressed on the same frame are resolved.
+    pub policy: AbilityClashPolicy<A>,
+    /// The outcome of each ability attempted the last time [`AutoTrigger::trigger`] was called.
+    outcomes: Vec<(A, Result<(), CannotUseAbility>)>,
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Abilitylike> Default for AutoTrigger<A> {
+    fn default() -> Self {
+        AutoTrigger::new(AbilityClashPolicy::default())
+    }
+}
+
+impl<A: Abilitylike> AutoTrigger<A> {
+    /// Creates a new [`AutoTrigger`], which resolves same-frame presses according to the `policy`.
+    #[must_use]
+    pub fn new(policy: AbilityClashPolicy<A>) -> Self {
+        AutoTrigger {
+            policy,
+            outcomes: Vec::new(),
+        }
+    }
+
+    /// Triggers the abilities that were just pressed in the `ability_state`, according to the [`AbilityClashPolicy`].
+    ///
+    /// Calls [`AbilityStateItem::trigger_all_just_pressed`], and stores the outcomes until the next call.
+    pub fn trigger<P: Pool + Component>(&mut self, ability_state: &mut AbilityStateItem<A, P>) {
+        self.outcomes = ability_state.trigger_all_just_pressed(&self.policy);
+    }
+
+    /// The outcome of each ability attempted the last time these abilities were triggered, in the order they were attempted.
+    #[inline]
+    pub fn outcomes(&self) -> &[(A, Result<(), CannotUseAbility>)] {
+        &self.outcomes
+    }
+
+    /// Returns an iterator over the abilities that were successfully triggered the last time these abilities were triggered.
+    #[inline]
+    pub fn triggered(&self) -> impl Iterator<Item = &A> {
+        self.outcomes
+            .iter()
+            .filter(|(_, outcome)| outcome.is_ok())
+            .map(|(action, _)| action)
+    }
+}
+
+/// The quantity that `action` would cost if it were triggered now, shared by [`AbilityStateItem::cost`] and [`AbilityStateReadOnlyItem::cost`].
+fn preview_cost<A: Abilitylike, P: Pool>(
+    action: &A,
+    maybe_pool: Option<&P>,
+    maybe_costs: Option<&AbilityCosts<A, P>>,
+    maybe_modifiers: Option<&CostModifiers<A, P>>,
+) -> Option<P::Quantity> {
+    let cost = modified_cost(action, maybe_pool, maybe_costs, maybe_modifiers)
+        .ok()
+        .flatten()?;
+    match (maybe_pool, cost) {
+        (Some(pool), cost) => cost.resolve(pool),
+        (None, AbilityCost::Flat(quantity)) => Some(quantity),
+        (None, _) => None,
+    }
 }
 
 impl<A: Abilitylike, P: Pool + Component> AbilityStateItem<'_, A, P> {
     /// Is this ability ready?
     ///
-    /// Calls [`Abilitylike::ready`] on the specified action.
+    /// Calls [`Abilitylike::ready_modified`] on the specified action.
     #[inline]
     pub fn ready(&self, action: &A) -> Result<(), CannotUseAbility> {
         let maybe_pool = self.pool.as_deref();
         let maybe_ability_costs = self.ability_costs.as_deref();
+        let maybe_cost_modifiers = self.cost_modifiers.as_deref();
 
-        action.ready(
+        action.ready_modified(
             &*self.charges,
             &*self.cooldowns,
             maybe_pool,
             maybe_ability_costs,
+            maybe_cost_modifiers,
+            self.disabled,
+        )
+    }
+
+    /// Returns every reason that this ability cannot be used, in priority order.
+    ///
+    /// Calls [`Abilitylike::ready_detailed`] on the specified action.
+    #[inline]
+    #[must_use]
+    pub fn ready_detailed(&self, action: &A) -> Vec<CannotUseAbility> {
+        action.ready_detailed(
+            &*self.charges,
+            &*self.cooldowns,
+            self.pool.as_deref(),
+            self.ability_costs.as_deref(),
+            self.cost_modifiers.as_deref(),
+            self.disabled,
         )
     }
 
+    /// The quantity that triggering this ability would spend from the pool right now, after any [`CostModifiers`].
+    ///
+    /// Returns [`None`] if the ability has no cost, or if its cost cannot be resolved without a pool.
+    #[inline]
+    #[must_use]
+    pub fn cost(&self, action: &A) -> Option<P::Quantity> {
+        preview_cost(
+            action,
+            self.pool.as_deref(),
+            self.ability_costs.as_deref(),
+            self.cost_modifiers.as_deref(),
+        )
+    }
+
+    /// Can the pool pay the cost of this ability right now, after any [`CostModifiers`]?
+    ///
+    /// This is exactly the pool check performed by [`Self::ready`]:
+    /// charges, cooldowns, presses and [`DisabledAbilities`] are ignored.
+    /// See [`Abilitylike::could_pay`] for more details.
+    #[inline]
+    pub fn can_afford(&self, action: &A) -> Result<(), CannotUseAbility> {
+        pool_can_pay(
+            self.pool.as_deref(),
+            modified_cost(
+                action,
+                self.pool.as_deref(),
+                self.ability_costs.as_deref(),
+                self.cost_modifiers.as_deref(),
+            )?,
+        )
+    }
+
+    /// Use [`Self::can_afford`] instead.
+    #[deprecated(note = "use `can_afford` instead")]
+    #[inline]
+    pub fn could_pay(&self, action: &A) -> Result<(), CannotUseAbility> {
+        self.can_afford(action)
+    }
+
+    /// How much the pool is short of paying the cost of this ability right now, after any [`CostModifiers`].
+    ///
+    /// Returns `Ok(None)` if the pool can pay, or if there is no pool or cost.
+    /// See [`AbilityCosts::shortfall`] for more details.
+    #[inline]
+    pub fn shortfall(
+        &self,
+        action: &A,
+    ) -> Result<Option<PoolShortfall<P::Quantity>>, CannotUseAbility> {
+        let Some(pool) = self.pool.as_deref() else {
+            return Ok(None);
+        };
+        let cost = modified_cost(
+            action,
+            Some(pool),
+            self.ability_costs.as_deref(),
+            self.cost_modifiers.as_deref(),
+        )?;
+        pool_shortfall(pool, cost)
+    }
+
     /// Is this ability both ready and pressed?
     ///
     /// The error value for "this ability is not pressed" will be prioritized over "this ability is not ready".
@@ -101,35 +446,160 @@ impl<A: Abilitylike, P: Pool + Component> AbilityStateItem<'_, A, P> {
 
     /// Triggers this ability, depleting a charge if available.
     ///
-    /// Calls [`Abilitylike::trigger`] on the specified action.
+    /// Calls [`Abilitylike::trigger_modified`] on the specified action.
+    /// The quantity paid is [recorded](CostPayments::record), so that [`Self::refund_cost`] can restore it exactly.
+    /// The attempt is recorded in the [`AbilityAttempts`], if present.
     #[inline]
     pub fn trigger(&mut self, action: &A) -> Result<(), CannotUseAbility> {
+        let outcome = self.trigger_unrecorded(action);
+        self.record_attempt(action, outcome)
+    }
+
+    /// Records an attempt to trigger `action` in the [`AbilityAttempts`], if present, then returns its `outcome`.
+    fn record_attempt(
+        &mut self,
+        action: &A,
+        outcome: Result<(), CannotUseAbility>,
+    ) -> Result<(), CannotUseAbility> {
+        if let Some(attempts) = self.attempts.as_deref_mut() {
+            attempts.record(action.clone(), outcome);
+        }
+        outcome
+    }
+
+    /// Triggers this ability, without recording the attempt in the [`AbilityAttempts`].
+    fn trigger_unrecorded(&mut self, action: &A) -> Result<(), CannotUseAbility> {
+        let maybe_paid = self.pool.as_ref().and_then(|_| self.cost(action));
         let maybe_pool = self.pool.as_deref_mut();
         let maybe_ability_costs = self.ability_costs.as_deref();
+        let maybe_cost_modifiers = self.cost_modifiers.as_deref_mut();
 
-        action.trigger(
+        action.trigger_modified(
             &mut *self.charges,
             &mut *self.cooldowns,
             maybe_pool,
             maybe_ability_costs,
+            maybe_cost_modifiers,
+            self.disabled,
+        )?;
+
+        if let (Some(cost_payments), Some(paid)) = (self.cost_payments.as_deref_mut(), maybe_paid) {
+            cost_payments.record(action.clone(), paid);
+        }
+        Ok(())
+    }
+
+    /// Is this ability ready to be used at the provided [`AbilityScale`], after any [`CostModifiers`]?
+    ///
+    /// See [`Abilitylike::ready_scaled`] for more details.
+    #[inline]
+    pub fn ready_scaled(&self, action: &A, scale: AbilityScale) -> Result<(), CannotUseAbility> {
+        if let Some(disabled) = self.disabled {
+            disabled.enabled(action)?;
+        }
+
+        ready_at_scale(
+            action,
+            &*self.charges,
+            &*self.cooldowns,
+            self.pool.as_deref(),
+            self.ability_costs.as_deref(),
+            self.cost_modifiers.as_deref(),
+            scale,
         )
     }
 
+    /// Triggers this ability at the provided [`AbilityScale`], paying its scaled cost after any [`CostModifiers`].
+    ///
+    /// The quantity paid is [recorded](CostPayments::record), so that [`Self::refund_cost`] can restore it exactly.
+    /// The attempt is recorded in the [`AbilityAttempts`], if present.
+    /// See [`Abilitylike::trigger_scaled`] for more details.
+    #[inline]
+    pub fn trigger_scaled(
+        &mut self,
+        action: &A,
+        scale: AbilityScale,
+    ) -> Result<(), CannotUseAbility> {
+        let outcome = self.trigger_scaled_unrecorded(action, scale);
+        self.record_attempt(action, outcome)
+    }
+
+    /// Triggers this ability at the provided [`AbilityScale`], without recording the attempt in the [`AbilityAttempts`].
+    fn trigger_scaled_unrecorded(
+        &mut self,
+        action: &A,
+        scale: AbilityScale,
+    ) -> Result<(), CannotUseAbility> {
+        if let Some(disabled) = self.disabled {
+            disabled.enabled(action)?;
+        }
+
+        // Invalid costs are rejected by `trigger_at_scale` below, so nothing is recorded for them
+        let maybe_paid = self.pool.as_deref().and_then(|pool| {
+            let cost = modified_cost(
+                action,
+                Some(pool),
+                self.ability_costs.as_deref(),
+                self.cost_modifiers.as_deref(),
+            )
+            .ok()
+            .flatten()?;
+            scale_cost::<P>(cost, scale.cost_multiplier)
+                .ok()?
+                .resolve(pool)
+        });
+
+        trigger_at_scale(
+            action,
+            &mut *self.charges,
+            &mut *self.cooldowns,
+            self.pool.as_deref_mut(),
+            self.ability_costs.as_deref(),
+            self.cost_modifiers.as_deref_mut(),
+            scale,
+        )?;
+
+        if let (Some(cost_payments), Some(paid)) = (self.cost_payments.as_deref_mut(), maybe_paid) {
+            cost_payments.record(action.clone(), paid);
+        }
+        Ok(())
+    }
+
+    /// Reverses the effects of triggering this ability, leaving the global cooldown consumed.
+    ///
+    /// Calls [`Abilitylike::refund`] on the specified action, then [`Self::refund_cost`].
+    #[inline]
+    pub fn refund(&mut self, action: &A) {
+        action.refund::<P>(&mut *self.charges, &mut *self.cooldowns, None, None);
+        self.refund_cost(action);
+    }
+
+    /// Returns the cost of this ability to the pool, such as when a cast is cancelled during its wind-up.
+    ///
+    /// Charges and cooldowns are not touched.
+    /// Calls [`CostPayments::refund`], which restores exactly the quantity paid by the last [`Self::trigger`],
+    /// including any [`CostModifiers`] that applied at the time.
+    /// Without [`CostPayments`], this falls back to [`AbilityCosts::refund`].
+    ///
+    /// Returns the quantity that was returned to the pool, or [`None`] if there is no pool or no costs.
+    #[inline]
+    pub fn refund_cost(&mut self, action: &A) -> Option<P::Quantity> {
+        let pool = self.pool.as_deref_mut()?;
+        let ability_costs = self.ability_costs.as_deref()?;
+
+        Some(match self.cost_payments.as_deref_mut() {
+            Some(cost_payments) => cost_payments.refund(action, pool, ability_costs),
+            None => ability_costs.refund(action, pool),
+        })
+    }
+
     /// Triggers this ability (and depletes available charges), if action is pressed.
     ///
-    /// Calls [`Abilitylike::trigger`] on the specified action.
+    /// Calls [`Abilitylike::trigger_modified`] on the specified action.
     #[inline]
     pub fn trigger_if_pressed(&mut self, action: &A) -> Result<(), CannotUseAbility> {
         if self.action_state.just_pressed(action) {
-            let maybe_pool = self.pool.as_deref_mut();
-            let maybe_ability_costs = self.ability_costs.as_deref();
-
-            action.trigger(
-                &mut *self.charges,
-                &mut *self.cooldowns,
-                maybe_pool,
-                maybe_ability_costs,
-            )
+            self.trigger(action)
         } else {
             Err(CannotUseAbility::NotPressed)
         }
@@ -137,32 +607,117 @@ impl<A: Abilitylike, P: Pool + Component> AbilityStateItem<'_, A, P> {
 
     /// Triggers this ability (and depletes available charges), if action was just pressed.
     ///
-    /// Calls [`Abilitylike::trigger`] on the specified action.
+    /// Calls [`Abilitylike::trigger_modified`] on the specified action.
     #[inline]
     pub fn trigger_if_just_pressed(&mut self, action: &A) -> Result<(), CannotUseAbility> {
         if self.action_state.just_pressed(action) {
-            let maybe_pool = self.pool.as_deref_mut();
-            let maybe_ability_costs = self.ability_costs.as_deref();
-
-            action.trigger(
-                &mut *self.charges,
-                &mut *self.cooldowns,
-                maybe_pool,
-                maybe_ability_costs,
-            )
+            self.trigger(action)
         } else {
             Err(CannotUseAbility::NotPressed)
         }
     }
+
+    /// Triggers the abilities that were just pressed, resolving same-frame presses according to the `policy`.
+    ///
+    /// Returns the outcome of each ability that was attempted, in the order they were attempted.
+    /// Abilities that were pressed but not attempted (because an earlier ability succeeded) are omitted.
+    pub fn trigger_all_just_pressed(
+        &mut self,
+        policy: &AbilityClashPolicy<A>,
+    ) -> Vec<(A, Result<(), CannotUseAbility>)> {
+        let mut just_pressed = self.action_state.get_just_pressed();
+        policy.sort(&mut just_pressed);
+
+        let mut outcomes = Vec::with_capacity(just_pressed.len());
+        for action in just_pressed {
+            let outcome = self.trigger(&action);
+            let succeeded = outcome.is_ok();
+            outcomes.push((action, outcome));
+
+            if succeeded && !policy.continue_after_success() {
+                break;
+            }
+        }
+
+        outcomes
+    }
 }
 
 impl<A: Abilitylike, P: Pool + Component> AbilityStateReadOnlyItem<'_, A, P> {
     /// Is this ability ready?
     ///
-    /// Calls [`Abilitylike::ready`] on the specified action.
+    /// Calls [`Abilitylike::ready_modified`] on the specified action.
     #[inline]
     pub fn ready(&self, action: &A) -> Result<(), CannotUseAbility> {
-        action.ready(self.charges, self.cooldowns, self.pool, self.ability_costs)
+        action.ready_modified(
+            self.charges,
+            self.cooldowns,
+            self.pool,
+            self.ability_costs,
+            self.cost_modifiers,
+            self.disabled,
+        )
+    }
+
+    /// Returns every reason that this ability cannot be used, in priority order.
+    ///
+    /// Calls [`Abilitylike::ready_detailed`] on the specified action.
+    #[inline]
+    #[must_use]
+    pub fn ready_detailed(&self, action: &A) -> Vec<CannotUseAbility> {
+        action.ready_detailed(
+            self.charges,
+            self.cooldowns,
+            self.pool,
+            self.ability_costs,
+            self.cost_modifiers,
+            self.disabled,
+        )
+    }
+
+    /// The quantity that triggering this ability would spend from the pool right now, after any [`CostModifiers`].
+    ///
+    /// Returns [`None`] if the ability has no cost, or if its cost cannot be resolved without a pool.
+    #[inline]
+    #[must_use]
+    pub fn cost(&self, action: &A) -> Option<P::Quantity> {
+        preview_cost(action, self.pool, self.ability_costs, self.cost_modifiers)
+    }
+
+    /// Can the pool pay the cost of this ability right now, after any [`CostModifiers`]?
+    ///
+    /// This is exactly the pool check performed by [`Self::ready`]:
+    /// charges, cooldowns, presses and [`DisabledAbilities`] are ignored.
+    /// See [`Abilitylike::could_pay`] for more details.
+    #[inline]
+    pub fn can_afford(&self, action: &A) -> Result<(), CannotUseAbility> {
+        pool_can_pay(
+            self.pool,
+            modified_cost(action, self.pool, self.ability_costs, self.cost_modifiers)?,
+        )
+    }
+
+    /// Use [`Self::can_afford`] instead.
+    #[deprecated(note = "use `can_afford` instead")]
+    #[inline]
+    pub fn could_pay(&self, action: &A) -> Result<(), CannotUseAbility> {
+        self.can_afford(action)
+    }
+
+    /// How much the pool is short of paying the cost of this ability right now, after any [`CostModifiers`].
+    ///
+    /// Returns `Ok(None)` if the pool can pay, or if there is no pool or cost.
+    /// See [`AbilityCosts::shortfall`] for more details.
+    #[inline]
+    pub fn shortfall(
+        &self,
+        action: &A,
+    ) -> Result<Option<PoolShortfall<P::Quantity>>, CannotUseAbility> {
+        let Some(pool) = self.pool else {
+            return Ok(None);
+        };
+        let cost = modified_cost(action, Some(pool), self.ability_costs, self.cost_modifiers)?;
+        pool_shortfall(pool, cost)
     }
 
     /// Is this ability both ready and pressed?
@@ -195,7 +750,10 @@ impl<A: Abilitylike, P: Pool + Component> AbilityStateReadOnlyItem<'_, A, P> {
 #[cfg(test)]
 mod tests {
     use crate as leafwing_abilities;
-    use crate::{AbilitiesBundle, AbilityState, Abilitylike};
+    use crate::{
+        AbilitiesBundle, AbilityClashPolicy, AbilityState, Abilitylike, CannotUseAbility,
+        ChargeState, Charges,
+    };
     use bevy::{prelude::*, reflect::Reflect};
     use leafwing_input_manager::{action_state::ActionState, Actionlike};
 
@@ -226,33 +784,282 @@ mod tests {
         let mut query_state = world.query::<AbilityState<TestAction>>();
         assert_eq!(query_state.iter(&world).len(), 1);
     }
+
+    fn clash_outcomes(
+        policy: AbilityClashPolicy<TestAction>,
+    ) -> Vec<(TestAction, Result<(), CannotUseAbility>)> {
+        let mut world = World::new();
+        let mut action_state = ActionState::<TestAction>::default();
+        action_state.press(&TestAction::Cover);
+        action_state.press(&TestAction::Duck);
+
+        world
+            .spawn(AbilitiesBundle::<TestAction> {
+                charges: ChargeState::new([(TestAction::Cover, Charges::simple(0))]),
+                ..Default::default()
+            })
+            .insert(action_state);
+
+        let mut query_state = world.query::<AbilityState<TestAction>>();
+        let mut ability_state = query_state.single_mut(&mut world);
+        ability_state.trigger_all_just_pressed(&policy)
+    }
+
+    #[test]
+    fn clash_index_order_uses_first_declared() {
+        let outcomes = clash_outcomes(AbilityClashPolicy::ActionlikeIndexOrder);
+        assert_eq!(outcomes, vec![(TestAction::Duck, Ok(()))]);
+    }
+
+    #[test]
+    fn clash_priority_list_falls_through_failures() {
+        let outcomes = clash_outcomes(AbilityClashPolicy::PriorityList(vec![TestAction::Cover]));
+        assert_eq!(
+            outcomes,
+            vec![
+                (TestAction::Cover, Err(CannotUseAbility::NoCharges)),
+                (TestAction::Duck, Ok(())),
+            ]
+        );
+    }
+
+    #[test]
+    fn clash_all_independently_attempts_everything() {
+        let outcomes = clash_outcomes(AbilityClashPolicy::AllIndependently);
+        assert_eq!(
+            outcomes,
+            vec![
+                (TestAction::Duck, Ok(())),
+                (TestAction::Cover, Err(CannotUseAbility::NoCharges)),
+            ]
+        );
+    }
+
+    #[test]
+    fn ability_state_without_a_pool_can_trigger() {
+        let mut world = World::new();
+        let mut action_state = ActionState::<TestAction>::default();
+        action_state.press(&TestAction::Duck);
+
+        let entity = world
+            .spawn(AbilitiesBundle::<TestAction> {
+                charges: ChargeState::new([(TestAction::Duck, Charges::simple(1))]),
+                ..Default::default()
+            })
+            .insert(action_state)
+            .id();
+
+        let mut query_state = world.query::<AbilityState<TestAction>>();
+        let mut ability_state = query_state.get_mut(&mut world, entity).unwrap();
+        assert!(ability_state.pool.is_none());
+        assert!(ability_state.ready(&TestAction::Duck).is_ok());
+        assert!(ability_state.trigger(&TestAction::Duck).is_ok());
+        assert_eq!(
+            ability_state.trigger(&TestAction::Duck),
+            Err(CannotUseAbility::NoCharges)
+        );
+    }
+
+    #[test]
+    fn ability_state_respects_disabled_abilities() {
+        use crate::disabled::DisabledAbilities;
+
+        let mut world = World::new();
+        world
+            .spawn(AbilitiesBundle::<TestAction> {
+                charges: ChargeState::new([(TestAction::Duck, Charges::simple(1))]),
+                ..Default::default()
+            })
+            .insert((
+                ActionState::<TestAction>::default(),
+                DisabledAbilities::new([TestAction::Duck]),
+            ));
+
+        let mut query_state = world.query::<AbilityState<TestAction>>();
+        let mut ability_state = query_state.single_mut(&mut world);
+        assert_eq!(
+            ability_state.trigger(&TestAction::Duck),
+            Err(CannotUseAbility::Disabled)
+        );
+        assert_eq!(
+            ability_state
+                .charges
+                .get(&TestAction::Duck)
+                .unwrap()
+                .charges(),
+            1
+        );
+        assert_eq!(
+            ability_state.ready_and_pressed(&TestAction::Duck),
+            Err(CannotUseAbility::NotPressed)
+        );
+        assert!(ability_state.trigger(&TestAction::Cover).is_ok());
+    }
+
+    #[test]
+    fn previewed_cost_matches_amount_spent() {
+        use crate::pool::{AbilityCost, AbilityCosts, CostModifiers, Pool};
+        use crate::premade_pools::mana::{Mana, ManaPool};
+
+        let mut world = World::new();
+        let mut modifiers = CostModifiers::<TestAction, ManaPool>::default();
+        modifiers
+            .set_action_multiplier(TestAction::Duck, 0.5)
+            .unwrap();
+
+        world
+            .spawn(AbilitiesBundle::<TestAction>::default())
+            .insert((
+                ActionState::<TestAction>::default(),
+                ManaPool::new(Mana(50.), Mana(100.), Mana(0.)),
+                AbilityCosts::<TestAction, ManaPool>::new([
+                    (TestAction::Duck, AbilityCost::Percent(20.)),
+                    (TestAction::Cover, AbilityCost::Flat(Mana(60.))),
+                ]),
+                modifiers,
+            ));
+
+        let mut query_state = world.query::<AbilityState<TestAction, ManaPool>>();
+        let mut ability_state = query_state.single_mut(&mut world);
+
+        let cost = ability_state.cost(&TestAction::Duck).unwrap();
+        assert_eq!(cost, Mana(10.));
+        assert_eq!(ability_state.can_afford(&TestAction::Duck), Ok(()));
+
+        let before = ability_state.pool.as_deref().unwrap().current();
+        ability_state.trigger(&TestAction::Duck).unwrap();
+        let after = ability_state.pool.as_deref().unwrap().current();
+        assert_eq!(before - after, cost);
+
+        assert_eq!(ability_state.cost(&TestAction::Cover), Some(Mana(60.)));
+        assert_eq!(
+            ability_state.can_afford(&TestAction::Cover),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+
+        let shortfall = ability_state
+            .shortfall(&TestAction::Cover)
+            .unwrap()
+            .unwrap();
+        assert_eq!(shortfall.required, Mana(60.));
+        assert_eq!(shortfall.available, Mana(40.));
+        assert_eq!(ability_state.shortfall(&TestAction::Duck), Ok(None));
+    }
+
+    #[test]
+    fn scaled_trigger_records_the_scaled_payment() {
+        use crate::pool::{AbilityCosts, Pool};
+        use crate::premade_pools::mana::{Mana, ManaPool};
+        use crate::AbilityScale;
+
+        let mut world = World::new();
+        world
+            .spawn(AbilitiesBundle::<TestAction> {
+                charges: ChargeState::new([(TestAction::Duck, Charges::simple(2))]),
+                ..Default::default()
+            })
+            .insert((
+                ActionState::<TestAction>::default(),
+                ManaPool::new(Mana(50.), Mana(100.), Mana(0.)),
+                AbilityCosts::<TestAction, ManaPool>::new([(TestAction::Duck, Mana(10.))]),
+            ));
+
+        let mut query_state = world.query::<AbilityState<TestAction, ManaPool>>();
+        let mut ability_state = query_state.single_mut(&mut world);
+
+        assert_eq!(
+            ability_state.ready_scaled(&TestAction::Duck, AbilityScale::uniform(3)),
+            Err(CannotUseAbility::NoCharges)
+        );
+        ability_state
+            .trigger_scaled(&TestAction::Duck, AbilityScale::uniform(2))
+            .unwrap();
+        assert_eq!(ability_state.pool.as_deref().unwrap().current(), Mana(30.));
+        assert_eq!(
+            ability_state
+                .charges
+                .get(&TestAction::Duck)
+                .unwrap()
+                .charges(),
+            0
+        );
+        assert_eq!(
+            ability_state.refund_cost(&TestAction::Duck),
+            Some(Mana(20.))
+        );
+    }
+
+    #[test]
+    fn refunding_cost_restores_modified_amount_paid() {
+        use crate::pool::{AbilityCost, AbilityCosts, CostModifiers, Pool};
+        use crate::premade_pools::mana::{Mana, ManaPool};
+
+        let mut world = World::new();
+        let mut modifiers = CostModifiers::<TestAction, ManaPool>::default();
+        modifiers
+            .set_next_use_multiplier(TestAction::Duck, 0.5)
+            .unwrap();
+
+        world
+            .spawn(AbilitiesBundle::<TestAction>::default())
+            .insert((
+                ActionState::<TestAction>::default(),
+                ManaPool::new(Mana(50.), Mana(100.), Mana(0.)),
+                AbilityCosts::<TestAction, ManaPool>::new([(
+                    TestAction::Duck,
+                    AbilityCost::Percent(25.),
+                )]),
+                modifiers,
+            ));
+
+        let mut query_state = world.query::<AbilityState<TestAction, ManaPool>>();
+        let mut ability_state = query_state.single_mut(&mut world);
+
+        ability_state.trigger(&TestAction::Duck).unwrap();
+        assert_eq!(ability_state.pool.as_deref().unwrap().current(), Mana(37.5));
+
+        assert_eq!(
+            ability_state.refund_cost(&TestAction::Duck),
+            Some(Mana(12.5))
+        );
+        let current = ability_state.pool.as_deref().unwrap().current();
+        assert!((current - Mana(50.)).0.abs() < f32::EPSILON);
+    }
 }
 
 /// A no-op type that implements [`Pool`] and [`Component`].
 ///
 /// Used in [`AbilityState`] to get the type system to play nice when no resource pool type is needed.
 ///
-/// Values of this type should never be constructed.
-#[derive(Component, Debug, Default)]
-pub struct NullPool;
+/// This type is uninhabited, so values of it can never be constructed:
+/// it is only ever used as a type parameter, and queried optionally.
+///
+/// ```compile_fail
+/// use leafwing_abilities::NullPool;
+///
+/// let null_pool = NullPool;
+/// ```
+#[derive(Component, Debug)]
+pub enum NullPool {}
 
 impl Pool for NullPool {
     type Quantity = f32;
     const MIN: f32 = 0.0;
+    const ZERO: f32 = 0.0;
 
     fn current(&self) -> Self::Quantity {
-        Self::MIN
+        match *self {}
     }
 
     fn set_current(&mut self, _new_quantity: Self::Quantity) -> Self::Quantity {
-        Self::MIN
+        match *self {}
     }
 
     fn max(&self) -> Self::Quantity {
-        Self::MIN
+        match *self {}
     }
 
     fn set_max(&mut self, _new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
-        Ok(())
+        match *self {}
     }
 }
@@ -2,18 +2,22 @@
 //! Actions may only be used if at least one charge is available.
 //! Unlike pools, charges are not shared across abilities.
 
+use bevy::utils::Duration;
 use bevy::{
-    ecs::prelude::{Component, Resource},
+    ecs::prelude::{
+        Component, Entity, Event, EventWriter, ReflectComponent, ReflectResource, Resource,
+    },
     reflect::Reflect,
 };
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 use std::{fmt::Display, marker::PhantomData};
 
-use crate::{Abilitylike, CannotUseAbility};
+use crate::{reassign_group_owner, sort_by_action, Abilitylike, CannotUseAbility};
 use std::collections::HashMap;
 
 /// A component / resource that stores the [`Charges`] for each [`Abilitylike`] action of type `A`.
 ///
-/// If [`Charges`] is set for an actions, it is only [`Abilitylike::ready`] when at least one charge is available.
+/// If [`Charges`] is set for an actions, it is only [`Abilitylike::ready`] when enough charges are available to pay its [charge cost](Charges::charge_cost).
 ///
 /// ```rust
 /// use bevy::reflect::Reflect;
@@ -39,10 +43,9 @@ use std::collections::HashMap;
 ///         // The differences are largely aesthetic.
 ///         ChargeState::default()
 ///             // Double jump!
-///             .set(Action::Jump, Charges::replenish_all(2))
+///             .with(Action::Jump, Charges::replenish_all(2))
 ///             // Store up to 3 spells at once
-///             .set(Action::Spell, Charges::replenish_one(3))
-///             .build()
+///             .with(Action::Spell, Charges::replenish_one(3))
 ///     }
 ///
 ///     fn cooldowns() -> CooldownState<Action> {
@@ -59,8 +62,18 @@ use std::collections::HashMap;
 ///             (Action::Spell, Mana(10.)),
 ///         ])
 ///     }
+///
+///     fn chained_mana_costs() -> AbilityCosts<Action, ManaPool> {
+///         // Costs can be chained with the builder pattern too
+///         AbilityCosts::default().with_cost(Action::Spell, Mana(10.))
+///     }
 /// }
 ///
+/// assert_eq!(
+///     Action::mana_costs().get(&Action::Spell),
+///     Action::chained_mana_costs().get(&Action::Spell)
+/// );
+///
 /// // In a real game you'd spawn a bundle with the appropriate components.
 /// let mut abilities_bundle = AbilitiesBundle {
 ///     cooldowns: Action::cooldowns(),
@@ -83,11 +96,17 @@ use std::collections::HashMap;
 ///     Action::Spell.trigger(&mut abilities_bundle.charges, &mut abilities_bundle.cooldowns, Some(&mut mana_bundle.pool), Some(&mut mana_bundle.ability_costs));
 /// }
 /// ```
-#[derive(Resource, Component, Clone, PartialEq, Eq, Debug, Reflect)]
+#[derive(Resource, Component, Clone, PartialEq, Eq, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Resource)]
 pub struct ChargeState<A: Abilitylike> {
     /// The underlying [`Charges`].
     charges_map: HashMap<A, Charges>,
+    /// Maps each [grouped](Self::set_group) action to the action whose entry in `charges_map` stores the group's charges.
+    ///
+    /// The action storing the charges is not included.
+    groups: HashMap<A, A>,
     #[reflect(ignore)]
+    #[serde(skip)]
     _phantom: PhantomData<A>,
 }
 
@@ -95,16 +114,57 @@ impl<A: Abilitylike> Default for ChargeState<A> {
     fn default() -> Self {
         ChargeState {
             charges_map: HashMap::new(),
+            groups: HashMap::new(),
             _phantom: PhantomData,
         }
     }
 }
 
+impl<A: Abilitylike> FromIterator<(A, Charges)> for ChargeState<A> {
+    /// Creates a new [`ChargeState`] from an iterator of `(action, charges)` pairs.
+    fn from_iter<I: IntoIterator<Item = (A, Charges)>>(iter: I) -> Self {
+        let mut state = Self::default();
+        state.extend(iter);
+        state
+    }
+}
+
+impl<A: Abilitylike> Extend<(A, Charges)> for ChargeState<A> {
+    /// Adds each `(action, charges)` pair, overwriting any existing entry for that action as [`set`](ChargeState::set) does.
+    fn extend<I: IntoIterator<Item = (A, Charges)>>(&mut self, iter: I) {
+        for (action, value) in iter {
+            self.set(action, value);
+        }
+    }
+}
+
+impl<A: Abilitylike> IntoIterator for ChargeState<A> {
+    type Item = (A, Charges);
+    type IntoIter = std::collections::hash_map::IntoIter<A, Charges>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.charges_map.into_iter()
+    }
+}
+
+impl<'a, A: Abilitylike> IntoIterator for &'a ChargeState<A> {
+    type Item = (&'a A, &'a Charges);
+    type IntoIter = std::collections::hash_map::Iter<'a, A, Charges>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.charges_map.iter()
+    }
+}
+
 /// Stores how many times an action can be used.
 ///
 /// Charges refresh when [`Charges::refresh`] is called manually,
-/// or when the corresponding cooldown expires (if the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) is added).
-#[derive(Clone, Default, PartialEq, Eq, Debug, Reflect)]
+/// when the corresponding cooldown expires (if the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) is added),
+/// or when their own [recharge timer](Charges::with_recharge_time) completes.
+///
+/// When deserializing, a current number of charges greater than the max is rejected with an error,
+/// rather than being silently clamped.
+#[derive(Clone, PartialEq, Eq, Debug, Reflect, Serialize)]
 pub struct Charges {
     current: u8,
     max: u8,
@@ -112,16 +172,129 @@ pub struct Charges {
     pub replenish_strat: ReplenishStrategy,
     /// How should the corresponding [`Cooldown`](crate::cooldown::Cooldown) interact with these charges?
     pub cooldown_strat: CooldownStrategy,
+    /// The time it takes for these charges to be replenished on their own, independent of any cooldown.
+    #[serde(default)]
+    recharge_time: Option<Duration>,
+    /// The time that has elapsed towards the next recharge.
+    #[serde(default)]
+    recharge_elapsed: Duration,
+    /// The number of charges spent each time the action is used.
+    charge_cost: u8,
+    /// Temporary charges in excess of the max, which are spent first and never replenished.
+    #[serde(default)]
+    bonus: u8,
+    /// The number of charges held by outstanding [`ChargeReservation`]s.
+    #[serde(default)]
+    reserved: u8,
+    /// The number of bonus charges spent by the last [`Charges::expend`], which [`Charges::refund`] returns as bonus charges.
+    #[serde(default)]
+    bonus_spent: u8,
+    /// The time it takes to reload these charges, if they can be reloaded.
+    #[serde(default)]
+    reload_time: Option<Duration>,
+    /// The time that has elapsed in the current reload, or [`None`] if not reloading.
+    #[serde(default)]
+    reload_elapsed: Option<Duration>,
+    /// The time it takes for a single charge to decay away, if these charges decay.
+    #[serde(default)]
+    decay_interval: Option<Duration>,
+    /// The time that has elapsed towards the next decay.
+    #[serde(default)]
+    decay_elapsed: Duration,
+    /// Is the decay timer reset whenever charges are gained or spent?
+    decay_resets_on_change: bool,
+    /// Must the corresponding [`Cooldown`](crate::cooldown::Cooldown) be ready as well, even while charges remain?
+    #[serde(default)]
+    requires_cooldown: bool,
+}
+
+// Cannot use derive(Default), as the charge cost must default to 1
+impl Default for Charges {
+    fn default() -> Self {
+        Charges::new(0, ReplenishStrategy::default(), CooldownStrategy::default())
+    }
+}
+
+impl<'de> Deserialize<'de> for Charges {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// The serialized form of [`Charges`], before it has been validated.
+        #[derive(Deserialize)]
+        struct UncheckedCharges {
+            current: u8,
+            max: u8,
+            replenish_strat: ReplenishStrategy,
+            cooldown_strat: CooldownStrategy,
+            #[serde(default)]
+            recharge_time: Option<Duration>,
+            #[serde(default)]
+            recharge_elapsed: Duration,
+            #[serde(default = "default_charge_cost")]
+            charge_cost: u8,
+            #[serde(default)]
+            bonus: u8,
+            #[serde(default)]
+            reserved: u8,
+            #[serde(default)]
+            bonus_spent: u8,
+            #[serde(default)]
+            reload_time: Option<Duration>,
+            #[serde(default)]
+            reload_elapsed: Option<Duration>,
+            #[serde(default)]
+            decay_interval: Option<Duration>,
+            #[serde(default)]
+            decay_elapsed: Duration,
+            #[serde(default = "default_true")]
+            decay_resets_on_change: bool,
+            #[serde(default)]
+            requires_cooldown: bool,
+        }
+
+        fn default_charge_cost() -> u8 {
+            1
+        }
+
+        fn default_true() -> bool {
+            true
+        }
+
+        let unchecked = UncheckedCharges::deserialize(deserializer)?;
+        if unchecked.current > unchecked.max {
+            return Err(D::Error::custom(format!(
+                "current charges ({}) cannot exceed max charges ({})",
+                unchecked.current, unchecked.max
+            )));
+        }
+
+        Ok(Charges {
+            current: unchecked.current,
+            max: unchecked.max,
+            replenish_strat: unchecked.replenish_strat,
+            cooldown_strat: unchecked.cooldown_strat,
+            recharge_time: unchecked.recharge_time,
+            recharge_elapsed: unchecked.recharge_elapsed,
+            charge_cost: unchecked.charge_cost,
+            bonus: unchecked.bonus,
+            reserved: unchecked.reserved,
+            bonus_spent: unchecked.bonus_spent,
+            reload_time: unchecked.reload_time,
+            reload_elapsed: unchecked.reload_elapsed,
+            decay_interval: unchecked.decay_interval,
+            decay_elapsed: unchecked.decay_elapsed,
+            decay_resets_on_change: unchecked.decay_resets_on_change,
+            requires_cooldown: unchecked.requires_cooldown,
+        })
+    }
 }
 
 impl Display for Charges {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}/{}", self.current, self.max)
+        write!(f, "{}/{}", self.charges(), self.max)
     }
 }
 
 /// What happens when [`Charges`] are replenished?
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 pub enum ReplenishStrategy {
     /// A single charge will be recovered.
     ///
@@ -135,7 +308,7 @@ pub enum ReplenishStrategy {
 }
 
 /// How do these charges replenish when cooldowns are refreshed?
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 pub enum CooldownStrategy {
     /// Cooldowns refresh will have no effect on the charges.
     Ignore,
@@ -174,6 +347,8 @@ impl<A: Abilitylike> ChargeState<A> {
     /// let charge_state = ChargeState::new([
     ///     (Action::Shoot, Charges::replenish_all(6)),
     ///     (Action::Dash, Charges::replenish_one(2)),
+    ///     // Starts empty, and must be built up over time
+    ///     (Action::Jump, Charges::replenish_one(2).with_current(0)),
     /// ]);
     /// ```
     #[must_use]
@@ -185,7 +360,7 @@ impl<A: Abilitylike> ChargeState<A> {
         charge_state
     }
 
-    /// Is at least one charge available for `action`?
+    /// Are enough charges available to pay the [charge cost](Charges::charge_cost) of `action`?
     ///
     /// Returns `true` if the underlying [`Charges`] is [`None`].
     #[inline]
@@ -198,10 +373,10 @@ impl<A: Abilitylike> ChargeState<A> {
         }
     }
 
-    /// Spends one charge for `action` if able.
+    /// Spends the [charge cost](Charges::charge_cost) of `action` if able.
     ///
-    /// Returns a boolean indicating whether a charge was available.
-    /// If no charges are available, `false` is returned and this call has no effect.
+    /// Returns a [`Result`] indicating whether enough charges were available.
+    /// If not, [`CannotUseAbility::NoCharges`] is returned and this call has no effect.
     ///
     /// Returns `true` if the underlying [`Charges`] is [`None`].
     #[inline]
@@ -213,6 +388,20 @@ impl<A: Abilitylike> ChargeState<A> {
         }
     }
 
+    /// Spends `n` charges for `action` if able, regardless of its [charge cost](Charges::charge_cost).
+    ///
+    /// If fewer than `n` charges are available, [`CannotUseAbility::NoCharges`] is returned and this call has no effect.
+    ///
+    /// Returns `Ok(())` if the underlying [`Charges`] is [`None`].
+    #[inline]
+    pub fn expend_n(&mut self, action: &A, n: u8) -> Result<(), CannotUseAbility> {
+        if let Some(charges) = self.get_mut(action) {
+            charges.expend_n(n)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Replenishes charges of `action`, up to its max charges.
     ///
     /// The exact effect is determined by the [`Charges`]'s [`ReplenishStrategy`].
@@ -224,52 +413,284 @@ impl<A: Abilitylike> ChargeState<A> {
         }
     }
 
+    /// Reserves the [charge cost](Charges::charge_cost) of `action` without spending it, as in [`Charges::reserve`].
+    ///
+    /// If the `action` is not associated with a [`Charges`], an empty reservation is returned.
+    #[inline]
+    pub fn reserve(&mut self, action: &A) -> Result<ChargeReservation, CannotUseAbility> {
+        if let Some(charges) = self.get_mut(action) {
+            charges.reserve()
+        } else {
+            Ok(ChargeReservation { charges: 0 })
+        }
+    }
+
+    /// Spends the charges of `action` held by the `reservation`, as in [`Charges::commit`].
+    #[inline]
+    pub fn commit(&mut self, action: &A, reservation: ChargeReservation) {
+        if let Some(charges) = self.get_mut(action) {
+            charges.commit(reservation);
+        }
+    }
+
+    /// Returns the charges of `action` held by the `reservation`, as in [`Charges::release`].
+    #[inline]
+    pub fn release(&mut self, action: &A, reservation: ChargeReservation) {
+        if let Some(charges) = self.get_mut(action) {
+            charges.release(reservation);
+        }
+    }
+
+    /// Spends the [charge cost](Charges::charge_cost) of `action` like [`ChargeState::expend`],
+    /// sending a [`ChargesExpended`] event if any charges were spent.
+    ///
+    /// `entity` should be the entity that this [`ChargeState`] belongs to, or [`None`] if it is stored as a resource.
+    pub fn expend_and_report(
+        &mut self,
+        action: &A,
+        entity: Option<Entity>,
+        events: &mut EventWriter<ChargesExpended<A>>,
+    ) -> Result<(), CannotUseAbility> {
+        let Some(charges) = self.get_mut(action) else {
+            return Ok(());
+        };
+
+        let before = charges.charges();
+        charges.expend()?;
+        if charges.charges() < before {
+            events.send(ChargesExpended {
+                entity,
+                action: action.clone(),
+                remaining: charges.charges(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Replenishes charges of `action` like [`ChargeState::replenish`],
+    /// sending a [`ChargeReplenished`] event if any charges were gained.
+    ///
+    /// `entity` should be the entity that this [`ChargeState`] belongs to, or [`None`] if it is stored as a resource.
+    pub fn replenish_and_report(
+        &mut self,
+        action: &A,
+        entity: Option<Entity>,
+        events: &mut EventWriter<ChargeReplenished<A>>,
+    ) {
+        let Some(charges) = self.get_mut(action) else {
+            return;
+        };
+
+        let before = charges.charges();
+        charges.replenish();
+        if charges.charges() > before {
+            events.send(ChargeReplenished {
+                entity,
+                action: action.clone(),
+                current: charges.charges(),
+                max: charges.max_charges(),
+            });
+        }
+    }
+
     /// Returns a reference to the underlying [`Charges`] for `action`, if set.
     #[inline]
     #[must_use]
     pub fn get(&self, action: &A) -> Option<&Charges> {
-        self.charges_map.get(action)
+        let owner = self.groups.get(action).unwrap_or(action);
+        self.charges_map.get(owner)
     }
 
     /// Returns a mutable reference to the underlying [`Charges`] for `action`, if set.
     #[inline]
     #[must_use]
     pub fn get_mut(&mut self, action: &A) -> Option<&mut Charges> {
-        self.charges_map.get_mut(action)
+        let owner = self.groups.get(action).unwrap_or(action);
+        self.charges_map.get_mut(owner)
     }
 
     /// Sets the underlying [`Charges`] for `action` to the provided value.
     ///
     /// Unless you're building a new [`ChargeState`] struct, you likely want to use [`Self::get_mut`].
+    /// If the action was part of a [group](Self::set_group), it is removed from the group first.
     #[inline]
     pub fn set(&mut self, action: A, charges: Charges) -> &mut Self {
+        self.remove(&action);
         self.charges_map.insert(action, charges);
 
         self
     }
 
+    /// Groups `actions` together, so that they draw from a single shared [`Charges`].
+    ///
+    /// Spending or replenishing the charges of any of these actions affects all of them,
+    /// and [`ChargeState::get`] returns the same charges for each of them.
+    /// Any existing charges or groups of these actions are replaced.
+    /// Grouped and individual actions can be freely mixed in the same [`ChargeState`].
+    pub fn set_group(
+        &mut self,
+        actions: impl IntoIterator<Item = A>,
+        charges: Charges,
+    ) -> &mut Self {
+        let mut actions = actions.into_iter();
+        let Some(first) = actions.next() else {
+            return self;
+        };
+
+        self.set(first.clone(), charges);
+        for action in actions {
+            if action != first {
+                self.remove(&action);
+                self.groups.insert(action, first.clone());
+            }
+        }
+
+        self
+    }
+
+    /// Sets the underlying [`Charges`] for `action`, returning the modified [`ChargeState`].
+    ///
+    /// This is the consuming counterpart to [`Self::set`], for building a new [`ChargeState`] without a trailing [`Self::build`].
+    #[inline]
+    #[must_use]
+    pub fn with(mut self, action: A, charges: Charges) -> Self {
+        self.set(action, charges);
+        self
+    }
+
+    /// Removes the [`Charges`] of `action`, returning it if it existed.
+    ///
+    /// The action will then be treated as if it had no charges.
+    /// The returned charges can be stored and [set](Self::set) again later, such as when swapping weapons.
+    ///
+    /// If `action` was part of a [group](Self::set_group), only this action is removed from it:
+    /// the other actions continue to share their charges, and a copy of them is returned.
+    pub fn remove(&mut self, action: &A) -> Option<Charges> {
+        if let Some(owner) = self.groups.remove(action) {
+            return self.charges_map.get(&owner).cloned();
+        }
+
+        let charges = self.charges_map.remove(action)?;
+
+        // Move the charges of a group to one of its remaining actions
+        if let Some(new_owner) = reassign_group_owner(&mut self.groups, action) {
+            self.charges_map.insert(new_owner, charges.clone());
+        }
+
+        Some(charges)
+    }
+
+    /// Removes the [`Charges`] of every action, including any [groups](Self::set_group).
+    #[inline]
+    pub fn clear(&mut self) {
+        self.charges_map.clear();
+        self.groups.clear();
+    }
+
+    /// Retains only the [`Charges`]s for which `keep` returns `true`.
+    ///
+    /// Each [group](Self::set_group) of actions is only passed to `keep` once, and is kept or removed as a whole.
+    #[inline]
+    pub fn retain(&mut self, mut keep: impl FnMut(&A, &mut Charges) -> bool) {
+        self.charges_map.retain(|action, value| keep(action, value));
+
+        let charges_map = &self.charges_map;
+        self.groups
+            .retain(|_, owner| charges_map.contains_key(owner));
+    }
+
     /// Collects a `&mut Self` into a `Self`.
     ///
-    /// Used to conclude the builder pattern. Actually just calls `self.clone()`.
+    /// Used to conclude the builder pattern. Actually just calls `self.clone()`:
+    /// prefer [`Self::with`], which does not need to clone.
     #[inline]
     #[must_use]
     pub fn build(&mut self) -> Self {
         self.clone()
     }
 
-    /// Returns an iterator of references to the underlying non-[`None`] [`Charges`]
+    /// Returns an iterator over each action and a reference to its underlying [`Charges`]
+    ///
+    /// Each [group](Self::set_group) of actions is only yielded once.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&A, &Charges)> {
+        self.charges_map.iter()
+    }
+
+    /// Returns an iterator over the actions that have [`Charges`] set
+    ///
+    /// This includes every action in each [group](Self::set_group).
     #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = &Charges> {
-        self.charges_map.values()
+    pub fn actions(&self) -> impl Iterator<Item = &A> {
+        self.charges_map.keys().chain(self.groups.keys())
     }
 
-    /// Returns an iterator of mutable references to the underlying non-[`None`] [`Charges`]
+    /// Returns an iterator over each action and a mutable reference to its underlying [`Charges`]
+    ///
+    /// Each [group](Self::set_group) of actions is only yielded once.
     #[inline]
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Charges> {
-        self.charges_map.values_mut()
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&A, &mut Charges)> {
+        self.charges_map.iter_mut()
+    }
+
+    /// Advances the [recharge timer](Charges::with_recharge_time) of every action's [`Charges`] by `delta_time`.
+    ///
+    /// This is independent of any [`CooldownState`](crate::cooldown::CooldownState),
+    /// and is called for you by [`tick_cooldowns`](crate::systems::tick_cooldowns).
+    pub fn tick(&mut self, delta_time: Duration) {
+        for charges in self.charges_map.values_mut() {
+            charges.tick(delta_time);
+        }
+    }
+
+    /// Captures the current [`Charges`] of every action, for rollback netcode.
+    ///
+    /// Equal states always produce equal snapshots, so snapshots can be serialized and hashed to detect desyncs.
+    /// [Groups](Self::set_group) are configuration, and are not captured.
+    #[must_use]
+    pub fn snapshot(&self) -> ChargeSnapshot<A> {
+        let mut charges: Vec<(A, Charges)> = self
+            .charges_map
+            .iter()
+            .map(|(action, charges)| (action.clone(), charges.clone()))
+            .collect();
+        sort_by_action(&mut charges);
+
+        ChargeSnapshot { charges }
+    }
+
+    /// Restores the [`Charges`] captured by [`ChargeState::snapshot`].
+    ///
+    /// Charges that were not present in the snapshot are removed.
+    /// Existing entries are overwritten in place, so restoring a snapshot of the same actions does not allocate.
+    pub fn restore(&mut self, snapshot: &ChargeSnapshot<A>) {
+        self.charges_map
+            .retain(|action, _| snapshot.charges.iter().any(|(saved, _)| saved == action));
+        for (action, charges) in &snapshot.charges {
+            match self.charges_map.get_mut(action) {
+                Some(existing) => existing.clone_from(charges),
+                None => {
+                    self.charges_map.insert(action.clone(), charges.clone());
+                }
+            }
+        }
+
+        let charges_map = &self.charges_map;
+        self.groups
+            .retain(|_, owner| charges_map.contains_key(owner));
     }
 }
 
+/// The state of the [`Charges`] in a [`ChargeState`] at a single point in time.
+///
+/// Created by [`ChargeState::snapshot`], and applied with [`ChargeState::restore`].
+/// Entries are stored in a deterministic order, so equal states always produce equal snapshots.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChargeSnapshot<A: Abilitylike> {
+    /// The charges of each action.
+    pub charges: Vec<(A, Charges)>,
+}
+
 impl Charges {
     /// Creates a new [`Charges`], which can be expended `max_charges` times before needing to be replenished.
     ///
@@ -286,167 +707,740 @@ impl Charges {
             max: max_charges,
             replenish_strat,
             cooldown_strat,
+            recharge_time: None,
+            recharge_elapsed: Duration::ZERO,
+            charge_cost: 1,
+            bonus: 0,
+            reserved: 0,
+            bonus_spent: 0,
+            reload_time: None,
+            reload_elapsed: None,
+            decay_interval: None,
+            decay_elapsed: Duration::ZERO,
+            decay_resets_on_change: true,
+            requires_cooldown: false,
         }
     }
 
+    /// Creates a new [`Charges`] which starts with `current_charges` available, rather than the max.
+    ///
+    /// `current_charges` is clamped to `max_charges`.
+    #[inline]
+    #[must_use]
+    pub fn new_with_current(
+        current_charges: u8,
+        max_charges: u8,
+        replenish_strat: ReplenishStrategy,
+        cooldown_strat: CooldownStrategy,
+    ) -> Charges {
+        Charges::new(max_charges, replenish_strat, cooldown_strat).with_current(current_charges)
+    }
+
+    /// Sets the current number of available charges, returning the modified [`Charges`].
+    ///
+    /// This is intended to be chained with the other constructors, such as `Charges::replenish_one(2).with_current(0)`.
+    /// `current_charges` is clamped to the max charges.
+    #[inline]
+    #[must_use]
+    pub fn with_current(mut self, current_charges: u8) -> Charges {
+        self.current = current_charges.min(self.max);
+        self
+    }
+
     /// Creates a new [`Charges`] with [`ReplenishStrategy::OneAtATime`] and [`CooldownStrategy::Ignore`].
     pub fn simple(max_charges: u8) -> Charges {
-        Charges {
-            current: max_charges,
-            max: max_charges,
-            replenish_strat: ReplenishStrategy::OneAtATime,
-            cooldown_strat: CooldownStrategy::Ignore,
-        }
+        Charges::new(
+            max_charges,
+            ReplenishStrategy::OneAtATime,
+            CooldownStrategy::Ignore,
+        )
     }
 
     /// Creates a new [`Charges`] with [`ReplenishStrategy::AllAtOnce`] and [`CooldownStrategy::Ignore`].
     pub fn ammo(max_charges: u8) -> Charges {
-        Charges {
-            current: max_charges,
-            max: max_charges,
-            replenish_strat: ReplenishStrategy::AllAtOnce,
-            cooldown_strat: CooldownStrategy::Ignore,
-        }
+        Charges::new(
+            max_charges,
+            ReplenishStrategy::AllAtOnce,
+            CooldownStrategy::Ignore,
+        )
     }
 
     /// Creates a new [`Charges`] with [`ReplenishStrategy::OneAtATime`] and [`CooldownStrategy::ConstantlyRefresh`].
     pub fn replenish_one(max_charges: u8) -> Charges {
-        Charges {
-            current: max_charges,
-            max: max_charges,
-            replenish_strat: ReplenishStrategy::OneAtATime,
-            cooldown_strat: CooldownStrategy::ConstantlyRefresh,
-        }
+        Charges::new(
+            max_charges,
+            ReplenishStrategy::OneAtATime,
+            CooldownStrategy::ConstantlyRefresh,
+        )
     }
 
     /// Creates a new [`Charges`] with [`ReplenishStrategy::AllAtOnce`] and [`CooldownStrategy::RefreshWhenEmpty`].
     pub fn replenish_all(max_charges: u8) -> Charges {
-        Charges {
-            current: max_charges,
-            max: max_charges,
-            replenish_strat: ReplenishStrategy::AllAtOnce,
-            cooldown_strat: CooldownStrategy::RefreshWhenEmpty,
-        }
+        Charges::new(
+            max_charges,
+            ReplenishStrategy::AllAtOnce,
+            CooldownStrategy::RefreshWhenEmpty,
+        )
     }
 
-    /// The current number of available charges
+    /// Makes these charges replenish on their own each time `recharge_time` elapses, returning the modified [`Charges`].
+    ///
+    /// Recharging is independent of any [`Cooldown`](crate::cooldown::Cooldown),
+    /// and is advanced by [`Charges::tick`] (called for you by [`tick_cooldowns`](crate::systems::tick_cooldowns)).
+    /// Each recharge replenishes charges according to the [`ReplenishStrategy`].
+    /// To avoid replenishing charges twice, use [`CooldownStrategy::Ignore`] or do not set a cooldown for this action.
+    ///
+    /// ```rust
+    /// use bevy::utils::Duration;
+    /// use leafwing_abilities::charges::Charges;
+    ///
+    /// // Regain one charge of blink every 6 seconds
+    /// let mut charges = Charges::simple(3).with_recharge_time(Duration::from_secs(6));
+    /// charges.expend().unwrap();
+    ///
+    /// charges.tick(Duration::from_secs(6));
+    /// assert_eq!(charges.charges(), 3);
+    /// ```
     #[inline]
     #[must_use]
-    pub fn charges(&self) -> u8 {
-        self.current
+    pub fn with_recharge_time(mut self, recharge_time: Duration) -> Charges {
+        self.recharge_time = Some(recharge_time);
+        self
     }
 
-    /// The maximum number of available charges
+    /// The time it takes for these charges to be replenished on their own, if a [recharge time](Charges::with_recharge_time) is set.
     #[inline]
     #[must_use]
-    pub fn max_charges(&self) -> u8 {
-        self.max
+    pub fn recharge_time(&self) -> Option<Duration> {
+        self.recharge_time
     }
 
-    /// Adds `charges` to the current number of available charges
+    /// The time that has elapsed towards the next recharge.
     ///
-    /// This will never exceed the maximum number of charges.
-    /// Returns the number of excess charges.
+    /// This is always [`Duration::ZERO`] while the charges are full.
     #[inline]
     #[must_use]
-    pub fn add_charges(&mut self, charges: u8) -> u8 {
-        let new_total = self.current.saturating_add(charges);
-
-        let excess = new_total.saturating_sub(self.max);
-        self.current = new_total.min(self.max);
-        excess
+    pub fn recharge_elapsed(&self) -> Duration {
+        self.recharge_elapsed
     }
 
-    /// Set the current number of available charges
+    /// Makes these charges reloadable, returning the modified [`Charges`].
     ///
-    /// This will never exceed the maximum number of charges.
-    /// Returns the number of excess charges.
-    #[inline]
-    pub fn set_charges(&mut self, charges: u8) -> u8 {
-        let excess = charges.saturating_sub(self.max);
-        self.current = charges.min(self.max);
-        excess
-    }
-
-    /// Set the maximmum number of available charges
+    /// Once started with [`Charges::begin_reload`], a reload replenishes charges according to the [`ReplenishStrategy`]
+    /// each time `reload_time` elapses, until the charges are full.
+    /// With [`ReplenishStrategy::AllAtOnce`], this refills the whole magazine at once,
+    /// while [`ReplenishStrategy::OneAtATime`] loads one shell at a time.
+    /// Reloads are advanced by [`Charges::tick`] (called for you by [`tick_cooldowns`](crate::systems::tick_cooldowns)).
     ///
-    /// If the number of charges available is greater than this number, it will be reduced to the new cap.
+    /// ```rust
+    /// use bevy::utils::Duration;
+    /// use leafwing_abilities::charges::Charges;
+    ///
+    /// let mut magazine = Charges::ammo(6).with_reload_time(Duration::from_secs(2));
+    /// magazine.set_charges(0);
+    ///
+    /// // Press R to reload
+    /// magazine.begin_reload();
+    /// magazine.tick(Duration::from_secs(2));
+    /// assert_eq!(magazine.charges(), 6);
+    /// ```
     #[inline]
-    pub fn set_max_charges(&mut self, max_charges: u8) {
-        self.max = max_charges;
-        self.current = self.current.min(self.max);
+    #[must_use]
+    pub fn with_reload_time(mut self, reload_time: Duration) -> Charges {
+        self.reload_time = Some(reload_time);
+        self
     }
 
-    /// Is at least one charge available?
+    /// The time it takes to reload these charges, if a [reload time](Charges::with_reload_time) is set.
     #[inline]
     #[must_use]
-    pub fn available(&self) -> bool {
-        self.current > 0
+    pub fn reload_time(&self) -> Option<Duration> {
+        self.reload_time
     }
 
-    /// Spends one charge for `action` if able.
+    /// Starts reloading these charges, returning `true` if a new reload was started.
     ///
-    /// Returns a [`Result`] indicating whether a charge was available.
-    /// If no charges are available, [`CannotUseAbility::NoCharges`] is returned and this call has no effect.
+    /// Nothing happens if no [reload time](Charges::with_reload_time) is set,
+    /// if the charges are already reloading, or if they are already full.
+    /// While reloading, no charges are [available](Charges::available).
     #[inline]
-    pub fn expend(&mut self) -> Result<(), CannotUseAbility> {
-        if self.current == 0 {
-            return Err(CannotUseAbility::NoCharges);
+    pub fn begin_reload(&mut self) -> bool {
+        if self.reload_time.is_none() || self.is_reloading() || self.current >= self.max {
+            return false;
         }
 
-        self.current = self.current.saturating_sub(1);
-        Ok(())
+        self.reload_elapsed = Some(Duration::ZERO);
+        true
     }
 
-    /// Replenishes charges of `action`, up to its max charges.
+    /// Stops the current reload, if any, discarding its progress.
     ///
-    /// The exact effect is determined by the [`ReplenishStrategy`] for this struct.
+    /// Charges that were already loaded are kept.
     #[inline]
-    pub fn replenish(&mut self) {
-        let charges_to_add = match self.replenish_strat {
-            ReplenishStrategy::OneAtATime => 1,
-            ReplenishStrategy::AllAtOnce => self.max,
-        };
-
-        // We don't care about overflowing our charges here.
-        let _ = self.add_charges(charges_to_add);
+    pub fn cancel_reload(&mut self) {
+        self.reload_elapsed = None;
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn charges_start_full() {
-        let charges = Charges::simple(3);
-        assert_eq!(charges.charges(), 3);
-        assert_eq!(charges.max_charges(), 3);
+    /// Are these charges currently being [reloaded](Charges::begin_reload)?
+    #[inline]
+    #[must_use]
+    pub fn is_reloading(&self) -> bool {
+        self.reload_elapsed.is_some()
     }
 
-    #[test]
-    fn charges_available() {
-        let mut charges = Charges::simple(3);
-        assert!(charges.available());
-        charges.set_charges(1);
-        assert!(charges.available());
-        charges.set_charges(0);
-        assert!(!charges.available());
+    /// The time that has elapsed towards the next reloaded charges, or [`None`] if not reloading.
+    #[inline]
+    #[must_use]
+    pub fn reload_elapsed(&self) -> Option<Duration> {
+        self.reload_elapsed
     }
 
-    #[test]
-    fn charges_deplete() {
-        let mut charges = Charges::simple(2);
-        charges.expend().unwrap();
-        assert_eq!(charges.charges(), 1);
-        charges.expend().unwrap();
-        assert_eq!(charges.charges(), 0);
-        assert_eq!(charges.expend(), Err(CannotUseAbility::NoCharges));
-        assert_eq!(charges.charges(), 0);
+    /// Makes these charges decay over time, losing one charge each time `interval` elapses, returning the modified [`Charges`].
+    ///
+    /// This is useful for mechanics like combo stacks, which are built up by hitting enemies and fade away when unused.
+    /// By default, the decay timer is reset whenever charges are gained or spent:
+    /// use [`Charges::with_decay_reset_on_change`] to change this.
+    /// Decay is advanced by [`Charges::tick`] (called for you by [`tick_cooldowns`](crate::systems::tick_cooldowns)),
+    /// and stops once no charges remain. The max charges are unaffected.
+    /// Charges held by a [reservation](Charges::reserve) never decay.
+    ///
+    /// ```rust
+    /// use bevy::utils::Duration;
+    /// use leafwing_abilities::charges::Charges;
+    ///
+    /// let mut combo = Charges::simple(5).with_current(0).with_decay(Duration::from_secs(3));
+    /// let _ = combo.add_charges(2);
+    ///
+    /// combo.tick(Duration::from_secs(3));
+    /// assert_eq!(combo.charges(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_decay(mut self, interval: Duration) -> Charges {
+        self.decay_interval = Some(interval);
+        self
     }
 
-    #[test]
-    fn charges_replenish_one_at_a_time() {
+    /// Sets whether the [decay timer](Charges::with_decay) is reset whenever charges are gained or spent, returning the modified [`Charges`].
+    ///
+    /// This is `true` by default. If `false`, charges decay at a steady rate regardless of how they are used.
+    #[inline]
+    #[must_use]
+    pub fn with_decay_reset_on_change(mut self, reset: bool) -> Charges {
+        self.decay_resets_on_change = reset;
+        self
+    }
+
+    /// Sets whether the corresponding [`Cooldown`](crate::cooldown::Cooldown) must also be ready to use these charges, returning the modified [`Charges`].
+    ///
+    /// This is `false` by default: while any charges remain, the cooldown is not consulted.
+    /// If `true`, using the ability spends a charge *and* starts the cooldown,
+    /// so the ability can be used at most once per cooldown until its charges run out.
+    ///
+    /// ```rust
+    /// use bevy::utils::Duration;
+    /// use leafwing_abilities::{ability_ready, trigger_ability, CannotUseAbility, NullPool};
+    /// use leafwing_abilities::charges::Charges;
+    /// use leafwing_abilities::cooldown::Cooldown;
+    ///
+    /// let mut charges = Charges::simple(3).with_required_cooldown(true);
+    /// let mut cooldown = Cooldown::from_secs(1.);
+    ///
+    /// let _ = trigger_ability::<NullPool>(Some(&mut charges), Some(&mut cooldown), None, None);
+    /// assert_eq!(charges.charges(), 2);
+    /// assert_eq!(
+    ///     ability_ready::<NullPool>(Some(&charges), Some(&cooldown), None, None),
+    ///     Err(CannotUseAbility::OnCooldown)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_required_cooldown(mut self, required: bool) -> Charges {
+        self.requires_cooldown = required;
+        self
+    }
+
+    /// Sets whether the corresponding [`Cooldown`](crate::cooldown::Cooldown) must also be ready to use these charges.
+    ///
+    /// See [`Charges::with_required_cooldown`] for more details.
+    #[inline]
+    pub fn set_requires_cooldown(&mut self, required: bool) -> &mut Charges {
+        self.requires_cooldown = required;
+        self
+    }
+
+    /// Must the corresponding [`Cooldown`](crate::cooldown::Cooldown) also be ready to use these charges?
+    ///
+    /// See [`Charges::with_required_cooldown`] for more details.
+    #[inline]
+    #[must_use]
+    pub fn requires_cooldown(&self) -> bool {
+        self.requires_cooldown
+    }
+
+    /// The time it takes for a single charge to decay away, if a [decay interval](Charges::with_decay) is set.
+    #[inline]
+    #[must_use]
+    pub fn decay_interval(&self) -> Option<Duration> {
+        self.decay_interval
+    }
+
+    /// The time that has elapsed towards the next [decay](Charges::with_decay).
+    #[inline]
+    #[must_use]
+    pub fn decay_elapsed(&self) -> Duration {
+        self.decay_elapsed
+    }
+
+    /// Resets the decay timer if it should be reset when charges are gained or spent.
+    fn reset_decay_on_change(&mut self) {
+        if self.decay_resets_on_change {
+            self.decay_elapsed = Duration::ZERO;
+        }
+    }
+
+    /// Advances any [reload](Charges::begin_reload), the [recharge timer](Charges::with_recharge_time)
+    /// and the [decay timer](Charges::with_decay) by `delta_time`, replenishing or removing charges each time they complete.
+    ///
+    /// Large values of `delta_time` can replenish or remove charges several times.
+    /// The recharge timer does not advance while the charges are full, so no time is banked towards future recharges.
+    /// Similarly, the decay timer does not advance while no charges remain.
+    /// Reloads end once the charges are full.
+    pub fn tick(&mut self, delta_time: Duration) {
+        self.tick_reload(delta_time);
+        self.tick_recharge(delta_time);
+        self.tick_decay(delta_time);
+    }
+
+    fn tick_decay(&mut self, delta_time: Duration) {
+        let Some(interval) = self.decay_interval else {
+            return;
+        };
+
+        // Zero intervals remove every unreserved charge, as each loop removes one
+        self.decay_elapsed = self.decay_elapsed.saturating_add(delta_time);
+        while self.decay_elapsed >= interval && self.unreserved() > 0 {
+            self.decay_elapsed -= interval;
+            // Cannot fail, as at least one unreserved charge remains
+            let _ = self.expend_n_unreserved(1);
+        }
+
+        // Reserved charges cannot decay, so the timer stops along with them
+        if self.unreserved() == 0 {
+            self.decay_elapsed = Duration::ZERO;
+        }
+    }
+
+    fn tick_reload(&mut self, delta_time: Duration) {
+        let (Some(reload_time), Some(elapsed)) = (self.reload_time, self.reload_elapsed) else {
+            return;
+        };
+
+        // Zero reload times complete immediately, as each loop replenishes at least one charge
+        let mut elapsed = elapsed.saturating_add(delta_time);
+        while elapsed >= reload_time && self.current < self.max {
+            elapsed -= reload_time;
+            self.replenish();
+        }
+
+        self.reload_elapsed = (self.current < self.max).then_some(elapsed);
+    }
+
+    fn tick_recharge(&mut self, delta_time: Duration) {
+        let Some(recharge_time) = self.recharge_time else {
+            return;
+        };
+
+        if self.current >= self.max {
+            self.recharge_elapsed = Duration::ZERO;
+            return;
+        }
+
+        if recharge_time == Duration::ZERO {
+            self.current = self.max;
+            return;
+        }
+
+        self.recharge_elapsed = self.recharge_elapsed.saturating_add(delta_time);
+        while self.recharge_elapsed >= recharge_time && self.current < self.max {
+            self.recharge_elapsed -= recharge_time;
+            self.replenish();
+        }
+
+        if self.current >= self.max {
+            self.recharge_elapsed = Duration::ZERO;
+        }
+    }
+
+    /// The current number of available charges, including any [bonus charges](Charges::add_bonus_charges).
+    #[inline]
+    #[must_use]
+    pub fn charges(&self) -> u8 {
+        self.current.saturating_add(self.bonus)
+    }
+
+    /// Adds `charges` temporary bonus charges, which can raise the current charges above the max.
+    ///
+    /// Bonus charges are spent before any others, and are never replenished:
+    /// [`Charges::replenish`] and [`Charges::add_charges`] only refill charges up to the max.
+    /// Use [`Charges::overfill`] to render bonus charges differently in UI,
+    /// and [`Charges::clear_bonus_charges`] to remove them when their effect expires.
+    #[inline]
+    pub fn add_bonus_charges(&mut self, charges: u8) {
+        self.bonus = self.bonus.saturating_add(charges);
+    }
+
+    /// The number of [bonus charges](Charges::add_bonus_charges) that are currently available.
+    #[inline]
+    #[must_use]
+    pub fn overfill(&self) -> u8 {
+        self.bonus
+    }
+
+    /// Removes all remaining [bonus charges](Charges::add_bonus_charges), returning how many were removed.
+    #[inline]
+    pub fn clear_bonus_charges(&mut self) -> u8 {
+        core::mem::take(&mut self.bonus)
+    }
+
+    /// Gives back the [charge cost](Charges::charge_cost) spent by the last [`Charges::expend`], such as when a cast is interrupted.
+    ///
+    /// Charges are returned to where they were spent from:
+    /// [bonus charges](Charges::add_bonus_charges) that were spent become bonus charges again,
+    /// while the rest are added to the current charges, up to the max.
+    /// Returns the number of excess charges, as [`Charges::add_charges`] does.
+    #[inline]
+    pub fn refund(&mut self) -> u8 {
+        let to_bonus = self.bonus_spent.min(self.charge_cost);
+        self.bonus_spent -= to_bonus;
+        self.add_bonus_charges(to_bonus);
+        self.add_charges(self.charge_cost - to_bonus)
+    }
+
+    /// The maximum number of available charges
+    #[inline]
+    #[must_use]
+    pub fn max_charges(&self) -> u8 {
+        self.max
+    }
+
+    /// Adds `charges` to the current number of available charges
+    ///
+    /// This will never exceed the maximum number of charges, and does not affect [bonus charges](Charges::add_bonus_charges).
+    /// Returns the number of excess charges.
+    #[inline]
+    #[must_use]
+    pub fn add_charges(&mut self, charges: u8) -> u8 {
+        let new_total = self.current.saturating_add(charges);
+
+        let excess = new_total.saturating_sub(self.max);
+        self.current = new_total.min(self.max);
+        self.reset_decay_on_change();
+        excess
+    }
+
+    /// Set the current number of available charges
+    ///
+    /// This will never exceed the maximum number of charges, and removes any [bonus charges](Charges::add_bonus_charges).
+    /// Returns the number of excess charges.
+    #[inline]
+    pub fn set_charges(&mut self, charges: u8) -> u8 {
+        let excess = charges.saturating_sub(self.max);
+        self.current = charges.min(self.max);
+        self.bonus = 0;
+        excess
+    }
+
+    /// Set the maximmum number of available charges
+    ///
+    /// If the number of charges available is greater than this number, it will be reduced to the new cap.
+    /// [Bonus charges](Charges::add_bonus_charges) are kept, and remain above the new cap.
+    ///
+    /// The current number of charges is otherwise unchanged, even if the max grows.
+    /// See [`Charges::set_max_charges_refill`] and [`Charges::set_max_charges_scale`] for alternatives.
+    #[inline]
+    pub fn set_max_charges(&mut self, max_charges: u8) {
+        self.max = max_charges;
+        self.current = self.current.min(self.max);
+    }
+
+    /// Set the maximum number of available charges, keeping every current charge.
+    ///
+    /// Unlike [`Charges::set_max_charges`], if the max shrinks below the current number of charges,
+    /// the excess is kept as [bonus charges](Charges::add_bonus_charges) rather than being removed.
+    #[inline]
+    pub fn set_max_charges_keep_current(&mut self, max_charges: u8) {
+        let excess = self.current.saturating_sub(max_charges);
+        self.set_max_charges(max_charges);
+        self.add_bonus_charges(excess);
+    }
+
+    /// Set the maximum number of available charges, and refill the current charges to the new max.
+    ///
+    /// This is useful when gaining a charge should feel rewarding, such as on level up.
+    /// If the max shrinks, the current charges are still set to the new max.
+    #[inline]
+    pub fn set_max_charges_refill(&mut self, max_charges: u8) {
+        self.max = max_charges;
+        self.current = max_charges;
+    }
+
+    /// Set the maximum number of available charges, scaling the current charges to keep the same fraction of the max.
+    ///
+    /// The new number of charges is rounded down, so growing from 1/2 to a max of 3 leaves 1 charge,
+    /// while shrinking from 3/4 to a max of 2 leaves 1 charge.
+    /// If the old max was 0, the charges are refilled to the new max.
+    #[inline]
+    pub fn set_max_charges_scale(&mut self, max_charges: u8) {
+        self.current = if self.max == 0 {
+            max_charges
+        } else {
+            // Widen to avoid overflowing during the multiplication
+            (u16::from(self.current) * u16::from(max_charges) / u16::from(self.max)) as u8
+        };
+        self.max = max_charges;
+    }
+
+    /// Are enough charges available to pay the [charge cost](Charges::charge_cost)?
+    ///
+    /// With the default charge cost of 1, this is true if at least one charge is available.
+    /// [Reserved](Charges::reserve) charges are not available, and no charges are available while [reloading](Charges::begin_reload).
+    #[inline]
+    #[must_use]
+    pub fn available(&self) -> bool {
+        !self.is_reloading() && self.unreserved() >= self.charge_cost
+    }
+
+    /// The number of charges that are held by outstanding [reservations](Charges::reserve).
+    ///
+    /// These charges are still counted by [`Charges::charges`], but cannot be spent by anything else.
+    #[inline]
+    #[must_use]
+    pub fn reserved(&self) -> u8 {
+        self.reserved
+    }
+
+    /// The number of charges that are not held by a [reservation](Charges::reserve).
+    #[inline]
+    #[must_use]
+    pub fn unreserved(&self) -> u8 {
+        self.charges().saturating_sub(self.reserved)
+    }
+
+    /// Reserves the [charge cost](Charges::charge_cost) of the action, without spending it.
+    ///
+    /// This is useful for abilities that are used in several steps, such as a targeted blink
+    /// which holds a charge while aiming, then either consumes it or gives it back.
+    /// Reserved charges are not [available](Charges::available), so they cannot be spent twice.
+    ///
+    /// The returned [`ChargeReservation`] must be passed to either [`Charges::commit`] or [`Charges::release`].
+    /// If not enough unreserved charges are available, [`CannotUseAbility::NoCharges`] is returned instead.
+    #[inline]
+    pub fn reserve(&mut self) -> Result<ChargeReservation, CannotUseAbility> {
+        if !self.available() {
+            return Err(CannotUseAbility::NoCharges);
+        }
+
+        self.reserved += self.charge_cost;
+        Ok(ChargeReservation {
+            charges: self.charge_cost,
+        })
+    }
+
+    /// Spends the charges held by the `reservation`.
+    ///
+    /// If the charges were reduced while the reservation was outstanding, such as by [`Charges::set_charges`],
+    /// only the remaining charges are spent.
+    #[inline]
+    pub fn commit(&mut self, reservation: ChargeReservation) {
+        self.reserved = self.reserved.saturating_sub(reservation.charges);
+        let n = reservation.charges.min(self.charges());
+        // Cannot fail, as there are at least `n` charges
+        if let Ok(from_bonus) = self.expend_n_unreserved(n) {
+            self.bonus_spent = from_bonus;
+        }
+        self.reset_decay_on_change();
+    }
+
+    /// Returns the charges held by the `reservation`, without spending them.
+    #[inline]
+    pub fn release(&mut self, reservation: ChargeReservation) {
+        self.reserved = self.reserved.saturating_sub(reservation.charges);
+    }
+
+    /// The number of charges spent each time the action is used, which defaults to 1.
+    #[inline]
+    #[must_use]
+    pub fn charge_cost(&self) -> u8 {
+        self.charge_cost
+    }
+
+    /// Sets the number of charges spent each time the action is used, returning the modified [`Charges`].
+    ///
+    /// This is useful for abilities like burst fire, which consume several rounds of ammo at once.
+    /// The action is only ready while at least `charge_cost` charges are available.
+    #[inline]
+    #[must_use]
+    pub fn with_charge_cost(mut self, charge_cost: u8) -> Charges {
+        self.charge_cost = charge_cost;
+        self
+    }
+
+    /// Spends the [charge cost](Charges::charge_cost) of the action if able.
+    ///
+    /// Returns a [`Result`] indicating whether enough charges were available.
+    /// If not, [`CannotUseAbility::NoCharges`] is returned and this call has no effect.
+    #[inline]
+    pub fn expend(&mut self) -> Result<(), CannotUseAbility> {
+        self.expend_n(self.charge_cost)
+    }
+
+    /// Spends exactly `n` charges if able, regardless of the [charge cost](Charges::charge_cost).
+    ///
+    /// This is all-or-nothing: if fewer than `n` unreserved charges are available, or the charges are [reloading](Charges::begin_reload),
+    /// [`CannotUseAbility::NoCharges`] is returned and this call has no effect.
+    /// [Bonus charges](Charges::add_bonus_charges) are spent first.
+    #[inline]
+    pub fn expend_n(&mut self, n: u8) -> Result<(), CannotUseAbility> {
+        if self.is_reloading() || self.unreserved() < n {
+            return Err(CannotUseAbility::NoCharges);
+        }
+
+        self.reset_decay_on_change();
+        self.bonus_spent = self.expend_n_unreserved(n)?;
+        Ok(())
+    }
+
+    /// Spends `n` charges, ignoring any reservations, and returns how many of them were bonus charges.
+    fn expend_n_unreserved(&mut self, n: u8) -> Result<u8, CannotUseAbility> {
+        if self.charges() < n {
+            return Err(CannotUseAbility::NoCharges);
+        }
+
+        let from_bonus = n.min(self.bonus);
+        self.bonus -= from_bonus;
+        self.current -= n - from_bonus;
+        Ok(from_bonus)
+    }
+
+    /// Should a cooldown that completes replenish these charges, according to their [`CooldownStrategy`]?
+    pub(crate) fn needs_refresh(&self) -> bool {
+        match self.cooldown_strat {
+            CooldownStrategy::Ignore => false,
+            CooldownStrategy::ConstantlyRefresh => self.current < self.max,
+            CooldownStrategy::RefreshWhenEmpty => self.charges() == 0,
+        }
+    }
+
+    /// Replenishes charges of `action`, up to its max charges.
+    ///
+    /// The exact effect is determined by the [`ReplenishStrategy`] for this struct.
+    #[inline]
+    pub fn replenish(&mut self) {
+        let charges_to_add = match self.replenish_strat {
+            ReplenishStrategy::OneAtATime => 1,
+            ReplenishStrategy::AllAtOnce => self.max,
+        };
+
+        // We don't care about overflowing our charges here.
+        let _ = self.add_charges(charges_to_add);
+    }
+}
+
+/// Charges that have been set aside by [`Charges::reserve`], but not yet spent.
+///
+/// This is plain data, and can be stored in a component between frames.
+/// Pass it to [`Charges::commit`] to spend the charges, or to [`Charges::release`] to give them back.
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub struct ChargeReservation {
+    charges: u8,
+}
+
+impl ChargeReservation {
+    /// The number of charges held by this reservation.
+    #[inline]
+    #[must_use]
+    pub fn charges(&self) -> u8 {
+        self.charges
+    }
+}
+
+/// An event that is sent when charges of `action` are gained during [`tick_cooldowns`](crate::systems::tick_cooldowns),
+/// or by [`ChargeState::replenish_and_report`].
+///
+/// Charges that are changed in other ways, such as by calling [`Charges::replenish`] directly, are not observed.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ChargeReplenished<A: Abilitylike> {
+    /// The entity whose [`ChargeState`] contains the charges, or [`None`] if it is stored as a resource.
+    pub entity: Option<Entity>,
+    /// The action whose charges were replenished.
+    pub action: A,
+    /// The number of charges available after they were replenished.
+    pub current: u8,
+    /// The maximum number of charges for this action.
+    pub max: u8,
+}
+
+/// An event that is sent when charges of `action` are spent by [`ChargeState::expend_and_report`].
+///
+/// Charges spent in other ways, such as by [`Abilitylike::trigger`] or by calling [`Charges::expend`] directly, are not observed.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ChargesExpended<A: Abilitylike> {
+    /// The entity whose [`ChargeState`] contains the charges, or [`None`] if it is stored as a resource.
+    pub entity: Option<Entity>,
+    /// The action whose charges were spent.
+    pub action: A,
+    /// The number of charges that remain available.
+    ///
+    /// When this is 0, the last charge was just spent.
+    pub remaining: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_abilities;
+    use crate::{ability_ready, cooldown::CooldownState, NullPool};
+    use leafwing_input_manager::Actionlike;
+
+    #[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    enum TestAbility {
+        A,
+        B,
+        C,
+    }
+
+    #[test]
+    fn charges_start_full() {
+        let charges = Charges::simple(3);
+        assert_eq!(charges.charges(), 3);
+        assert_eq!(charges.max_charges(), 3);
+    }
+
+    #[test]
+    fn charges_available() {
+        let mut charges = Charges::simple(3);
+        assert!(charges.available());
+        charges.set_charges(1);
+        assert!(charges.available());
+        charges.set_charges(0);
+        assert!(!charges.available());
+    }
+
+    #[test]
+    fn charges_deplete() {
+        let mut charges = Charges::simple(2);
+        charges.expend().unwrap();
+        assert_eq!(charges.charges(), 1);
+        charges.expend().unwrap();
+        assert_eq!(charges.charges(), 0);
+        assert_eq!(charges.expend(), Err(CannotUseAbility::NoCharges));
+        assert_eq!(charges.charges(), 0);
+    }
+
+    #[test]
+    fn charges_replenish_one_at_a_time() {
         let mut charges = Charges::replenish_one(3);
         charges.set_charges(0);
         assert_eq!(charges.charges(), 0);
@@ -468,4 +1462,423 @@ mod tests {
         charges.replenish();
         assert_eq!(charges.charges(), 3);
     }
+
+    #[test]
+    fn charges_recharge_over_time() {
+        let mut charges = Charges::simple(3).with_recharge_time(Duration::from_secs(6));
+        charges.set_charges(0);
+
+        charges.tick(Duration::from_secs(5));
+        assert_eq!(charges.charges(), 0);
+        charges.tick(Duration::from_secs(1));
+        assert_eq!(charges.charges(), 1);
+
+        // A large delta grants several charges, and carries over the remainder
+        charges.set_charges(0);
+        charges.tick(Duration::from_secs(15));
+        assert_eq!(charges.charges(), 2);
+        assert_eq!(charges.recharge_elapsed(), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn charges_do_not_bank_recharge_time_while_full() {
+        let mut charges = Charges::ammo(2).with_recharge_time(Duration::from_secs(2));
+        charges.tick(Duration::from_secs(100));
+        assert_eq!(charges.recharge_elapsed(), Duration::ZERO);
+
+        charges.expend().unwrap();
+        charges.expend().unwrap();
+        charges.tick(Duration::from_secs(1));
+        assert_eq!(charges.charges(), 0);
+
+        // All charges are replenished at once, and any excess time is discarded
+        charges.tick(Duration::from_secs(5));
+        assert_eq!(charges.charges(), 2);
+        assert_eq!(charges.recharge_elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn charges_can_start_below_max() {
+        let charges = Charges::replenish_one(2).with_current(0);
+        assert_eq!(charges.charges(), 0);
+        assert_eq!(charges.max_charges(), 2);
+        assert!(!charges.available());
+
+        let clamped =
+            Charges::new_with_current(5, 2, ReplenishStrategy::AllAtOnce, CooldownStrategy::Ignore);
+        assert_eq!(clamped.charges(), 2);
+    }
+
+    #[test]
+    fn multiple_charges_can_be_expended_at_once() {
+        let mut charges = Charges::simple(5);
+        charges.expend_n(3).unwrap();
+        assert_eq!(charges.charges(), 2);
+
+        assert_eq!(charges.expend_n(3), Err(CannotUseAbility::NoCharges));
+        assert_eq!(charges.charges(), 2);
+
+        charges.expend_n(2).unwrap();
+        assert_eq!(charges.charges(), 0);
+    }
+
+    #[test]
+    fn charge_cost_is_paid_by_expend() {
+        let mut charges = Charges::ammo(6).with_charge_cost(3);
+        assert_eq!(charges.charge_cost(), 3);
+
+        charges.expend().unwrap();
+        charges.expend().unwrap();
+        assert_eq!(charges.charges(), 0);
+        assert!(!charges.available());
+
+        // Reloading refills the magazine, allowing two more bursts
+        charges.replenish();
+        assert_eq!(charges.charges(), 6);
+        charges.expend().unwrap();
+        assert_eq!(charges.charges(), 3);
+
+        charges.set_charges(2);
+        assert!(!charges.available());
+        assert_eq!(charges.expend(), Err(CannotUseAbility::NoCharges));
+        assert_eq!(charges.charges(), 2);
+    }
+
+    #[test]
+    fn bonus_charges_exceed_max_and_are_not_replenished() {
+        let mut charges = Charges::replenish_one(3);
+        charges.add_bonus_charges(1);
+        assert_eq!(charges.charges(), 4);
+        assert_eq!(charges.overfill(), 1);
+        assert_eq!(charges.to_string(), "4/3");
+
+        // Bonus charges are spent first
+        charges.expend().unwrap();
+        assert_eq!(charges.charges(), 3);
+        assert_eq!(charges.overfill(), 0);
+        charges.expend().unwrap();
+        assert_eq!(charges.charges(), 2);
+
+        charges.replenish();
+        charges.replenish();
+        assert_eq!(charges.charges(), 3);
+        assert_eq!(charges.overfill(), 0);
+    }
+
+    #[test]
+    fn refunds_return_charges_to_where_they_were_spent() {
+        let mut charges = Charges::simple(2).with_current(1);
+        charges.add_bonus_charges(1);
+
+        // The bonus charge is spent first, so it is refunded as a bonus charge
+        charges.expend().unwrap();
+        assert_eq!(charges.refund(), 0);
+        assert_eq!(charges.overfill(), 1);
+        assert_eq!(charges.charges(), 2);
+
+        charges.expend().unwrap();
+        charges.expend().unwrap();
+        assert_eq!(charges.refund(), 0);
+        assert_eq!(charges.overfill(), 0);
+        assert_eq!(charges.charges(), 1);
+    }
+
+    #[test]
+    fn bonus_charges_survive_max_changes() {
+        let mut charges = Charges::simple(3);
+        charges.add_bonus_charges(2);
+
+        charges.set_max_charges(2);
+        assert_eq!(charges.charges(), 4);
+        assert_eq!(charges.overfill(), 2);
+
+        assert_eq!(charges.clear_bonus_charges(), 2);
+        assert_eq!(charges.charges(), 2);
+    }
+
+    #[test]
+    fn released_reservations_can_be_reused() {
+        let mut charges = Charges::simple(1);
+        let reservation = charges.reserve().unwrap();
+        assert_eq!(reservation.charges(), 1);
+        assert_eq!(charges.charges(), 1);
+        assert!(!charges.available());
+        assert_eq!(charges.reserve(), Err(CannotUseAbility::NoCharges));
+        assert_eq!(charges.expend(), Err(CannotUseAbility::NoCharges));
+
+        charges.release(reservation);
+        assert!(charges.available());
+        let reservation = charges.reserve().unwrap();
+        charges.commit(reservation);
+        assert_eq!(charges.charges(), 0);
+        assert_eq!(charges.reserved(), 0);
+    }
+
+    #[test]
+    fn committed_reservations_spend_charges() {
+        let mut charges = Charges::ammo(6).with_charge_cost(2);
+        let reservation = charges.reserve().unwrap();
+        assert_eq!(charges.unreserved(), 4);
+
+        charges.expend().unwrap();
+        assert_eq!(charges.charges(), 4);
+
+        charges.commit(reservation);
+        assert_eq!(charges.charges(), 2);
+        assert_eq!(charges.reserved(), 0);
+    }
+
+    #[test]
+    fn charges_replenish_while_reserved() {
+        let mut charges = Charges::replenish_one(2);
+        charges.expend().unwrap();
+        let reservation = charges.reserve().unwrap();
+        assert!(!charges.available());
+
+        charges.replenish();
+        assert_eq!(charges.charges(), 2);
+        assert!(charges.available());
+
+        charges.commit(reservation);
+        assert_eq!(charges.charges(), 1);
+        assert!(charges.available());
+    }
+
+    #[test]
+    fn set_max_charges_keeps_current_by_default() {
+        let mut charges = Charges::simple(2).with_current(1);
+        charges.set_max_charges(3);
+        assert_eq!(charges.charges(), 1);
+
+        charges.set_charges(3);
+        charges.set_max_charges(2);
+        assert_eq!(charges.charges(), 2);
+        assert_eq!(charges.overfill(), 0);
+    }
+
+    #[test]
+    fn set_max_charges_keep_current_stores_excess_as_overfill() {
+        let mut charges = Charges::simple(2).with_current(1);
+        charges.set_max_charges_keep_current(3);
+        assert_eq!(charges.charges(), 1);
+
+        charges.set_charges(3);
+        charges.set_max_charges_keep_current(1);
+        assert_eq!(charges.charges(), 3);
+        assert_eq!(charges.max_charges(), 1);
+        assert_eq!(charges.overfill(), 2);
+    }
+
+    #[test]
+    fn set_max_charges_refill_fills_to_new_max() {
+        let mut charges = Charges::simple(2).with_current(1);
+        charges.set_max_charges_refill(3);
+        assert_eq!(charges.charges(), 3);
+
+        charges.expend().unwrap();
+        charges.set_max_charges_refill(1);
+        assert_eq!(charges.charges(), 1);
+    }
+
+    #[test]
+    fn set_max_charges_scale_preserves_fraction() {
+        let mut charges = Charges::simple(2).with_current(1);
+        charges.set_max_charges_scale(4);
+        assert_eq!(charges.charges(), 2);
+
+        charges.set_max_charges_scale(3);
+        assert_eq!(charges.charges(), 1);
+
+        charges.set_charges(3);
+        charges.set_max_charges_scale(200);
+        assert_eq!(charges.charges(), 200);
+
+        charges.set_charges(150);
+        charges.set_max_charges_scale(2);
+        assert_eq!(charges.charges(), 1);
+    }
+
+    #[test]
+    fn reloading_refills_charges() {
+        let mut charges = Charges::ammo(6).with_reload_time(Duration::from_secs(2));
+        assert!(!charges.begin_reload());
+
+        charges.set_charges(0);
+        assert_eq!(
+            ability_ready::<NullPool>(Some(&charges), None, None, None),
+            Err(CannotUseAbility::NoCharges)
+        );
+        assert!(charges.begin_reload());
+        assert!(!charges.begin_reload());
+
+        charges.tick(Duration::from_secs(1));
+        assert!(charges.is_reloading());
+        assert_eq!(charges.charges(), 0);
+        assert_eq!(
+            ability_ready::<NullPool>(Some(&charges), None, None, None),
+            Err(CannotUseAbility::NoCharges)
+        );
+
+        // The magazine can be fired the moment the reload finishes
+        charges.tick(Duration::from_secs(1));
+        assert!(!charges.is_reloading());
+        assert_eq!(charges.charges(), 6);
+        assert!(ability_ready::<NullPool>(Some(&charges), None, None, None).is_ok());
+        charges.expend().unwrap();
+    }
+
+    #[test]
+    fn cancelled_reloads_lose_progress() {
+        let mut charges = Charges::ammo(6).with_reload_time(Duration::from_secs(2));
+        charges.set_charges(2);
+        charges.begin_reload();
+        assert!(!charges.available());
+
+        charges.tick(Duration::from_secs(1));
+        charges.cancel_reload();
+        assert_eq!(charges.charges(), 2);
+        assert!(charges.available());
+
+        charges.begin_reload();
+        charges.tick(Duration::from_secs(1));
+        assert_eq!(charges.charges(), 2);
+        charges.tick(Duration::from_secs(1));
+        assert_eq!(charges.charges(), 6);
+    }
+
+    #[test]
+    fn reloading_one_at_a_time_loads_each_charge() {
+        let mut charges = Charges::simple(3).with_reload_time(Duration::from_secs(1));
+        charges.set_charges(0);
+        charges.begin_reload();
+
+        charges.tick(Duration::from_millis(2500));
+        assert_eq!(charges.charges(), 2);
+        assert_eq!(charges.reload_elapsed(), Some(Duration::from_millis(500)));
+
+        charges.tick(Duration::from_millis(500));
+        assert_eq!(charges.charges(), 3);
+        assert!(!charges.is_reloading());
+    }
+
+    #[test]
+    fn charges_decay_to_zero() {
+        let mut charges = Charges::simple(3).with_decay(Duration::from_secs(3));
+
+        for _ in 0..10 {
+            charges.tick(Duration::from_secs(1));
+        }
+        assert_eq!(charges.charges(), 0);
+        assert_eq!(charges.max_charges(), 3);
+        assert_eq!(charges.decay_elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn spending_charges_resets_decay() {
+        let mut charges = Charges::simple(3).with_decay(Duration::from_secs(3));
+        charges.tick(Duration::from_secs(2));
+        charges.expend().unwrap();
+        assert_eq!(charges.decay_elapsed(), Duration::ZERO);
+
+        charges.tick(Duration::from_secs(2));
+        assert_eq!(charges.charges(), 2);
+        charges.tick(Duration::from_secs(1));
+        assert_eq!(charges.charges(), 1);
+    }
+
+    #[test]
+    fn reserved_charges_do_not_decay() {
+        let mut charges = Charges::simple(3).with_decay(Duration::from_secs(1));
+        let reservation = charges.reserve().unwrap();
+
+        charges.tick(Duration::from_secs(10));
+        assert_eq!(charges.charges(), 1);
+        assert_eq!(charges.reserved(), 1);
+        assert_eq!(charges.decay_elapsed(), Duration::ZERO);
+
+        charges.commit(reservation);
+        assert_eq!(charges.charges(), 0);
+    }
+
+    #[test]
+    fn decay_can_ignore_changes() {
+        let mut charges = Charges::simple(3)
+            .with_decay(Duration::from_secs(3))
+            .with_decay_reset_on_change(false);
+        charges.tick(Duration::from_secs(2));
+        charges.expend().unwrap();
+
+        charges.tick(Duration::from_secs(1));
+        assert_eq!(charges.charges(), 1);
+    }
+
+    #[test]
+    fn charge_state_iterators_yield_actions() {
+        let mut charges = ChargeState::new([
+            (TestAbility::A, Charges::simple(1)),
+            (TestAbility::B, Charges::simple(2)),
+            (TestAbility::C, Charges::simple(3)),
+        ]);
+
+        let mut seen: Vec<(TestAbility, u8)> = charges
+            .iter()
+            .map(|(action, charges)| (*action, charges.max_charges()))
+            .collect();
+        seen.sort_by_key(|(_, max)| *max);
+        assert_eq!(
+            seen,
+            vec![
+                (TestAbility::A, 1),
+                (TestAbility::B, 2),
+                (TestAbility::C, 3)
+            ]
+        );
+
+        for (action, charges) in charges.iter_mut() {
+            if *action == TestAbility::B {
+                charges.set_charges(0);
+            }
+        }
+        assert_eq!(charges.get(&TestAbility::B).unwrap().charges(), 0);
+        assert_eq!(charges.get(&TestAbility::C).unwrap().charges(), 3);
+    }
+
+    #[test]
+    fn grouped_actions_share_charges() {
+        let mut charges = ChargeState::default();
+        charges
+            .set_group([TestAbility::A, TestAbility::B], Charges::simple(4))
+            .set(TestAbility::C, Charges::simple(1));
+        let mut cooldowns = CooldownState::default();
+
+        TestAbility::A
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        assert_eq!(charges.get(&TestAbility::B).unwrap().charges(), 3);
+        assert_eq!(charges.get(&TestAbility::C).unwrap().charges(), 1);
+
+        charges.expend(&TestAbility::B).unwrap();
+        assert_eq!(charges.get(&TestAbility::A).unwrap().charges(), 2);
+
+        charges.replenish(&TestAbility::A);
+        assert_eq!(charges.get(&TestAbility::A).unwrap().charges(), 3);
+        assert_eq!(charges.get(&TestAbility::B).unwrap().charges(), 3);
+        assert_eq!(charges.iter().count(), 2);
+        assert_eq!(charges.actions().count(), 3);
+    }
+
+    #[test]
+    fn removing_a_grouped_action_only_removes_it_from_the_group() {
+        let mut charges = ChargeState::default();
+        charges.set_group(
+            [TestAbility::A, TestAbility::B, TestAbility::C],
+            Charges::simple(4),
+        );
+
+        charges.remove(&TestAbility::A);
+        assert!(charges.get(&TestAbility::A).is_none());
+
+        charges.expend(&TestAbility::B).unwrap();
+        assert_eq!(charges.get(&TestAbility::C).unwrap().charges(), 3);
+    }
 }
@@ -1,17 +1,26 @@
 //! Cooldowns tick down until actions are ready to be used.
 
 use crate::{
-    charges::{ChargeState, Charges},
+    charges::{ChargeState, Charges, CooldownStrategy},
+    reassign_group_owner, scale_duration, sort_by_action,
+    tags::{AbilityTag, AbilityTags},
     Abilitylike, CannotUseAbility,
 };
 
+use bevy::time::{Stopwatch, Timer, TimerMode};
 use bevy::utils::Duration;
 use bevy::{
-    ecs::prelude::{Component, Resource},
+    ecs::prelude::{Component, Entity, Event, ReflectComponent, ReflectResource, Resource},
     reflect::Reflect,
 };
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Display, marker::PhantomData};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    marker::PhantomData,
+};
+use thiserror::Error;
 
 /// The time until each action of type `A` can be used again.
 ///
@@ -52,19 +61,41 @@ use std::{collections::HashMap, fmt::Display, marker::PhantomData};
 /// // We just jumped, so the cooldown isn't ready yet
 /// assert_eq!(cooldowns.ready(&Action::Jump), Err(CannotUseAbility::OnCooldown));
 /// ```
-#[derive(Resource, Component, Debug, Clone, PartialEq, Eq, Reflect)]
+#[derive(Resource, Component, Debug, Clone, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Resource)]
 pub struct CooldownState<A: Abilitylike> {
     /// The [`Cooldown`] of each action
     ///
-    /// If [`None`], the action can always be used
+    /// If [`None`], the action can always be used.
+    /// For [dense](Self::dense) cooldown states, this only stores the actions that do not fit in `dense_cooldowns`.
     cooldown_map: HashMap<A, Cooldown>,
+    /// The [`Cooldown`] of each action, stored by [`Abilitylike::index`].
+    ///
+    /// Only used by [dense](Self::dense) cooldown states.
+    #[serde(default = "Option::default", skip_serializing_if = "Option::is_none")]
+    dense_cooldowns: Option<DenseCooldowns<A>>,
     /// A shared cooldown between all actions of type `A`.
     ///
     /// No action of type `A` will be ready unless this is ready.
     /// Whenever any cooldown for an action of type `A` is triggered,
     /// this global cooldown is triggered.
-    pub global_cooldown: Option<Cooldown>,
+    global_cooldown: Option<Cooldown>,
+    /// Actions which neither check nor trigger the [`global_cooldown`](Self::global_cooldown).
+    gcd_exempt: HashSet<A>,
+    /// Cooldowns shared between all actions in the same category, such as potions or shouts.
+    shared_cooldowns: HashMap<Cow<'static, str>, Cooldown>,
+    /// The shared cooldown category of each action, if any.
+    categories: HashMap<A, Cow<'static, str>>,
+    /// Maps each [linked](Self::link) action to the action whose entry in `cooldown_map` stores the group's cooldown.
+    ///
+    /// The action storing the cooldown is not included.
+    links: HashMap<A, A>,
+    /// The rate at which all cooldowns recover, including the global cooldown.
+    ///
+    /// See [`CooldownState::cooldown_rate`] for more details.
+    cooldown_rate: CooldownRate,
     #[reflect(ignore)]
+    #[serde(skip)]
     _phantom: PhantomData<A>,
 }
 
@@ -73,12 +104,81 @@ impl<A: Abilitylike> Default for CooldownState<A> {
     fn default() -> Self {
         CooldownState {
             cooldown_map: HashMap::new(),
+            dense_cooldowns: None,
             global_cooldown: None,
+            gcd_exempt: HashSet::new(),
+            shared_cooldowns: HashMap::new(),
+            categories: HashMap::new(),
+            links: HashMap::new(),
+            cooldown_rate: CooldownRate(1.0),
             _phantom: PhantomData,
         }
     }
 }
 
+impl<A: Abilitylike> FromIterator<(A, Cooldown)> for CooldownState<A> {
+    /// Creates a new [`CooldownState`] from an iterator of `(action, cooldown)` pairs.
+    fn from_iter<I: IntoIterator<Item = (A, Cooldown)>>(iter: I) -> Self {
+        let mut state = Self::default();
+        state.extend(iter);
+        state
+    }
+}
+
+impl<A: Abilitylike> Extend<(A, Cooldown)> for CooldownState<A> {
+    /// Adds each `(action, cooldown)` pair, overwriting any existing entry for that action as [`set`](CooldownState::set) does.
+    fn extend<I: IntoIterator<Item = (A, Cooldown)>>(&mut self, iter: I) {
+        for (action, value) in iter {
+            self.set(action, value);
+        }
+    }
+}
+
+impl<A: Abilitylike> IntoIterator for CooldownState<A> {
+    type Item = (A, Cooldown);
+    type IntoIter = std::iter::Chain<
+        std::collections::hash_map::IntoIter<A, Cooldown>,
+        std::iter::Flatten<std::vec::IntoIter<Option<(A, Cooldown)>>>,
+    >;
+
+    /// Iterates over the [`Cooldown`] of each action, in an arbitrary order.
+    ///
+    /// The global and shared cooldowns are not included,
+    /// and each [linked](CooldownState::link) cooldown is only yielded once, for one of its actions.
+    fn into_iter(self) -> Self::IntoIter {
+        let slots = self
+            .dense_cooldowns
+            .map(|dense| dense.slots)
+            .unwrap_or_default();
+        self.cooldown_map
+            .into_iter()
+            .chain(slots.into_iter().flatten())
+    }
+}
+
+impl<'a, A: Abilitylike> IntoIterator for &'a CooldownState<A> {
+    type Item = (&'a A, &'a Cooldown);
+    type IntoIter = std::iter::Chain<
+        std::collections::hash_map::Iter<'a, A, Cooldown>,
+        std::iter::Map<
+            std::iter::Flatten<std::slice::Iter<'a, Option<(A, Cooldown)>>>,
+            fn(&'a (A, Cooldown)) -> (&'a A, &'a Cooldown),
+        >,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let slots = self
+            .dense_cooldowns
+            .as_ref()
+            .map_or(&[][..], |dense| &dense.slots[..]);
+        let split: fn(&'a (A, Cooldown)) -> (&'a A, &'a Cooldown) =
+            |(action, cooldown)| (action, cooldown);
+        self.cooldown_map
+            .iter()
+            .chain(slots.iter().flatten().map(split))
+    }
+}
+
 impl<A: Abilitylike> CooldownState<A> {
     /// Creates a new [`CooldownState`] from an iterator of `(cooldown, action)` pairs
     ///
@@ -115,6 +215,70 @@ impl<A: Abilitylike> CooldownState<A> {
         cooldowns
     }
 
+    /// Creates a new, empty [`CooldownState`] that stores its cooldowns in a [`Vec`] indexed by [`Abilitylike::index`],
+    /// rather than in a [`HashMap`].
+    ///
+    /// This makes looking up and ticking cooldowns faster for enums with a handful of variants,
+    /// at the cost of an empty slot for each variant without a cooldown.
+    /// Actions that share an index with another stored action (such as enum variants with fields),
+    /// or whose index is too large, are stored in a [`HashMap`] as usual.
+    /// Otherwise, dense cooldown states behave exactly like the default, sparse ones.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::reflect::Reflect;
+    /// use leafwing_abilities::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Abilitylike, Clone, Copy, Hash, PartialEq, Eq, Debug, Reflect)]
+    /// enum Action {
+    ///     Run,
+    ///     Jump,
+    /// }
+    ///
+    /// let cooldowns = CooldownState::dense().with(Action::Jump, Cooldown::from_secs(1.));
+    /// assert!(cooldowns.is_dense());
+    /// assert!(cooldowns.get(&Action::Jump).is_some());
+    /// ```
+    #[must_use]
+    pub fn dense() -> Self {
+        CooldownState {
+            dense_cooldowns: Some(DenseCooldowns::default()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns `true` if this [`CooldownState`] was created with [`CooldownState::dense`].
+    #[inline]
+    #[must_use]
+    pub fn is_dense(&self) -> bool {
+        self.dense_cooldowns.is_some()
+    }
+
+    /// Creates a new [`CooldownState`] from an iterator of `(cooldown, action)` pairs, with a global cooldown shared between all actions.
+    ///
+    /// See [`CooldownState::global_cooldown`] for more details.
+    #[must_use]
+    pub fn new_with_gcd(
+        action_cooldown_pairs: impl IntoIterator<Item = (A, Cooldown)>,
+        global_cooldown: Cooldown,
+    ) -> Self {
+        CooldownState::new(action_cooldown_pairs).with_global_cooldown(global_cooldown)
+    }
+
+    /// Creates a new [`CooldownState`] from an iterator of `(cooldown, action)` pairs, with every cooldown already triggered.
+    ///
+    /// Each cooldown will only be ready once its full duration has elapsed.
+    /// The global cooldown is not set, and any [`ChargeState`] is unaffected.
+    #[must_use]
+    pub fn new_triggered(action_cooldown_pairs: impl IntoIterator<Item = (A, Cooldown)>) -> Self {
+        let mut cooldowns = CooldownState::new(action_cooldown_pairs);
+        for cooldown in cooldowns.iter_mut() {
+            cooldown.set_elapsed(Duration::ZERO);
+        }
+        cooldowns
+    }
+
     /// Triggers the cooldown of the `action` if it is available to be used.
     ///
     /// This can be paired with [`Cooldowns::ready`],
@@ -130,358 +294,3096 @@ impl<A: Abilitylike> CooldownState<A> {
             cooldown.trigger()?;
         }
 
+        self.trigger_shared(action)
+    }
+
+    /// Triggers the [shared cooldown](Self::set_shared_cooldown) of the category of `action` and the global cooldown, if applicable.
+    ///
+    /// This is called by [`CooldownState::trigger`] and [`Abilitylike::trigger`],
+    /// and should be used if you are triggering an action's [`Cooldown`] manually.
+    #[inline]
+    pub fn trigger_shared(&mut self, action: &A) -> Result<(), CannotUseAbility> {
+        self.shared_ready(action)?;
+
+        if let Some(category) = self.categories.get(action) {
+            if let Some(shared_cooldown) = self.shared_cooldowns.get_mut(category) {
+                shared_cooldown.trigger()?;
+            }
+        }
+
+        self.trigger_gcd_for(action)
+    }
+
+    /// Triggers the global cooldown, unless `action` is [exempt](Self::set_gcd_exempt) from it.
+    ///
+    /// This is called by [`CooldownState::trigger`] and [`Abilitylike::trigger`],
+    /// and should be used if you are triggering an action's [`Cooldown`] manually.
+    #[inline]
+    pub fn trigger_gcd_for(&mut self, action: &A) -> Result<(), CannotUseAbility> {
+        if self.is_gcd_exempt(action) {
+            return Ok(());
+        }
+
+        self.trigger_gcd()
+    }
+
+    /// Triggers the global cooldown, without using any action.
+    ///
+    /// This is useful for events like weapon swaps, which should lock out abilities without triggering their cooldowns.
+    /// Returns [`CannotUseAbility::OnGlobalCooldown`] if the global cooldown is already running,
+    /// and `Ok(())` if no global cooldown is set.
+    #[inline]
+    pub fn trigger_gcd(&mut self) -> Result<(), CannotUseAbility> {
         if let Some(global_cooldown) = self.global_cooldown.as_mut() {
-            global_cooldown.trigger()?;
+            global_cooldown
+                .trigger()
+                .map_err(|_| CannotUseAbility::OnGlobalCooldown)?;
         }
 
         Ok(())
     }
 
+    /// Refreshes the global cooldown, if any, making it ready immediately.
+    ///
+    /// The cooldowns of individual actions are unaffected.
+    #[inline]
+    pub fn refresh_gcd(&mut self) {
+        if let Some(global_cooldown) = self.global_cooldown.as_mut() {
+            global_cooldown.refresh();
+        }
+    }
+
     /// Can the corresponding `action` be used?
     ///
     /// This will be `Ok` if the underlying [`Cooldown::ready`] call is true,
     /// or if no cooldown is stored for this action.
+    /// The global cooldown must also be ready, unless this action is [exempt](Self::set_gcd_exempt) from it.
     #[inline]
     pub fn ready(&self, action: &A) -> Result<(), CannotUseAbility> {
         if let Some(cooldown) = self.get(action) {
             cooldown.ready()?;
         }
 
-        self.gcd_ready()
+        self.shared_ready(action)
     }
 
-    /// Has the global cooldown for actions of type `A` expired?
+    /// Are the [shared cooldown](Self::set_shared_cooldown) of the category of `action` and the global cooldown ready?
     ///
-    /// Returns `Ok(())` if no GCD is set.
+    /// Unlike [`CooldownState::ready`], the cooldown of `action` itself is not checked.
     #[inline]
-    pub fn gcd_ready(&self) -> Result<(), CannotUseAbility> {
-        if let Some(global_cooldown) = self.global_cooldown.as_ref() {
-            global_cooldown
-                .ready()
-                .map_err(|_| CannotUseAbility::OnGlobalCooldown)
-        } else {
-            Ok(())
+    pub fn shared_ready(&self, action: &A) -> Result<(), CannotUseAbility> {
+        if let Some(category) = self.categories.get(action) {
+            if let Some(shared_cooldown) = self.shared_cooldowns.get(category) {
+                shared_cooldown
+                    .ready()
+                    .map_err(|_| CannotUseAbility::OnSharedCooldown)?;
+            }
         }
+
+        self.gcd_ready_for(action)
     }
 
-    /// Advances each underlying [`Cooldown`] according to the elapsed `delta_time`.
+    /// Did the cooldown of `action` become ready during the last tick, with its shared and global cooldowns also ready?
     ///
-    /// When you have a [`Option<Mut<ActionCharges<A>>>`](bevy::ecs::change_detection::Mut),
-    /// use `charges.map(|res| res.into_inner())` to convert it to the correct form.
-    pub fn tick(&mut self, delta_time: Duration, maybe_charges: Option<&mut ChargeState<A>>) {
-        if let Some(charge_state) = maybe_charges {
-            for (action, cooldown) in self.cooldown_map.iter_mut() {
-                let charges = charge_state.get_mut(action);
-                cooldown.tick(delta_time, charges);
-            }
+    /// This is useful for one-shot effects, like a flash on an ability icon.
+    /// See [`Cooldown::just_finished`] for more details.
+    /// Returns `false` if no cooldown is stored for this action.
+    #[inline]
+    #[must_use]
+    pub fn just_became_ready(&self, action: &A) -> bool {
+        self.get(action).is_some_and(Cooldown::just_finished) && self.shared_ready(action).is_ok()
+    }
+
+    /// Is the global cooldown ready for `action`?
+    ///
+    /// Returns `Ok(())` if no GCD is set, or if `action` is [exempt](Self::set_gcd_exempt) from it.
+    #[inline]
+    pub fn gcd_ready_for(&self, action: &A) -> Result<(), CannotUseAbility> {
+        if self.is_gcd_exempt(action) {
+            Ok(())
         } else {
-            for (_, cooldown) in self.cooldown_map.iter_mut() {
-                cooldown.tick(delta_time, None);
-            }
+            self.gcd_ready()
         }
+    }
 
-        if let Some(global_cooldown) = self.global_cooldown.as_mut() {
-            global_cooldown.tick(delta_time, None);
+    /// Sets whether `action` is exempt from the [`global_cooldown`](Self::global_cooldown).
+    ///
+    /// Exempt actions can be used while the global cooldown is running,
+    /// and do not trigger the global cooldown when used.
+    /// This is useful for abilities like movement, which should not interfere with spellcasting.
+    pub fn set_gcd_exempt(&mut self, action: A, exempt: bool) -> &mut Self {
+        if exempt {
+            self.gcd_exempt.insert(action);
+        } else {
+            self.gcd_exempt.remove(&action);
         }
+
+        self
     }
 
-    /// The cooldown associated with the specified `action`, if any.
+    /// Is `action` exempt from the [`global_cooldown`](Self::global_cooldown)?
     #[inline]
     #[must_use]
-    pub fn get(&self, action: &A) -> Option<&Cooldown> {
-        self.cooldown_map.get(action)
+    pub fn is_gcd_exempt(&self, action: &A) -> bool {
+        self.gcd_exempt.contains(action)
     }
 
-    /// A mutable reference to the cooldown associated with the specified `action`, if any.
+    /// Sets the [`Cooldown`] shared between all actions in `category`.
+    ///
+    /// Actions in the same category can only be used when this cooldown is ready,
+    /// and using any of them triggers it.
+    /// Use [`CooldownState::set_category`] to assign actions to a category.
+    /// Actions without a category are only affected by the [`global_cooldown`](Self::global_cooldown).
+    pub fn set_shared_cooldown(
+        &mut self,
+        category: impl Into<Cow<'static, str>>,
+        cooldown: Cooldown,
+    ) -> &mut Self {
+        self.shared_cooldowns.insert(category.into(), cooldown);
+        self
+    }
+
+    /// The [`Cooldown`] shared between all actions in `category`, if any.
     #[inline]
     #[must_use]
-    pub fn get_mut(&mut self, action: &A) -> Option<&mut Cooldown> {
-        self.cooldown_map.get_mut(action)
+    pub fn shared_cooldown(&self, category: &str) -> Option<&Cooldown> {
+        self.shared_cooldowns.get(category)
     }
 
-    /// Set a cooldown for the specified `action`.
-    ///
-    /// If a cooldown already existed, it will be replaced by a new cooldown with the specified duration.
+    /// A mutable reference to the [`Cooldown`] shared between all actions in `category`, if any.
     #[inline]
-    pub fn set(&mut self, action: A, cooldown: Cooldown) -> &mut Self {
-        self.cooldown_map.insert(action, cooldown);
-        self
+    #[must_use]
+    pub fn shared_cooldown_mut(&mut self, category: &str) -> Option<&mut Cooldown> {
+        self.shared_cooldowns.get_mut(category)
     }
 
-    /// Collects a `&mut Self` into a `Self`.
+    /// Assigns `action` to the shared cooldown `category`, replacing any previous category.
     ///
-    /// Used to conclude the builder pattern. Actually just calls `self.clone()`.
+    /// If no [shared cooldown](Self::set_shared_cooldown) is set for this category, it has no effect.
+    pub fn set_category(&mut self, action: A, category: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.categories.insert(action, category.into());
+        self
+    }
+
+    /// Removes `action` from its shared cooldown category, returning the category if it had one.
+    pub fn remove_category(&mut self, action: &A) -> Option<Cow<'static, str>> {
+        self.categories.remove(action)
+    }
+
+    /// The shared cooldown category of `action`, if any.
     #[inline]
     #[must_use]
-    pub fn build(&mut self) -> Self {
-        self.clone()
+    pub fn category(&self, action: &A) -> Option<&str> {
+        self.categories
+            .get(action)
+            .map(|category| category.as_ref())
     }
 
-    /// Returns an iterator of references to the underlying non-[`None`] [`Cooldown`]s
+    /// The cooldown shared between all actions of type `A`, if any.
+    ///
+    /// No action will be ready unless the global cooldown is ready,
+    /// and triggering any action triggers the global cooldown,
+    /// unless the action is [exempt](Self::set_gcd_exempt) from it.
     #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = &Cooldown> {
-        self.cooldown_map.values()
+    #[must_use]
+    pub fn global_cooldown(&self) -> Option<&Cooldown> {
+        self.global_cooldown.as_ref()
     }
 
-    /// Returns an iterator of mutable references to the underlying non-[`None`] [`Cooldown`]s
+    /// A mutable reference to the [global cooldown](Self::global_cooldown), if any.
     #[inline]
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Cooldown> {
-        self.cooldown_map.values_mut()
+    #[must_use]
+    pub fn global_cooldown_mut(&mut self) -> Option<&mut Cooldown> {
+        self.global_cooldown.as_mut()
     }
-}
-
-/// A timer-like struct that records the amount of time until an action is available to be used again.
-///
-/// Cooldowns are typically stored in an [`ActionState`](crate::action_state::ActionState), associated with an action that is to be
-/// cooldown-regulated.
-///
-/// When initialized, cooldowns are always fully available.
-///
-/// ```rust
-/// use bevy::utils::Duration;
-/// use leafwing_abilities::cooldown::Cooldown;
-/// use leafwing_abilities::CannotUseAbility;
-///
-/// let mut cooldown = Cooldown::new(Duration::from_secs(3));
-/// assert_eq!(cooldown.remaining(), Duration::ZERO);
-///
-/// cooldown.trigger();
-/// assert_eq!(cooldown.remaining(), Duration::from_secs(3));
-///
-/// cooldown.tick(Duration::from_secs(1), None);
-/// assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
-///
-/// cooldown.tick(Duration::from_secs(5), None);
-/// let triggered = cooldown.trigger();
-/// assert!(triggered.is_ok());
-///
-/// cooldown.refresh();
-/// assert!(cooldown.ready().is_ok());
-/// ```
-#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect)]
-pub struct Cooldown {
-    max_time: Duration,
-    /// The amount of time that has elapsed since all [`Charges`](crate::charges::Charges) were fully replenished.
-    elapsed_time: Duration,
-}
 
-impl Cooldown {
-    /// Creates a new [`Cooldown`], which will take `max_time` after it is used until it is ready again.
+    /// The rate at which all cooldowns recover, including the global cooldown.
     ///
-    /// # Panics
+    /// The `delta_time` passed to [`CooldownState::tick`] is multiplied by this value,
+    /// so 2.0 makes cooldowns recover twice as fast, while 0.0 freezes them entirely.
+    /// Defaults to 1.0.
+    #[inline]
+    #[must_use]
+    pub fn cooldown_rate(&self) -> f32 {
+        self.cooldown_rate.0
+    }
+
+    /// Sets the [rate](Self::cooldown_rate) at which all cooldowns recover, such as for haste-like effects.
     ///
-    /// The provided max time cannot be [`Duration::ZERO`].
-    /// Instead, use [`None`] in the [`Cooldowns`] struct for an action without a cooldown.
-    pub fn new(max_time: Duration) -> Cooldown {
-        assert!(max_time != Duration::ZERO);
+    /// Negative and NaN rates are treated as 0.0, freezing every cooldown.
+    #[inline]
+    pub fn set_cooldown_rate(&mut self, cooldown_rate: f32) -> &mut Self {
+        self.cooldown_rate = CooldownRate::from(cooldown_rate);
+        self
+    }
 
-        Cooldown {
-            max_time,
-            elapsed_time: max_time,
-        }
+    /// Sets the [global cooldown](Self::global_cooldown), or removes it if [`None`] is provided.
+    #[inline]
+    pub fn set_global_cooldown(&mut self, global_cooldown: Option<Cooldown>) -> &mut Self {
+        self.global_cooldown = global_cooldown;
+        self
     }
 
-    /// Creates a new [`Cooldown`] with a [`f32`] number of seconds, which will take `max_time` after it is used until it is ready again.
+    /// Sets the [global cooldown](Self::global_cooldown), returning the modified [`CooldownState`].
     ///
-    /// # Panics
+    /// Unlike [`CooldownState::build`], this consumes `self` rather than cloning it.
     ///
-    /// The provided max time must be greater than 0.
-    /// Instead, use [`None`] in the [`CooldownState`] struct for an action without a cooldown.
-    pub fn from_secs(max_time: f32) -> Cooldown {
-        assert!(max_time > 0.);
-        let max_time = Duration::from_secs_f32(max_time);
+    /// ```rust
+    /// use bevy::reflect::Reflect;
+    /// use leafwing_abilities::cooldown::{Cooldown, CooldownState};
+    /// use leafwing_abilities::Abilitylike;
+    /// use leafwing_input_manager::Actionlike;
+    ///
+    /// #[derive(Actionlike, Abilitylike, Clone, Copy, Hash, PartialEq, Eq, Debug, Reflect)]
+    /// enum Action {
+    ///     Fireball,
+    ///     Frostbolt,
+    /// }
+    ///
+    /// let cooldowns = CooldownState::new([(Action::Fireball, Cooldown::from_secs(3.))])
+    ///     .with_global_cooldown(Cooldown::from_secs(1.));
+    ///
+    /// assert!(cooldowns.gcd_ready().is_ok());
+    /// assert_eq!(cooldowns.global_cooldown(), Some(&Cooldown::from_secs(1.)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_global_cooldown(mut self, global_cooldown: Cooldown) -> Self {
+        self.global_cooldown = Some(global_cooldown);
+        self
+    }
 
-        Cooldown::new(max_time)
+    /// Has the global cooldown for actions of type `A` expired?
+    ///
+    /// Returns `Ok(())` if no GCD is set.
+    #[inline]
+    pub fn gcd_ready(&self) -> Result<(), CannotUseAbility> {
+        if let Some(global_cooldown) = self.global_cooldown.as_ref() {
+            global_cooldown
+                .ready()
+                .map_err(|_| CannotUseAbility::OnGlobalCooldown)
+        } else {
+            Ok(())
+        }
     }
 
-    /// Advance the cooldown by `delta_time`.
+    /// Advances each underlying [`Cooldown`] according to the elapsed `delta_time`,
+    /// scaled by the [`cooldown_rate`](Self::cooldown_rate).
     ///
-    /// If the elapsed time is enough to reset the cooldown, the number of available charges will
-    /// increase by one.
-    pub fn tick(&mut self, delta_time: Duration, charges: Option<&mut Charges>) {
-        // Don't tick cooldowns when they are fully elapsed
-        if self.elapsed_time == self.max_time {
-            return;
+    /// When you have a [`Option<Mut<ActionCharges<A>>>`](bevy::ecs::change_detection::Mut),
+    /// use `charges.map(|res| res.into_inner())` to convert it to the correct form.
+    ///
+    /// Returns the [`CooldownTickResult`] of each action whose cooldown completed at least one cycle, in an arbitrary order.
+    /// The shared and global cooldowns are not included.
+    pub fn tick(
+        &mut self,
+        delta_time: Duration,
+        mut maybe_charges: Option<&mut ChargeState<A>>,
+    ) -> Vec<(A, CooldownTickResult)> {
+        let delta_time = self.scaled_delta_time(delta_time);
+        let mut results = Vec::new();
+
+        for (action, cooldown) in self.iter_stored_mut() {
+            results.extend(tick_action(
+                action,
+                cooldown,
+                delta_time,
+                maybe_charges.as_deref_mut(),
+            ));
         }
+        self.report_linked(&mut results);
 
-        assert!(self.max_time != Duration::ZERO);
+        for shared_cooldown in self.shared_cooldowns.values_mut() {
+            shared_cooldown.tick(delta_time, None);
+        }
 
-        if let Some(charges) = charges {
-            let total_time = self.elapsed_time.saturating_add(delta_time);
+        if let Some(global_cooldown) = self.global_cooldown.as_mut() {
+            global_cooldown.tick(delta_time, None);
+        }
 
-            let total_nanos: u64 = total_time.as_nanos().try_into().unwrap_or(u64::MAX);
-            let max_nanos: u64 = self.max_time.as_nanos().try_into().unwrap_or(u64::MAX);
+        results
+    }
 
-            let n_completed = (total_nanos / max_nanos).try_into().unwrap_or(u8::MAX);
-            let extra_time = Duration::from_nanos(total_nanos % max_nanos);
+    /// Adds a copy of the result of each [linked](Self::link) cooldown for the other actions in its group.
+    fn report_linked(&self, results: &mut Vec<(A, CooldownTickResult)>) {
+        if self.links.is_empty() {
+            return;
+        }
 
-            let excess_completions = charges.add_charges(n_completed);
-            if excess_completions == 0 {
-                self.elapsed_time =
-                    (self.elapsed_time.saturating_add(extra_time)).min(self.max_time);
-            } else {
-                self.elapsed_time = self.max_time;
+        for (action, linked_to) in self.links.iter() {
+            if let Some((_, result)) = results.iter().find(|(ticked, _)| ticked == linked_to) {
+                results.push((action.clone(), *result));
             }
-        } else {
-            self.elapsed_time = self
-                .elapsed_time
-                .saturating_add(delta_time)
-                .min(self.max_time);
         }
     }
 
-    /// Is this action ready to be used?
+    /// Advances the [`Cooldown`]s of only the provided `actions` according to the elapsed `delta_time`,
+    /// scaled by the [`cooldown_rate`](Self::cooldown_rate).
     ///
-    /// This will be true if and only if at least one charge is available.
-    /// For cooldowns without charges, this will be true if `time_remaining` is [`Duration::Zero`].
-    pub fn ready(&self) -> Result<(), CannotUseAbility> {
-        match self.elapsed_time >= self.max_time {
-            true => Ok(()),
-            false => Err(CannotUseAbility::OnCooldown),
+    /// The cooldowns of all other actions are left untouched, which is useful for skipping work on distant or inactive entities.
+    /// The global cooldown, and the [shared cooldowns](Self::set_shared_cooldown) of the provided actions, are always ticked.
+    /// Each action should only be provided once: duplicates are ticked multiple times.
+    /// Similarly, only one action from each group of [linked](Self::link) actions should be provided.
+    ///
+    /// Charges are replenished and results are returned exactly as in [`CooldownState::tick`].
+    pub fn tick_only<'a>(
+        &mut self,
+        actions: impl IntoIterator<Item = &'a A>,
+        delta_time: Duration,
+        mut maybe_charges: Option<&mut ChargeState<A>>,
+    ) -> Vec<(A, CooldownTickResult)>
+    where
+        A: 'a,
+    {
+        let delta_time = self.scaled_delta_time(delta_time);
+        let mut results = Vec::new();
+        let mut ticked_categories = HashSet::new();
+
+        for action in actions {
+            let linked_to = self.links.get(action).unwrap_or(action);
+            if let Some(cooldown) =
+                stored_mut(&mut self.cooldown_map, &mut self.dense_cooldowns, linked_to)
+            {
+                results.extend(tick_action(
+                    linked_to,
+                    cooldown,
+                    delta_time,
+                    maybe_charges.as_deref_mut(),
+                ));
+            }
+
+            if let Some(category) = self.categories.get(action) {
+                if ticked_categories.insert(category) {
+                    if let Some(shared_cooldown) = self.shared_cooldowns.get_mut(category) {
+                        shared_cooldown.tick(delta_time, None);
+                    }
+                }
+            }
+        }
+
+        self.report_linked(&mut results);
+
+        if let Some(global_cooldown) = self.global_cooldown.as_mut() {
+            global_cooldown.tick(delta_time, None);
         }
+
+        results
     }
 
-    /// Refreshes the cooldown, causing the underlying action to be ready to use immediately.
+    /// Advances each underlying [turn-based](Cooldown::from_turns) [`Cooldown`] by `turns` turns.
     ///
-    /// If this cooldown has charges, the number of available charges is increased by one (but the point within the cycle is unchanged).
+    /// This is equivalent to [`CooldownState::tick`] with a `delta_time` of `turns` [`Cooldown::TURN`]s,
+    /// so the [`cooldown_rate`](Self::cooldown_rate) still applies.
     #[inline]
-    pub fn refresh(&mut self) {
-        self.elapsed_time = self.max_time
+    pub fn tick_turns(
+        &mut self,
+        turns: u32,
+        maybe_charges: Option<&mut ChargeState<A>>,
+    ) -> Vec<(A, CooldownTickResult)> {
+        self.tick(Cooldown::TURN.saturating_mul(turns), maybe_charges)
     }
 
-    /// Use the underlying cooldown if and only if it is ready, resetting the cooldown to its maximum value.
-    ///
-    /// If this cooldown has multiple charges, only one will be consumed.
+    /// Returns the fraction of the cooldown of `action` that remains, from 1.0 when just triggered to 0.0 when ready.
     ///
-    /// Returns a result indicating whether the cooldown was ready.
+    /// If a [shared cooldown](Self::set_shared_cooldown) or global cooldown also applies to this action,
+    /// whichever of the fractions is largest is returned,
+    /// as that is the cooldown that prevents the action from being used.
+    /// Returns [`None`] if there is neither a cooldown for this action nor a shared or global cooldown.
+    #[must_use]
+    pub fn fraction_remaining(&self, action: &A) -> Option<f32> {
+        self.blocking_cooldowns(action)
+            .map(Cooldown::fraction_remaining)
+            .reduce(f32::max)
+    }
+
+    /// Returns how far the next charge of `action` has recharged, from 0.0 when it has just started to 1.0 when complete.
+    ///
+    /// This is intended for HUDs that show progress towards the next charge, such as "2 charges, next one 40% recharged".
+    /// Charges are recharged by the cooldown of `action` (unless their [`CooldownStrategy`] is [`CooldownStrategy::Ignore`]),
+    /// or by their own [recharge timer](Charges::with_recharge_time).
+    /// Returns 0.0 if nothing is recharging, such as when the charges are full,
+    /// or when [`CooldownStrategy::RefreshWhenEmpty`] charges still have charges remaining.
+    /// Returns [`None`] if `action` has no charges.
+    ///
+    /// ```rust
+    /// use bevy::{reflect::Reflect, utils::Duration};
+    /// use leafwing_abilities::prelude::*;
+    /// use leafwing_input_manager::Actionlike;
+    ///
+    /// #[derive(Actionlike, Abilitylike, Clone, Copy, Debug, Hash, PartialEq, Eq, Reflect)]
+    /// enum Action {
+    ///     Roll,
+    /// }
+    ///
+    /// let mut cooldowns = CooldownState::new([(Action::Roll, Cooldown::from_secs(5.))]);
+    /// let mut charges = ChargeState::new([(Action::Roll, Charges::replenish_one(3))]);
+    ///
+    /// Action::Roll.trigger_no_costs(&mut charges, &mut cooldowns).unwrap();
+    /// cooldowns.tick(Duration::from_secs(2), Some(&mut charges));
+    ///
+    /// let roll_charges = charges.get(&Action::Roll).unwrap();
+    /// let next_charge = cooldowns.charge_recharge_fraction(&Action::Roll, &charges).unwrap();
+    /// let hud_text = format!("{} charges, next one {:.0}% recharged", roll_charges.charges(), next_charge * 100.);
+    /// assert_eq!(hud_text, "2 charges, next one 40% recharged");
+    /// ```
+    #[must_use]
+    pub fn charge_recharge_fraction(&self, action: &A, charges: &ChargeState<A>) -> Option<f32> {
+        let charges = charges.get(action)?;
+        if charges.charges() >= charges.max_charges() {
+            return Some(0.0);
+        }
+
+        if charges.cooldown_strat != CooldownStrategy::Ignore {
+            if let Some(cooldown) = self.get(action) {
+                if cooldown.ready().is_err() {
+                    return Some(cooldown.fraction_elapsed());
+                }
+            }
+        }
+
+        let fraction = match charges.recharge_time() {
+            Some(recharge_time) if recharge_time > Duration::ZERO => {
+                (charges.recharge_elapsed().as_secs_f64() / recharge_time.as_secs_f64())
+                    .clamp(0.0, 1.0) as f32
+            }
+            _ => 0.0,
+        };
+        Some(fraction)
+    }
+
+    /// Returns how long it will be until `action` is no longer blocked by any cooldown.
+    ///
+    /// This is the longest of the time [`remaining`](Cooldown::remaining) on the cooldown of `action`,
+    /// its [shared cooldown](Self::set_shared_cooldown) and the global cooldown (unless `action` is [exempt](Self::set_gcd_exempt)).
+    /// Returns [`Duration::ZERO`] if the action is ready, or if no cooldowns apply to it.
+    /// Note that the [`cooldown_rate`](Self::cooldown_rate) is not taken into account.
+    #[must_use]
+    pub fn time_until_ready(&self, action: &A) -> Duration {
+        self.blocking_cooldowns(action)
+            .map(Cooldown::remaining)
+            .max()
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Reduces the time remaining on the cooldown of `action` by `amount`, as if it had been ticked.
+    ///
+    /// This is intended for effects like "on kill, reduce the cooldown of Dash by 1 second".
+    /// If the reduction completes the cooldown, a charge is granted to the supplied `charges`.
+    /// The [`cooldown_rate`](Self::cooldown_rate) is not applied, and paused cooldowns are still advanced.
+    /// Does nothing if no cooldown is stored for `action`.
+    pub fn advance(&mut self, action: &A, amount: Duration, charges: Option<&mut Charges>) {
+        if let Some(cooldown) = self.get_mut(action) {
+            cooldown.advance(amount, charges);
+        }
+    }
+
+    /// Returns the cooldown of `action`, its shared cooldown and the global cooldown, if they apply to it.
+    fn blocking_cooldowns(&self, action: &A) -> impl Iterator<Item = &Cooldown> {
+        let action_cooldown = self.get(action);
+        let shared_cooldown = self
+            .categories
+            .get(action)
+            .and_then(|category| self.shared_cooldowns.get(category));
+        let global_cooldown = self
+            .global_cooldown
+            .as_ref()
+            .filter(|_| !self.is_gcd_exempt(action));
+
+        [action_cooldown, shared_cooldown, global_cooldown]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Scales `delta_time` by the [`cooldown_rate`](Self::cooldown_rate).
+    fn scaled_delta_time(&self, delta_time: Duration) -> Duration {
+        scale_duration(delta_time, self.cooldown_rate.0)
+    }
+
+    /// Refreshes the [`Cooldown`] of every action, as well as the shared and global cooldowns.
+    ///
+    /// If `charges` are supplied, each action with a cooldown also has its charges restored to their maximum.
+    /// This is useful for effects like resetting all abilities when a player respawns.
+    pub fn refresh_all(&mut self, charges: Option<&mut ChargeState<A>>) {
+        for cooldown in self.iter_mut() {
+            cooldown.refresh();
+        }
+
+        for shared_cooldown in self.shared_cooldowns.values_mut() {
+            shared_cooldown.refresh();
+        }
+
+        if let Some(global_cooldown) = self.global_cooldown.as_mut() {
+            global_cooldown.refresh();
+        }
+
+        if let Some(charge_state) = charges {
+            for action in self.actions() {
+                if let Some(charges) = charge_state.get_mut(action) {
+                    charges.set_charges(charges.max_charges());
+                }
+            }
+        }
+    }
+
+    /// Refreshes the [`Cooldown`] of every action with `tag` in `tags`, such as for "reset all Fire spells".
+    ///
+    /// Shared and global cooldowns are left untouched, as are the cooldowns of actions without the tag.
+    pub fn refresh_tagged<T: AbilityTag>(&mut self, tags: &AbilityTags<A, T>, tag: T) {
+        for action in tags.actions_with(tag) {
+            if let Some(cooldown) = self.get_mut(action) {
+                cooldown.refresh();
+            }
+        }
+    }
+
+    /// Puts the [`Cooldown`] of every action, as well as the shared and global cooldowns, on cooldown.
+    ///
+    /// Unlike [`trigger`](Self::trigger), this does not check whether the actions are ready,
+    /// as it is intended for external effects (like an "exhaust" debuff), rather than ability use.
+    /// Otherwise, each cooldown is restarted exactly as by [`Cooldown::trigger`], rolling a new duration for cooldowns [with jitter](Cooldown::with_jitter).
+    /// If `charges` are supplied, each action with a cooldown also has all of its charges expended.
+    /// Actions without a cooldown are not affected, as their charges would never be replenished.
+    pub fn trigger_all(&mut self, charges: Option<&mut ChargeState<A>>) {
+        for cooldown in self.iter_mut() {
+            cooldown.restart();
+        }
+
+        for shared_cooldown in self.shared_cooldowns.values_mut() {
+            shared_cooldown.restart();
+        }
+
+        if let Some(global_cooldown) = self.global_cooldown.as_mut() {
+            global_cooldown.restart();
+        }
+
+        if let Some(charge_state) = charges {
+            for action in self.actions() {
+                if let Some(charges) = charge_state.get_mut(action) {
+                    charges.set_charges(0);
+                }
+            }
+        }
+    }
+
+    /// Pauses the [`Cooldown`] of every action.
+    ///
+    /// The global cooldown is not affected: pause it separately if desired.
+    pub fn pause_all(&mut self) {
+        for cooldown in self.iter_mut() {
+            cooldown.pause();
+        }
+    }
+
+    /// Unpauses the [`Cooldown`] of every action.
+    ///
+    /// The global cooldown is not affected: unpause it separately if desired.
+    pub fn unpause_all(&mut self) {
+        for cooldown in self.iter_mut() {
+            cooldown.unpause();
+        }
+    }
+
+    /// The cooldown associated with the specified `action`, if any.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, action: &A) -> Option<&Cooldown> {
+        let linked_to = self.links.get(action).unwrap_or(action);
+        self.get_stored(linked_to)
+    }
+
+    /// A mutable reference to the cooldown associated with the specified `action`, if any.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, action: &A) -> Option<&mut Cooldown> {
+        let linked_to = self.links.get(action).unwrap_or(action);
+        stored_mut(&mut self.cooldown_map, &mut self.dense_cooldowns, linked_to)
+    }
+
+    /// Set a cooldown for the specified `action`.
+    ///
+    /// If a cooldown already existed, it will be replaced by a new cooldown with the specified duration.
+    /// If the action was [linked](Self::link) to other actions, it is unlinked from them first.
+    #[inline]
+    pub fn set(&mut self, action: A, cooldown: Cooldown) -> &mut Self {
+        self.remove(&action);
+        self.insert_stored(action, cooldown);
+        self
+    }
+
+    /// Changes the max time of the cooldown for `action` without resetting it,
+    /// rescaling its elapsed time so that the [fraction elapsed](Cooldown::fraction_elapsed) is unchanged.
+    ///
+    /// This is useful for ability ranks or haste effects, where rebuilding the cooldown would lose its progress.
+    /// If `action` has no cooldown, a new, ready [`Cooldown`] with the `max_time` is added instead.
+    /// See [`Cooldown::set_max_time_preserving_fraction`] for more details.
+    #[inline]
+    pub fn set_max_time_preserving_fraction(&mut self, action: A, max_time: Duration) -> &mut Self {
+        match self.get_mut(&action) {
+            Some(cooldown) => cooldown.set_max_time_preserving_fraction(max_time),
+            None => {
+                self.set(action, Cooldown::new(max_time));
+            }
+        }
+        self
+    }
+
+    /// Sets a cooldown for the specified `action`, returning the modified [`CooldownState`].
+    ///
+    /// This is the consuming counterpart to [`Self::set`], for building a new [`CooldownState`] without a trailing [`Self::build`].
+    #[inline]
+    #[must_use]
+    pub fn with(mut self, action: A, cooldown: Cooldown) -> Self {
+        self.set(action, cooldown);
+        self
+    }
+
+    /// Links `actions` together, so that they share a single [`Cooldown`].
+    ///
+    /// Triggering, ticking or modifying the cooldown of any of these actions affects all of them,
+    /// and [`CooldownState::get`] returns the same cooldown for each of them.
+    /// Any existing cooldowns or links of these actions are replaced.
+    ///
+    /// Unlike [shared cooldowns](Self::set_shared_cooldown), which are checked in addition to each action's own cooldown,
+    /// linked actions have no cooldown of their own.
+    pub fn link(&mut self, actions: impl IntoIterator<Item = A>, cooldown: Cooldown) -> &mut Self {
+        let mut actions = actions.into_iter();
+        let Some(first) = actions.next() else {
+            return self;
+        };
+
+        self.set(first.clone(), cooldown);
+        for action in actions {
+            if action != first {
+                self.remove(&action);
+                self.links.insert(action, first.clone());
+            }
+        }
+
+        self
+    }
+
+    /// Removes the [`Cooldown`] of `action`, returning it if it existed.
+    ///
+    /// The action will then be treated as if it had no cooldown, although the global cooldown still applies.
+    /// The returned cooldown can be stored and [set](Self::set) again later, such as when swapping weapons.
+    ///
+    /// If `action` was [linked](Self::link) to other actions, only this action is unlinked:
+    /// the other actions continue to share their cooldown, and a copy of it is returned.
+    pub fn remove(&mut self, action: &A) -> Option<Cooldown> {
+        if let Some(linked_to) = self.links.remove(action) {
+            return self.get_stored(&linked_to).cloned();
+        }
+
+        let cooldown = self.remove_stored(action)?;
+
+        // Move the cooldown of a linked group to one of its remaining actions
+        if let Some(new_owner) = reassign_group_owner(&mut self.links, action) {
+            self.insert_stored(new_owner, cooldown.clone());
+        }
+
+        Some(cooldown)
+    }
+
+    /// Removes the [`Cooldown`] of every action, including any [links](Self::link) between them.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cooldown_map.clear();
+        if let Some(dense) = self.dense_cooldowns.as_mut() {
+            dense.slots.clear();
+        }
+        self.links.clear();
+    }
+
+    /// Retains only the [`Cooldown`]s for which `keep` returns `true`.
+    ///
+    /// Each group of [linked](Self::link) actions is only passed to `keep` once, and is kept or removed as a whole.
+    #[inline]
+    pub fn retain(&mut self, keep: impl FnMut(&A, &mut Cooldown) -> bool) {
+        self.retain_stored(keep);
+        self.retain_valid_links();
+    }
+
+    /// Collects a `&mut Self` into a `Self`.
+    ///
+    /// Used to conclude the builder pattern. Actually just calls `self.clone()`:
+    /// prefer [`Self::with`], which does not need to clone.
+    #[inline]
+    #[must_use]
+    pub fn build(&mut self) -> Self {
+        self.clone()
+    }
+
+    /// Returns an iterator of references to the underlying non-[`None`] [`Cooldown`]s
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Cooldown> {
+        self.into_iter().map(|(_, cooldown)| cooldown)
+    }
+
+    /// Returns an iterator over the actions that have [`Cooldown`] set
+    ///
+    /// This includes every action in each group of [linked](Self::link) actions.
+    #[inline]
+    pub fn actions(&self) -> impl Iterator<Item = &A> {
+        self.into_iter()
+            .map(|(action, _)| action)
+            .chain(self.links.keys())
+    }
+
+    /// Returns an iterator of mutable references to the underlying non-[`None`] [`Cooldown`]s
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Cooldown> {
+        self.iter_stored_mut().map(|(_, cooldown)| cooldown)
+    }
+
+    /// Captures the current state of every action, shared and global [`Cooldown`], for rollback netcode.
+    ///
+    /// Equal states always produce equal snapshots, so snapshots can be serialized and hashed to detect desyncs.
+    /// Configuration, such as [links](Self::link), categories, [exemptions](Self::set_gcd_exempt)
+    /// and the [`cooldown_rate`](Self::cooldown_rate), is not captured.
+    #[must_use]
+    pub fn snapshot(&self) -> CooldownSnapshot<A> {
+        let mut cooldowns: Vec<(A, Cooldown)> = self
+            .into_iter()
+            .map(|(action, cooldown)| (action.clone(), cooldown.clone()))
+            .collect();
+        sort_by_action(&mut cooldowns);
+
+        let mut shared_cooldowns: Vec<(Cow<'static, str>, Cooldown)> = self
+            .shared_cooldowns
+            .iter()
+            .map(|(category, cooldown)| (category.clone(), cooldown.clone()))
+            .collect();
+        shared_cooldowns.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        CooldownSnapshot {
+            cooldowns,
+            shared_cooldowns,
+            global_cooldown: self.global_cooldown.clone(),
+        }
+    }
+
+    /// Restores the [`Cooldown`]s captured by [`CooldownState::snapshot`].
+    ///
+    /// Cooldowns that were not present in the snapshot are removed.
+    /// Existing entries are overwritten in place, so restoring a snapshot of the same actions does not allocate.
+    pub fn restore(&mut self, snapshot: &CooldownSnapshot<A>) {
+        self.retain_stored(|action, _| snapshot.cooldowns.iter().any(|(saved, _)| saved == action));
+        for (action, cooldown) in &snapshot.cooldowns {
+            match stored_mut(&mut self.cooldown_map, &mut self.dense_cooldowns, action) {
+                Some(existing) => existing.clone_from(cooldown),
+                None => self.insert_stored(action.clone(), cooldown.clone()),
+            }
+        }
+
+        self.shared_cooldowns.retain(|category, _| {
+            snapshot
+                .shared_cooldowns
+                .iter()
+                .any(|(saved, _)| saved == category)
+        });
+        for (category, cooldown) in &snapshot.shared_cooldowns {
+            match self.shared_cooldowns.get_mut(category) {
+                Some(existing) => existing.clone_from(cooldown),
+                None => {
+                    self.shared_cooldowns
+                        .insert(category.clone(), cooldown.clone());
+                }
+            }
+        }
+
+        self.global_cooldown.clone_from(&snapshot.global_cooldown);
+        self.retain_valid_links();
+    }
+
+    /// The [`Cooldown`] stored for `action`, without following [links](Self::link).
+    fn get_stored(&self, action: &A) -> Option<&Cooldown> {
+        match self
+            .dense_cooldowns
+            .as_ref()
+            .and_then(|dense| dense.get(action))
+        {
+            Some(cooldown) => Some(cooldown),
+            None => self.cooldown_map.get(action),
+        }
+    }
+
+    /// Stores the `cooldown` of `action`, in its dense slot if possible, without updating any [links](Self::link).
+    fn insert_stored(&mut self, action: A, cooldown: Cooldown) {
+        let Some(dense) = self.dense_cooldowns.as_mut() else {
+            self.cooldown_map.insert(action, cooldown);
+            return;
+        };
+
+        // The slot may have been freed since the action was stored in the map
+        self.cooldown_map.remove(&action);
+        if let Some((action, cooldown)) = dense.insert(action, cooldown) {
+            self.cooldown_map.insert(action, cooldown);
+        }
+    }
+
+    /// Removes the [`Cooldown`] stored for `action`, without updating any [links](Self::link).
+    fn remove_stored(&mut self, action: &A) -> Option<Cooldown> {
+        match self
+            .dense_cooldowns
+            .as_mut()
+            .and_then(|dense| dense.remove(action))
+        {
+            Some(cooldown) => Some(cooldown),
+            None => self.cooldown_map.remove(action),
+        }
+    }
+
+    /// Iterates over each stored `(action, cooldown)` pair, yielding each [linked](Self::link) cooldown once.
+    fn iter_stored_mut(&mut self) -> impl Iterator<Item = (&A, &mut Cooldown)> {
+        let slots = self
+            .dense_cooldowns
+            .as_mut()
+            .map_or(&mut [][..], |dense| &mut dense.slots[..]);
+        self.cooldown_map.iter_mut().chain(
+            slots
+                .iter_mut()
+                .flatten()
+                .map(|(action, cooldown)| (&*action, cooldown)),
+        )
+    }
+
+    /// Retains only the stored [`Cooldown`]s for which `keep` returns `true`, without updating any [links](Self::link).
+    fn retain_stored(&mut self, mut keep: impl FnMut(&A, &mut Cooldown) -> bool) {
+        self.cooldown_map
+            .retain(|action, cooldown| keep(action, cooldown));
+        if let Some(dense) = self.dense_cooldowns.as_mut() {
+            for slot in dense.slots.iter_mut() {
+                if let Some((action, cooldown)) = slot {
+                    if !keep(action, cooldown) {
+                        *slot = None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Removes the [links](Self::link) to actions that no longer have a stored [`Cooldown`].
+    fn retain_valid_links(&mut self) {
+        let mut links = std::mem::take(&mut self.links);
+        links.retain(|_, linked_to| self.get_stored(linked_to).is_some());
+        self.links = links;
+    }
+}
+
+/// The [`Cooldown`] stored for `action` in either storage of a [`CooldownState`], without following links.
+///
+/// This only borrows the storage, so the links of the [`CooldownState`] can be borrowed at the same time.
+fn stored_mut<'a, A: Abilitylike>(
+    cooldown_map: &'a mut HashMap<A, Cooldown>,
+    dense_cooldowns: &'a mut Option<DenseCooldowns<A>>,
+    action: &A,
+) -> Option<&'a mut Cooldown> {
+    match dense_cooldowns
+        .as_mut()
+        .and_then(|dense| dense.get_mut(action))
+    {
+        Some(cooldown) => Some(cooldown),
+        None => cooldown_map.get_mut(action),
+    }
+}
+
+/// The [rate](CooldownState::cooldown_rate) at which the cooldowns of a [`CooldownState`] recover.
+///
+/// This is never negative or NaN, so that [`CooldownState`] can implement [`Eq`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(from = "f32", into = "f32")]
+struct CooldownRate(f32);
+
+impl From<f32> for CooldownRate {
+    /// Treats negative and NaN rates as 0.0.
+    fn from(cooldown_rate: f32) -> Self {
+        CooldownRate(cooldown_rate.max(0.))
+    }
+}
+
+impl From<CooldownRate> for f32 {
+    fn from(cooldown_rate: CooldownRate) -> Self {
+        cooldown_rate.0
+    }
+}
+
+// NaN is rejected when the rate is set, so equality is reflexive
+impl Eq for CooldownRate {}
+
+/// The largest number of slots a [dense](CooldownState::dense) [`CooldownState`] will allocate.
+///
+/// Actions with a larger [`Abilitylike::index`] are stored in a [`HashMap`] instead.
+const MAX_DENSE_SLOTS: usize = 256;
+
+/// The [`Cooldown`]s of a [dense](CooldownState::dense) [`CooldownState`], stored by [`Abilitylike::index`].
+///
+/// Each slot also stores its action, to tell apart actions that share an index.
+#[derive(Debug, Clone, Reflect, Deserialize)]
+#[serde(bound(deserialize = "A: Deserialize<'de>"))]
+#[serde(try_from = "Vec<Option<(A, Cooldown)>>")]
+struct DenseCooldowns<A: Abilitylike> {
+    slots: Vec<Option<(A, Cooldown)>>,
+}
+
+impl<A: Abilitylike> DenseCooldowns<A> {
+    fn get(&self, action: &A) -> Option<&Cooldown> {
+        match self.slots.get(action.index()) {
+            Some(Some((stored, cooldown))) if stored == action => Some(cooldown),
+            _ => None,
+        }
+    }
+
+    fn get_mut(&mut self, action: &A) -> Option<&mut Cooldown> {
+        match self.slots.get_mut(action.index()) {
+            Some(Some((stored, cooldown))) if stored == action => Some(cooldown),
+            _ => None,
+        }
+    }
+
+    /// Stores the `cooldown` of `action` in its slot, handing them back if the slot is taken by another action.
+    fn insert(&mut self, action: A, cooldown: Cooldown) -> Option<(A, Cooldown)> {
+        let index = action.index();
+        if index >= MAX_DENSE_SLOTS {
+            return Some((action, cooldown));
+        }
+
+        if index >= self.slots.len() {
+            self.slots.resize_with(index + 1, || None);
+        }
+        match &mut self.slots[index] {
+            Some((stored, _)) if *stored != action => Some((action, cooldown)),
+            slot => {
+                *slot = Some((action, cooldown));
+                None
+            }
+        }
+    }
+
+    fn remove(&mut self, action: &A) -> Option<Cooldown> {
+        let slot = self.slots.get_mut(action.index())?;
+        match slot {
+            Some((stored, _)) if stored == action => slot.take().map(|(_, cooldown)| cooldown),
+            _ => None,
+        }
+    }
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Abilitylike> Default for DenseCooldowns<A> {
+    fn default() -> Self {
+        Self { slots: Vec::new() }
+    }
+}
+
+impl<A: Abilitylike> PartialEq for DenseCooldowns<A> {
+    /// Compares the stored cooldowns, ignoring any trailing empty slots.
+    fn eq(&self, other: &Self) -> bool {
+        self.slots.iter().flatten().eq(other.slots.iter().flatten())
+    }
+}
+
+impl<A: Abilitylike> Eq for DenseCooldowns<A> {}
+
+impl<A: Abilitylike + Serialize> Serialize for DenseCooldowns<A> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.slots.serialize(serializer)
+    }
+}
+
+impl<A: Abilitylike> TryFrom<Vec<Option<(A, Cooldown)>>> for DenseCooldowns<A> {
+    type Error = String;
+
+    /// Rebuilds the slots from deserialized data, rejecting actions stored in the wrong slot.
+    fn try_from(slots: Vec<Option<(A, Cooldown)>>) -> Result<Self, String> {
+        for (index, slot) in slots.iter().enumerate() {
+            if let Some((action, _)) = slot {
+                if action.index() != index {
+                    return Err(format!(
+                        "the dense cooldown of {action:?} was stored in slot {index}, rather than at its index {}",
+                        action.index()
+                    ));
+                }
+            }
+        }
+
+        Ok(Self { slots })
+    }
+}
+
+/// The state of every [`Cooldown`] in a [`CooldownState`] at a single point in time.
+///
+/// Created by [`CooldownState::snapshot`], and applied with [`CooldownState::restore`].
+/// Entries are stored in a deterministic order, so equal states always produce equal snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CooldownSnapshot<A: Abilitylike> {
+    /// The cooldown of each action.
+    pub cooldowns: Vec<(A, Cooldown)>,
+    /// The [shared cooldown](CooldownState::set_shared_cooldown) of each category.
+    pub shared_cooldowns: Vec<(Cow<'static, str>, Cooldown)>,
+    /// The [global cooldown](CooldownState::global_cooldown), if any.
+    pub global_cooldown: Option<Cooldown>,
+}
+
+/// A timer-like struct that records the amount of time until an action is available to be used again.
+///
+/// Cooldowns are typically stored in an [`ActionState`](crate::action_state::ActionState), associated with an action that is to be
+/// cooldown-regulated.
+///
+/// When initialized, cooldowns are always fully available.
+///
+/// ```rust
+/// use bevy::utils::Duration;
+/// use leafwing_abilities::cooldown::Cooldown;
+/// use leafwing_abilities::CannotUseAbility;
+///
+/// let mut cooldown = Cooldown::new(Duration::from_secs(3));
+/// assert_eq!(cooldown.remaining(), Duration::ZERO);
+///
+/// cooldown.trigger();
+/// assert_eq!(cooldown.remaining(), Duration::from_secs(3));
+///
+/// cooldown.tick(Duration::from_secs(1), None);
+/// assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
+///
+/// cooldown.tick(Duration::from_secs(5), None);
+/// let triggered = cooldown.trigger();
+/// assert!(triggered.is_ok());
+///
+/// cooldown.refresh();
+/// assert!(cooldown.ready().is_ok());
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect)]
+pub struct Cooldown {
+    max_time: Duration,
+    /// The amount of time that has elapsed since all [`Charges`](crate::charges::Charges) were fully replenished.
+    elapsed_time: Duration,
+    /// Is this cooldown currently paused?
+    #[serde(default)]
+    paused: bool,
+    /// The maximum amount by which the duration of each cycle may differ from `max_time`.
+    #[serde(default)]
+    jitter: Duration,
+    /// The duration of the current cycle, rolled when the cooldown is triggered.
+    ///
+    /// Only used when `jitter` is non-zero.
+    #[serde(default)]
+    rolled_time: Duration,
+    /// The state of the random number generator used to roll jittered durations.
+    #[serde(default)]
+    rng_state: u64,
+    /// Did this cooldown become ready during the last call to [`Cooldown::tick`]?
+    #[serde(default)]
+    just_finished: bool,
+}
+
+impl Cooldown {
+    /// The duration that represents a single turn, for [turn-based cooldowns](Cooldown::from_turns).
+    pub const TURN: Duration = Duration::from_secs(1);
+
+    /// Creates a new [`Cooldown`], which will take `max_time` after it is used until it is ready again.
+    ///
+    /// A `max_time` of [`Duration::ZERO`] creates a cooldown that is always ready, even immediately after being triggered.
+    /// This is useful when cooldown durations are loaded from data or reduced by stats.
+    pub fn new(max_time: Duration) -> Cooldown {
+        Cooldown {
+            max_time,
+            elapsed_time: max_time,
+            paused: false,
+            jitter: Duration::ZERO,
+            rolled_time: max_time,
+            rng_state: 0,
+            just_finished: false,
+        }
+    }
+
+    /// Creates a new [`Cooldown`] whose duration varies randomly each time it is triggered.
+    ///
+    /// Each call to [`Cooldown::trigger`] picks a duration uniformly from `[max_time - jitter, max_time + jitter]`,
+    /// so that (for example) groups of enemies do not use their abilities in lockstep.
+    /// The durations are rolled from a deterministic random number generator seeded with `seed`,
+    /// which is stored (and serialized) with the cooldown, making it safe to use with tests and rollback netcode.
+    /// Give each cooldown a different `seed` to avoid them rolling the same sequence of durations.
+    ///
+    /// [`Cooldown::max_time`] reports the nominal `max_time`,
+    /// while [`Cooldown::current_max_time`] and [`Cooldown::remaining`] use the rolled duration.
+    ///
+    /// A `jitter` larger than `max_time` is clamped to `max_time`, as durations cannot be negative.
+    pub fn with_jitter(max_time: Duration, jitter: Duration, seed: u64) -> Cooldown {
+        let mut cooldown = Cooldown::new(max_time);
+        cooldown.jitter = jitter.min(max_time);
+        cooldown.rng_state = seed;
+        cooldown
+    }
+
+    /// Creates a new [`Cooldown`] with a [`f32`] number of seconds, which will take `max_time` after it is used until it is ready again.
+    ///
+    /// A NaN `max_time` creates a cooldown that is always ready, as with [`Duration::ZERO`],
+    /// while infinite or overly large values saturate to [`Duration::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// The provided max time cannot be negative.
+    pub fn from_secs(max_time: f32) -> Cooldown {
+        Cooldown::new(duration_from_secs(max_time))
+    }
+
+    /// Creates a new [`Cooldown`] that has just been triggered, and so will only be ready once `max_time` has elapsed.
+    ///
+    /// This is useful for entities that should spawn with their abilities on cooldown, such as summoned units.
+    /// Any [`Charges`] associated with this cooldown are unaffected: they stay at their max unless you also adjust them.
+    pub fn new_triggered(max_time: Duration) -> Cooldown {
+        let mut cooldown = Cooldown::new(max_time);
+        cooldown.elapsed_time = Duration::ZERO;
+        cooldown
+    }
+
+    /// Creates a new [`Cooldown`] with a [`f32`] number of seconds that has just been triggered.
+    ///
+    /// See [`Cooldown::new_triggered`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// The provided max time cannot be negative.
+    pub fn from_secs_triggered(max_time: f32) -> Cooldown {
+        Cooldown::new_triggered(duration_from_secs(max_time))
+    }
+
+    /// Creates a new turn-based [`Cooldown`], which will take `turns` turns after it is used until it is ready again.
+    ///
+    /// Turn-based cooldowns should be advanced with [`Cooldown::tick_turns`] (or [`CooldownState::tick_turns`]),
+    /// and inspected with [`Cooldown::remaining_turns`].
+    /// Internally, each turn is stored as one [`Cooldown::TURN`],
+    /// so all other methods continue to work, but report durations rather than turns.
+    ///
+    /// Turn-based cooldowns should not be ticked by [`AbilityPlugin`](crate::plugin::AbilityPlugin),
+    /// so store them in a [`CooldownState`] that is not ticked automatically.
+    #[must_use]
+    pub fn from_turns(turns: u32) -> Cooldown {
+        Cooldown::new(Cooldown::TURN.saturating_mul(turns))
+    }
+
+    /// Advances a [turn-based](Cooldown::from_turns) cooldown by `turns` turns.
+    ///
+    /// Charges are replenished exactly as in [`Cooldown::tick`].
+    #[inline]
+    pub fn tick_turns(&mut self, turns: u32, charges: Option<&mut Charges>) -> CooldownTickResult {
+        self.tick(Cooldown::TURN.saturating_mul(turns), charges)
+    }
+
+    /// Advance the cooldown by `delta_time`.
+    ///
+    /// If `charges` are supplied, their [`CooldownStrategy`] determines what happens each time the cooldown completes:
+    ///
+    /// - [`CooldownStrategy::Ignore`]: the charges are untouched, and the cooldown behaves as if it had no charges.
+    /// - [`CooldownStrategy::ConstantlyRefresh`]: the charges are [replenished](Charges::replenish) while they are below their max.
+    /// - [`CooldownStrategy::RefreshWhenEmpty`]: the charges are [replenished](Charges::replenish) only if none are available.
+    ///
+    /// While the charges still need refreshing, the cooldown starts a new cycle, carrying over any excess time,
+    /// so a long `delta_time` can complete several cycles.
+    /// Once they no longer need refreshing, the cooldown becomes ready and any excess completions are discarded.
+    /// As a result, [`CooldownStrategy::RefreshWhenEmpty`] replenishes charges at most once per tick.
+    ///
+    /// Paused cooldowns are not advanced.
+    ///
+    /// The [`just_finished`](Self::just_finished) flag is cleared at the start of each tick.
+    pub fn tick(
+        &mut self,
+        delta_time: Duration,
+        charges: Option<&mut Charges>,
+    ) -> CooldownTickResult {
+        self.just_finished = false;
+
+        if self.paused {
+            return CooldownTickResult::default();
+        }
+
+        self.advance(delta_time, charges)
+    }
+
+    /// Reduces the time remaining on this cooldown by `amount`, as if it had been ticked.
+    ///
+    /// If the reduction completes the cooldown, charges are replenished exactly as in [`Cooldown::tick`].
+    /// Unlike [`Cooldown::tick`], this applies even if the cooldown is paused.
+    pub fn advance(
+        &mut self,
+        amount: Duration,
+        charges: Option<&mut Charges>,
+    ) -> CooldownTickResult {
+        let max_time = self.current_max_time();
+
+        // Don't advance cooldowns when they are fully elapsed.
+        // This includes zero-duration cooldowns, which are always ready
+        if self.elapsed_time >= max_time {
+            return CooldownTickResult::default();
+        }
+
+        let was_ready = self.ready().is_ok();
+        let mut completions = 0;
+
+        match charges {
+            Some(charges) if charges.cooldown_strat != CooldownStrategy::Ignore => {
+                let total_time = self.elapsed_time.saturating_add(amount);
+
+                let total_nanos: u64 = total_time.as_nanos().try_into().unwrap_or(u64::MAX);
+                let max_nanos: u64 = max_time.as_nanos().try_into().unwrap_or(u64::MAX);
+
+                let n_completed = total_nanos / max_nanos;
+                let extra_time = Duration::from_nanos(total_nanos % max_nanos);
+
+                self.elapsed_time = total_time;
+                for _ in 0..n_completed {
+                    completions += 1;
+                    if charges.needs_refresh() {
+                        charges.replenish();
+                    }
+
+                    // Once the charges no longer need refreshing, the cooldown stops,
+                    // discarding any excess completions
+                    if !charges.needs_refresh() || completions == u8::MAX {
+                        self.elapsed_time = max_time;
+                        break;
+                    }
+                    self.elapsed_time = extra_time;
+                }
+            }
+            // Charges that ignore the cooldown are never replenished, and the cooldown behaves as if it had no charges
+            _ => {
+                self.elapsed_time = self.elapsed_time.saturating_add(amount).min(max_time);
+                completions = u8::from(self.elapsed_time == max_time);
+            }
+        }
+
+        let just_finished = !was_ready && self.ready().is_ok();
+        self.just_finished |= just_finished;
+
+        CooldownTickResult {
+            completions,
+            just_finished,
+        }
+    }
+
+    /// Did this cooldown become ready during the last call to [`Cooldown::tick`] (or a later [`Cooldown::advance`])?
+    ///
+    /// Like [`Timer::just_finished`], this is true for exactly one tick, however much that tick overshoots the cooldown.
+    /// It is false for cooldowns that were already ready, and is cleared when the cooldown is triggered.
+    #[inline]
+    #[must_use]
+    pub fn just_finished(&self) -> bool {
+        self.just_finished
+    }
+
+    /// Pauses the cooldown, preventing it from advancing when [`Cooldown::tick`] is called.
+    ///
+    /// This is useful for effects like stuns, which should freeze cooldowns until they expire.
+    /// Paused cooldowns can still be triggered and refreshed.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Unpauses the cooldown, allowing it to advance again from the same elapsed time.
+    #[inline]
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    /// Is this cooldown paused?
+    #[inline]
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Is this action ready to be used?
+    ///
+    /// This will be true if and only if at least one charge is available.
+    /// For cooldowns without charges, this will be true if `time_remaining` is [`Duration::Zero`].
+    pub fn ready(&self) -> Result<(), CannotUseAbility> {
+        match self.elapsed_time >= self.current_max_time() {
+            true => Ok(()),
+            false => Err(CannotUseAbility::OnCooldown),
+        }
+    }
+
+    /// Refreshes the cooldown, causing the underlying action to be ready to use immediately.
+    ///
+    /// If this cooldown has charges, the number of available charges is increased by one (but the point within the cycle is unchanged).
+    #[inline]
+    pub fn refresh(&mut self) {
+        self.elapsed_time = self.current_max_time()
+    }
+
+    /// Use the underlying cooldown if and only if it is ready, resetting the cooldown to its maximum value.
+    ///
+    /// If this cooldown has multiple charges, only one will be consumed.
+    ///
+    /// Returns a result indicating whether the cooldown was ready.
     /// If the cooldown was not ready, [`CannotUseAbility::OnCooldown`] is returned and this call has no effect.
+    ///
+    /// If this cooldown was created [with jitter](Cooldown::with_jitter), a new duration is rolled.
+    #[inline]
+    pub fn trigger(&mut self) -> Result<(), CannotUseAbility> {
+        self.ready()?;
+        self.restart();
+        Ok(())
+    }
+
+    /// Resets the cooldown to its maximum value, whether or not it was ready, rolling a new duration if it has jitter.
+    fn restart(&mut self) {
+        self.elapsed_time = Duration::ZERO;
+        self.just_finished = false;
+
+        if self.jitter != Duration::ZERO {
+            self.rolled_time = self.roll_duration();
+        }
+    }
+
+    /// Picks a duration uniformly from `[max_time - jitter, max_time + jitter]`, advancing the random number generator.
+    fn roll_duration(&mut self) -> Duration {
+        // SplitMix64: tiny, fast and good enough for gameplay variance
+        self.rng_state = self.rng_state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.rng_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        let range = self.jitter.as_nanos().saturating_mul(2).saturating_add(1);
+        // Multiplying and shifting maps the random bits onto [0, range) without modulo bias
+        let offset = (z as u128).saturating_mul(range) >> 64;
+        let offset = Duration::from_nanos(offset.try_into().unwrap_or(u64::MAX));
+
+        (self.max_time - self.jitter).saturating_add(offset)
+    }
+
+    /// Returns the time that it will take for this action to be ready to use again after being triggered.
+    ///
+    /// This is the nominal duration: for cooldowns [with jitter](Cooldown::with_jitter),
+    /// use [`Cooldown::current_max_time`] to get the duration of the current cycle.
+    #[inline]
+    pub fn max_time(&self) -> Duration {
+        self.max_time
+    }
+
+    /// Returns the duration of the current cycle of this cooldown.
+    ///
+    /// This is the same as [`Cooldown::max_time`], unless this cooldown was created [with jitter](Cooldown::with_jitter),
+    /// in which case it is the duration rolled when the cooldown was last triggered.
+    #[inline]
+    #[must_use]
+    pub fn current_max_time(&self) -> Duration {
+        if self.jitter == Duration::ZERO {
+            self.max_time
+        } else {
+            self.rolled_time
+        }
+    }
+
+    /// Returns the maximum amount by which the duration of each cycle can differ from the [`max_time`](Self::max_time).
+    ///
+    /// This is [`Duration::ZERO`] unless the cooldown was created with [`Cooldown::with_jitter`].
+    #[inline]
+    #[must_use]
+    pub fn jitter(&self) -> Duration {
+        self.jitter
+    }
+
+    /// Sets the time that it will take for this action to be ready to use again after being triggered.
+    ///
+    /// If the current time remaining is greater than the new max time, it will be clamped to the `max_time`.
+    /// This means that the fraction of the cooldown that has elapsed can jump suddenly:
+    /// for effects that change the cooldown mid-flight, like attack speed or haste,
+    /// use [`Cooldown::set_max_time_preserving_fraction`] instead.
+    ///
+    /// Setting the max time to [`Duration::ZERO`] makes the cooldown always ready, as with [`Cooldown::new`].
+    ///
+    /// For cooldowns with [jitter](Self::with_jitter), the current roll is kept as an offset from the new `max_time`,
+    /// clamped to the jitter that the new `max_time` allows.
+    /// A ready cooldown whose rolled duration does not grow stays ready.
+    #[inline]
+    pub fn set_max_time(&mut self, max_time: Duration) {
+        let jitter = self
+            .jitter
+            .min(max_time.saturating_sub(Duration::from_nanos(1)));
+        self.rolled_time = if self.rolled_time >= self.max_time {
+            max_time.saturating_add((self.rolled_time - self.max_time).min(jitter))
+        } else {
+            max_time.saturating_sub((self.max_time - self.rolled_time).min(jitter))
+        };
+        self.max_time = max_time;
+        self.jitter = jitter;
+        self.elapsed_time = self.elapsed_time.min(self.current_max_time());
+    }
+
+    /// Sets the time that it will take for this action to be ready to use again after being triggered,
+    /// rescaling the elapsed time so that the [fraction elapsed](Self::fraction_elapsed) is unchanged.
+    ///
+    /// For example, a 2 second cooldown that is halfway done becomes a 4 second cooldown with 2 seconds remaining.
+    /// This is the method that should be used for haste effects, as progress bars in UI will not jump.
+    #[inline]
+    pub fn set_max_time_preserving_fraction(&mut self, max_time: Duration) {
+        let fraction_elapsed = self.fraction_elapsed() as f64;
+        self.set_max_time(max_time);
+        self.set_elapsed(self.current_max_time().mul_f64(fraction_elapsed));
+    }
+
+    /// Returns the time that has passed since the cooldown was triggered.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed_time
+    }
+
+    /// Sets the time that has passed since the cooldown was triggered.
+    ///
+    /// This will always be clamped between [`Duration::ZERO`] and the [`current_max_time`](Self::current_max_time) of this cooldown.
+    #[inline]
+    pub fn set_elapsed(&mut self, elapsed_time: Duration) {
+        self.elapsed_time = elapsed_time.clamp(Duration::ZERO, self.current_max_time());
+    }
+
+    /// Returns the time remaining until the next charge is ready.
+    ///
+    /// When a cooldown is fully charged, this will return [`Duration::ZERO`].
+    #[inline]
+    pub fn remaining(&self) -> Duration {
+        self.current_max_time().saturating_sub(self.elapsed_time)
+    }
+
+    /// Returns the number of turns remaining until a [turn-based](Cooldown::from_turns) cooldown is ready.
+    ///
+    /// Partial turns are rounded up, so this is only 0 when the cooldown is ready.
+    #[inline]
+    #[must_use]
+    pub fn remaining_turns(&self) -> u32 {
+        self.remaining()
+            .as_nanos()
+            .div_ceil(Cooldown::TURN.as_nanos())
+            .try_into()
+            .unwrap_or(u32::MAX)
+    }
+
+    /// Sets the time remaining until the next charge is ready.
+    ///
+    /// This will always be clamped between [`Duration::ZERO`] and the [`current_max_time`](Self::current_max_time) of this cooldown.
+    #[inline]
+    pub fn set_remaining(&mut self, time_remaining: Duration) {
+        let max_time = self.current_max_time();
+        self.elapsed_time = max_time.saturating_sub(time_remaining.clamp(Duration::ZERO, max_time));
+    }
+
+    /// Returns the fraction of the cooldown that has elapsed, from 0.0 when just triggered to 1.0 when ready.
+    ///
+    /// This is always within `[0.0, 1.0]`, and never NaN.
+    ///
+    /// ```rust
+    /// use bevy::utils::Duration;
+    /// use leafwing_abilities::cooldown::Cooldown;
+    ///
+    /// let mut cooldown = Cooldown::from_secs(2.);
+    /// assert_eq!(cooldown.fraction_elapsed(), 1.0);
+    ///
+    /// cooldown.trigger();
+    /// cooldown.tick(Duration::from_secs(1), None);
+    /// assert_eq!(cooldown.fraction_elapsed(), 0.5);
+    /// assert_eq!(cooldown.fraction_remaining(), 0.5);
+    /// ```
     #[inline]
-    pub fn trigger(&mut self) -> Result<(), CannotUseAbility> {
-        self.ready()?;
-        self.elapsed_time = Duration::ZERO;
+    #[must_use]
+    pub fn fraction_elapsed(&self) -> f32 {
+        1.0 - self.fraction_remaining()
+    }
+
+    /// Returns the fraction of the cooldown that remains, from 1.0 when just triggered to 0.0 when ready.
+    ///
+    /// This is always within `[0.0, 1.0]`, and never NaN.
+    /// Ready cooldowns always return exactly 0.0.
+    #[inline]
+    #[must_use]
+    pub fn fraction_remaining(&self) -> f32 {
+        let remaining = self.remaining();
+        if remaining == Duration::ZERO {
+            return 0.0;
+        }
+
+        (remaining.as_secs_f64() / self.current_max_time().as_secs_f64()).clamp(0.0, 1.0) as f32
+    }
+}
+
+impl Display for Cooldown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} / {:?}", self.elapsed_time, self.current_max_time())
+    }
+}
+
+impl Cooldown {
+    /// Converts this cooldown into a non-repeating [`Timer`], for use with code that expects timers.
+    ///
+    /// The timer's duration is the `max_time` of this cooldown, and its elapsed time is the elapsed time of this cooldown.
+    /// Paused cooldowns produce paused timers.
+    /// As a result, a ready cooldown produces a finished timer.
+    /// Because the finished state of a [`Timer`] is only updated when it is ticked,
+    /// the returned timer has been ticked by [`Duration::ZERO`]:
+    /// this means that timers produced from ready cooldowns will also report [`Timer::just_finished`].
+    #[must_use]
+    pub fn as_timer(&self) -> Timer {
+        let mut timer = Timer::new(self.current_max_time(), TimerMode::Once);
+        timer.set_elapsed(self.elapsed_time);
+        timer.tick(Duration::ZERO);
+        if self.paused {
+            timer.pause();
+        }
+        timer
+    }
+
+    /// Converts this cooldown into a [`Stopwatch`] that has recorded the time elapsed since the cooldown was triggered.
+    #[must_use]
+    pub fn as_stopwatch(&self) -> Stopwatch {
+        let mut stopwatch = Stopwatch::new();
+        stopwatch.set_elapsed(self.elapsed_time);
+        if self.paused {
+            stopwatch.pause();
+        }
+        stopwatch
+    }
+}
+
+impl From<Timer> for Cooldown {
+    /// Creates a [`Cooldown`] whose `max_time` is the timer's duration, and whose elapsed time is the timer's elapsed time.
+    ///
+    /// A finished timer produces a ready cooldown, while a freshly reset timer produces a cooldown that was just triggered.
+    /// Paused timers produce paused cooldowns.
+    /// Repeating timers lose their mode: cooldowns never restart on their own.
+    /// As repeating timers wrap their elapsed time on completion, they will generally produce cooldowns that are not ready.
+    fn from(timer: Timer) -> Cooldown {
+        let mut cooldown = Cooldown::new(timer.duration());
+        cooldown.set_elapsed(timer.elapsed());
+        cooldown.paused = timer.paused();
+        cooldown
+    }
+}
+
+impl TryFrom<Duration> for Cooldown {
+    type Error = ZeroCooldownDuration;
+
+    /// Creates a new, ready [`Cooldown`] with the provided `max_time`.
+    ///
+    /// Unlike [`Cooldown::new`], which creates an always-ready cooldown,
+    /// this returns an error if the duration is [`Duration::ZERO`].
+    fn try_from(max_time: Duration) -> Result<Cooldown, ZeroCooldownDuration> {
+        if max_time == Duration::ZERO {
+            Err(ZeroCooldownDuration)
+        } else {
+            Ok(Cooldown::new(max_time))
+        }
+    }
+}
+
+/// Converts a number of seconds into a [`Duration`] for [`Cooldown::from_secs`] and [`Cooldown::from_secs_triggered`].
+///
+/// NaN becomes [`Duration::ZERO`], and values that are too large to represent saturate to [`Duration::MAX`].
+///
+/// # Panics
+///
+/// `secs` cannot be negative.
+fn duration_from_secs(secs: f32) -> Duration {
+    assert!(secs >= 0. || secs.is_nan());
+
+    if secs.is_nan() {
+        Duration::ZERO
+    } else {
+        Duration::try_from_secs_f32(secs).unwrap_or(Duration::MAX)
+    }
+}
+
+/// Ticks the `cooldown` of `action`, returning the result if the cooldown completed at least one cycle.
+fn tick_action<A: Abilitylike>(
+    action: &A,
+    cooldown: &mut Cooldown,
+    delta_time: Duration,
+    maybe_charges: Option<&mut ChargeState<A>>,
+) -> Option<(A, CooldownTickResult)> {
+    let charges = maybe_charges.and_then(|charge_state| charge_state.get_mut(action));
+    let result = cooldown.tick(delta_time, charges);
+    (result.completions > 0).then(|| (action.clone(), result))
+}
+
+/// The outcome of a call to [`Cooldown::tick`] or [`Cooldown::advance`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CooldownTickResult {
+    /// The number of full cycles of the cooldown that were completed.
+    ///
+    /// Without [`Charges`], this is either 0 or 1.
+    /// With charges, each completion replenishes charges, so a long frame can complete several cycles.
+    pub completions: u8,
+    /// Did the cooldown become ready during this tick, having not been ready before it?
+    pub just_finished: bool,
+}
+
+/// An event that is sent when the [`Cooldown`] of `action` becomes ready during [`tick_cooldowns`](crate::systems::tick_cooldowns).
+///
+/// Exactly one event is sent each time the cooldown transitions from not ready to ready,
+/// regardless of how much time has elapsed.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct CooldownFinished<A: Abilitylike> {
+    /// The entity whose [`CooldownState`] contains the cooldown, or [`None`] if it is stored as a resource.
+    pub entity: Option<Entity>,
+    /// The action whose cooldown finished.
+    pub action: A,
+}
+
+/// An event that is sent when the global cooldown of a [`CooldownState`] becomes ready during [`tick_cooldowns`](crate::systems::tick_cooldowns).
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct GlobalCooldownFinished<A: Abilitylike> {
+    /// The entity whose [`CooldownState`] contains the global cooldown, or [`None`] if it is stored as a resource.
+    pub entity: Option<Entity>,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Abilitylike> GlobalCooldownFinished<A> {
+    /// Creates a new [`GlobalCooldownFinished`] event for the provided `entity`.
+    #[must_use]
+    pub fn new(entity: Option<Entity>) -> Self {
+        Self {
+            entity,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// The max time of a [`Cooldown`] was set to [`Duration::ZERO`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("The max time of a cooldown cannot be zero.")]
+pub struct ZeroCooldownDuration;
+
+#[cfg(test)]
+mod tick_tests {
+    use super::*;
+    use crate as leafwing_abilities;
+    use crate::charges::ReplenishStrategy;
+    use leafwing_input_manager::Actionlike;
+
+    #[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    enum TestAbility {
+        A,
+        B,
+        C,
+    }
+
+    #[test]
+    fn zero_duration_cooldown_is_always_ready() {
+        let mut cooldown = Cooldown::new(Duration::ZERO);
+        assert!(cooldown.ready().is_ok());
+        assert_eq!(cooldown.fraction_remaining(), 0.0);
+
+        assert!(cooldown.trigger().is_ok());
+        assert!(cooldown.ready().is_ok());
+        assert!(cooldown.trigger().is_ok());
+        assert_eq!(cooldown.remaining(), Duration::ZERO);
+        assert_eq!(cooldown.fraction_remaining(), 0.0);
+    }
+
+    #[test]
+    fn tick_has_no_effect_on_zero_duration_cooldown() {
+        let mut cooldown = Cooldown::from_secs(0.);
+        cooldown.trigger().unwrap();
+
+        let result = cooldown.tick(Duration::from_secs(1), None);
+        assert_eq!(result, CooldownTickResult::default());
+        assert_eq!(cooldown.elapsed(), Duration::ZERO);
+        assert!(cooldown.ready().is_ok());
+    }
+
+    #[test]
+    fn setting_max_time_to_zero_makes_cooldown_ready() {
+        let mut cooldown = Cooldown::from_secs(3.);
+        cooldown.trigger().unwrap();
+        assert!(cooldown.ready().is_err());
+
+        cooldown.set_max_time(Duration::ZERO);
+        assert!(cooldown.ready().is_ok());
+        assert!(cooldown.trigger().is_ok());
+        assert!(cooldown.ready().is_ok());
+    }
+
+    #[test]
+    fn tick_has_no_effect_on_fresh_cooldown() {
+        let cooldown = Cooldown::from_secs(1.);
+        let mut cloned_cooldown = cooldown.clone();
+        cloned_cooldown.tick(Duration::from_secs_f32(1.234), None);
+        assert_eq!(cooldown, cloned_cooldown);
+    }
+
+    #[test]
+    fn cooldowns_start_ready() {
+        let cooldown = Cooldown::from_secs(1.);
+        assert!(cooldown.ready().is_ok());
+    }
+
+    #[test]
+    fn cooldowns_are_ready_when_refreshed() {
+        let mut cooldown = Cooldown::from_secs(1.);
+        assert!(cooldown.ready().is_ok());
+        let _ = cooldown.trigger();
+        assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
+        cooldown.refresh();
+        assert!(cooldown.ready().is_ok());
+    }
+
+    #[test]
+    fn ticking_changes_cooldown() {
+        let cooldown = Cooldown::new(Duration::from_millis(1000));
+        let mut cloned_cooldown = cooldown.clone();
+        let _ = cloned_cooldown.trigger();
+        assert_ne!(cooldown, cloned_cooldown);
+
+        cloned_cooldown.tick(Duration::from_millis(123), None);
+        assert_ne!(cooldown, cloned_cooldown);
+    }
+
+    #[test]
+    fn cooldowns_reset_after_being_ticked() {
+        let mut cooldown = Cooldown::from_secs(1.);
+        let _ = cooldown.trigger();
+        assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
+
+        cooldown.tick(Duration::from_secs(3), None);
+        assert!(cooldown.ready().is_ok());
+    }
+
+    #[test]
+    fn time_remaining_on_fresh_cooldown_is_zero() {
+        let cooldown = Cooldown::from_secs(1.);
+        assert_eq!(cooldown.remaining(), Duration::ZERO);
+    }
+
+    #[test]
+    fn fresh_timer_converts_to_triggered_cooldown() {
+        let timer = Timer::new(Duration::from_secs(2), TimerMode::Once);
+        let cooldown = Cooldown::from(timer);
+
+        assert_eq!(cooldown.max_time(), Duration::from_secs(2));
+        assert_eq!(cooldown.elapsed(), Duration::ZERO);
+        assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
+    }
+
+    #[test]
+    fn partially_elapsed_timer_converts_to_partially_elapsed_cooldown() {
+        let mut timer = Timer::new(Duration::from_secs(2), TimerMode::Once);
+        timer.tick(Duration::from_millis(500));
+        let cooldown = Cooldown::from(timer);
+
+        assert_eq!(cooldown.elapsed(), Duration::from_millis(500));
+        assert_eq!(cooldown.remaining(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn just_finished_timer_converts_to_ready_cooldown() {
+        let mut timer = Timer::new(Duration::from_secs(2), TimerMode::Once);
+        timer.tick(Duration::from_secs(3));
+        assert!(timer.just_finished());
+
+        let cooldown = Cooldown::from(timer);
+        assert!(cooldown.ready().is_ok());
+        assert_eq!(cooldown.remaining(), Duration::ZERO);
+    }
+
+    #[test]
+    fn repeating_timer_wraps_elapsed_time() {
+        let mut timer = Timer::new(Duration::from_secs(2), TimerMode::Repeating);
+        timer.tick(Duration::from_millis(2500));
+        let cooldown = Cooldown::from(timer);
+
+        assert_eq!(cooldown.elapsed(), Duration::from_millis(500));
+        assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
+    }
+
+    #[test]
+    fn triggered_cooldown_converts_to_unfinished_timer() {
+        let mut cooldown = Cooldown::new(Duration::from_secs(3));
+        cooldown.trigger().unwrap();
+        cooldown.tick(Duration::from_secs(1), None);
+        let timer = cooldown.as_timer();
+
+        assert_eq!(timer.duration(), Duration::from_secs(3));
+        assert_eq!(timer.elapsed(), Duration::from_secs(1));
+        assert_eq!(timer.remaining(), cooldown.remaining());
+        assert_eq!(timer.mode(), TimerMode::Once);
+        assert!(!timer.finished());
+    }
+
+    #[test]
+    fn ready_cooldown_converts_to_finished_timer() {
+        let cooldown = Cooldown::new(Duration::from_secs(3));
+        let timer = cooldown.as_timer();
+
+        assert!(timer.finished());
+        assert!(timer.just_finished());
+        assert_eq!(timer.remaining(), Duration::ZERO);
+    }
+
+    #[test]
+    fn timer_round_trip_preserves_cooldown() {
+        let mut cooldown = Cooldown::new(Duration::from_secs(3));
+        cooldown.trigger().unwrap();
+        cooldown.tick(Duration::from_millis(1234), None);
+
+        assert_eq!(Cooldown::from(cooldown.as_timer()), cooldown);
+    }
+
+    #[test]
+    fn stopwatch_records_elapsed_time() {
+        let mut cooldown = Cooldown::new(Duration::from_secs(3));
+        cooldown.trigger().unwrap();
+        cooldown.tick(Duration::from_secs(2), None);
+
+        assert_eq!(cooldown.as_stopwatch().elapsed(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn durations_convert_to_ready_cooldowns() {
+        let cooldown = Cooldown::try_from(Duration::from_secs(1)).unwrap();
+        assert_eq!(cooldown, Cooldown::new(Duration::from_secs(1)));
+        assert!(cooldown.ready().is_ok());
+
+        assert_eq!(
+            Cooldown::try_from(Duration::ZERO),
+            Err(ZeroCooldownDuration)
+        );
+    }
+
+    #[test]
+    fn non_finite_seconds_do_not_panic() {
+        let cooldown = Cooldown::from_secs(f32::NAN);
+        assert_eq!(cooldown.max_time(), Duration::ZERO);
+        assert!(cooldown.ready().is_ok());
+
+        let mut cooldown = Cooldown::from_secs_triggered(f32::INFINITY);
+        assert_eq!(cooldown.max_time(), Duration::MAX);
+        cooldown.tick(Duration::from_secs(1), None);
+        assert!(cooldown.ready().is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_seconds_panic() {
+        let _ = Cooldown::from_secs(-1.);
+    }
+
+    #[test]
+    fn paused_cooldowns_do_not_tick() {
+        let mut cooldown = Cooldown::from_secs(3.);
+        cooldown.trigger().unwrap();
+        cooldown.tick(Duration::from_secs(1), None);
+        cooldown.pause();
+        assert!(cooldown.is_paused());
+
+        let paused_cooldown = cooldown.clone();
+        for _ in 0..10 {
+            cooldown.tick(Duration::from_secs(1), None);
+        }
+        assert_eq!(cooldown, paused_cooldown);
+        assert_eq!(cooldown.elapsed(), Duration::from_secs(1));
+
+        cooldown.unpause();
+        assert!(!cooldown.is_paused());
+        cooldown.tick(Duration::from_millis(500), None);
+        assert_eq!(cooldown.elapsed(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn paused_cooldowns_do_not_replenish_charges() {
+        let mut cooldown = Cooldown::from_secs(1.);
+        let mut charges = Charges::replenish_one(2);
+        charges.expend().unwrap();
+        cooldown.trigger().unwrap();
+        cooldown.pause();
+
+        cooldown.tick(Duration::from_secs(5), Some(&mut charges));
+        assert_eq!(charges.charges(), 1);
+
+        cooldown.unpause();
+        cooldown.tick(Duration::from_secs(1), Some(&mut charges));
+        assert_eq!(charges.charges(), 2);
+    }
+
+    #[test]
+    fn pause_all_does_not_pause_global_cooldown() {
+        let mut cooldowns = CooldownState::new([
+            (TestAbility::A, Cooldown::from_secs(2.)),
+            (TestAbility::B, Cooldown::from_secs(2.)),
+        ]);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(1.)));
+        cooldowns.trigger(&TestAbility::A).unwrap();
+
+        cooldowns.pause_all();
+        assert!(cooldowns.iter().all(Cooldown::is_paused));
+        assert!(!cooldowns.global_cooldown().unwrap().is_paused());
+
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert_eq!(cooldowns.gcd_ready(), Ok(()));
+        assert_eq!(
+            cooldowns.ready(&TestAbility::A),
+            Err(CannotUseAbility::OnCooldown)
+        );
+
+        cooldowns.unpause_all();
+        cooldowns.tick(Duration::from_secs(2), None);
+        assert_eq!(cooldowns.ready(&TestAbility::A), Ok(()));
+    }
+
+    #[test]
+    fn global_cooldown_can_be_paused_independently() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(1.))]);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(1.)));
+        cooldowns.trigger(&TestAbility::A).unwrap();
+
+        cooldowns.global_cooldown_mut().unwrap().pause();
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert_eq!(cooldowns.get(&TestAbility::A).unwrap().ready(), Ok(()));
+        assert_eq!(
+            cooldowns.gcd_ready(),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
+    }
+
+    #[test]
+    fn paused_cooldowns_convert_to_paused_timers() {
+        let mut cooldown = Cooldown::from_secs(1.);
+        cooldown.pause();
+        let timer = cooldown.as_timer();
+        assert!(timer.paused());
+        assert!(Cooldown::from(timer).is_paused());
+    }
+
+    #[test]
+    fn cooldown_rate_scales_tick() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(4.))]);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(4.)));
+        cooldowns.trigger(&TestAbility::A).unwrap();
+
+        cooldowns.set_cooldown_rate(2.0);
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert_eq!(
+            cooldowns.get(&TestAbility::A).unwrap().elapsed(),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            cooldowns.global_cooldown().unwrap().elapsed(),
+            Duration::from_secs(2)
+        );
+
+        cooldowns.set_cooldown_rate(0.5);
+        cooldowns.tick(Duration::from_secs(2), None);
+        assert_eq!(
+            cooldowns.get(&TestAbility::A).unwrap().elapsed(),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            cooldowns.global_cooldown().unwrap().elapsed(),
+            Duration::from_secs(3)
+        );
+    }
+
+    #[test]
+    fn zero_cooldown_rate_freezes_cooldowns() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(1.))]);
+        cooldowns.trigger(&TestAbility::A).unwrap();
+        cooldowns.set_cooldown_rate(0.0);
+
+        cooldowns.tick(Duration::from_secs(10), None);
+        assert_eq!(
+            cooldowns.get(&TestAbility::A).unwrap().elapsed(),
+            Duration::ZERO
+        );
+
+        cooldowns.set_cooldown_rate(-1.0);
+        cooldowns.tick(Duration::from_secs(10), None);
+        assert_eq!(
+            cooldowns.get(&TestAbility::A).unwrap().elapsed(),
+            Duration::ZERO
+        );
+
+        cooldowns.set_cooldown_rate(f32::NAN);
+        assert_eq!(cooldowns.cooldown_rate(), 0.0);
+        assert_eq!(cooldowns, cooldowns.clone());
+    }
+
+    #[test]
+    fn cooldown_rate_applies_to_charge_replenishment() {
+        fn charges_after_tick(cooldown_rate: f32, delta_time: Duration) -> u8 {
+            let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(1.))]);
+            let mut charges = ChargeState::new([(TestAbility::A, Charges::replenish_one(3))]);
+            for _ in 0..3 {
+                charges.expend(&TestAbility::A).unwrap();
+            }
+            cooldowns.trigger(&TestAbility::A).unwrap();
+
+            cooldowns.set_cooldown_rate(cooldown_rate);
+            cooldowns.tick(delta_time, Some(&mut charges));
+            charges.get(&TestAbility::A).unwrap().charges()
+        }
+
+        // 1.5 seconds at double speed completes the cooldown three times
+        assert_eq!(charges_after_tick(2.0, Duration::from_millis(1500)), 3);
+        // 2 seconds at half speed only completes it once
+        assert_eq!(charges_after_tick(0.5, Duration::from_secs(2)), 1);
+    }
+
+    #[test]
+    fn advance_reduces_remaining_time() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(3.))]);
+        cooldowns.trigger(&TestAbility::A).unwrap();
+
+        cooldowns.advance(&TestAbility::A, Duration::from_secs(1), None);
+        assert_eq!(
+            cooldowns.get(&TestAbility::A).unwrap().remaining(),
+            Duration::from_secs(2)
+        );
+    }
+
+    #[test]
+    fn advancing_past_zero_grants_charge() {
+        let mut cooldown = Cooldown::from_secs(3.);
+        let mut charges = Charges::replenish_one(2);
+        charges.expend().unwrap();
+        charges.expend().unwrap();
+        cooldown.trigger().unwrap();
+        cooldown.tick(Duration::from_secs(2), Some(&mut charges));
+        assert_eq!(charges.charges(), 0);
+
+        cooldown.advance(Duration::from_secs(2), Some(&mut charges));
+        assert_eq!(charges.charges(), 1);
+    }
+
+    #[test]
+    fn advance_matches_tick() {
+        let mut ticked = Cooldown::from_secs(2.);
+        let mut charges_ticked = Charges::replenish_one(3);
+        ticked.trigger().unwrap();
+        charges_ticked.set_charges(0);
+        let mut advanced = ticked.clone();
+        let mut charges_advanced = charges_ticked.clone();
+
+        ticked.tick(Duration::from_millis(4500), Some(&mut charges_ticked));
+        advanced.advance(Duration::from_millis(4500), Some(&mut charges_advanced));
+        assert_eq!(ticked, advanced);
+        assert_eq!(charges_ticked, charges_advanced);
+    }
+
+    #[test]
+    fn advancing_missing_cooldown_does_nothing() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(3.))]);
+        let unchanged = cooldowns.clone();
+
+        cooldowns.advance(&TestAbility::B, Duration::from_secs(1), None);
+        assert_eq!(cooldowns, unchanged);
+    }
+
+    #[test]
+    fn paused_cooldowns_can_be_advanced() {
+        let mut cooldown = Cooldown::from_secs(3.);
+        cooldown.trigger().unwrap();
+        cooldown.pause();
+
+        cooldown.advance(Duration::from_secs(1), None);
+        assert_eq!(cooldown.elapsed(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn ready_cooldowns_have_no_fraction_remaining() {
+        let cooldown = Cooldown::from_secs(1.);
+        assert_eq!(cooldown.fraction_remaining(), 0.0);
+        assert_eq!(cooldown.fraction_elapsed(), 1.0);
+    }
 
-        Ok(())
+    #[test]
+    fn triggered_cooldowns_have_full_fraction_remaining() {
+        let mut cooldown = Cooldown::from_secs(1.);
+        cooldown.trigger().unwrap();
+        assert_eq!(cooldown.fraction_remaining(), 1.0);
+        assert_eq!(cooldown.fraction_elapsed(), 0.0);
+
+        cooldown.tick(Duration::from_millis(250), None);
+        assert_eq!(cooldown.fraction_remaining(), 0.75);
+        assert_eq!(cooldown.fraction_elapsed(), 0.25);
     }
 
-    /// Returns the time that it will take for this action to be ready to use again after being triggered.
-    #[inline]
-    pub fn max_time(&self) -> Duration {
-        self.max_time
+    #[test]
+    fn default_cooldown_fraction_is_not_nan() {
+        let cooldown = Cooldown::default();
+        assert_eq!(cooldown.fraction_remaining(), 0.0);
+        assert_eq!(cooldown.fraction_elapsed(), 1.0);
     }
 
-    /// Sets the time that it will take for this action to be ready to use again after being triggered.
-    ///
-    /// If the current time remaining is greater than the new max time, it will be clamped to the `max_time`.
-    ///
-    /// # Panics
-    ///
-    /// The provided max time cannot be [`Duration::ZERO`].
-    /// Instead, use [`None`] in the [`Cooldowns`] struct for an action without a cooldown.
-    #[inline]
-    pub fn set_max_time(&mut self, max_time: Duration) {
-        assert!(max_time != Duration::ZERO);
+    #[test]
+    fn fraction_remaining_uses_larger_of_global_and_action_cooldown() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(4.))]);
+        assert_eq!(cooldowns.fraction_remaining(&TestAbility::A), Some(0.0));
+        assert_eq!(cooldowns.fraction_remaining(&TestAbility::B), None);
 
-        self.max_time = max_time;
-        self.elapsed_time = self.elapsed_time.min(max_time);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(1.)));
+        cooldowns.trigger(&TestAbility::A).unwrap();
+        cooldowns.tick(Duration::from_millis(500), None);
+
+        // The action cooldown has 3.5 of 4 seconds remaining, while the GCD has 0.5 of 1
+        assert_eq!(cooldowns.fraction_remaining(&TestAbility::A), Some(0.875));
+        // Actions without their own cooldown are still gated by the GCD
+        assert_eq!(cooldowns.fraction_remaining(&TestAbility::B), Some(0.5));
     }
 
-    /// Returns the time that has passed since the cooldown was triggered.
-    #[inline]
-    pub fn elapsed(&self) -> Duration {
-        self.elapsed_time
+    #[test]
+    fn refresh_all_includes_global_cooldown() {
+        let mut cooldowns = CooldownState::new([
+            (TestAbility::A, Cooldown::from_secs(1.)),
+            (TestAbility::B, Cooldown::from_secs(2.)),
+        ]);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(0.5)));
+        let mut charges = ChargeState::new([(TestAbility::B, Charges::simple(3))]);
+
+        cooldowns.trigger(&TestAbility::A).unwrap();
+        cooldowns.global_cooldown_mut().unwrap().refresh();
+        cooldowns.trigger(&TestAbility::B).unwrap();
+        charges.expend(&TestAbility::B).unwrap();
+        assert!(cooldowns.gcd_ready().is_err());
+
+        cooldowns.refresh_all(Some(&mut charges));
+
+        assert!(cooldowns.gcd_ready().is_ok());
+        assert!(cooldowns.ready(&TestAbility::A).is_ok());
+        assert!(cooldowns.ready(&TestAbility::B).is_ok());
+        assert_eq!(charges.get(&TestAbility::B).unwrap().charges(), 3);
     }
 
-    /// Sets the time that has passed since the cooldown was triggered.
-    ///
-    /// This will always be clamped between [`Duration::ZERO`] and the `max_time` of this cooldown.
-    #[inline]
-    pub fn set_elapsed(&mut self, elapsed_time: Duration) {
-        self.elapsed_time = elapsed_time.clamp(Duration::ZERO, self.max_time);
+    #[test]
+    fn trigger_all_includes_global_cooldown() {
+        let mut cooldowns = CooldownState::new([
+            (TestAbility::A, Cooldown::from_secs(1.)),
+            (TestAbility::B, Cooldown::from_secs(2.)),
+        ]);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(0.5)));
+        let mut charges = ChargeState::new([(TestAbility::B, Charges::simple(3))]);
+
+        // Triggering one action first puts the GCD on cooldown, which must not block trigger_all
+        cooldowns.trigger(&TestAbility::A).unwrap();
+        cooldowns.trigger_all(Some(&mut charges));
+
+        assert_eq!(
+            cooldowns.gcd_ready(),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
+        assert_eq!(
+            cooldowns.get(&TestAbility::A).unwrap().ready(),
+            Err(CannotUseAbility::OnCooldown)
+        );
+        assert_eq!(
+            cooldowns.get(&TestAbility::B).unwrap().ready(),
+            Err(CannotUseAbility::OnCooldown)
+        );
+        assert_eq!(charges.get(&TestAbility::B).unwrap().charges(), 0);
     }
 
-    /// Returns the time remaining until the next charge is ready.
-    ///
-    /// When a cooldown is fully charged, this will return [`Duration::ZERO`].
-    #[inline]
-    pub fn remaining(&self) -> Duration {
-        self.max_time.saturating_sub(self.elapsed_time)
+    #[test]
+    fn gcd_exempt_actions_ignore_global_cooldown() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(1.))]);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(1.)));
+        cooldowns.set_gcd_exempt(TestAbility::B, true);
+        assert!(cooldowns.is_gcd_exempt(&TestAbility::B));
+
+        cooldowns.trigger(&TestAbility::A).unwrap();
+        assert_eq!(
+            cooldowns.gcd_ready(),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
+
+        // The exempt action can be used while the GCD is running
+        assert_eq!(cooldowns.ready(&TestAbility::B), Ok(()));
+        assert_eq!(cooldowns.trigger(&TestAbility::B), Ok(()));
+
+        // And does not start the GCD
+        cooldowns.refresh_all(None);
+        cooldowns.trigger(&TestAbility::B).unwrap();
+        assert_eq!(cooldowns.gcd_ready(), Ok(()));
+        assert_eq!(cooldowns.ready(&TestAbility::A), Ok(()));
+
+        cooldowns.set_gcd_exempt(TestAbility::B, false);
+        cooldowns.trigger(&TestAbility::B).unwrap();
+        assert_eq!(
+            cooldowns.ready(&TestAbility::B),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
     }
 
-    /// Sets the time remaining until the next charge is ready.
-    ///
-    /// This will always be clamped between [`Duration::ZERO`] and the `max_time` of this cooldown.
-    #[inline]
-    pub fn set_remaining(&mut self, time_remaining: Duration) {
-        self.elapsed_time = self
-            .max_time
-            .saturating_sub(time_remaining.clamp(Duration::ZERO, self.max_time));
+    #[test]
+    fn abilitylike_respects_global_cooldown_and_exemptions() {
+        let mut charges = ChargeState::default();
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(1.))]);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(1.)));
+        cooldowns.set_gcd_exempt(TestAbility::B, true);
+
+        TestAbility::A
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        assert_eq!(
+            cooldowns.gcd_ready(),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
+
+        cooldowns.get_mut(&TestAbility::A).unwrap().refresh();
+        assert_eq!(
+            TestAbility::A.ready_no_costs(&charges, &cooldowns),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
+        assert_eq!(
+            TestAbility::A.trigger_no_costs(&mut charges, &mut cooldowns),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
+        // The failed trigger did not consume the action's cooldown
+        assert_eq!(cooldowns.get(&TestAbility::A).unwrap().ready(), Ok(()));
+
+        // The exempt action fires during the GCD, without restarting it
+        cooldowns
+            .global_cooldown_mut()
+            .unwrap()
+            .tick(Duration::from_millis(500), None);
+        assert_eq!(TestAbility::B.ready_no_costs(&charges, &cooldowns), Ok(()));
+        TestAbility::B
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        assert_eq!(
+            cooldowns.global_cooldown().unwrap().remaining(),
+            Duration::from_millis(500)
+        );
     }
-}
 
-impl Display for Cooldown {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?} / {:?}", self.elapsed_time, self.max_time)
+    #[test]
+    fn shared_cooldowns_block_actions_in_the_same_category() {
+        #[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+        enum Consumable {
+            HealthPotion,
+            ManaPotion,
+            BattleShout,
+            Bandage,
+        }
+
+        let mut cooldowns = CooldownState::default();
+        cooldowns
+            .set_shared_cooldown("potions", Cooldown::from_secs(1.))
+            .set_shared_cooldown("shouts", Cooldown::from_secs(2.))
+            .set_category(Consumable::HealthPotion, "potions")
+            .set_category(Consumable::ManaPotion, "potions")
+            .set_category(Consumable::BattleShout, "shouts");
+        assert_eq!(cooldowns.category(&Consumable::ManaPotion), Some("potions"));
+        assert_eq!(cooldowns.category(&Consumable::Bandage), None);
+
+        cooldowns.trigger(&Consumable::HealthPotion).unwrap();
+        assert_eq!(
+            cooldowns.ready(&Consumable::ManaPotion),
+            Err(CannotUseAbility::OnSharedCooldown)
+        );
+        assert_eq!(
+            cooldowns.fraction_remaining(&Consumable::ManaPotion),
+            Some(1.0)
+        );
+        // Actions in other categories, or without a category, are unaffected
+        assert_eq!(cooldowns.ready(&Consumable::BattleShout), Ok(()));
+        assert_eq!(cooldowns.ready(&Consumable::Bandage), Ok(()));
+        assert_eq!(cooldowns.fraction_remaining(&Consumable::Bandage), None);
+
+        cooldowns.trigger(&Consumable::BattleShout).unwrap();
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert_eq!(cooldowns.ready(&Consumable::ManaPotion), Ok(()));
+        assert_eq!(
+            cooldowns.ready(&Consumable::BattleShout),
+            Err(CannotUseAbility::OnSharedCooldown)
+        );
+
+        // Shared cooldowns are also respected by the Abilitylike methods
+        let mut charges = ChargeState::default();
+        Consumable::ManaPotion
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        assert_eq!(
+            Consumable::HealthPotion.ready_no_costs(&charges, &cooldowns),
+            Err(CannotUseAbility::OnSharedCooldown)
+        );
+
+        cooldowns.remove_category(&Consumable::HealthPotion);
+        assert_eq!(cooldowns.ready(&Consumable::HealthPotion), Ok(()));
     }
-}
 
-#[cfg(test)]
-mod tick_tests {
-    use super::*;
+    #[test]
+    fn set_max_time_preserving_fraction() {
+        // Shrinking
+        let mut cooldown = Cooldown::from_secs(2.);
+        cooldown.trigger().unwrap();
+        cooldown.tick(Duration::from_secs(1), None);
+        cooldown.set_max_time_preserving_fraction(Duration::from_secs(1));
+        assert_eq!(cooldown.fraction_remaining(), 0.5);
+        assert_eq!(cooldown.remaining(), Duration::from_millis(500));
+        assert!(cooldown.ready().is_err());
+
+        // Growing
+        let mut cooldown = Cooldown::from_secs(2.);
+        cooldown.trigger().unwrap();
+        cooldown.tick(Duration::from_millis(500), None);
+        cooldown.set_max_time_preserving_fraction(Duration::from_secs(4));
+        assert_eq!(cooldown.fraction_remaining(), 0.75);
+        assert_eq!(cooldown.remaining(), Duration::from_secs(3));
+
+        // Ready cooldowns stay ready
+        let mut cooldown = Cooldown::from_secs(2.);
+        cooldown.set_max_time_preserving_fraction(Duration::from_secs(5));
+        assert!(cooldown.ready().is_ok());
+    }
 
     #[test]
-    #[should_panic]
-    fn zero_duration_cooldown_cannot_be_constructed() {
-        Cooldown::new(Duration::ZERO);
+    fn removed_cooldowns_and_charges_are_always_ready() {
+        let mut cooldowns = CooldownState::new([
+            (TestAbility::A, Cooldown::from_secs(1.)),
+            (TestAbility::B, Cooldown::from_secs(2.)),
+        ]);
+        let mut charges = ChargeState::new([(TestAbility::A, Charges::simple(1))]);
+        charges.expend(&TestAbility::A).unwrap();
+        cooldowns.trigger(&TestAbility::A).unwrap();
+        cooldowns.trigger(&TestAbility::B).unwrap();
+
+        // The in-flight cooldown is returned, so that it can be restored later
+        let stashed = cooldowns.remove(&TestAbility::A).unwrap();
+        assert!(stashed.ready().is_err());
+        assert_eq!(cooldowns.remove(&TestAbility::A), None);
+        assert_eq!(cooldowns.ready(&TestAbility::A), Ok(()));
+        assert_eq!(
+            TestAbility::A.ready_no_costs(&charges, &cooldowns),
+            Err(CannotUseAbility::NoCharges)
+        );
+
+        assert_eq!(charges.remove(&TestAbility::A).unwrap().charges(), 0);
+        assert_eq!(TestAbility::A.ready_no_costs(&charges, &cooldowns), Ok(()));
+
+        cooldowns.set(TestAbility::A, stashed);
+        cooldowns.retain(|action, _| *action == TestAbility::B);
+        assert_eq!(
+            cooldowns.actions().collect::<Vec<_>>(),
+            vec![&TestAbility::B]
+        );
+        assert_eq!(TestAbility::A.ready_no_costs(&charges, &cooldowns), Ok(()));
+
+        cooldowns.clear();
+        assert_eq!(cooldowns.ready(&TestAbility::B), Ok(()));
+        assert_eq!(TestAbility::B.ready_no_costs(&charges, &cooldowns), Ok(()));
     }
 
     #[test]
-    fn tick_has_no_effect_on_fresh_cooldown() {
-        let cooldown = Cooldown::from_secs(1.);
-        let mut cloned_cooldown = cooldown.clone();
-        cloned_cooldown.tick(Duration::from_secs_f32(1.234), None);
-        assert_eq!(cooldown, cloned_cooldown);
+    fn triggered_cooldowns_start_on_cooldown() {
+        let mut cooldown = Cooldown::from_secs_triggered(1.);
+        assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
+        cooldown.tick(Duration::from_millis(999), None);
+        assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
+        cooldown.tick(Duration::from_millis(1), None);
+        assert_eq!(cooldown.ready(), Ok(()));
+
+        let mut cooldowns = CooldownState::new_triggered([
+            (TestAbility::A, Cooldown::new(Duration::from_secs(1))),
+            (
+                TestAbility::B,
+                Cooldown::new_triggered(Duration::from_secs(2)),
+            ),
+        ]);
+        assert_eq!(
+            cooldowns.ready(&TestAbility::A),
+            Err(CannotUseAbility::OnCooldown)
+        );
+        assert_eq!(
+            cooldowns.ready(&TestAbility::B),
+            Err(CannotUseAbility::OnCooldown)
+        );
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert_eq!(cooldowns.ready(&TestAbility::A), Ok(()));
+        assert_eq!(
+            cooldowns.ready(&TestAbility::B),
+            Err(CannotUseAbility::OnCooldown)
+        );
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert_eq!(cooldowns.ready(&TestAbility::B), Ok(()));
     }
 
     #[test]
-    fn cooldowns_start_ready() {
-        let cooldown = Cooldown::from_secs(1.);
+    fn jittered_cooldowns_roll_durations_within_bounds() {
+        let max_time = Duration::from_secs(2);
+        let jitter = Duration::from_millis(500);
+        let mut cooldown = Cooldown::with_jitter(max_time, jitter, 42);
+        assert_eq!(cooldown.max_time(), max_time);
+        assert_eq!(cooldown.jitter(), jitter);
         assert!(cooldown.ready().is_ok());
+
+        let mut durations = Vec::new();
+        for _ in 0..8 {
+            cooldown.trigger().unwrap();
+            let duration = cooldown.current_max_time();
+            assert!(duration >= max_time - jitter);
+            assert!(duration <= max_time + jitter);
+            // The nominal duration is unchanged, while the remaining time uses the rolled duration
+            assert_eq!(cooldown.max_time(), max_time);
+            assert_eq!(cooldown.remaining(), duration);
+
+            cooldown.tick(duration - Duration::from_nanos(1), None);
+            assert!(cooldown.ready().is_err());
+            cooldown.tick(Duration::from_nanos(1), None);
+            assert!(cooldown.ready().is_ok());
+
+            durations.push(duration);
+        }
+        assert_ne!(durations[0], durations[1]);
+
+        // The same seed produces the same durations
+        let mut replay = Cooldown::with_jitter(max_time, jitter, 42);
+        for duration in durations {
+            replay.trigger().unwrap();
+            assert_eq!(replay.current_max_time(), duration);
+            replay.refresh();
+        }
     }
 
     #[test]
-    fn cooldowns_are_ready_when_refreshed() {
+    fn excessive_jitter_is_clamped() {
+        let max_time = Duration::from_secs(1);
+        let mut cooldown = Cooldown::with_jitter(max_time, Duration::from_secs(5), 3);
+        assert_eq!(cooldown.jitter(), max_time);
+
+        for _ in 0..8 {
+            cooldown.trigger().unwrap();
+            assert!(cooldown.current_max_time() <= max_time * 2);
+            cooldown.refresh();
+        }
+    }
+
+    #[test]
+    fn trigger_all_rolls_jitter_like_trigger() {
+        let cooldown =
+            Cooldown::with_jitter(Duration::from_secs(2), Duration::from_millis(500), 42);
+        let mut cooldowns = CooldownState::new([(TestAbility::A, cooldown.clone())]);
+        cooldowns.set_global_cooldown(Some(cooldown.clone()));
+
+        let mut triggered = cooldown;
+        triggered.trigger().unwrap();
+        assert_ne!(triggered.current_max_time(), triggered.max_time());
+
+        cooldowns.trigger_all(None);
+        assert_eq!(cooldowns.get(&TestAbility::A), Some(&triggered));
+        assert_eq!(cooldowns.global_cooldown(), Some(&triggered));
+    }
+
+    #[test]
+    fn changing_max_time_keeps_jitter_roll() {
+        let max_time = Duration::from_secs(1);
+        let jitter = Duration::from_millis(300);
+        let mut cooldown = Cooldown::with_jitter(max_time, jitter, 7);
+
+        for _ in 0..8 {
+            cooldown.trigger().unwrap();
+            let rolled = cooldown.current_max_time();
+            cooldown.tick(rolled, None);
+            assert!(cooldown.ready().is_ok());
+
+            // The roll is kept as an offset from the new max time
+            let mut shorter = cooldown.clone();
+            shorter.set_max_time(Duration::from_millis(900));
+            assert_eq!(
+                shorter.current_max_time() + Duration::from_millis(100),
+                rolled
+            );
+            assert!(shorter.ready().is_ok());
+
+            let mut hasted = cooldown.clone();
+            hasted.set_max_time_preserving_fraction(Duration::from_secs(2));
+            assert!(hasted.ready().is_ok());
+
+            // Shrinking the max time also shrinks the allowed jitter
+            let mut tiny = cooldown.clone();
+            tiny.set_max_time(Duration::from_millis(100));
+            assert!(tiny.current_max_time() < Duration::from_millis(200));
+            assert!(tiny.ready().is_ok());
+        }
+    }
+
+    #[test]
+    fn tick_reports_completions() {
+        let mut cooldown = Cooldown::from_secs(1.);
+        let mut charges = Charges::replenish_one(5);
+        charges.set_charges(0);
+        cooldown.trigger().unwrap();
+
+        let result = cooldown.tick(Duration::from_secs_f32(3.5), Some(&mut charges));
+        assert_eq!(result.completions, 3);
+        assert_eq!(charges.charges(), 3);
+
+        let mut cooldown = Cooldown::from_secs(1.);
+        cooldown.trigger().unwrap();
+        let result = cooldown.tick(Duration::from_millis(500), None);
+        assert_eq!(result, CooldownTickResult::default());
+        let result = cooldown.tick(Duration::from_secs(2), None);
+        assert_eq!(
+            result,
+            CooldownTickResult {
+                completions: 1,
+                just_finished: true
+            }
+        );
+
+        // Ready cooldowns do not complete again
+        let result = cooldown.tick(Duration::from_secs(2), None);
+        assert_eq!(result.completions, 0);
+        assert!(!result.just_finished);
+    }
+
+    #[test]
+    fn cooldown_state_tick_reports_completed_actions() {
+        let mut cooldowns = CooldownState::new([
+            (TestAbility::A, Cooldown::from_secs(1.)),
+            (TestAbility::B, Cooldown::from_secs(2.)),
+        ]);
+        cooldowns.trigger(&TestAbility::A).unwrap();
+        cooldowns.trigger(&TestAbility::B).unwrap();
+
+        let results = cooldowns.tick(Duration::from_millis(1500), None);
+        assert_eq!(
+            results,
+            vec![(
+                TestAbility::A,
+                CooldownTickResult {
+                    completions: 1,
+                    just_finished: true
+                }
+            )]
+        );
+        assert!(cooldowns.tick(Duration::ZERO, None).is_empty());
+    }
+
+    #[test]
+    fn ignore_strategy_leaves_charges_untouched() {
         let mut cooldown = Cooldown::from_secs(1.);
+        let mut charges = Charges::simple(3);
+        charges.set_charges(0);
+        cooldown.trigger().unwrap();
+
+        let result = cooldown.tick(Duration::from_millis(3500), Some(&mut charges));
+        assert_eq!(charges.charges(), 0);
+        assert_eq!(result.completions, 1);
         assert!(cooldown.ready().is_ok());
-        let _ = cooldown.trigger();
-        assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
-        cooldown.refresh();
+    }
+
+    #[test]
+    fn constantly_refresh_strategy_replenishes_each_completion() {
+        let mut cooldown = Cooldown::from_secs(1.);
+        let mut charges = Charges::replenish_one(5);
+        charges.set_charges(0);
+        cooldown.trigger().unwrap();
+
+        // Excess time carries over into the next cycle
+        let result = cooldown.tick(Duration::from_millis(2500), Some(&mut charges));
+        assert_eq!(result.completions, 2);
+        assert_eq!(charges.charges(), 2);
+        assert_eq!(cooldown.elapsed(), Duration::from_millis(500));
+        assert!(cooldown.ready().is_err());
+
+        // Completions beyond the max charges are discarded, and the cooldown stops
+        let result = cooldown.tick(Duration::from_secs(10), Some(&mut charges));
+        assert_eq!(result.completions, 3);
+        assert!(result.just_finished);
+        assert_eq!(charges.charges(), 5);
         assert!(cooldown.ready().is_ok());
     }
 
     #[test]
-    fn ticking_changes_cooldown() {
-        let cooldown = Cooldown::new(Duration::from_millis(1000));
-        let mut cloned_cooldown = cooldown.clone();
-        let _ = cloned_cooldown.trigger();
-        assert_ne!(cooldown, cloned_cooldown);
+    fn refresh_when_empty_strategy_only_replenishes_empty_charges() {
+        // All at once, as recommended
+        let mut cooldown = Cooldown::from_secs(1.);
+        let mut charges = Charges::replenish_all(6);
+        charges.set_charges(0);
+        cooldown.trigger().unwrap();
 
-        cloned_cooldown.tick(Duration::from_millis(123), None);
-        assert_ne!(cooldown, cloned_cooldown);
+        let result = cooldown.tick(Duration::from_millis(3500), Some(&mut charges));
+        assert_eq!(result.completions, 1);
+        assert_eq!(charges.charges(), 6);
+        assert!(cooldown.ready().is_ok());
+
+        // One at a time only ever recovers a single charge per tick
+        let mut cooldown = Cooldown::from_secs(1.);
+        let mut charges = Charges::new(
+            6,
+            ReplenishStrategy::OneAtATime,
+            CooldownStrategy::RefreshWhenEmpty,
+        );
+        charges.set_charges(0);
+        cooldown.trigger().unwrap();
+
+        cooldown.tick(Duration::from_millis(3500), Some(&mut charges));
+        assert_eq!(charges.charges(), 1);
+        assert!(cooldown.ready().is_ok());
+
+        // Charges that are not empty are not replenished
+        let mut cooldown = Cooldown::from_secs(1.);
+        let mut charges = Charges::replenish_all(6);
+        charges.set_charges(2);
+        cooldown.trigger().unwrap();
+
+        let result = cooldown.tick(Duration::from_secs(2), Some(&mut charges));
+        assert_eq!(result.completions, 1);
+        assert_eq!(charges.charges(), 2);
     }
 
     #[test]
-    fn cooldowns_reset_after_being_ticked() {
+    fn time_until_ready_accounts_for_global_cooldown() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(3.))]);
+        assert_eq!(cooldowns.time_until_ready(&TestAbility::A), Duration::ZERO);
+        assert_eq!(cooldowns.time_until_ready(&TestAbility::B), Duration::ZERO);
+
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(1.)));
+        cooldowns.trigger(&TestAbility::B).unwrap();
+        // No cooldown, but an active GCD
+        assert_eq!(
+            cooldowns.time_until_ready(&TestAbility::B),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            cooldowns.time_until_ready(&TestAbility::A),
+            Duration::from_secs(1)
+        );
+
+        cooldowns.tick(Duration::from_secs(1), None);
+        cooldowns.trigger(&TestAbility::A).unwrap();
+        cooldowns.tick(Duration::from_millis(500), None);
+        // The cooldown is longer than the GCD
+        assert_eq!(
+            cooldowns.time_until_ready(&TestAbility::A),
+            Duration::from_millis(2500)
+        );
+
+        cooldowns.refresh_all(None);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(5.)));
+        cooldowns.trigger(&TestAbility::A).unwrap();
+        // The GCD is longer than the cooldown
+        assert_eq!(
+            cooldowns.time_until_ready(&TestAbility::A),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn just_finished_is_true_for_exactly_one_tick() {
         let mut cooldown = Cooldown::from_secs(1.);
-        let _ = cooldown.trigger();
-        assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
+        cooldown.tick(Duration::from_secs(1), None);
+        assert!(!cooldown.just_finished());
+
+        cooldown.trigger().unwrap();
+        cooldown.tick(Duration::from_millis(500), None);
+        assert!(!cooldown.just_finished());
 
+        // Overshooting the cooldown still only sets the flag once
         cooldown.tick(Duration::from_secs(3), None);
+        assert!(cooldown.just_finished());
+
+        cooldown.tick(Duration::from_secs(1), None);
+        assert!(!cooldown.just_finished());
+    }
+
+    #[test]
+    fn just_became_ready_requires_global_cooldown() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(1.))]);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(2.)));
+        cooldowns.trigger(&TestAbility::A).unwrap();
+
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert!(cooldowns.get(&TestAbility::A).unwrap().just_finished());
+        assert!(!cooldowns.just_became_ready(&TestAbility::A));
+
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert!(!cooldowns.just_became_ready(&TestAbility::A));
+        assert!(!cooldowns.just_became_ready(&TestAbility::B));
+
+        cooldowns.set_global_cooldown(None);
+        cooldowns.trigger(&TestAbility::A).unwrap();
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert!(cooldowns.just_became_ready(&TestAbility::A));
+    }
+
+    #[test]
+    fn trigger_gcd_blocks_actions_without_triggering_their_cooldowns() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(1.))]);
+        cooldowns.set_global_cooldown(Some(Cooldown::from_secs(2.)));
+
+        assert!(cooldowns.trigger_gcd().is_ok());
+        assert_eq!(
+            cooldowns.trigger_gcd(),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
+        assert_eq!(
+            cooldowns.ready(&TestAbility::A),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
+        assert_eq!(
+            cooldowns.ready(&TestAbility::B),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
+        assert!(cooldowns.get(&TestAbility::A).unwrap().ready().is_ok());
+
+        cooldowns.refresh_gcd();
+        assert!(cooldowns.ready(&TestAbility::A).is_ok());
+        assert!(cooldowns.ready(&TestAbility::B).is_ok());
+    }
+
+    #[test]
+    fn turn_based_cooldowns_advance_in_turns() {
+        let mut cooldown = Cooldown::from_turns(3);
+        assert_eq!(cooldown.remaining_turns(), 0);
+
+        cooldown.trigger().unwrap();
+        assert_eq!(cooldown.remaining_turns(), 3);
+
+        cooldown.tick_turns(1, None);
+        assert_eq!(cooldown.remaining_turns(), 2);
+        assert_eq!(cooldown.ready(), Err(CannotUseAbility::OnCooldown));
+
+        let result = cooldown.tick_turns(5, None);
+        assert!(result.just_finished);
+        assert_eq!(cooldown.remaining_turns(), 0);
         assert!(cooldown.ready().is_ok());
     }
 
     #[test]
-    fn time_remaining_on_fresh_cooldown_is_zero() {
-        let cooldown = Cooldown::from_secs(1.);
-        assert_eq!(cooldown.remaining(), Duration::ZERO);
+    fn multiple_turns_replenish_charges() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_turns(2))]);
+        let mut charges = ChargeState::default();
+        charges.set(TestAbility::A, Charges::replenish_one(3));
+
+        for _ in 0..3 {
+            TestAbility::A
+                .trigger_no_costs(&mut charges, &mut cooldowns)
+                .unwrap();
+        }
+        assert_eq!(charges.get(&TestAbility::A).unwrap().charges(), 0);
+
+        cooldowns.tick_turns(1, Some(&mut charges));
+        assert_eq!(charges.get(&TestAbility::A).unwrap().charges(), 0);
+
+        // Along with the previous turn, five more turns complete all three cycles
+        cooldowns.tick_turns(5, Some(&mut charges));
+        assert_eq!(charges.get(&TestAbility::A).unwrap().charges(), 3);
+        assert!(cooldowns.get(&TestAbility::A).unwrap().ready().is_ok());
+    }
+
+    #[test]
+    fn tick_only_leaves_other_actions_untouched() {
+        let mut cooldowns = CooldownState::new([
+            (TestAbility::A, Cooldown::from_secs(3.)),
+            (TestAbility::B, Cooldown::from_secs(3.)),
+        ])
+        .with_global_cooldown(Cooldown::from_secs(1.));
+        let mut charges = ChargeState::default();
+        charges.set(TestAbility::A, Charges::replenish_one(2));
+
+        TestAbility::A
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        cooldowns
+            .get_mut(&TestAbility::B)
+            .unwrap()
+            .trigger()
+            .unwrap();
+
+        let results = cooldowns.tick_only(
+            [&TestAbility::A],
+            Duration::from_secs(3),
+            Some(&mut charges),
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, TestAbility::A);
+        assert!(cooldowns.get(&TestAbility::A).unwrap().ready().is_ok());
+        assert_eq!(charges.get(&TestAbility::A).unwrap().charges(), 2);
+        assert_eq!(
+            cooldowns.get(&TestAbility::B).unwrap().remaining(),
+            Duration::from_secs(3)
+        );
+        assert!(cooldowns.gcd_ready().is_ok());
+    }
+
+    #[test]
+    fn cooldowns_round_trip_through_iterators() {
+        let mut cooldowns: CooldownState<TestAbility> = [
+            (TestAbility::A, Cooldown::from_secs(1.)),
+            (TestAbility::B, Cooldown::from_secs(2.)),
+        ]
+        .into_iter()
+        .collect();
+        cooldowns.trigger(&TestAbility::B).unwrap();
+        cooldowns.tick(Duration::from_millis(500), None);
+
+        let borrowed: CooldownState<TestAbility> = (&cooldowns)
+            .into_iter()
+            .map(|(action, cooldown)| (*action, cooldown.clone()))
+            .collect();
+        assert_eq!(borrowed, cooldowns);
+
+        let round_tripped: CooldownState<TestAbility> = cooldowns.clone().into_iter().collect();
+        assert_eq!(round_tripped, cooldowns);
+        assert_eq!(
+            round_tripped.get(&TestAbility::B).unwrap().elapsed(),
+            Duration::from_millis(500)
+        );
+
+        let mut extended = round_tripped;
+        extended.extend([(TestAbility::A, Cooldown::from_secs(5.))]);
+        assert_eq!(
+            extended.get(&TestAbility::A).unwrap().max_time(),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn charges_round_trip_through_iterators() {
+        let mut charges: ChargeState<TestAbility> = [
+            (TestAbility::A, Charges::simple(2)),
+            (TestAbility::B, Charges::replenish_all(3)),
+        ]
+        .into_iter()
+        .collect();
+        charges.get_mut(&TestAbility::B).unwrap().expend().unwrap();
+
+        let round_tripped: ChargeState<TestAbility> = charges.clone().into_iter().collect();
+        assert_eq!(round_tripped, charges);
+        assert_eq!((&round_tripped).into_iter().count(), 2);
+
+        let mut extended = round_tripped;
+        extended.extend([(TestAbility::B, Charges::simple(1))]);
+        assert_eq!(extended.get(&TestAbility::B), Some(&Charges::simple(1)));
+    }
+
+    #[test]
+    fn linked_actions_share_a_cooldown() {
+        let mut cooldowns = CooldownState::new([(TestAbility::C, Cooldown::from_secs(5.))]);
+        cooldowns.link([TestAbility::A, TestAbility::B], Cooldown::from_secs(2.));
+
+        cooldowns.trigger(&TestAbility::B).unwrap();
+        assert_eq!(
+            cooldowns.ready(&TestAbility::A),
+            Err(CannotUseAbility::OnCooldown)
+        );
+        assert_eq!(
+            cooldowns.ready(&TestAbility::B),
+            Err(CannotUseAbility::OnCooldown)
+        );
+        assert!(cooldowns.ready(&TestAbility::C).is_ok());
+
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert_eq!(
+            cooldowns.get(&TestAbility::A),
+            cooldowns.get(&TestAbility::B)
+        );
+
+        let results = cooldowns.tick(Duration::from_secs(1), None);
+        assert!(cooldowns.ready(&TestAbility::A).is_ok());
+        assert!(cooldowns.ready(&TestAbility::B).is_ok());
+        assert_eq!(results.len(), 2);
+        assert_eq!(cooldowns.actions().count(), 3);
+    }
+
+    #[test]
+    fn removing_a_linked_action_only_unlinks_it() {
+        let mut cooldowns = CooldownState::default();
+        cooldowns.link(
+            [TestAbility::A, TestAbility::B, TestAbility::C],
+            Cooldown::from_secs(2.),
+        );
+        cooldowns.trigger(&TestAbility::C).unwrap();
+
+        let removed = cooldowns.remove(&TestAbility::A).unwrap();
+        assert_eq!(removed.remaining(), Duration::from_secs(2));
+        assert!(cooldowns.get(&TestAbility::A).is_none());
+        assert!(cooldowns.ready(&TestAbility::A).is_ok());
+
+        // The remaining actions are still linked
+        cooldowns.tick(Duration::from_secs(2), None);
+        cooldowns.trigger(&TestAbility::B).unwrap();
+        assert_eq!(
+            cooldowns.ready(&TestAbility::C),
+            Err(CannotUseAbility::OnCooldown)
+        );
+
+        cooldowns.set(TestAbility::B, Cooldown::from_secs(1.));
+        assert!(cooldowns.ready(&TestAbility::B).is_ok());
+        assert_eq!(
+            cooldowns.ready(&TestAbility::C),
+            Err(CannotUseAbility::OnCooldown)
+        );
+    }
+
+    #[test]
+    fn consuming_builders_match_mutable_builders() {
+        let built = CooldownState::default()
+            .set(TestAbility::A, Cooldown::from_secs(1.))
+            .set(TestAbility::B, Cooldown::from_secs(2.))
+            .build();
+        let consumed = CooldownState::default()
+            .with(TestAbility::A, Cooldown::from_secs(1.))
+            .with(TestAbility::B, Cooldown::from_secs(2.));
+        assert_eq!(built, consumed);
+
+        let built = ChargeState::default()
+            .set(TestAbility::A, Charges::replenish_all(2))
+            .set(TestAbility::C, Charges::replenish_one(3))
+            .build();
+        let consumed = ChargeState::default()
+            .with(TestAbility::A, Charges::replenish_all(2))
+            .with(TestAbility::C, Charges::replenish_one(3));
+        assert_eq!(built, consumed);
+    }
+
+    #[test]
+    fn charge_recharge_fraction_follows_the_cooldown() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(4.))]);
+        let mut charges = ChargeState::new([(TestAbility::A, Charges::replenish_one(2))]);
+        assert_eq!(
+            cooldowns.charge_recharge_fraction(&TestAbility::B, &charges),
+            None
+        );
+        assert_eq!(
+            cooldowns.charge_recharge_fraction(&TestAbility::A, &charges),
+            Some(0.0)
+        );
+
+        TestAbility::A
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        cooldowns.tick(Duration::from_secs(1), Some(&mut charges));
+        assert_eq!(
+            cooldowns.charge_recharge_fraction(&TestAbility::A, &charges),
+            Some(0.25)
+        );
+
+        cooldowns.tick(Duration::from_secs(3), Some(&mut charges));
+        assert_eq!(
+            cooldowns.charge_recharge_fraction(&TestAbility::A, &charges),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn charge_recharge_fraction_waits_until_empty() {
+        let mut cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(4.))]);
+        let mut charges = ChargeState::new([(TestAbility::A, Charges::replenish_all(2))]);
+
+        TestAbility::A
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        cooldowns.tick(Duration::from_secs(1), Some(&mut charges));
+        // A charge remains, so the cooldown has not started
+        assert_eq!(
+            cooldowns.charge_recharge_fraction(&TestAbility::A, &charges),
+            Some(0.0)
+        );
+
+        TestAbility::A
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        cooldowns.tick(Duration::from_secs(2), Some(&mut charges));
+        assert_eq!(
+            cooldowns.charge_recharge_fraction(&TestAbility::A, &charges),
+            Some(0.5)
+        );
+    }
+
+    #[test]
+    fn charge_recharge_fraction_uses_recharge_timers() {
+        let cooldowns = CooldownState::new([(TestAbility::A, Cooldown::from_secs(4.))]);
+        let mut charges = ChargeState::new([(
+            TestAbility::A,
+            Charges::simple(2).with_recharge_time(Duration::from_secs(10)),
+        )]);
+
+        // Charges that ignore cooldowns are only recharged by their own timer
+        charges.expend(&TestAbility::A).unwrap();
+        charges.tick(Duration::from_secs(3));
+        assert_eq!(
+            cooldowns.charge_recharge_fraction(&TestAbility::A, &charges),
+            Some(0.3)
+        );
+    }
+
+    #[test]
+    fn dense_cooldowns_match_sparse_cooldowns() {
+        let mut sparse = CooldownState::default();
+        let mut dense = CooldownState::dense();
+        assert!(!sparse.is_dense());
+        assert!(dense.is_dense());
+
+        for cooldowns in [&mut sparse, &mut dense] {
+            cooldowns
+                .set(TestAbility::C, Cooldown::from_secs(3.))
+                .set(TestAbility::A, Cooldown::from_secs(1.));
+            cooldowns.link([TestAbility::A, TestAbility::B], Cooldown::from_secs(2.));
+            cooldowns.trigger(&TestAbility::B).unwrap();
+            cooldowns.trigger(&TestAbility::C).unwrap();
+        }
+        assert_eq!(sparse.snapshot(), dense.snapshot());
+        assert_eq!(dense.iter().count(), 2);
+        assert_eq!(dense.actions().count(), 3);
+
+        let mut sparse_results = sparse.tick(Duration::from_secs(2), None);
+        let mut dense_results = dense.tick(Duration::from_secs(2), None);
+        sort_by_action(&mut sparse_results);
+        sort_by_action(&mut dense_results);
+        assert_eq!(sparse_results, dense_results);
+        assert!(dense.ready(&TestAbility::A).is_ok());
+        assert!(dense.ready(&TestAbility::C).is_err());
+
+        // Removing the owner of a linked group moves its cooldown to another action
+        assert!(dense.remove(&TestAbility::A).is_some());
+        assert!(dense.get(&TestAbility::A).is_none());
+        assert!(dense.get(&TestAbility::B).is_some());
+
+        let snapshot = sparse.snapshot();
+        dense.retain(|action, _| *action == TestAbility::B);
+        dense.restore(&snapshot);
+        assert_eq!(dense.snapshot(), snapshot);
+
+        dense.clear();
+        assert_eq!(dense.iter().count(), 0);
+        assert_eq!(dense, CooldownState::dense());
+    }
+
+    #[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    enum RankedAbility {
+        Bolt(u8),
+        Shield,
+    }
+
+    #[test]
+    fn dense_cooldowns_store_actions_sharing_an_index() {
+        let mut cooldowns = CooldownState::dense()
+            .with(RankedAbility::Bolt(1), Cooldown::from_secs(1.))
+            .with(RankedAbility::Bolt(2), Cooldown::from_secs(2.))
+            .with(RankedAbility::Shield, Cooldown::from_secs(3.));
+
+        let max_time = |cooldowns: &CooldownState<RankedAbility>, action| {
+            cooldowns.get(&action).map(Cooldown::max_time)
+        };
+        assert_eq!(
+            max_time(&cooldowns, RankedAbility::Bolt(1)),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            max_time(&cooldowns, RankedAbility::Bolt(2)),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(max_time(&cooldowns, RankedAbility::Bolt(3)), None);
+        assert_eq!(cooldowns.iter().count(), 3);
+
+        // Freeing the slot lets the action that overflowed move into it
+        cooldowns.remove(&RankedAbility::Bolt(1));
+        cooldowns.set(RankedAbility::Bolt(2), Cooldown::from_secs(4.));
+        assert_eq!(cooldowns.iter().count(), 2);
+        assert_eq!(
+            max_time(&cooldowns, RankedAbility::Bolt(2)),
+            Some(Duration::from_secs(4))
+        );
     }
 }
@@ -0,0 +1,247 @@
+//! Abilities that exist but cannot currently be used, such as while silenced or before they are unlocked.
+//!
+//! Unlike cooldowns, disabled abilities do not recover on their own:
+//! they stay disabled until they are explicitly re-enabled.
+
+use crate::{
+    tags::{AbilityTag, AbilityTags},
+    Abilitylike, CannotUseAbility,
+};
+use bevy::{ecs::prelude::*, reflect::Reflect};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The abilities of type `A` that are currently disabled.
+///
+/// Disabled abilities are never ready: [`Abilitylike::ready_modified`] and [`Abilitylike::trigger_modified`]
+/// return [`CannotUseAbility::Disabled`] for them without consuming anything.
+/// [`AbilityState`](crate::AbilityState) fetches this component optionally,
+/// so entities without it can use all of their abilities.
+///
+/// Individual actions can be disabled with [`DisabledAbilities::disable`],
+/// or every action at once (such as for a silence) with [`DisabledAbilities::set_all`].
+#[derive(Component, Resource, Debug, Clone, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Resource)]
+pub struct DisabledAbilities<A: Abilitylike> {
+    /// The actions that have been disabled individually.
+    actions: HashSet<A>,
+    /// Are all actions disabled, regardless of which ones are in `actions`?
+    all: bool,
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Abilitylike> Default for DisabledAbilities<A> {
+    fn default() -> Self {
+        DisabledAbilities {
+            actions: HashSet::new(),
+            all: false,
+        }
+    }
+}
+
+impl<A: Abilitylike> FromIterator<A> for DisabledAbilities<A> {
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        DisabledAbilities {
+            actions: iter.into_iter().collect(),
+            all: false,
+        }
+    }
+}
+
+impl<A: Abilitylike> DisabledAbilities<A> {
+    /// Creates a new [`DisabledAbilities`], with each of the provided `actions` disabled.
+    #[must_use]
+    pub fn new(actions: impl IntoIterator<Item = A>) -> Self {
+        actions.into_iter().collect()
+    }
+
+    /// Is `action` disabled, either individually or because all actions are disabled?
+    #[inline]
+    #[must_use]
+    pub fn is_disabled(&self, action: &A) -> bool {
+        self.all || self.actions.contains(action)
+    }
+
+    /// Returns [`CannotUseAbility::Disabled`] if `action` is disabled, and [`Ok(())`] otherwise.
+    #[inline]
+    pub fn enabled(&self, action: &A) -> Result<(), CannotUseAbility> {
+        if self.is_disabled(action) {
+            Err(CannotUseAbility::Disabled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Disables `action`.
+    #[inline]
+    pub fn disable(&mut self, action: A) -> &mut Self {
+        self.actions.insert(action);
+        self
+    }
+
+    /// Re-enables `action`, if it was disabled individually.
+    ///
+    /// Returns `true` if `action` was disabled individually.
+    /// If all actions are disabled, `action` remains disabled until [`DisabledAbilities::set_all`] is called with `false`.
+    #[inline]
+    pub fn enable(&mut self, action: &A) -> bool {
+        self.actions.remove(action)
+    }
+
+    /// Disables every action with `tag` in `tags`, such as when a disarm disables all Weapon abilities.
+    #[inline]
+    pub fn disable_tagged<T: AbilityTag>(&mut self, tags: &AbilityTags<A, T>, tag: T) -> &mut Self {
+        self.actions.extend(tags.actions_with(tag).cloned());
+        self
+    }
+
+    /// Re-enables every action with `tag` in `tags` that was disabled individually.
+    #[inline]
+    pub fn enable_tagged<T: AbilityTag>(&mut self, tags: &AbilityTags<A, T>, tag: T) {
+        for action in tags.actions_with(tag) {
+            self.actions.remove(action);
+        }
+    }
+
+    /// Are all actions disabled?
+    #[inline]
+    #[must_use]
+    pub fn all(&self) -> bool {
+        self.all
+    }
+
+    /// Disables or re-enables every action at once, such as when a silence starts or ends.
+    ///
+    /// Actions that were disabled individually stay disabled when `all` is set back to `false`.
+    #[inline]
+    pub fn set_all(&mut self, all: bool) -> &mut Self {
+        self.all = all;
+        self
+    }
+
+    /// Re-enables every action, including those that were disabled individually.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.actions.clear();
+        self.all = false;
+    }
+
+    /// Returns an iterator over the actions that have been disabled individually.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &A> {
+        self.actions.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_abilities;
+    use crate::charges::{ChargeState, Charges};
+    use crate::cooldown::{Cooldown, CooldownState};
+    use crate::pool::{AbilityCosts, Pool};
+    use crate::premade_pools::mana::{Mana, ManaPool};
+    use leafwing_input_manager::Actionlike;
+
+    #[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    enum Action {
+        Fireball,
+        Frostbolt,
+    }
+
+    #[test]
+    fn disabling_one_action_leaves_its_sibling_usable() {
+        let mut charges = ChargeState::default();
+        let mut cooldowns = CooldownState::default();
+        let disabled = DisabledAbilities::new([Action::Fireball]);
+
+        assert_eq!(
+            Action::Fireball.ready_modified::<ManaPool>(
+                &charges,
+                &cooldowns,
+                None,
+                None,
+                None,
+                Some(&disabled)
+            ),
+            Err(CannotUseAbility::Disabled)
+        );
+        assert_eq!(
+            Action::Frostbolt.trigger_modified::<ManaPool>(
+                &mut charges,
+                &mut cooldowns,
+                None,
+                None,
+                None,
+                Some(&disabled)
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn triggering_a_disabled_action_consumes_nothing() {
+        let mut charges = ChargeState::new([(Action::Fireball, Charges::simple(1))]);
+        let mut cooldowns = CooldownState::new_with_gcd(
+            [(Action::Fireball, Cooldown::from_secs(1.))],
+            Cooldown::from_secs(1.),
+        );
+        let costs = AbilityCosts::new([(Action::Fireball, Mana(10.))]);
+        let mut mana = ManaPool::new(Mana(10.), Mana(10.), Mana(0.));
+        let disabled = DisabledAbilities::new([Action::Fireball]);
+
+        assert_eq!(
+            Action::Fireball.trigger_modified(
+                &mut charges,
+                &mut cooldowns,
+                Some(&mut mana),
+                Some(&costs),
+                None,
+                Some(&disabled)
+            ),
+            Err(CannotUseAbility::Disabled)
+        );
+        assert_eq!(charges.get(&Action::Fireball).unwrap().charges(), 1);
+        assert!(cooldowns.ready(&Action::Fireball).is_ok());
+        assert!(cooldowns.gcd_ready().is_ok());
+        assert_eq!(mana.current(), Mana(10.));
+    }
+
+    #[test]
+    fn disabled_outranks_other_reasons() {
+        let mut charges = ChargeState::new([(Action::Fireball, Charges::simple(1))]);
+        let mut cooldowns = CooldownState::default();
+        Action::Fireball
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+
+        let mut disabled = DisabledAbilities::default();
+        disabled.set_all(true);
+
+        assert_eq!(
+            Action::Fireball.ready_detailed::<ManaPool>(
+                &charges,
+                &cooldowns,
+                None,
+                None,
+                None,
+                Some(&disabled)
+            ),
+            vec![CannotUseAbility::Disabled, CannotUseAbility::NoCharges]
+        );
+    }
+
+    #[test]
+    fn silences_do_not_forget_individually_disabled_actions() {
+        let mut disabled = DisabledAbilities::new([Action::Fireball]);
+        disabled.set_all(true);
+        assert!(disabled.is_disabled(&Action::Frostbolt));
+
+        disabled.set_all(false);
+        assert!(disabled.is_disabled(&Action::Fireball));
+        assert_eq!(disabled.enabled(&Action::Frostbolt), Ok(()));
+
+        assert!(disabled.enable(&Action::Fireball));
+        assert!(!disabled.is_disabled(&Action::Fireball));
+    }
+}
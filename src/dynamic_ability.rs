@@ -0,0 +1,365 @@
+//! Abilities that are defined at runtime, rather than as variants of a compile-time enum.
+//!
+//! This is intended for moddable games, which load their abilities from data files.
+//! Each [`AbilityDefinition`] is registered in an [`AbilityRegistry`] resource,
+//! which issues a compact [`DynamicAbility`] identifier for it.
+//! As [`DynamicAbility`] implements [`Abilitylike`], it can be used with all of the usual containers,
+//! such as [`CooldownState<DynamicAbility>`], [`ChargeState<DynamicAbility>`] and [`AbilityState<DynamicAbility>`](crate::AbilityState).
+//!
+//! ```rust
+//! use leafwing_abilities::dynamic_ability::{AbilityDefinition, AbilityRegistry};
+//! use leafwing_abilities::prelude::*;
+//!
+//! let mut registry = AbilityRegistry::default();
+//! let frost_nova = registry
+//!     .register(AbilityDefinition {
+//!         name: "Frost Nova".to_string(),
+//!         cooldown: Some(5.0),
+//!         ..Default::default()
+//!     })
+//!     .unwrap();
+//!
+//! assert_eq!(registry.get("Frost Nova"), Some(frost_nova));
+//!
+//! let mut cooldowns = registry.cooldown_state();
+//! cooldowns.trigger(&frost_nova).unwrap();
+//! assert_eq!(cooldowns.ready(&frost_nova), Err(CannotUseAbility::OnCooldown));
+//! ```
+
+use crate::{
+    charges::{ChargeState, Charges},
+    cooldown::{Cooldown, CooldownState},
+    pool::{AbilityCosts, Pool},
+    AbilitiesBundle, Abilitylike,
+};
+
+use bevy::{ecs::prelude::Resource, reflect::Reflect, utils::HashMap};
+use leafwing_input_manager::{Actionlike, InputControlKind};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// An ability whose definition is stored in an [`AbilityRegistry`].
+///
+/// These identifiers are issued in registration order by [`AbilityRegistry::register`],
+/// and are only meaningful for the registry that issued them.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect, Serialize, Deserialize,
+)]
+pub struct DynamicAbility(u16);
+
+impl DynamicAbility {
+    /// The raw identifier of this ability.
+    ///
+    /// Identifiers are assigned sequentially, starting from 0.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Actionlike for DynamicAbility {
+    fn input_control_kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+}
+
+impl Abilitylike for DynamicAbility {
+    /// Dynamic abilities are ordered by their [`id`](DynamicAbility::id), which is their registration order.
+    fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// The data needed to create a [`DynamicAbility`], typically loaded from a file.
+///
+/// Costs are stored as raw numbers, and converted into the [`Pool::Quantity`] of your choice
+/// by [`AbilityRegistry::ability_costs`].
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AbilityDefinition {
+    /// The unique name of this ability.
+    pub name: String,
+    /// The cooldown of this ability, in seconds.
+    ///
+    /// If [`None`], the ability has no cooldown.
+    #[serde(default)]
+    pub cooldown: Option<f32>,
+    /// The maximum number of charges of this ability.
+    ///
+    /// If [`Some`], the ability uses [`Charges::replenish_one`].
+    #[serde(default)]
+    pub charges: Option<u8>,
+    /// The amount that must be paid from the ability's [`Pool`] to use it.
+    #[serde(default)]
+    pub cost: Option<f32>,
+}
+
+/// A resource that assigns a [`DynamicAbility`] to each registered [`AbilityDefinition`].
+///
+/// Use the `*_state` methods to build the components needed to use these abilities.
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub struct AbilityRegistry {
+    ids: HashMap<String, DynamicAbility>,
+    definitions: Vec<AbilityDefinition>,
+}
+
+impl AbilityRegistry {
+    /// Registers a new ability, returning its newly issued identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an ability with the same name was already registered,
+    /// if the cooldown is not a positive number of seconds,
+    /// if the cost is negative or not finite,
+    /// or if the registry is full.
+    pub fn register(
+        &mut self,
+        definition: AbilityDefinition,
+    ) -> Result<DynamicAbility, AbilityRegistrationError> {
+        if self.ids.contains_key(&definition.name) {
+            return Err(AbilityRegistrationError::DuplicateName(definition.name));
+        }
+
+        if let Some(cooldown) = definition.cooldown {
+            if !(cooldown.is_finite() && cooldown > 0.) {
+                return Err(AbilityRegistrationError::InvalidCooldown(
+                    definition.name,
+                    cooldown,
+                ));
+            }
+        }
+
+        if let Some(cost) = definition.cost {
+            if !(cost.is_finite() && cost >= 0.) {
+                return Err(AbilityRegistrationError::InvalidCost(definition.name, cost));
+            }
+        }
+
+        let id = u16::try_from(self.definitions.len())
+            .map_err(|_| AbilityRegistrationError::RegistryFull)?;
+        let ability = DynamicAbility(id);
+
+        self.ids.insert(definition.name.clone(), ability);
+        self.definitions.push(definition);
+        Ok(ability)
+    }
+
+    /// Registers each of the provided abilities in order, returning their identifiers.
+    ///
+    /// # Errors
+    ///
+    /// Stops and returns the first error encountered: abilities registered before the error remain registered.
+    pub fn register_all(
+        &mut self,
+        definitions: impl IntoIterator<Item = AbilityDefinition>,
+    ) -> Result<Vec<DynamicAbility>, AbilityRegistrationError> {
+        definitions
+            .into_iter()
+            .map(|definition| self.register(definition))
+            .collect()
+    }
+
+    /// The ability registered with the provided `name`, if any.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<DynamicAbility> {
+        self.ids.get(name).copied()
+    }
+
+    /// The definition of the provided `ability`, if it was issued by this registry.
+    #[inline]
+    #[must_use]
+    pub fn definition(&self, ability: DynamicAbility) -> Option<&AbilityDefinition> {
+        self.definitions.get(ability.0 as usize)
+    }
+
+    /// The name of the provided `ability`, if it was issued by this registry.
+    #[inline]
+    #[must_use]
+    pub fn name(&self, ability: DynamicAbility) -> Option<&str> {
+        self.definition(ability)
+            .map(|definition| definition.name.as_str())
+    }
+
+    /// The number of registered abilities.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.definitions.len()
+    }
+
+    /// Have no abilities been registered?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+
+    /// Returns an iterator over the registered abilities and their definitions, in registration order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (DynamicAbility, &AbilityDefinition)> {
+        self.definitions
+            .iter()
+            .enumerate()
+            .map(|(id, definition)| (DynamicAbility(id as u16), definition))
+    }
+
+    /// Creates a [`CooldownState`] containing the cooldown of each registered ability that has one.
+    #[must_use]
+    pub fn cooldown_state(&self) -> CooldownState<DynamicAbility> {
+        CooldownState::new(self.iter().filter_map(|(ability, definition)| {
+            definition
+                .cooldown
+                .map(|secs| (ability, Cooldown::from_secs(secs)))
+        }))
+    }
+
+    /// Creates a [`ChargeState`] containing the charges of each registered ability that has them.
+    #[must_use]
+    pub fn charge_state(&self) -> ChargeState<DynamicAbility> {
+        ChargeState::new(self.iter().filter_map(|(ability, definition)| {
+            definition
+                .charges
+                .map(|max_charges| (ability, Charges::replenish_one(max_charges)))
+        }))
+    }
+
+    /// Creates an [`AbilitiesBundle`] containing the cooldowns and charges of every registered ability.
+    #[must_use]
+    pub fn abilities_bundle(&self) -> AbilitiesBundle<DynamicAbility> {
+        AbilitiesBundle {
+            cooldowns: self.cooldown_state(),
+            charges: self.charge_state(),
+        }
+    }
+
+    /// Creates an [`AbilityCosts`] containing the cost of each registered ability that has one,
+    /// converting the raw costs into the [`Pool::Quantity`] of `P`.
+    #[must_use]
+    pub fn ability_costs<P: Pool>(&self) -> AbilityCosts<DynamicAbility, P>
+    where
+        P::Quantity: From<f32>,
+    {
+        AbilityCosts::new(self.iter().filter_map(|(ability, definition)| {
+            definition
+                .cost
+                .map(|cost| (ability, P::Quantity::from(cost)))
+        }))
+    }
+}
+
+/// An [`AbilityDefinition`] could not be registered in an [`AbilityRegistry`].
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum AbilityRegistrationError {
+    /// An ability with this name was already registered.
+    #[error("An ability named {0:?} has already been registered.")]
+    DuplicateName(String),
+    /// The cooldown of this ability was not a positive number of seconds.
+    #[error(
+        "The cooldown of the ability {0:?} must be a positive number of seconds, but was {1}."
+    )]
+    InvalidCooldown(String, f32),
+    /// The cost of this ability was negative or not finite.
+    #[error("The cost of the ability {0:?} must be a finite, non-negative number, but was {1}.")]
+    InvalidCost(String, f32),
+    /// Every available [`DynamicAbility`] identifier has already been issued.
+    #[error("No more abilities can be registered.")]
+    RegistryFull,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::AbilityCost;
+    use crate::premade_pools::mana::{Mana, ManaPool};
+    use crate::CannotUseAbility;
+
+    fn definition(name: &str) -> AbilityDefinition {
+        AbilityDefinition {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn abilities_are_issued_sequential_ids() {
+        let mut registry = AbilityRegistry::default();
+        let fireball = registry.register(definition("Fireball")).unwrap();
+        let blink = registry.register(definition("Blink")).unwrap();
+
+        assert_eq!(fireball.id(), 0);
+        assert_eq!(blink.id(), 1);
+        assert_eq!(registry.get("Blink"), Some(blink));
+        assert_eq!(registry.name(fireball), Some("Fireball"));
+        assert_eq!(registry.len(), 2);
+    }
+
+    #[test]
+    fn invalid_definitions_are_rejected() {
+        let mut registry = AbilityRegistry::default();
+        registry.register(definition("Fireball")).unwrap();
+
+        assert_eq!(
+            registry.register(definition("Fireball")),
+            Err(AbilityRegistrationError::DuplicateName(
+                "Fireball".to_string()
+            ))
+        );
+        assert_eq!(
+            registry.register(AbilityDefinition {
+                cooldown: Some(0.),
+                ..definition("Blink")
+            }),
+            Err(AbilityRegistrationError::InvalidCooldown(
+                "Blink".to_string(),
+                0.
+            ))
+        );
+        for cost in [-5., f32::NAN, f32::INFINITY] {
+            assert!(matches!(
+                registry.register(AbilityDefinition {
+                    cost: Some(cost),
+                    ..definition("Blink")
+                }),
+                Err(AbilityRegistrationError::InvalidCost(..))
+            ));
+        }
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get("Blink"), None);
+    }
+
+    #[test]
+    fn registry_builds_state_from_definitions() {
+        let mut registry = AbilityRegistry::default();
+        let fireball = registry
+            .register(AbilityDefinition {
+                cooldown: Some(1.),
+                cost: Some(10.),
+                ..definition("Fireball")
+            })
+            .unwrap();
+        let blink = registry
+            .register(AbilityDefinition {
+                charges: Some(2),
+                ..definition("Blink")
+            })
+            .unwrap();
+
+        let mut bundle = registry.abilities_bundle();
+        assert!(bundle.cooldowns.get(&fireball).is_some());
+        assert!(bundle.cooldowns.get(&blink).is_none());
+        assert_eq!(bundle.charges.get(&blink).unwrap().max_charges(), 2);
+
+        let costs = registry.ability_costs::<ManaPool>();
+        assert_eq!(costs.get(&fireball), Some(&AbilityCost::Flat(Mana(10.))));
+        assert_eq!(costs.get(&blink), None);
+
+        fireball
+            .trigger_no_costs(&mut bundle.charges, &mut bundle.cooldowns)
+            .unwrap();
+        assert_eq!(
+            fireball.ready_no_costs(&bundle.charges, &bundle.cooldowns),
+            Err(CannotUseAbility::OnCooldown)
+        );
+    }
+}
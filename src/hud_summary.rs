@@ -0,0 +1,137 @@
+//! A per-player summary of the state of each ability, for use in HUDs.
+//!
+//! Querying the full [`AbilityState`](crate::AbilityState) from UI code requires threading the [`Pool`] type and several components through your systems.
+//! Instead, enable [`AbilityPlugin::with_hud_summary`](crate::plugin::AbilityPlugin::with_hud_summary),
+//! add a [`PlayerId`] component to each player entity, and read the [`AbilityHudSummary`] resource.
+
+use crate::{
+    charges::{ChargeState, Charges},
+    cooldown::CooldownState,
+    disabled::DisabledAbilities,
+    pool::{modified_cost, pool_can_pay, AbilityCosts, CostModifiers, Pool},
+    Abilitylike, CannotUseAbility,
+};
+
+use bevy::{
+    ecs::prelude::{Component, Query, Resource},
+    reflect::Reflect,
+    utils::{HashMap, HashSet},
+};
+
+/// A user-supplied identifier for a player, used to key the [`AbilityHudSummary`].
+///
+/// Add this component to each player entity whose abilities should be summarized.
+/// Each player should have a unique id.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect)]
+pub struct PlayerId(pub u32);
+
+/// The state of a single ability at the time the [`AbilityHudSummary`] was updated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilitySnapshot<A: Abilitylike> {
+    /// The ability that this snapshot describes.
+    pub action: A,
+    /// The result of [`Abilitylike::ready_modified`], including any costs, [`CostModifiers`] and [`DisabledAbilities`].
+    pub ready: Result<(), CannotUseAbility>,
+    /// The fraction of the cooldown that remains, from 1.0 when just triggered to 0.0 when ready.
+    ///
+    /// This accounts for the global cooldown, as described in [`CooldownState::fraction_remaining`].
+    /// [`None`] if this ability has no cooldown and there is no global cooldown.
+    pub cooldown_remaining: Option<f32>,
+    /// The charges of this ability, if any.
+    pub charges: Option<Charges>,
+    /// Can the cost of this ability, after any [`CostModifiers`], be paid from the player's pool?
+    ///
+    /// [`None`] if this ability has no cost.
+    pub affordable: Option<bool>,
+}
+
+/// A resource containing an [`AbilitySnapshot`] of each ability for every entity with a [`PlayerId`].
+///
+/// This is updated once per frame by [`update_ability_hud_summary`](crate::systems::update_ability_hud_summary),
+/// in [`AbilitySystem::UpdateHudSummary`](crate::plugin::AbilitySystem::UpdateHudSummary).
+/// Only abilities with a cooldown, charges or cost are included, in the order of [`Abilitylike::index`].
+/// Abilities that share an index, such as variants that carry data, are ordered by their [`Debug`] representation,
+/// so the order is the same every frame.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct AbilityHudSummary<A: Abilitylike> {
+    players: HashMap<PlayerId, Vec<AbilitySnapshot<A>>>,
+}
+
+// Cannot use derive(Default), as it forces an undesirable bound on our generics
+impl<A: Abilitylike> Default for AbilityHudSummary<A> {
+    fn default() -> Self {
+        Self {
+            players: HashMap::default(),
+        }
+    }
+}
+
+impl<A: Abilitylike> AbilityHudSummary<A> {
+    /// The snapshots of each ability of the provided `player`, if they exist.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, player: PlayerId) -> Option<&[AbilitySnapshot<A>]> {
+        self.players.get(&player).map(Vec::as_slice)
+    }
+
+    /// The snapshot of the `action` of the provided `player`, if it exists.
+    #[must_use]
+    pub fn snapshot(&self, player: PlayerId, action: &A) -> Option<&AbilitySnapshot<A>> {
+        self.get(player)?
+            .iter()
+            .find(|snapshot| snapshot.action == *action)
+    }
+
+    /// Returns an iterator over the players and the snapshots of their abilities, in an arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (PlayerId, &[AbilitySnapshot<A>])> {
+        self.players
+            .iter()
+            .map(|(player, snapshots)| (*player, snapshots.as_slice()))
+    }
+
+    /// Replaces the contents of this summary with the state of each player's abilities.
+    pub(crate) fn update<P: Pool + Component>(
+        &mut self,
+        query: &Query<(
+            &PlayerId,
+            &CooldownState<A>,
+            &ChargeState<A>,
+            Option<&P>,
+            Option<&AbilityCosts<A, P>>,
+            Option<&CostModifiers<A, P>>,
+            Option<&DisabledAbilities<A>>,
+        )>,
+    ) {
+        self.players.clear();
+
+        for (player, cooldowns, charges, pool, costs, modifiers, disabled) in query.iter() {
+            let mut actions: Vec<&A> = cooldowns
+                .actions()
+                .chain(charges.actions())
+                .chain(costs.into_iter().flat_map(AbilityCosts::actions))
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect();
+            actions.sort_by_cached_key(|action| (action.index(), format!("{action:?}")));
+
+            let snapshots = actions
+                .into_iter()
+                .map(|action| AbilitySnapshot {
+                    action: action.clone(),
+                    ready: action
+                        .ready_modified(charges, cooldowns, pool, costs, modifiers, disabled),
+                    cooldown_remaining: cooldowns.fraction_remaining(action),
+                    charges: charges.get(action).cloned(),
+                    // Costs that the modifiers cannot be applied to can never be paid
+                    affordable: modified_cost(action, pool, costs, modifiers)
+                        .map_or(Some(false), |cost| {
+                            cost.map(|cost| pool_can_pay(pool, Some(cost)).is_ok())
+                        }),
+                })
+                .collect();
+
+            self.players.insert(*player, snapshots);
+        }
+    }
+}
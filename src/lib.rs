@@ -4,21 +4,38 @@
 #![doc = include_str!("../README.md")]
 
 use crate::cooldown::CooldownState;
-use bevy::{ecs::prelude::*, reflect::Reflect};
+use bevy::{
+    ecs::prelude::*,
+    reflect::{Reflect, ReflectRef},
+    utils::{Duration, FixedState},
+};
 use charges::{ChargeState, Charges};
 use cooldown::Cooldown;
+use disabled::DisabledAbilities;
 use leafwing_input_manager::Actionlike;
-use pool::{AbilityCosts, Pool};
+use pool::{
+    check_cost_multiplier, modified_cost, scale_cost, AbilityCost, AbilityCosts, CostModifiers,
+    MultiPool, MultiPoolMut, Pool,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, hash::BuildHasher};
 use thiserror::Error;
 
+pub mod ability_log;
 mod ability_state;
 pub mod charges;
 pub mod cooldown;
+pub mod disabled;
+pub mod dynamic_ability;
+pub mod hud_summary;
 pub mod plugin;
 pub mod pool;
 #[cfg(feature = "premade_pools")]
 pub mod premade_pools;
+pub mod ranks;
+pub mod snapshot;
 pub mod systems;
+pub mod tags;
 pub use ability_state::*;
 
 // Importing the derive macro
@@ -28,11 +45,18 @@ pub use leafwing_abilities_macros::Abilitylike;
 pub mod prelude {
     pub use crate::charges::{ChargeState, Charges};
     pub use crate::cooldown::{Cooldown, CooldownState};
-    pub use crate::pool::{AbilityCosts, Pool, PoolBundle};
+    pub use crate::disabled::DisabledAbilities;
+    pub use crate::pool::{
+        AbilityCost, AbilityCosts, CostModifiers, CostPayments, Pool, PoolBundle,
+    };
+    pub use crate::tags::AbilityTags;
 
     pub use crate::plugin::AbilityPlugin;
     pub use crate::CannotUseAbility;
-    pub use crate::{AbilitiesBundle, AbilityState, Abilitylike};
+    pub use crate::{
+        AbilitiesBundle, AbilityClashPolicy, AbilityScale, AbilityState, AbilityTimeDilation,
+        Abilitylike, AutoTrigger,
+    };
 }
 
 /// Allows a type to be used as a gameplay action in an input-agnostic fashion
@@ -70,8 +94,11 @@ pub mod prelude {
 pub trait Abilitylike: Actionlike {
     /// Is this ability ready?
     ///
-    /// If this ability has charges, at least one charge must be available.
+    /// If this ability has charges, enough charges must be available to pay its [charge cost](Charges::charge_cost).
     /// If this ability has a cooldown but no charges, the cooldown must be ready.
+    /// The [shared cooldown](CooldownState::set_shared_cooldown) of its category must also be ready,
+    /// as must the global cooldown unless this ability is [exempt](CooldownState::set_gcd_exempt).
+    /// If a pool and costs are provided, the pool must be able to pay the cost of this ability too.
     /// Otherwise, returns [`Ok(())`].
     ///
     /// Calls [`ability_ready`], which can be used manually if you already know the [`Charges`] and [`Cooldown`] of interest.
@@ -82,21 +109,95 @@ pub trait Abilitylike: Actionlike {
         maybe_pool: Option<&P>,
         maybe_costs: Option<&AbilityCosts<Self, P>>,
     ) -> Result<(), CannotUseAbility> {
+        self.ready_modified(charges, cooldowns, maybe_pool, maybe_costs, None, None)
+    }
+
+    /// Is this ability ready, once its cost has been adjusted by any [`CostModifiers`]?
+    ///
+    /// If this ability is disabled by the provided [`DisabledAbilities`], returns [`CannotUseAbility::Disabled`].
+    ///
+    /// See [`Abilitylike::ready`] for more details.
+    fn ready_modified<P: Pool>(
+        &self,
+        charges: &ChargeState<Self>,
+        cooldowns: &CooldownState<Self>,
+        maybe_pool: Option<&P>,
+        maybe_costs: Option<&AbilityCosts<Self, P>>,
+        maybe_modifiers: Option<&CostModifiers<Self, P>>,
+        maybe_disabled: Option<&DisabledAbilities<Self>>,
+    ) -> Result<(), CannotUseAbility> {
+        match self
+            .ready_detailed(
+                charges,
+                cooldowns,
+                maybe_pool,
+                maybe_costs,
+                maybe_modifiers,
+                maybe_disabled,
+            )
+            .first()
+        {
+            Some(reason) => Err(*reason),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns every reason that this ability cannot be used, rather than only the first.
+    ///
+    /// This is useful for tooltips, which may want to explain that an ability is both on cooldown and unaffordable.
+    /// Charges and cooldowns are checked separately, so an ability whose charges [require its cooldown](Charges::with_required_cooldown)
+    /// can report both [`CannotUseAbility::NoCharges`] and [`CannotUseAbility::OnCooldown`].
+    ///
+    /// The reasons are sorted in the priority order of [`CannotUseAbility`],
+    /// so the first one is what [`Abilitylike::ready_modified`] returns.
+    /// The ability is ready if the list is empty.
+    fn ready_detailed<P: Pool>(
+        &self,
+        charges: &ChargeState<Self>,
+        cooldowns: &CooldownState<Self>,
+        maybe_pool: Option<&P>,
+        maybe_costs: Option<&AbilityCosts<Self, P>>,
+        maybe_modifiers: Option<&CostModifiers<Self, P>>,
+        maybe_disabled: Option<&DisabledAbilities<Self>>,
+    ) -> Vec<CannotUseAbility> {
+        let cost = modified_cost(self, maybe_pool, maybe_costs, maybe_modifiers);
+        let shared_cooldown = cooldowns
+            .category(self)
+            .and_then(|category| cooldowns.shared_cooldown(category));
         let charges = charges.get(self);
-        let cooldown = cooldowns.get(self);
+        // As in `ability_ready`, the cooldown only gates abilities without charges, or whose charges require it
+        let cooldown = cooldowns
+            .get(self)
+            .filter(|_| charges.is_none_or(Charges::requires_cooldown));
 
-        ability_ready(
-            charges,
-            cooldown,
-            maybe_pool,
-            maybe_costs.and_then(|costs| costs.get(self)).copied(),
-        )
+        let mut reasons: Vec<CannotUseAbility> = [
+            maybe_disabled.map_or(Ok(()), |disabled| disabled.enabled(self)),
+            match charges {
+                Some(charges) if !charges.available() => Err(CannotUseAbility::NoCharges),
+                _ => Ok(()),
+            },
+            cooldown.map_or(Ok(()), Cooldown::ready),
+            cost.and_then(|cost| pool::pool_can_pay(maybe_pool, cost)),
+            shared_cooldown.map_or(Ok(()), |shared_cooldown| {
+                shared_cooldown
+                    .ready()
+                    .map_err(|_| CannotUseAbility::OnSharedCooldown)
+            }),
+            cooldowns.gcd_ready_for(self),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect();
+        reasons.sort();
+        reasons
     }
 
     /// Triggers this ability, depleting a charge if available.
     ///
     /// Returns `true` if the ability could be used, and `false` if it could not be.
     /// Abilities can only be used if they are ready.
+    /// The [shared cooldown](CooldownState::set_shared_cooldown) of its category is triggered as well,
+    /// as is the global cooldown unless this ability is [exempt](CooldownState::set_gcd_exempt).
     ///     
     /// Calls [`trigger_ability`], which can be used manually if you already know the [`Charges`] and [`Cooldown`] of interest.
     fn trigger<P: Pool>(
@@ -106,17 +207,250 @@ pub trait Abilitylike: Actionlike {
         maybe_pool: Option<&mut P>,
         maybe_costs: Option<&AbilityCosts<Self, P>>,
     ) -> Result<(), CannotUseAbility> {
-        let charges = charges.get_mut(self);
-        let cooldown = cooldowns.get_mut(self);
+        self.trigger_modified(charges, cooldowns, maybe_pool, maybe_costs, None, None)
+    }
+
+    /// Triggers this ability, paying its cost as adjusted by any [`CostModifiers`].
+    ///
+    /// If the ability is successfully used, the one-shot [next use multiplier](CostModifiers::set_next_use_multiplier)
+    /// for this ability is consumed. Otherwise, the modifiers are left untouched.
+    ///
+    /// If this ability is not ready, the same error as [`Abilitylike::ready_modified`] is returned
+    /// and nothing is consumed.
+    ///
+    /// See [`Abilitylike::trigger`] for more details.
+    fn trigger_modified<P: Pool>(
+        &self,
+        charges: &mut ChargeState<Self>,
+        cooldowns: &mut CooldownState<Self>,
+        maybe_pool: Option<&mut P>,
+        maybe_costs: Option<&AbilityCosts<Self, P>>,
+        maybe_modifiers: Option<&mut CostModifiers<Self, P>>,
+        maybe_disabled: Option<&DisabledAbilities<Self>>,
+    ) -> Result<(), CannotUseAbility> {
+        // Check readiness first, so that nothing is spent if any check fails
+        self.ready_modified(
+            charges,
+            cooldowns,
+            maybe_pool.as_deref(),
+            maybe_costs,
+            maybe_modifiers.as_deref(),
+            maybe_disabled,
+        )?;
 
+        let cost = modified_cost(
+            self,
+            maybe_pool.as_deref(),
+            maybe_costs,
+            maybe_modifiers.as_deref(),
+        )?;
         trigger_ability(
+            charges.get_mut(self),
+            cooldowns.get_mut(self),
+            maybe_pool,
+            cost,
+        )?;
+
+        if let Some(modifiers) = maybe_modifiers {
+            modifiers.consume_next_use(self);
+        }
+
+        cooldowns.trigger_shared(self)
+    }
+
+    /// Reverses the effects of [`Abilitylike::trigger`], such as when a cast is interrupted.
+    ///
+    /// The [charge cost](Charges::charge_cost) is [refunded](Charges::refund), the cost is returned to the pool,
+    /// and the cooldown of this ability is refreshed, unless it is still needed to recharge charges that were spent earlier.
+    /// The global cooldown and the [shared cooldown](CooldownState::set_shared_cooldown) of its category are not touched:
+    /// they stay consumed, just as if the ability had been used.
+    ///
+    /// This does not check whether the ability was actually triggered:
+    /// only call it to undo a successful call to [`Abilitylike::trigger`].
+    /// To undo [`Abilitylike::trigger_modified`], use [`Abilitylike::refund_modified`] instead.
+    ///
+    /// [`AbilityCost::Computed`] costs are not returned to the pool, as their formula would be evaluated after the cost was paid:
+    /// use [`AbilityStateItem::refund`], which restores the [recorded payment](crate::pool::CostPayments::record).
+    fn refund<P: Pool>(
+        &self,
+        charges: &mut ChargeState<Self>,
+        cooldowns: &mut CooldownState<Self>,
+        maybe_pool: Option<&mut P>,
+        maybe_costs: Option<&AbilityCosts<Self, P>>,
+    ) {
+        self.refund_modified(charges, cooldowns, maybe_pool, maybe_costs, None);
+    }
+
+    /// Reverses the effects of [`Abilitylike::trigger_modified`], returning the cost as adjusted by any [`CostModifiers`].
+    ///
+    /// The one-shot [next use multiplier](CostModifiers::set_next_use_multiplier) was consumed when the ability was triggered,
+    /// so it no longer applies: use [`AbilityStateItem::refund`], which restores the [recorded payment](crate::pool::CostPayments::record),
+    /// to refund exactly what was paid.
+    ///
+    /// See [`Abilitylike::refund`] for more details.
+    fn refund_modified<P: Pool>(
+        &self,
+        charges: &mut ChargeState<Self>,
+        cooldowns: &mut CooldownState<Self>,
+        maybe_pool: Option<&mut P>,
+        maybe_costs: Option<&AbilityCosts<Self, P>>,
+        maybe_modifiers: Option<&CostModifiers<Self, P>>,
+    ) {
+        let mut still_recharging = false;
+        if let Some(charges) = charges.get_mut(self) {
+            // We don't care about overflowing our charges here.
+            let _ = charges.refund();
+            still_recharging = charges.needs_refresh();
+        }
+
+        if !still_recharging {
+            if let Some(cooldown) = cooldowns.get_mut(self) {
+                cooldown.refresh();
+            }
+        }
+
+        // Computed costs would be evaluated against the pool after they were paid, giving a different quantity
+        let computed = matches!(
+            maybe_costs.and_then(|costs| costs.get(self)),
+            Some(AbilityCost::Computed(_))
+        );
+        if let Some(pool) = maybe_pool.filter(|_| !computed) {
+            let cost = modified_cost(self, Some(&*pool), maybe_costs, maybe_modifiers);
+            if let Some(cost) = cost.ok().flatten().and_then(|cost| cost.resolve(pool)) {
+                pool.replenish(cost);
+            }
+        }
+    }
+
+    /// Could the `maybe_pool` pay the cost of this ability, ignoring its charges and cooldowns?
+    ///
+    /// This is exactly the pool check performed by [`Abilitylike::ready`] and [`ability_ready`]:
+    /// missing costs can always be paid, while costs greater than [`Pool::MIN`] cannot be paid without a pool.
+    /// This is useful for AI that plans which ability to use next while cooldowns are still ticking.
+    fn could_pay<P: Pool>(
+        &self,
+        maybe_pool: Option<&P>,
+        maybe_costs: Option<&AbilityCosts<Self, P>>,
+    ) -> Result<(), CannotUseAbility> {
+        pool::pool_can_pay(
+            maybe_pool,
+            modified_cost(self, maybe_pool, maybe_costs, None)?,
+        )
+    }
+
+    /// Is this ability ready to be used at the provided [`AbilityScale`]?
+    ///
+    /// This is checked exactly like [`Abilitylike::ready`], except that the `scale` replaces the [charge cost](Charges::charge_cost)
+    /// of this ability and multiplies its cost from the pool.
+    /// A scale with a [`charge_count`](AbilityScale::charge_count) of zero is rejected with [`CannotUseAbility::NoCharges`].
+    ///
+    /// If the [`cost_multiplier`](AbilityScale::cost_multiplier) is negative or not finite,
+    /// or if it is not `1.0` and the pool cannot [scale its quantities](Pool::scale_quantity),
+    /// the cost cannot be paid and [`CannotUseAbility::PoolInsufficient`] is returned.
+    fn ready_scaled<P: Pool>(
+        &self,
+        charges: &ChargeState<Self>,
+        cooldowns: &CooldownState<Self>,
+        maybe_pool: Option<&P>,
+        maybe_costs: Option<&AbilityCosts<Self, P>>,
+        scale: AbilityScale,
+    ) -> Result<(), CannotUseAbility> {
+        ready_at_scale(
+            self,
             charges,
-            cooldown,
+            cooldowns,
             maybe_pool,
-            maybe_costs.and_then(|costs| costs.get(self)).copied(),
+            maybe_costs,
+            None,
+            scale,
         )
     }
 
+    /// Triggers this ability at the provided [`AbilityScale`], such as for hold-to-charge or "consume all combo points" abilities.
+    ///
+    /// [`charge_count`](AbilityScale::charge_count) charges are spent, rather than the [charge cost](Charges::charge_cost),
+    /// and the cost paid from the pool is multiplied by the [`cost_multiplier`](AbilityScale::cost_multiplier).
+    ///
+    /// This is atomic: if the ability is not ready at this scale, such as when fewer than `charge_count` charges are available,
+    /// nothing is spent and no cooldowns are triggered.
+    /// See [`Abilitylike::ready_scaled`] for more details, including which cost multipliers are rejected.
+    fn trigger_scaled<P: Pool>(
+        &self,
+        charges: &mut ChargeState<Self>,
+        cooldowns: &mut CooldownState<Self>,
+        maybe_pool: Option<&mut P>,
+        maybe_costs: Option<&AbilityCosts<Self, P>>,
+        scale: AbilityScale,
+    ) -> Result<(), CannotUseAbility> {
+        trigger_at_scale(
+            self,
+            charges,
+            cooldowns,
+            maybe_pool,
+            maybe_costs,
+            None,
+            scale,
+        )
+    }
+
+    /// Is this ability ready, for abilities whose costs are paid from several pools?
+    ///
+    /// `pools` is a tuple of two or three optional pool references, such as `(Some(&mana), Some(&stamina))`,
+    /// and `costs` is the matching tuple of optional [`AbilityCosts`] references.
+    ///
+    /// As with [`Abilitylike::ready`], charges and cooldowns are checked first, as in [`Abilitylike::ready_no_costs`],
+    /// and their error is returned without checking any pool.
+    /// Then each pool is checked in tuple order, returning [`CannotUseAbility::PoolInsufficient`] for the first that cannot pay.
+    ///
+    /// See [`MultiPool`](crate::pool::MultiPool) for more details.
+    fn ready_multi<M: MultiPool<Self, C>, C>(
+        &self,
+        charges: &ChargeState<Self>,
+        cooldowns: &CooldownState<Self>,
+        pools: M,
+        costs: C,
+    ) -> Result<(), CannotUseAbility> {
+        self.ready_no_costs(charges, cooldowns)?;
+        pools.available(self, &costs)
+    }
+
+    /// Triggers this ability, paying its costs from several pools.
+    ///
+    /// `pools` is a tuple of two or three optional mutable pool references, such as `(Some(&mut mana), Some(&mut stamina))`,
+    /// and `costs` is the matching tuple of optional [`AbilityCosts`] references.
+    ///
+    /// This is atomic: if the ability is not ready or any pool cannot pay its cost,
+    /// nothing is spent and no cooldowns are triggered.
+    ///
+    /// See [`MultiPoolMut`](crate::pool::MultiPoolMut) for more details.
+    fn trigger_multi<M: MultiPoolMut<Self, C>, C>(
+        &self,
+        charges: &mut ChargeState<Self>,
+        cooldowns: &mut CooldownState<Self>,
+        mut pools: M,
+        costs: C,
+    ) -> Result<(), CannotUseAbility> {
+        self.ready_no_costs(charges, cooldowns)?;
+        pools.available(self, &costs)?;
+
+        self.trigger_no_costs(charges, cooldowns)?;
+        pools.expend(self, &costs)
+    }
+
+    /// Reverses the effects of [`Abilitylike::trigger_multi`], returning the costs to each pool.
+    ///
+    /// See [`Abilitylike::refund`] for more details.
+    fn refund_multi<M: MultiPoolMut<Self, C>, C>(
+        &self,
+        charges: &mut ChargeState<Self>,
+        cooldowns: &mut CooldownState<Self>,
+        mut pools: M,
+        costs: C,
+    ) {
+        self.refund::<NullPool>(charges, cooldowns, None, None);
+        pools.replenish(self, &costs);
+    }
+
     /// Triggers this ability, depleting a charge if available.
     ///
     /// Returns `true` if the ability could be used, and `false` if it could not be.
@@ -124,7 +458,8 @@ pub trait Abilitylike: Actionlike {
     ///     
     /// Calls [`Abilitylike::trigger`], passing in [`None`] for both the pools or costs.
     /// This is useful when you don't have any pools or costs to check,
-    /// or when multiple distinct pools may be needed.
+    /// or when you are handling the costs manually.
+    /// For abilities whose costs are paid from several pools, see [`Abilitylike::trigger_multi`].
     fn trigger_no_costs(
         &self,
         charges: &mut ChargeState<Self>,
@@ -135,13 +470,14 @@ pub trait Abilitylike: Actionlike {
 
     /// Is this ability ready?
     ///
-    /// If this ability has charges, at least one charge must be available.
+    /// If this ability has charges, enough charges must be available to pay its [charge cost](Charges::charge_cost).
     /// If this ability has a cooldown but no charges, the cooldown must be ready.
     /// Otherwise, returns [`Ok(())`].
     ///
     /// Calls [`Abilitylike::ready`], passing in [`None`] for both the pools or costs.
     /// This is useful when you don't have any pools or costs to check,
-    /// or when multiple distinct pools may be needed.
+    /// or when you are handling the costs manually.
+    /// For abilities whose costs are paid from several pools, see [`Abilitylike::ready_multi`].
     fn ready_no_costs(
         &self,
         charges: &ChargeState<Self>,
@@ -149,6 +485,22 @@ pub trait Abilitylike: Actionlike {
     ) -> Result<(), CannotUseAbility> {
         self.ready::<NullPool>(charges, cooldowns, None, None)
     }
+
+    /// The position of this ability in a stable order over every ability of this type.
+    ///
+    /// This order is used to resolve abilities pressed on the same frame under [`AbilityClashPolicy::ActionlikeIndexOrder`],
+    /// and to sort abilities in the [`AbilityHudSummary`](crate::hud_summary::AbilityHudSummary).
+    ///
+    /// By default, this is the index of the enum variant, in declaration order, read via reflection.
+    /// Actions that are not enums are all placed last, at [`usize::MAX`]:
+    /// override this method to order them.
+    #[must_use]
+    fn index(&self) -> usize {
+        match self.reflect_ref() {
+            ReflectRef::Enum(dyn_enum) => dyn_enum.variant_index(),
+            _ => usize::MAX,
+        }
+    }
 }
 
 /// An [`Error`](std::error::Error) type that explains why an ability could not be used.
@@ -157,30 +509,63 @@ pub trait Abilitylike: Actionlike {
 /// For example, if an ability is out of charges and also not pressed,
 /// [`ready_and_pressed`](crate::ability_state::AbilityStateItem) will return `Err(CannotUseAbility::NotPressed)`,
 /// rather than `Err(CannotUseAbility::NoCharges)`, even though both are true.
-#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// ## Wire format
+///
+/// This type is serialized as a map tagged by the name of the variant, such as `{"reason":"NoCharges"}`,
+/// so that variants carrying data can be added later without breaking existing messages.
+/// Each variant also has an explicit discriminant, for compact encodings that cast it to a [`u8`]:
+///
+/// | Variant              | Discriminant |
+/// |----------------------|--------------|
+/// | [`NotPressed`](Self::NotPressed)             | 0 |
+/// | [`Disabled`](Self::Disabled)                 | 1 |
+/// | [`NoCharges`](Self::NoCharges)               | 2 |
+/// | [`OnCooldown`](Self::OnCooldown)             | 3 |
+/// | [`OnSharedCooldown`](Self::OnSharedCooldown) | 4 |
+/// | [`OnGlobalCooldown`](Self::OnGlobalCooldown) | 5 |
+/// | [`PoolInsufficient`](Self::PoolInsufficient) | 6 |
+///
+/// Neither the variant names nor these discriminants will change between releases.
+#[derive(
+    Error, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Reflect, Serialize, Deserialize,
+)]
+#[serde(tag = "reason")]
+#[repr(u8)]
 pub enum CannotUseAbility {
     /// The corresponding [`ActionState`](leafwing_input_manager::action_state::ActionState) was not pressed
     #[error("The ability was not pressed.")]
-    NotPressed,
+    NotPressed = 0,
+    /// This ability was disabled by [`DisabledAbilities`]
+    #[error("The ability is disabled.")]
+    Disabled = 1,
     /// There were no [`Charges`] available for this ability
     #[error("No charges available.")]
-    NoCharges,
+    NoCharges = 2,
     /// The [`Cooldown`] of this ability was not ready
     #[error("Cooldown not ready.")]
-    OnCooldown,
+    OnCooldown = 3,
+    /// The [shared cooldown](CooldownState::set_shared_cooldown) of this ability's category was not ready
+    #[error("Shared cooldown not ready.")]
+    OnSharedCooldown = 4,
     /// The Global [`Cooldown`] for this [`CooldownState`] was not ready
     #[error("Global cooldown not ready.")]
-    OnGlobalCooldown,
+    OnGlobalCooldown = 5,
     /// Not enough resources from the corresponding [`Pool`]s are available
     #[error("Not enough resources.")]
-    PoolInsufficient,
+    PoolInsufficient = 6,
 }
 
 /// Checks if a [`Charges`], [`Cooldown`] pair associated with an ability is ready to use.
 ///
-/// If this ability has charges, at least one charge must be available.
+/// If this ability has charges, enough charges must be available to pay its [charge cost](Charges::charge_cost).
 /// If this ability has a cooldown but no charges, the cooldown must be ready.
-/// Otherwise, returns `true`.
+/// If the charges [require the cooldown](Charges::with_required_cooldown), both must be ready,
+/// with [`CannotUseAbility::NoCharges`] reported first.
+///
+/// In either case, the `pool` must also be able to pay the `cost`,
+/// with [`AbilityCost::Percent`] costs resolved against the current max of the `pool`
+/// and [`AbilityCost::Computed`] costs evaluated against its current state.
 ///
 /// If you don't have an associated resource pool to check, pass in [`NullPool`] as `P`.
 #[inline]
@@ -188,45 +573,47 @@ pub fn ability_ready<P: Pool>(
     charges: Option<&Charges>,
     cooldown: Option<&Cooldown>,
     pool: Option<&P>,
-    cost: Option<P::Quantity>,
+    cost: Option<AbilityCost<P::Quantity>>,
 ) -> Result<(), CannotUseAbility> {
     if let Some(charges) = charges {
-        if charges.charges() > 0 {
-            Ok(())
-        } else {
-            Err(CannotUseAbility::NoCharges)
-        }
-    } else if let Some(cooldown) = cooldown {
-        cooldown.ready()
-    } else if let Some(pool) = pool {
-        if let Some(cost) = cost {
-            pool.available(cost)
-        } else {
-            Ok(())
+        if !charges.available() {
+            return Err(CannotUseAbility::NoCharges);
         }
-    // The pool does not exist, but the cost does
-    } else if let Some(cost) = cost {
-        if cost > P::MIN {
-            Err(CannotUseAbility::PoolInsufficient)
-        } else {
-            Ok(())
+
+        if charges.requires_cooldown() {
+            if let Some(cooldown) = cooldown {
+                cooldown.ready()?;
+            }
         }
-    } else {
-        Ok(())
+    } else if let Some(cooldown) = cooldown {
+        cooldown.ready()?;
     }
+
+    pool::pool_can_pay(pool, cost)
 }
 
 /// Triggers an implicit ability, depleting a charge if available.
 ///
 /// If no `charges` is [`None`], this will be based off the [`Cooldown`] alone, triggering it if possible.
+/// Otherwise, a charge is spent, and the [`Cooldown`] is started if it is not already running
+/// and the charges now need it to recharge, according to their [`CooldownStrategy`](charges::CooldownStrategy).
+/// If the charges [require the cooldown](Charges::with_required_cooldown), the cooldown is always started.
+/// The `cost` is paid from the `pool` as well: if it cannot be paid, [`CannotUseAbility::PoolInsufficient`] is returned
+/// and nothing is consumed.
 /// If you don't have an associated resource pool to check, pass in [`NullPool`] as `P`.
 #[inline]
 pub fn trigger_ability<P: Pool>(
     mut charges: Option<&mut Charges>,
     mut cooldown: Option<&mut Cooldown>,
     pool: Option<&mut P>,
-    cost: Option<P::Quantity>,
+    cost: Option<AbilityCost<P::Quantity>>,
 ) -> Result<(), CannotUseAbility> {
+    // Resolve the cost once, so that the readiness check and the expenditure agree
+    let cost = match (pool.as_deref(), cost) {
+        (Some(pool), Some(cost)) => cost.resolve(pool).map(AbilityCost::Flat).or(Some(cost)),
+        (_, cost) => cost,
+    };
+
     ability_ready(
         charges.as_deref(),
         cooldown.as_deref(),
@@ -234,21 +621,125 @@ pub fn trigger_ability<P: Pool>(
         cost,
     )?;
 
+    // Pay the cost first, so that nothing else is consumed if the pool cannot afford it
+    if let Some(pool) = pool {
+        if let Some(cost) = cost.and_then(|cost| cost.resolve(pool)) {
+            pool.expend(cost)?;
+        }
+    }
+
     if let Some(ref mut charges) = charges {
         charges.expend()?;
+
+        if let Some(cooldown) = cooldown {
+            if charges.requires_cooldown() || (charges.needs_refresh() && cooldown.ready().is_ok())
+            {
+                cooldown.trigger()?;
+            }
+        }
     } else if let Some(ref mut cooldown) = cooldown {
         cooldown.trigger()?;
     }
 
-    if let Some(pool) = pool {
-        if let Some(cost) = cost {
-            let _pool_result = pool.expend(cost);
-            // This is good to check, but panics in release mode are miserable
-            debug_assert!(_pool_result.is_ok());
-        }
+    Ok(())
+}
+
+/// The cost of `action` at the provided `scale`, after applying any `maybe_modifiers`.
+fn scaled_cost<A: Abilitylike, P: Pool>(
+    action: &A,
+    maybe_pool: Option<&P>,
+    maybe_costs: Option<&AbilityCosts<A, P>>,
+    maybe_modifiers: Option<&CostModifiers<A, P>>,
+    scale: AbilityScale,
+) -> Result<Option<AbilityCost<P::Quantity>>, CannotUseAbility> {
+    let Some(cost) = modified_cost(action, maybe_pool, maybe_costs, maybe_modifiers)? else {
+        return Ok(None);
+    };
+    Ok(Some(scale_cost::<P>(cost, scale.cost_multiplier)?))
+}
+
+/// Checks whether `action` is ready at the provided `scale`.
+///
+/// Shared by [`Abilitylike::ready_scaled`] and [`AbilityStateItem::ready_scaled`], which also applies [`CostModifiers`].
+pub(crate) fn ready_at_scale<A: Abilitylike, P: Pool>(
+    action: &A,
+    charges: &ChargeState<A>,
+    cooldowns: &CooldownState<A>,
+    maybe_pool: Option<&P>,
+    maybe_costs: Option<&AbilityCosts<A, P>>,
+    maybe_modifiers: Option<&CostModifiers<A, P>>,
+    scale: AbilityScale,
+) -> Result<(), CannotUseAbility> {
+    check_cost_multiplier::<P>(scale.cost_multiplier)?;
+    if scale.charge_count == 0 {
+        return Err(CannotUseAbility::NoCharges);
     }
 
-    Ok(())
+    let scaled_charges = charges
+        .get(action)
+        .map(|charges| charges.clone().with_charge_cost(scale.charge_count));
+    let cost = scaled_cost(action, maybe_pool, maybe_costs, maybe_modifiers, scale)?;
+
+    ability_ready(
+        scaled_charges.as_ref(),
+        cooldowns.get(action),
+        maybe_pool,
+        cost,
+    )?;
+    cooldowns.shared_ready(action)
+}
+
+/// Triggers `action` at the provided `scale`, consuming the one-shot multiplier of any `maybe_modifiers` on success.
+///
+/// Shared by [`Abilitylike::trigger_scaled`] and [`AbilityStateItem::trigger_scaled`].
+pub(crate) fn trigger_at_scale<A: Abilitylike, P: Pool>(
+    action: &A,
+    charges: &mut ChargeState<A>,
+    cooldowns: &mut CooldownState<A>,
+    maybe_pool: Option<&mut P>,
+    maybe_costs: Option<&AbilityCosts<A, P>>,
+    maybe_modifiers: Option<&mut CostModifiers<A, P>>,
+    scale: AbilityScale,
+) -> Result<(), CannotUseAbility> {
+    // Check everything first, so that nothing is spent if the ability is not ready at this scale
+    ready_at_scale(
+        action,
+        charges,
+        cooldowns,
+        maybe_pool.as_deref(),
+        maybe_costs,
+        maybe_modifiers.as_deref(),
+        scale,
+    )?;
+
+    let cost = scaled_cost(
+        action,
+        maybe_pool.as_deref(),
+        maybe_costs,
+        maybe_modifiers.as_deref(),
+        scale,
+    )?;
+    // Spend the scaled charges from a copy, then restore the original charge cost
+    let mut scaled_charges = charges
+        .get(action)
+        .map(|charges| charges.clone().with_charge_cost(scale.charge_count));
+    trigger_ability(
+        scaled_charges.as_mut(),
+        cooldowns.get_mut(action),
+        maybe_pool,
+        cost,
+    )?;
+
+    if let (Some(charges), Some(scaled_charges)) = (charges.get_mut(action), scaled_charges) {
+        let charge_cost = charges.charge_cost();
+        *charges = scaled_charges.with_charge_cost(charge_cost);
+    }
+
+    if let Some(modifiers) = maybe_modifiers {
+        modifiers.consume_next_use(action);
+    }
+
+    cooldowns.trigger_shared(action)
 }
 
 /// This [`Bundle`] allows entities to manage their [`Abilitylike`] actions effectively.
@@ -277,16 +768,129 @@ impl<A: Abilitylike> Default for AbilitiesBundle<A> {
     }
 }
 
+/// How strongly an ability is used by [`Abilitylike::trigger_scaled`].
+///
+/// The default scale spends the usual [charge cost](Charges::charge_cost) of 1 and pays the usual cost,
+/// just like [`Abilitylike::trigger`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct AbilityScale {
+    /// The number of charges to spend, replacing the [charge cost](Charges::charge_cost) of the ability.
+    ///
+    /// This must be at least 1, even for abilities without charges.
+    pub charge_count: u8,
+    /// The factor that the cost of the ability is multiplied by.
+    ///
+    /// This must be finite and non-negative,
+    /// and must be `1.0` unless the pool [can scale its quantities](Pool::scale_quantity):
+    /// otherwise, the ability cannot be used at this scale.
+    pub cost_multiplier: f32,
+}
+
+impl Default for AbilityScale {
+    fn default() -> Self {
+        AbilityScale {
+            charge_count: 1,
+            cost_multiplier: 1.0,
+        }
+    }
+}
+
+impl AbilityScale {
+    /// Uses the ability `n` times over at once, spending `n` charges and paying `n` times the cost.
+    #[must_use]
+    pub fn uniform(n: u8) -> Self {
+        AbilityScale {
+            charge_count: n,
+            cost_multiplier: n as f32,
+        }
+    }
+}
+
+/// Scales the passage of time for the abilities and pools of a single entity.
+///
+/// When present, the `delta_time` used by [`tick_cooldowns`](crate::systems::tick_cooldowns)
+/// and [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool) for this entity is multiplied by this value.
+/// For example, 0.5 halves the speed at which cooldowns recover and pools regenerate, while 0.0 freezes them entirely.
+/// Negative values are treated as 0.0.
+///
+/// Cooldowns and pools stored as resources are unaffected.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct AbilityTimeDilation(pub f32);
+
+impl Default for AbilityTimeDilation {
+    /// Time passes at the normal rate.
+    fn default() -> Self {
+        AbilityTimeDilation(1.0)
+    }
+}
+
+impl AbilityTimeDilation {
+    /// Scales the provided `delta_time` by this dilation.
+    #[inline]
+    #[must_use]
+    pub fn dilate(&self, delta_time: Duration) -> Duration {
+        scale_duration(delta_time, self.0)
+    }
+}
+
+/// Sorts `entries` by a hash of their action that is stable between runs and machines,
+/// so that snapshots of equal states are identical.
+pub(crate) fn sort_by_action<A: Abilitylike, T>(entries: &mut [(A, T)]) {
+    entries.sort_by_cached_key(|(action, _)| FixedState.hash_one(action));
+}
+
+/// Hands a group of actions over to a new owner after its owner, `removed`, leaves the group.
+///
+/// `links` maps each other member of a group to the owner, which is the action that stores the group's shared value,
+/// as used by [`CooldownState::link`] and [`ChargeState::set_group`].
+/// Returns the new owner, under which the caller should store the shared value again,
+/// or [`None`] if no other actions were in the group.
+pub(crate) fn reassign_group_owner<A: Abilitylike>(
+    links: &mut HashMap<A, A>,
+    removed: &A,
+) -> Option<A> {
+    if links.is_empty() {
+        return None;
+    }
+
+    let remaining: Vec<A> = links
+        .iter()
+        .filter(|(_, owner)| *owner == removed)
+        .map(|(member, _)| member.clone())
+        .collect();
+
+    let (new_owner, others) = remaining.split_first()?;
+    links.remove(new_owner);
+    for other in others {
+        links.insert(other.clone(), new_owner.clone());
+    }
+    Some(new_owner.clone())
+}
+
+/// Multiplies `duration` by `factor`, treating negative factors as 0.0 and saturating on overflow.
+pub(crate) fn scale_duration(duration: Duration, factor: f32) -> Duration {
+    if factor == 1.0 {
+        return duration;
+    }
+
+    let factor = factor.max(0.) as f64;
+    Duration::try_from_secs_f64(duration.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+}
+
 #[cfg(test)]
 mod tests {
     use bevy::reflect::Reflect;
-    use leafwing_abilities_macros::Abilitylike;
     use leafwing_input_manager::Actionlike;
 
-    use crate::charges::Charges;
-    use crate::cooldown::Cooldown;
+    use crate::charges::{ChargeState, Charges};
+    use crate::cooldown::{Cooldown, CooldownState};
+    use crate::pool::{AbilityCost, AbilityCosts, CostModifiers, InvalidCostMultiplier, Pool};
+    use crate::premade_pools::life::{Life, LifePool};
+    use crate::premade_pools::mana::{Mana, ManaPool};
     use crate::NullPool;
-    use crate::{ability_ready, trigger_ability, CannotUseAbility};
+    use crate::{ability_ready, trigger_ability, AbilityScale, Abilitylike, CannotUseAbility};
+    use bevy::utils::Duration;
 
     use crate as leafwing_abilities;
 
@@ -328,6 +932,477 @@ mod tests {
         );
     }
 
+    #[test]
+    fn abilities_starting_without_charges_are_not_ready() {
+        let mut charges = ChargeState::new([(
+            TestAbility::TestAction,
+            Charges::replenish_one(2).with_current(0),
+        )]);
+        let cooldowns = CooldownState::default();
+
+        assert_eq!(
+            TestAbility::TestAction.ready_no_costs(&charges, &cooldowns),
+            Err(CannotUseAbility::NoCharges)
+        );
+
+        charges
+            .get_mut(&TestAbility::TestAction)
+            .unwrap()
+            .replenish();
+        assert!(TestAbility::TestAction
+            .ready_no_costs(&charges, &cooldowns)
+            .is_ok());
+    }
+
+    #[test]
+    fn refunding_reverses_trigger_except_for_global_cooldown() {
+        let mut charges = ChargeState::new([(TestAbility::TestAction, Charges::replenish_one(2))]);
+        let mut cooldowns = CooldownState::new_with_gcd(
+            [(TestAbility::TestAction, Cooldown::from_secs(5.))],
+            Cooldown::from_secs(1.),
+        );
+        let costs = AbilityCosts::new([(TestAbility::TestAction, Mana(10.))]);
+        let mut mana = ManaPool::new(Mana(20.), Mana(20.), Mana(0.));
+
+        let charges_before = charges.clone();
+        let cooldown_before = cooldowns.get(&TestAbility::TestAction).cloned();
+        let mana_before = mana.clone();
+
+        TestAbility::TestAction
+            .trigger(&mut charges, &mut cooldowns, Some(&mut mana), Some(&costs))
+            .unwrap();
+        TestAbility::TestAction.refund(&mut charges, &mut cooldowns, Some(&mut mana), Some(&costs));
+
+        assert_eq!(charges, charges_before);
+        assert_eq!(
+            cooldowns.get(&TestAbility::TestAction).cloned(),
+            cooldown_before
+        );
+        assert_eq!(mana, mana_before);
+        assert_eq!(
+            cooldowns.gcd_ready(),
+            Err(CannotUseAbility::OnGlobalCooldown)
+        );
+    }
+
+    #[test]
+    fn refunding_modified_trigger_returns_modified_cost() {
+        let mut charges = ChargeState::default();
+        let mut cooldowns = CooldownState::default();
+        let costs = AbilityCosts::new([(TestAbility::TestAction, Mana(10.))]);
+        let mut modifiers = CostModifiers::default();
+        modifiers.set_global(0.5).unwrap();
+        let mut mana = ManaPool::new(Mana(20.), Mana(20.), Mana(0.));
+
+        TestAbility::TestAction
+            .trigger_modified(
+                &mut charges,
+                &mut cooldowns,
+                Some(&mut mana),
+                Some(&costs),
+                Some(&mut modifiers),
+                None,
+            )
+            .unwrap();
+        assert_eq!(mana.current(), Mana(15.));
+
+        // Drain the pool, so that refunding the unmodified cost would give back too much
+        mana.expend(Mana(10.)).unwrap();
+        TestAbility::TestAction.refund_modified(
+            &mut charges,
+            &mut cooldowns,
+            Some(&mut mana),
+            Some(&costs),
+            Some(&modifiers),
+        );
+        assert_eq!(mana.current(), Mana(10.));
+    }
+
+    #[test]
+    fn cost_modifiers_require_scalable_pools() {
+        use crate::premade_pools::combo::ComboPointPool;
+
+        let mut modifiers = CostModifiers::<TestAbility, ComboPointPool>::default();
+        assert_eq!(
+            modifiers.set_global(0.5),
+            Err(InvalidCostMultiplier::PoolCannotScale)
+        );
+        assert_eq!(modifiers.global(), 1.0);
+        modifiers.set_global(1.0).unwrap();
+    }
+
+    #[test]
+    fn overflowing_cost_modifiers_cannot_be_paid() {
+        let mut charges = ChargeState::default();
+        let mut cooldowns = CooldownState::default();
+        let costs = AbilityCosts::new([(TestAbility::TestAction, Mana(10.))]);
+        let mut modifiers = CostModifiers::default();
+        modifiers.set_global(1e30).unwrap();
+        modifiers
+            .set_action_multiplier(TestAbility::TestAction, 1e30)
+            .unwrap();
+        let mut mana = ManaPool::new(Mana(20.), Mana(20.), Mana(0.));
+
+        assert_eq!(
+            TestAbility::TestAction.trigger_modified(
+                &mut charges,
+                &mut cooldowns,
+                Some(&mut mana),
+                Some(&costs),
+                Some(&mut modifiers),
+                None,
+            ),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert_eq!(mana.current(), Mana(20.));
+    }
+
+    #[test]
+    fn ready_detailed_reports_every_reason() {
+        let mut charges = ChargeState::new([(TestAbility::TestAction, Charges::simple(1))]);
+        let mut cooldowns = CooldownState::new_with_gcd([], Cooldown::from_secs(1.));
+        let costs = AbilityCosts::new([(TestAbility::TestAction, Mana(10.))]);
+        let mana = ManaPool::new(Mana(5.), Mana(20.), Mana(0.));
+
+        TestAbility::TestAction
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+
+        assert_eq!(
+            TestAbility::TestAction.ready_detailed(
+                &charges,
+                &cooldowns,
+                Some(&mana),
+                Some(&costs),
+                None,
+                None
+            ),
+            vec![
+                CannotUseAbility::NoCharges,
+                CannotUseAbility::OnGlobalCooldown,
+                CannotUseAbility::PoolInsufficient,
+            ]
+        );
+        assert_eq!(
+            TestAbility::TestAction.ready(&charges, &cooldowns, Some(&mana), Some(&costs)),
+            Err(CannotUseAbility::NoCharges)
+        );
+    }
+
+    #[test]
+    fn ready_detailed_reports_charges_and_cooldown_together() {
+        let mut charges = ChargeState::new([(
+            TestAbility::TestAction,
+            Charges::simple(1).with_required_cooldown(true),
+        )]);
+        let mut cooldowns =
+            CooldownState::new([(TestAbility::TestAction, Cooldown::from_secs(1.))]);
+        let costs = AbilityCosts::new([(TestAbility::TestAction, Mana(10.))]);
+        let mut mana = ManaPool::new(Mana(10.), Mana(20.), Mana(0.));
+
+        TestAbility::TestAction
+            .trigger(&mut charges, &mut cooldowns, Some(&mut mana), Some(&costs))
+            .unwrap();
+
+        let reasons = TestAbility::TestAction.ready_detailed(
+            &charges,
+            &cooldowns,
+            Some(&mana),
+            Some(&costs),
+            None,
+            None,
+        );
+        assert_eq!(
+            reasons,
+            vec![
+                CannotUseAbility::NoCharges,
+                CannotUseAbility::OnCooldown,
+                CannotUseAbility::PoolInsufficient,
+            ]
+        );
+        assert_eq!(
+            TestAbility::TestAction.trigger(
+                &mut charges,
+                &mut cooldowns,
+                Some(&mut mana),
+                Some(&costs)
+            ),
+            Err(reasons[0])
+        );
+    }
+
+    #[test]
+    fn scaled_trigger_spends_two_charges() {
+        let mut charges = ChargeState::new([(TestAbility::TestAction, Charges::simple(3))]);
+        let mut cooldowns = CooldownState::default();
+        let scale = AbilityScale {
+            charge_count: 2,
+            cost_multiplier: 1.,
+        };
+
+        TestAbility::TestAction
+            .trigger_scaled::<NullPool>(&mut charges, &mut cooldowns, None, None, scale)
+            .unwrap();
+        let remaining = charges.get(&TestAbility::TestAction).unwrap();
+        assert_eq!(remaining.charges(), 1);
+        assert_eq!(remaining.charge_cost(), 1);
+
+        // Not enough charges left for another empowered use, so nothing is spent
+        assert_eq!(
+            TestAbility::TestAction.trigger_scaled::<NullPool>(
+                &mut charges,
+                &mut cooldowns,
+                None,
+                None,
+                scale
+            ),
+            Err(CannotUseAbility::NoCharges)
+        );
+        assert_eq!(charges.get(&TestAbility::TestAction).unwrap().charges(), 1);
+        assert!(TestAbility::TestAction
+            .ready_no_costs(&charges, &cooldowns)
+            .is_ok());
+    }
+
+    #[test]
+    fn scaled_trigger_pays_three_times_the_cost() {
+        let mut charges = ChargeState::default();
+        let mut cooldowns = CooldownState::default();
+        let costs = AbilityCosts::new([(TestAbility::TestAction, Mana(10.))]);
+        let mut mana = ManaPool::new(Mana(40.), Mana(40.), Mana(0.));
+        let scale = AbilityScale {
+            charge_count: 1,
+            cost_multiplier: 3.,
+        };
+
+        TestAbility::TestAction
+            .trigger_scaled(
+                &mut charges,
+                &mut cooldowns,
+                Some(&mut mana),
+                Some(&costs),
+                scale,
+            )
+            .unwrap();
+        assert_eq!(mana.current(), Mana(10.));
+
+        assert_eq!(
+            TestAbility::TestAction.ready_scaled(
+                &charges,
+                &cooldowns,
+                Some(&mana),
+                Some(&costs),
+                scale
+            ),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert!(TestAbility::TestAction
+            .ready(&charges, &cooldowns, Some(&mana), Some(&costs))
+            .is_ok());
+    }
+
+    #[test]
+    fn scaling_to_zero_charges_is_rejected() {
+        let mut charges = ChargeState::new([(TestAbility::TestAction, Charges::simple(1))]);
+        let mut cooldowns = CooldownState::default();
+        let scale = AbilityScale {
+            charge_count: 0,
+            cost_multiplier: 1.,
+        };
+
+        assert_eq!(
+            TestAbility::TestAction.trigger_scaled::<NullPool>(
+                &mut charges,
+                &mut cooldowns,
+                None,
+                None,
+                scale
+            ),
+            Err(CannotUseAbility::NoCharges)
+        );
+        assert_eq!(charges.get(&TestAbility::TestAction).unwrap().charges(), 1);
+    }
+
+    #[test]
+    fn negative_cost_multipliers_are_rejected() {
+        let mut charges = ChargeState::default();
+        let mut cooldowns = CooldownState::default();
+        let costs = AbilityCosts::new([(TestAbility::TestAction, Mana(10.))]);
+        let mut mana = ManaPool::new(Mana(10.), Mana(40.), Mana(0.));
+        let scale = AbilityScale {
+            charge_count: 1,
+            cost_multiplier: -1.,
+        };
+
+        assert_eq!(
+            TestAbility::TestAction.trigger_scaled(
+                &mut charges,
+                &mut cooldowns,
+                Some(&mut mana),
+                Some(&costs),
+                scale,
+            ),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert_eq!(mana.current(), Mana(10.));
+    }
+
+    #[test]
+    fn scaled_costs_require_scalable_pools() {
+        use crate::premade_pools::combo::{ComboPointPool, ComboPoints};
+
+        let mut charges = ChargeState::default();
+        let mut cooldowns = CooldownState::default();
+        let costs = AbilityCosts::new([(TestAbility::TestAction, ComboPoints(1))]);
+        let mut combo = ComboPointPool::new(ComboPoints(5), ComboPoints(5));
+
+        assert_eq!(
+            TestAbility::TestAction.trigger_scaled(
+                &mut charges,
+                &mut cooldowns,
+                Some(&mut combo),
+                Some(&costs),
+                AbilityScale::uniform(2),
+            ),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert_eq!(combo.current(), ComboPoints(5));
+    }
+
+    #[test]
+    fn could_pay_matches_ready_without_charges_or_cooldowns() {
+        let charges = ChargeState::default();
+        let cooldowns = CooldownState::default();
+        let costs = AbilityCosts::new([(TestAbility::TestAction, Mana(10.))]);
+
+        for current in [Mana(0.), Mana(10.), Mana(20.)] {
+            let mana = ManaPool::new(current, Mana(20.), Mana(0.));
+            assert_eq!(
+                TestAbility::TestAction.could_pay(Some(&mana), Some(&costs)),
+                TestAbility::TestAction.ready(&charges, &cooldowns, Some(&mana), Some(&costs))
+            );
+        }
+
+        assert_eq!(
+            TestAbility::TestAction.could_pay::<ManaPool>(None, None),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn could_pay_without_a_pool_matches_ready() {
+        let charges = ChargeState::default();
+        let cooldowns = CooldownState::default();
+        let costs =
+            AbilityCosts::<TestAbility, ManaPool>::new([(TestAbility::TestAction, Mana(10.))]);
+
+        assert_eq!(
+            TestAbility::TestAction.could_pay(None, Some(&costs)),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert_eq!(
+            TestAbility::TestAction.ready(&charges, &cooldowns, None, Some(&costs)),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+    }
+
+    #[test]
+    fn charges_available_but_pool_empty() {
+        let mut charges = ChargeState::new([(TestAbility::TestAction, Charges::simple(2))]);
+        let mut cooldowns = CooldownState::default();
+        let costs = AbilityCosts::new([(TestAbility::TestAction, Mana(10.))]);
+        let mut mana = ManaPool::new(Mana(0.), Mana(20.), Mana(0.));
+
+        assert_eq!(
+            TestAbility::TestAction.ready(&charges, &cooldowns, Some(&mana), Some(&costs)),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert_eq!(
+            TestAbility::TestAction.trigger(
+                &mut charges,
+                &mut cooldowns,
+                Some(&mut mana),
+                Some(&costs)
+            ),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert_eq!(charges.get(&TestAbility::TestAction).unwrap().charges(), 2);
+        assert_eq!(mana.current(), Mana(0.));
+    }
+
+    #[test]
+    fn cooldown_ready_but_pool_empty() {
+        let mut charges = ChargeState::default();
+        let mut cooldowns =
+            CooldownState::new([(TestAbility::TestAction, Cooldown::from_secs(1.))]);
+        let costs = AbilityCosts::new([(TestAbility::TestAction, Mana(10.))]);
+        let mut mana = ManaPool::new(Mana(0.), Mana(20.), Mana(0.));
+
+        assert_eq!(
+            TestAbility::TestAction.ready(&charges, &cooldowns, Some(&mana), Some(&costs)),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert_eq!(
+            TestAbility::TestAction.trigger(
+                &mut charges,
+                &mut cooldowns,
+                Some(&mut mana),
+                Some(&costs)
+            ),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert!(cooldowns
+            .get(&TestAbility::TestAction)
+            .unwrap()
+            .ready()
+            .is_ok());
+        assert_eq!(mana.current(), Mana(0.));
+    }
+
+    #[test]
+    fn computed_costs_follow_the_live_pool() {
+        fn soul_burn(current: Life, _max: Life) -> Life {
+            current * 0.1
+        }
+
+        let mut charges = ChargeState::default();
+        let mut cooldowns = CooldownState::default();
+        let costs = AbilityCosts::new([(
+            TestAbility::TestAction,
+            AbilityCost::Computed(soul_burn as fn(Life, Life) -> Life),
+        )]);
+
+        let mut life = LifePool::new(Life(100.), Life(100.), Life(0.));
+        TestAbility::TestAction
+            .trigger(&mut charges, &mut cooldowns, Some(&mut life), Some(&costs))
+            .unwrap();
+        assert_eq!(life.current(), Life(90.));
+
+        let mut life = LifePool::new(Life(50.), Life(100.), Life(0.));
+        TestAbility::TestAction
+            .trigger(&mut charges, &mut cooldowns, Some(&mut life), Some(&costs))
+            .unwrap();
+        assert_eq!(life.current(), Life(45.));
+    }
+
+    #[test]
+    fn refunding_keeps_recharging_cooldowns_running() {
+        let mut charges = ChargeState::new([(TestAbility::TestAction, Charges::replenish_one(2))]);
+        let mut cooldowns =
+            CooldownState::new([(TestAbility::TestAction, Cooldown::from_secs(5.))]);
+
+        TestAbility::TestAction
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        TestAbility::TestAction
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        TestAbility::TestAction.refund::<NullPool>(&mut charges, &mut cooldowns, None, None);
+
+        assert_eq!(charges.get(&TestAbility::TestAction).unwrap().charges(), 1);
+        assert!(cooldowns.ready(&TestAbility::TestAction).is_err());
+    }
+
     #[test]
     fn ability_ready_cooldown_and_charges() {
         let mut charges = Some(Charges::simple(1));
@@ -396,6 +1471,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn required_cooldown_limits_rapid_presses() {
+        let mut charges = Charges::simple(3).with_required_cooldown(true);
+        let mut cooldown = Cooldown::from_secs(1.);
+
+        let mut fired = 0;
+        for _second in 0..5 {
+            // Press three times in the same frame
+            for _press in 0..3 {
+                if trigger_ability::<NullPool>(Some(&mut charges), Some(&mut cooldown), None, None)
+                    .is_ok()
+                {
+                    fired += 1;
+                }
+            }
+            cooldown.tick(Duration::from_secs(1), Some(&mut charges));
+        }
+
+        // Once per second, until the charges run out
+        assert_eq!(fired, 3);
+        assert_eq!(charges.charges(), 0);
+    }
+
+    #[test]
+    fn required_cooldown_blocks_while_charges_remain() {
+        let mut charges = Charges::simple(3).with_required_cooldown(true);
+        let mut cooldown = Cooldown::from_secs(1.);
+
+        trigger_ability::<NullPool>(Some(&mut charges), Some(&mut cooldown), None, None).unwrap();
+        assert_eq!(charges.charges(), 2);
+        assert_eq!(
+            ability_ready::<NullPool>(Some(&charges), Some(&cooldown), None, None),
+            Err(CannotUseAbility::OnCooldown)
+        );
+        assert_eq!(
+            trigger_ability::<NullPool>(Some(&mut charges), Some(&mut cooldown), None, None),
+            Err(CannotUseAbility::OnCooldown)
+        );
+        assert_eq!(charges.charges(), 2);
+
+        // Without the flag, the cooldown is ignored while charges remain
+        charges.set_requires_cooldown(false);
+        assert!(ability_ready::<NullPool>(Some(&charges), Some(&cooldown), None, None).is_ok());
+    }
+
     #[test]
     fn trigger_ability_cooldown_and_charges() {
         let mut charges = Some(Charges::simple(1));
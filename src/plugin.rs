@@ -1,10 +1,17 @@
 //! Contains main plugin exported by this crate.
 
-use crate::Abilitylike;
+use crate::ability_log::{AbilityAttempts, AbilityLog, AbilityTriggered};
+use crate::charges::{ChargeReplenished, ChargeState, Charges, ChargesExpended};
+use crate::cooldown::{Cooldown, CooldownFinished, CooldownState, GlobalCooldownFinished};
+use crate::disabled::DisabledAbilities;
+use crate::hud_summary::AbilityHudSummary;
+use crate::pool::{AbilityCosts, Pool, RegeneratingPool};
+use crate::{AbilityTimeDilation, Abilitylike, CannotUseAbility};
 use bevy::ecs::prelude::*;
+use bevy::reflect::GetTypeRegistration;
 use core::marker::PhantomData;
 
-use bevy::app::{App, Plugin, PreUpdate};
+use bevy::app::{App, Plugin, PostUpdate, PreUpdate};
 use leafwing_input_manager::plugin::InputManagerSystem;
 
 /// A [`Plugin`] that collects [`Input`](bevy::input::Input) from disparate sources, producing an [`ActionState`](crate::action_state::ActionState) that can be conveniently checked
@@ -37,6 +44,10 @@ use leafwing_input_manager::plugin::InputManagerSystem;
 ///    - labeled [`InputManagerSystem::Update`]
 /// - [`release_on_disable`](crate::systems::release_on_disable), which resets action states when [`ToggleActions`] is flipped, to avoid persistent presses.
 pub struct AbilityPlugin<A: Abilitylike> {
+    logging: bool,
+    action_state_sync: bool,
+    /// Additional setup that depends on types other than `A`, such as the [`Pool`] types used.
+    extensions: Vec<fn(&mut App)>,
     _phantom: PhantomData<A>,
 }
 
@@ -45,19 +56,169 @@ pub struct AbilityPlugin<A: Abilitylike> {
 pub enum AbilitySystem {
     /// Updates the cooldowns of all abilities,
     /// including their charges and global cooldowns if applicable.
+    ///
+    /// Pools added with [`AbilityPlugin::with_regenerating_pool`] are regenerated here too.
     TickCooldowns,
+    /// Records attempts to use abilities in the [`AbilityLog`].
+    ///
+    /// Only present if [`AbilityPlugin::with_logging`] was used.
+    LogTriggers,
+    /// Triggers the abilities that were just pressed on each entity with an [`AutoTrigger`](crate::AutoTrigger).
+    ///
+    /// Only present if [`AbilityPlugin::with_auto_trigger`] was used.
+    /// Systems that read [`AutoTrigger::triggered`](crate::AutoTrigger::triggered) should run after this set.
+    AutoTrigger,
+    /// Disables actions in the [`ActionState`](leafwing_input_manager::action_state::ActionState) whose abilities are not ready.
+    ///
+    /// Only present if [`AbilityPlugin::with_action_state_sync`] was used.
+    SyncActionState,
+    /// Updates the [`AbilityHudSummary`] with the current state of each player's abilities.
+    ///
+    /// Only present if [`AbilityPlugin::with_hud_summary`] was used.
+    /// UI systems that read the summary should run after this set.
+    UpdateHudSummary,
 }
 
 // Deriving default induces an undesired bound on the generic
 impl<A: Abilitylike> Default for AbilityPlugin<A> {
     fn default() -> Self {
         Self {
+            logging: false,
+            action_state_sync: false,
+            extensions: Vec::new(),
             _phantom: PhantomData,
         }
     }
 }
 
-impl<A: Abilitylike> Plugin for AbilityPlugin<A> {
+impl<A: Abilitylike> AbilityPlugin<A> {
+    /// Records each ability triggered through [`AbilityState`](crate::AbilityState), and each [`AbilityTriggered`] event, in an [`AbilityLog`] resource.
+    ///
+    /// Every entity with a [`CooldownState`] is given an [`AbilityAttempts`] component to record its attempts,
+    /// so this must be added before any such entities are spawned.
+    /// If an [`AbilityLog`] resource has already been inserted, its capacity is respected.
+    /// Logging is disabled by default, and costs nothing unless enabled:
+    /// the [`AbilityTriggered`] event is only registered when it is.
+    #[must_use]
+    pub fn with_logging(mut self) -> Self {
+        self.logging = true;
+        self
+    }
+
+    /// Mirrors the readiness of each ability into the disabled state of its action in the [`ActionState`](leafwing_input_manager::action_state::ActionState).
+    ///
+    /// This is useful when systems that only read the `ActionState` (such as animation or movement canceling)
+    /// should ignore presses of abilities that cannot currently be used.
+    /// Actions that were disabled by other code are never re-enabled.
+    /// See [`sync_action_state`](crate::systems::sync_action_state) for more details.
+    #[must_use]
+    pub fn with_action_state_sync(mut self) -> Self {
+        self.action_state_sync = true;
+        self
+    }
+
+    /// Maintains an [`AbilityHudSummary`] resource, summarizing the abilities of every entity with a [`PlayerId`](crate::hud_summary::PlayerId).
+    ///
+    /// Costs are checked against pools of type `P`: use [`NullPool`](crate::NullPool) if your abilities have no costs.
+    /// This should only be called once per ability type `A`.
+    #[must_use]
+    pub fn with_hud_summary<P: Pool + Component>(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.init_resource::<AbilityHudSummary<A>>().add_systems(
+                PostUpdate,
+                crate::systems::update_ability_hud_summary::<A, P>
+                    .in_set(AbilitySystem::UpdateHudSummary),
+            );
+        });
+        self
+    }
+
+    /// Triggers the abilities that were just pressed on each entity with an [`AutoTrigger`](crate::AutoTrigger) every frame,
+    /// by adding [`auto_trigger_abilities::<A, P>`](crate::systems::auto_trigger_abilities) to [`AbilitySystem::AutoTrigger`].
+    ///
+    /// Costs are paid from pools of type `P`: use [`NullPool`](crate::NullPool) if your abilities have no costs.
+    /// This should only be called once per ability type `A`.
+    #[must_use]
+    pub fn with_auto_trigger<P: Pool + Component>(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.add_systems(
+                PreUpdate,
+                crate::systems::auto_trigger_abilities::<A, P>
+                    .in_set(AbilitySystem::AutoTrigger)
+                    .after(AbilitySystem::TickCooldowns)
+                    .after(InputManagerSystem::Update),
+            );
+        });
+        self
+    }
+
+    /// Registers the [`AbilityCosts`] of abilities of type `A`, paid from pools of type `P`, for reflection.
+    ///
+    /// The [`AbilityCost`](crate::pool::AbilityCost) of each ability can then be read and edited through reflection, such as in an inspector.
+    /// This can be chained to register costs for several pool types.
+    #[must_use]
+    pub fn with_ability_costs<P>(mut self) -> Self
+    where
+        P: Pool + Component,
+        AbilityCosts<A, P>: GetTypeRegistration,
+    {
+        self.extensions.push(|app| {
+            app.register_type::<AbilityCosts<A, P>>();
+        });
+        self
+    }
+
+    /// Logs a warning whenever an entity is spawned with an ability whose cost can never be paid from its pool of type `P`,
+    /// by adding [`validate_ability_costs::<A, P>`](crate::systems::validate_ability_costs).
+    ///
+    /// This catches configuration mistakes, like a cost of 1000 mana on a pool with a max of 100,
+    /// which would otherwise make the ability silently unusable.
+    #[must_use]
+    pub fn with_cost_validation<P>(mut self) -> Self
+    where
+        P: Pool + Component,
+        P::Quantity: core::fmt::Debug,
+    {
+        self.extensions.push(|app| {
+            app.add_systems(PostUpdate, crate::systems::validate_ability_costs::<A, P>);
+        });
+        self
+    }
+
+    /// Regenerates pools of type `P` every frame, by adding [`regenerate_resource_pool::<P>`](crate::systems::regenerate_resource_pool)
+    /// to [`AbilitySystem::TickCooldowns`], and registers `P` for reflection.
+    ///
+    /// This can be chained to regenerate several pool types.
+    /// Pools that are shared between several ability types are only regenerated once per frame,
+    /// even if this is called on more than one [`AbilityPlugin`].
+    #[must_use]
+    pub fn with_regenerating_pool<P>(mut self) -> Self
+    where
+        P: RegeneratingPool + Component + Resource + GetTypeRegistration,
+    {
+        self.extensions.push(|app| {
+            if app.world().contains_resource::<RegeneratingPoolAdded<P>>() {
+                return;
+            }
+
+            app.insert_resource(RegeneratingPoolAdded::<P>(PhantomData))
+                .register_type::<P>()
+                .add_systems(
+                    PreUpdate,
+                    crate::systems::regenerate_resource_pool::<P>
+                        .in_set(AbilitySystem::TickCooldowns),
+                );
+        });
+        self
+    }
+}
+
+/// Marks that [`regenerate_resource_pool::<P>`](crate::systems::regenerate_resource_pool) has been added by [`AbilityPlugin::with_regenerating_pool`],
+/// so that it is not added twice.
+#[derive(Resource)]
+struct RegeneratingPoolAdded<P>(PhantomData<P>);
+
+impl<A: Abilitylike + GetTypeRegistration> Plugin for AbilityPlugin<A> {
     fn build(&self, app: &mut App) {
         use crate::systems::*;
 
@@ -69,5 +230,43 @@ impl<A: Abilitylike> Plugin for AbilityPlugin<A> {
                 .in_set(InputManagerSystem::Tick)
                 .before(InputManagerSystem::Update),
         );
+
+        if self.action_state_sync {
+            app.add_systems(
+                PreUpdate,
+                sync_action_state::<A>
+                    .in_set(AbilitySystem::SyncActionState)
+                    .after(AbilitySystem::TickCooldowns)
+                    .before(InputManagerSystem::Update),
+            );
+        }
+
+        // Reflection
+        app.register_type::<CooldownState<A>>()
+            .register_type::<ChargeState<A>>()
+            .register_type::<Cooldown>()
+            .register_type::<Charges>()
+            .register_type::<DisabledAbilities<A>>()
+            .register_type::<CannotUseAbility>()
+            .register_type::<AbilityTimeDilation>();
+
+        app.add_event::<CooldownFinished<A>>()
+            .add_event::<GlobalCooldownFinished<A>>()
+            .add_event::<ChargeReplenished<A>>()
+            .add_event::<ChargesExpended<A>>();
+
+        for extension in &self.extensions {
+            extension(app);
+        }
+
+        if self.logging {
+            app.register_required_components::<CooldownState<A>, AbilityAttempts<A>>()
+                .add_event::<AbilityTriggered<A>>()
+                .init_resource::<AbilityLog<A>>()
+                .add_systems(
+                    PostUpdate,
+                    log_ability_triggers::<A>.in_set(AbilitySystem::LogTriggers),
+                );
+        }
     }
 }
@@ -5,13 +5,18 @@
 //! Life, mana, energy and rage might all be modelled effectively as pools.
 //! Pools have a maximum value and a minimum value (almost always zero), can regenerate over time, and can be spent to pay for abilities.
 //!
-//! The [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool) system will regenerate resource pools of a given type if manually added.
+//! The [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool) system will regenerate resource pools of a given type if manually added,
+//! or if registered with [`AbilityPlugin::with_regenerating_pool`](crate::plugin::AbilityPlugin::with_regenerating_pool).
+//!
+//! To display a pool in your UI (e.g. as a health bar), use [`Pool::fraction`] or [`Pool::percentage`].
 //!
 //! Remember to manually register these types for reflection with [`App::register_type`](bevy::app::App::register_type) if you wish to serialize or inspect them.
 
 use bevy::utils::Duration;
 use bevy::{ecs::prelude::*, reflect::Reflect};
-use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, AddAssign, Div, Sub, SubAssign};
+use serde::{ser::Error as _, Deserialize, Serialize, Serializer};
 use std::{collections::HashMap, marker::PhantomData};
 use thiserror::Error;
 
@@ -33,8 +38,14 @@ pub trait Pool: Sized {
     ///
     /// Unlike a [`Pool`] type, which stores a max, min and regeneration,
     /// quantities are lighter weight and should be used for things like damage amounts, mana costs and regen rates.
+    ///
+    /// Dividing one quantity by another must produce their ratio, which is used by [`Pool::fraction`].
+    /// No other floating point arithmetic is required, so quantities may be integers:
+    /// see [`ComboPoints`](crate::premade_pools::combo::ComboPoints) for an example.
+    /// Integer quantities should saturate rather than overflow when added or subtracted.
     type Quantity: Add<Output = Self::Quantity>
         + Sub<Output = Self::Quantity>
+        + Div<Output = f32>
         + AddAssign
         + SubAssign
         + PartialEq
@@ -50,6 +61,12 @@ pub trait Pool: Sized {
     /// At this point, no resources remain to be spent.
     const MIN: Self::Quantity;
 
+    /// The zero value of [`Pool::Quantity`].
+    ///
+    /// This is the additive identity: adding it to a quantity leaves that quantity unchanged.
+    /// It is used for empty reservations, costs that are not paid and to tell regeneration from decay.
+    const ZERO: Self::Quantity;
+
     /// The current quantity of resources in the pool.
     ///
     /// # Panics
@@ -58,14 +75,103 @@ pub trait Pool: Sized {
     fn current(&self) -> Self::Quantity;
 
     /// Check if the given cost can be paid by this pool.
+    ///
+    /// If this pool has a [debt floor](Pool::debt_floor), costs may be paid as long as the pool would not fall below it.
+    /// [Reserved](Pool::reserved) resources cannot be used to pay costs.
+    ///
+    /// Calls [`Pool::available_detailed`], discarding the amounts.
     fn available(&self, amount: Self::Quantity) -> Result<(), CannotUseAbility> {
-        if self.current() >= amount {
+        self.available_detailed(amount)
+            .map_err(CannotUseAbility::from)
+    }
+
+    /// Check if the given cost can be paid by this pool, reporting how much was required and available if it cannot.
+    ///
+    /// This is useful for UI that explains how much more of a resource is needed.
+    /// Pools that override [`Pool::available`] should override this method to match.
+    fn available_detailed(
+        &self,
+        amount: Self::Quantity,
+    ) -> Result<(), PoolShortfall<Self::Quantity>> {
+        let spendable = self.current() - self.reserved();
+        let (can_pay, available) = match self.debt_floor() {
+            Some(floor) => (spendable - amount >= floor, spendable - floor),
+            None => (spendable >= amount, spendable),
+        };
+
+        if can_pay {
             Ok(())
         } else {
-            Err(CannotUseAbility::PoolInsufficient)
+            Err(PoolShortfall {
+                required: amount,
+                available,
+            })
         }
     }
 
+    /// How far below [`Pool::MIN`] the current value of this pool may fall, if at all.
+    ///
+    /// Pools cannot go into debt by default.
+    /// When a floor is set, [`Pool::available`] (and so [`Pool::expend`] and ability costs)
+    /// allows spending down to the floor, and [`Pool::set_current`] clamps to the floor rather than [`Pool::MIN`].
+    /// Regenerating pools work their way back up out of debt as normal.
+    #[inline]
+    #[must_use]
+    fn debt_floor(&self) -> Option<Self::Quantity> {
+        None
+    }
+
+    /// Is the current value of this pool below [`Pool::MIN`]?
+    ///
+    /// This can only occur for pools with a [debt floor](Pool::debt_floor).
+    #[inline]
+    #[must_use]
+    fn is_in_debt(&self) -> bool {
+        self.current() < Self::MIN
+    }
+
+    /// The quantity of this pool that is currently held by [`PoolReservation`]s.
+    ///
+    /// Reserved resources are still included in [`Pool::current`], but cannot be spent.
+    /// Pools cannot hold reservations unless they implement [`ReservablePool`], so this is zero by default.
+    #[inline]
+    #[must_use]
+    fn reserved(&self) -> Self::Quantity {
+        Self::ZERO
+    }
+
+    /// The current quantity of this pool, excluding any [reserved](Pool::reserved) resources.
+    #[inline]
+    #[must_use]
+    fn unreserved(&self) -> Self::Quantity {
+        self.current() - self.reserved()
+    }
+
+    /// Multiplies `quantity` by `factor`, returning [`None`] if this pool's quantities cannot be scaled.
+    ///
+    /// [`Pool::Quantity`] is not required to support multiplication, so this returns [`None`] by default.
+    /// Override it to allow [`AbilityCost::Percent`] costs to be paid from this pool.
+    #[inline]
+    #[must_use]
+    fn scale_quantity(_quantity: Self::Quantity, _factor: f32) -> Option<Self::Quantity> {
+        None
+    }
+
+    /// Is `quantity` a valid value for this pool to store or be modified by?
+    ///
+    /// Every quantity is valid by default.
+    /// Pools with floating point quantities should override this to reject NaN and infinite values,
+    /// which otherwise make every comparison fail and leave the pool stuck.
+    /// The premade pools do so.
+    ///
+    /// Invalid quantities passed to the methods of [`Pool`] trigger a panic in debug builds,
+    /// and are rejected in release builds, leaving the pool unchanged.
+    #[inline]
+    #[must_use]
+    fn is_valid_quantity(_quantity: Self::Quantity) -> bool {
+        true
+    }
+
     /// Sets the current quantity of resources in the pool.
     ///
     /// This will be bounded by the minimum and maximum values of this pool.
@@ -79,10 +185,75 @@ pub trait Pool: Sized {
     ///
     /// The current value will be reduced to the new max if necessary.
     ///
-    /// Has no effect if `new_max < Pool::MIN`.
+    /// Has no effect if `new_max < Pool::MIN`, or if it is not a [valid quantity](Pool::is_valid_quantity).
     /// Returns a [`MaxPoolLessThanMin`] error if this occurs.
     fn set_max(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin>;
 
+    /// Sets the maximum of this pool, scaling the current value so that the [fraction](Pool::fraction) filled is unchanged.
+    ///
+    /// For example, a pool at 80 / 100 becomes 96 / 120.
+    /// This is the usual choice for life totals that change with equipment,
+    /// as it prevents swapping gear back and forth from healing the character.
+    ///
+    /// Requires [`Pool::scale_quantity`]: pools whose quantities cannot be scaled fall back to [`Pool::set_max`].
+    /// Has no effect if `new_max < Pool::MIN`, returning a [`MaxPoolLessThanMin`] error.
+    fn set_max_preserving_fraction(
+        &mut self,
+        new_max: Self::Quantity,
+    ) -> Result<(), MaxPoolLessThanMin> {
+        let fraction = self.fraction();
+        self.set_max(new_max)?;
+
+        if let Some(filled) = Self::scale_quantity(self.max() - Self::MIN, fraction) {
+            self.set_current(Self::MIN + filled);
+        }
+        Ok(())
+    }
+
+    /// Sets the maximum of this pool, adding the change in the max to the current value.
+    ///
+    /// For example, a pool at 80 / 100 becomes 100 / 120, while shrinking it to a max of 90 leaves it at 70 / 90.
+    /// This is the usual choice for resources like mana when leveling up,
+    /// as the newly gained capacity is immediately usable.
+    ///
+    /// Has no effect if `new_max < Pool::MIN`, returning a [`MaxPoolLessThanMin`] error.
+    fn set_max_adding_delta(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
+        let old_max = self.max();
+        let old_current = self.current();
+        self.set_max(new_max)?;
+
+        let max = self.max();
+        if max >= old_max {
+            self.set_current(old_current + (max - old_max));
+        } else {
+            self.set_current(old_current - (old_max - max));
+        }
+        Ok(())
+    }
+
+    /// The fraction of this pool that is currently filled, between `0.0` (empty) and `1.0` (full).
+    ///
+    /// This is measured from [`Pool::MIN`], and is intended for UI elements like health bars.
+    /// Returns `0.0` if the max is equal to [`Pool::MIN`].
+    /// Pools that are [overfilled](TemporaryMaxPool::set_overfill) beyond their max return `1.0`.
+    #[must_use]
+    fn fraction(&self) -> f32 {
+        if self.max() <= Self::MIN {
+            return 0.0;
+        }
+
+        ((self.current() - Self::MIN) / (self.max() - Self::MIN)).clamp(0.0, 1.0)
+    }
+
+    /// The percentage of this pool that is currently filled, between `0.0` (empty) and `100.0` (full).
+    ///
+    /// See [`Pool::fraction`] for more details.
+    #[inline]
+    #[must_use]
+    fn percentage(&self) -> f32 {
+        self.fraction() * 100.0
+    }
+
     /// Is the pool currently full?
     #[inline]
     #[must_use]
@@ -93,16 +264,20 @@ pub trait Pool: Sized {
     /// Is the pool currently empty?
     ///
     /// Note that this compares the current value to [`Pool::MIN`], not `0`.
+    /// Pools that are [in debt](Pool::is_in_debt) are also empty.
     #[inline]
     #[must_use]
     fn is_empty(&self) -> bool {
-        self.current() == Self::MIN
+        self.current() <= Self::MIN
     }
 
     /// Spend the specified amount from the pool, if there is that much available.
     ///
     /// Otherwise, return the error [`CannotUseAbility::PoolEmpty`].
     fn expend(&mut self, amount: Self::Quantity) -> Result<(), CannotUseAbility> {
+        if !check_quantity::<Self>(amount) {
+            return Err(CannotUseAbility::PoolInsufficient);
+        }
         self.available(amount)?;
 
         let new_current = self.current() - amount;
@@ -110,14 +285,50 @@ pub trait Pool: Sized {
         Ok(())
     }
 
+    /// Adds the signed `delta` to the current value of the pool, returning the change that was actually applied.
+    ///
+    /// As the pool is bounded, the applied change may be smaller than `delta`:
+    /// dealing 50 damage (a `delta` of -50) to a pool with 30 remaining returns -30,
+    /// while healing a nearly full pool returns the effective amount healed.
+    fn apply(&mut self, delta: Self::Quantity) -> Self::Quantity {
+        let old_current = self.current();
+        if !check_quantity::<Self>(delta) {
+            return Self::ZERO;
+        }
+
+        let new_current = self.set_current(old_current + delta);
+        new_current - old_current
+    }
+
+    /// Spend as much of the specified amount from the pool as possible.
+    ///
+    /// Unlike [`Self::expend`], this never fails: if less than `amount` is available, the pool is emptied.
+    /// Returns the amount that was actually removed from the pool.
+    fn expend_up_to(&mut self, amount: Self::Quantity) -> Self::Quantity {
+        let old_current = self.current();
+        if !check_quantity::<Self>(amount) {
+            return Self::ZERO;
+        }
+
+        let new_current = self.set_current(old_current - amount);
+        old_current - new_current
+    }
+
     /// Replenish the pool by the specified amount.
     ///
     /// This cannot cause the pool to exceed maximum value that can be stored in the pool.
     /// This is the sign-flipped counterpart to [`Self::expend`],
     /// however, unlike [`Self::expend`], this method will not return an error if the pool is empty.
-    fn replenish(&mut self, amount: Self::Quantity) {
-        let new_current = self.current() + amount;
-        self.set_current(new_current);
+    ///
+    /// Returns the overflow: the part of `amount` that did not fit in the pool (which is zero if it all fit).
+    fn replenish(&mut self, amount: Self::Quantity) -> Self::Quantity {
+        if !check_quantity::<Self>(amount) {
+            return amount;
+        }
+
+        let target = self.current() + amount;
+        let new_current = self.set_current(target);
+        target - new_current
     }
 }
 
@@ -142,188 +353,1991 @@ pub trait RegeneratingPool: Pool {
     fn regenerate(&mut self, delta_time: Duration);
 }
 
-/// The maximum value for a [`Pool`] was set to be less than [`Pool::MIN`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
-#[error(
-    "The maximum quantity that can be stored in a pool must be greater than the minimum value."
-)]
-pub struct MaxPoolLessThanMin;
+/// Scales the regeneration rate of a [`RegeneratingPool`] based on how full it is.
+///
+/// The multiplier is evaluated at the start of each call to [`RegeneratingPool::regenerate`],
+/// using the [`Pool::fraction`] of the pool.
+/// Custom curves are plain function pointers, so that regeneration remains deterministic.
+///
+/// Curves are stored by the premade [`ManaPool`](crate::premade_pools::mana::ManaPool)
+/// and [`EnergyPool`](crate::premade_pools::energy::EnergyPool): other pools always regenerate at a constant rate.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum RegenCurve {
+    /// The pool regenerates at its normal rate, regardless of how full it is.
+    #[default]
+    Constant,
+    /// The multiplier is linearly interpolated between `at_empty` and `at_full`.
+    Linear {
+        /// The multiplier when the pool is empty.
+        at_empty: f32,
+        /// The multiplier when the pool is full.
+        at_full: f32,
+    },
+    /// The multiplier is computed by the provided function, which is passed the fraction of the pool that is full.
+    ///
+    /// Function pointers cannot be saved, so serializing this variant returns an error,
+    /// and it cannot be deserialized.
+    #[serde(skip_deserializing, serialize_with = "reject_custom_curve")]
+    Custom(fn(f32) -> f32),
+}
 
-/// Stores the cost (in terms of the [`Pool::Quantity`] of ability) associated with each ability of type `A`.
-#[derive(Component, Resource, Debug, Reflect)]
-pub struct AbilityCosts<A: Abilitylike, P: Pool> {
-    /// The underlying cost of each ability.
-    cost_map: HashMap<A, P::Quantity>,
-    _phantom: PhantomData<A>,
+/// Fails to serialize [`RegenCurve::Custom`], explaining why.
+fn reject_custom_curve<S: Serializer>(
+    _curve: &fn(f32) -> f32,
+    _serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Err(S::Error::custom(
+        "custom regen curves are function pointers and cannot be serialized: use `RegenCurve::Linear` instead",
+    ))
 }
 
-impl<A: Abilitylike, P: Pool> Clone for AbilityCosts<A, P> {
-    fn clone(&self) -> Self {
-        AbilityCosts {
-            cost_map: self.cost_map.clone(),
-            _phantom: PhantomData,
+impl RegenCurve {
+    /// The multiplier applied to the regeneration rate when the pool is `fraction_full`.
+    #[must_use]
+    pub fn multiplier(&self, fraction_full: f32) -> f32 {
+        match self {
+            RegenCurve::Constant => 1.,
+            RegenCurve::Linear { at_empty, at_full } => {
+                at_empty + (at_full - at_empty) * fraction_full
+            }
+            RegenCurve::Custom(curve) => curve(fraction_full),
         }
     }
 }
 
-impl<A: Abilitylike, P: Pool> Default for AbilityCosts<A, P> {
-    fn default() -> Self {
-        AbilityCosts {
-            cost_map: HashMap::new(),
-            _phantom: PhantomData,
+impl PartialEq for RegenCurve {
+    /// Custom curves are compared by address, which may consider copies of the same function unequal.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (RegenCurve::Constant, RegenCurve::Constant) => true,
+            (
+                RegenCurve::Linear { at_empty, at_full },
+                RegenCurve::Linear {
+                    at_empty: other_at_empty,
+                    at_full: other_at_full,
+                },
+            ) => at_empty == other_at_empty && at_full == other_at_full,
+            (RegenCurve::Custom(curve), RegenCurve::Custom(other_curve)) => {
+                core::ptr::fn_addr_eq(*curve, *other_curve)
+            }
+            _ => false,
         }
     }
 }
 
-impl<A: Abilitylike, P: Pool> AbilityCosts<A, P> {
-    /// Creates a new [`AbilityCosts`] from an iterator of `(charges, action)` pairs
+/// A resource pool whose maximum can be temporarily raised by buffs, and whose current value may exceed its maximum.
+///
+/// Temporary increases to the max expire during [`RegeneratingPool::regenerate`],
+/// at which point the current value is clamped down to the new max (plus any overfill).
+/// [`Pool::max`] returns the effective max, including any temporary increases.
+pub trait TemporaryMaxPool: RegeneratingPool {
+    /// The maximum of this pool, excluding any temporary increases.
     ///
-    /// If a [`Pool::Quantity`] is not provided for an action, that action will have no cost in terms of the stored resource pool.
+    /// [`Pool::set_max`] sets this value.
+    fn base_max(&self) -> Self::Quantity;
+
+    /// Raises the max of this pool by `amount` for `duration`.
     ///
-    /// To create an empty [`AbilityCosts`] struct, use the [`Default::default`] method instead.
-    #[must_use]
-    pub fn new(action_cost_pairs: impl IntoIterator<Item = (A, P::Quantity)>) -> Self {
-        let mut ability_costs = AbilityCosts::default();
-        for (action, cost) in action_cost_pairs.into_iter() {
-            ability_costs.set(action, cost);
-        }
-        ability_costs
-    }
+    /// The current value is not changed: heal the pool to fill the new space.
+    /// Negative and [invalid](Pool::is_valid_quantity) amounts are ignored.
+    fn add_temporary_max(&mut self, amount: Self::Quantity, duration: Duration);
 
-    /// Are enough resources available in the `pool` to use the `action`?
+    /// Removes all temporary increases to the max of this pool, clamping the current value if needed.
+    fn clear_temporary_max(&mut self);
+
+    /// How far the current value of this pool may exceed its max.
     ///
-    /// Returns `true` if the underlying resource is [`None`].
-    #[inline]
-    #[must_use]
-    pub fn available(&self, action: &A, pool: &P) -> bool {
-        if let Some(cost) = self.get(action) {
-            pool.available(*cost).is_ok()
-        } else {
-            true
-        }
-    }
+    /// This is the minimum of the pool by default, which prevents overfilling.
+    fn overfill(&self) -> Self::Quantity;
 
-    /// Pay the ability cost for the `action` from the `pool`, if able
+    /// Sets how far the current value of this pool may exceed its max, such as for overheal mechanics.
     ///
-    /// The cost of the action is expended from the [`Pool`].
+    /// Regeneration never overfills a pool: only explicit changes like [`Pool::replenish`] do.
+    fn set_overfill(&mut self, overfill: Self::Quantity);
+}
+
+/// A resource pool that can hold resources in reserve, such as for channeled abilities.
+///
+/// Reserving resources makes them unavailable to pay other costs, without removing them from the pool.
+/// The returned [`PoolReservation`] is then either [committed](PoolReservation::commit), spending the resources,
+/// or [released](PoolReservation::release), making them available again.
+pub trait ReservablePool: Pool {
+    /// Sets the quantity of this pool that is held by reservations.
     ///
-    /// If the underlying pool does not have enough resources to pay the action's cost,
-    /// a [`CannotUseAbility::PoolEmpty`] error is returned and this call has no effect.
+    /// This is called by [`ReservablePool::reserve`] and [`PoolReservation`]: you should rarely need to call it directly.
+    fn set_reserved(&mut self, reserved: Self::Quantity);
+
+    /// Reserves `amount` from this pool, if that much is [available](Pool::available).
     ///
-    /// Returns [`Ok(())`] if the underlying [`Pool`] can support the cost of the action.
-    #[inline]
-    pub fn pay_cost(&mut self, action: &A, pool: &mut P) -> Result<(), CannotUseAbility> {
-        if let Some(cost) = self.get(action) {
-            pool.expend(*cost)
-        } else {
-            Ok(())
+    /// Otherwise, returns [`CannotUseAbility::PoolInsufficient`] and reserves nothing.
+    fn reserve(
+        &mut self,
+        amount: Self::Quantity,
+    ) -> Result<PoolReservation<Self>, CannotUseAbility> {
+        self.available(amount)?;
+        self.set_reserved(self.reserved() + amount);
+        Ok(PoolReservation::new(amount))
+    }
+}
+
+/// Resources held in a [`ReservablePool`], created by [`ReservablePool::reserve`].
+///
+/// This is plain data, so it can be stored in a component (or elsewhere) until the reservation is resolved.
+/// Each reservation should be resolved exactly once, using the same pool that created it.
+pub struct PoolReservation<P: Pool> {
+    amount: P::Quantity,
+    _phantom: PhantomData<fn() -> P>,
+}
+
+impl<P: ReservablePool> PoolReservation<P> {
+    fn new(amount: P::Quantity) -> Self {
+        PoolReservation {
+            amount,
+            _phantom: PhantomData,
         }
     }
 
-    /// Returns a reference to the underlying [`Pool::Quantity`] cost for `action`, if set.
+    /// The quantity held by this reservation.
     #[inline]
     #[must_use]
-    pub fn get(&self, action: &A) -> Option<&P::Quantity> {
-        self.cost_map.get(action)
+    pub fn amount(&self) -> P::Quantity {
+        self.amount
     }
 
-    /// Returns a mutable reference to the underlying [`Pool::Quantity`] cost for `action`, if set.
-    #[inline]
-    #[must_use]
-    pub fn get_mut(&mut self, action: &A) -> Option<&mut P::Quantity> {
-        self.cost_map.get_mut(action)
+    /// Spends the reserved resources from the `pool`.
+    pub fn commit(self, pool: &mut P) {
+        pool.set_reserved(pool.reserved() - self.amount);
+        pool.set_current(pool.current() - self.amount);
     }
 
-    /// Sets the underlying [`Pool::Quantity`] cost for `action` to the provided value.
-    ///
-    /// Unless you're building a new [`AbilityCosts`] struct, you likely want to use [`Self::get_mut`].
-    #[inline]
-    pub fn set(&mut self, action: A, cost: P::Quantity) -> &mut Self {
-        self.cost_map.insert(action, cost);
-        self
+    /// Returns the reserved resources to the `pool`, making them available again.
+    pub fn release(self, pool: &mut P) {
+        pool.set_reserved(pool.reserved() - self.amount);
     }
 
-    /// Collects a `&mut Self` into a `Self`.
+    /// Spends `used` of the reserved resources from the `pool`, and releases the rest.
     ///
-    /// Used to conclude the builder pattern. Actually just calls `self.clone()`.
+    /// This is useful when a channel is interrupted partway through.
+    /// `used` is capped at the [amount](Self::amount) of this reservation.
+    pub fn commit_partial(self, pool: &mut P, used: P::Quantity) {
+        let used = if used > self.amount {
+            self.amount
+        } else {
+            used
+        };
+        pool.set_reserved(pool.reserved() - self.amount);
+        pool.set_current(pool.current() - used);
+    }
+}
+
+impl<P: Pool> Clone for PoolReservation<P> {
+    fn clone(&self) -> Self {
+        PoolReservation {
+            amount: self.amount,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P: Pool> PartialEq for PoolReservation<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.amount == other.amount
+    }
+}
+
+impl<P: Pool> core::fmt::Debug for PoolReservation<P>
+where
+    P::Quantity: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PoolReservation")
+            .field("amount", &self.amount)
+            .finish()
+    }
+}
+
+/// Checks that `quantity` is [valid](Pool::is_valid_quantity) for pools of type `P`, panicking in debug builds if it is not.
+///
+/// Returns `false` for invalid quantities in release builds, so that they can be rejected.
+#[inline]
+#[track_caller]
+pub(crate) fn check_quantity<P: Pool>(quantity: P::Quantity) -> bool {
+    let valid = P::is_valid_quantity(quantity);
+    debug_assert!(
+        valid,
+        "invalid quantity for a pool, such as NaN or infinity"
+    );
+    valid
+}
+
+/// The maximum value for a [`Pool`] was set to be less than [`Pool::MIN`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error(
+    "The maximum quantity that can be stored in a pool must be greater than the minimum value."
+)]
+pub struct MaxPoolLessThanMin;
+
+/// A cost multiplier could not be applied to costs paid from a [`Pool`].
+///
+/// Returned by the setters of [`CostModifiers`], and converts into [`CannotUseAbility::PoolInsufficient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum InvalidCostMultiplier {
+    /// The multiplier was negative or not finite, which would turn costs into refunds.
+    #[error("Cost multipliers must be finite and non-negative.")]
+    NotFiniteOrNegative,
+    /// The multiplier was not `1.0`, but the pool cannot [scale its quantities](Pool::scale_quantity).
+    #[error("This pool cannot scale its quantities, so cost multipliers other than 1.0 cannot be applied.")]
+    PoolCannotScale,
+    /// The scaled cost was not a [valid quantity](Pool::is_valid_quantity) for the pool.
+    #[error("The scaled cost is not a valid quantity for this pool.")]
+    ScaledCostInvalid,
+}
+
+impl From<InvalidCostMultiplier> for CannotUseAbility {
+    fn from(_: InvalidCostMultiplier) -> Self {
+        CannotUseAbility::PoolInsufficient
+    }
+}
+
+/// A [`Pool`] could not pay a cost, because it had less available than was required.
+///
+/// Returned by [`Pool::available_detailed`], and converts into [`CannotUseAbility::PoolInsufficient`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoolShortfall<Q> {
+    /// The quantity that was required.
+    pub required: Q,
+    /// The quantity that could be spent from the pool, excluding [reserved](Pool::reserved) resources.
+    pub available: Q,
+}
+
+impl<Q: Copy + Sub<Output = Q>> PoolShortfall<Q> {
+    /// How much more was required than was available.
     #[inline]
     #[must_use]
-    pub fn build(&mut self) -> Self {
-        self.clone()
+    pub fn missing(&self) -> Q {
+        self.required - self.available
     }
+}
 
-    /// Returns an iterator of references to the underlying non-[`None`] [`Charges`]
-    #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = &P::Quantity> {
-        self.cost_map.values()
+impl<Q: core::fmt::Debug> Display for PoolShortfall<Q> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Not enough resources: {:?} required, but only {:?} available.",
+            self.required, self.available
+        )
     }
+}
 
-    /// Returns an iterator of mutable references to the underlying non-[`None`] [`Charges`]
-    #[inline]
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut P::Quantity> {
-        self.cost_map.values_mut()
+impl<Q: core::fmt::Debug> std::error::Error for PoolShortfall<Q> {}
+
+impl<Q> From<PoolShortfall<Q>> for CannotUseAbility {
+    fn from(_: PoolShortfall<Q>) -> Self {
+        CannotUseAbility::PoolInsufficient
     }
 }
 
-/// Stores a resource pool and the associated costs for each ability.
+/// Wraps a [`RegeneratingPool`] so that its regeneration is interrupted for a period after each expenditure.
 ///
-/// Note that if your abilities do not cost the given resource,
-/// you can still add your [`Pool`] type as a component.
+/// For example, wrapping a mana pool with a `regen_delay` of five seconds means that mana does not regenerate
+/// until five seconds after the last spell was cast.
+/// Spending with [`Pool::expend`] (including when paying for abilities) restarts the delay;
+/// other ways of lowering the current value do not, but [`InterruptiblePool::interrupt`] can be called manually.
 ///
-/// This is particularly common when working with life totals,
-/// as you want the other functionality of pools (current, max, regen, depletion)
-/// but often cannot spend it on abilities.
+/// As this type implements [`RegeneratingPool`] itself, it can be used anywhere that the wrapped pool can,
+/// including as the pool type for [`AbilityCosts`] and in [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool).
 ///
-/// # Usage
+/// Decay (a negative `regen_per_second`) is never interrupted by spending.
 ///
-/// Note that resource pools are not controlled by [`AbilityPlugin`](crate::plugin::AbilityPlugin).
-/// If you want regeneration to occur automatically, add [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool)
-/// to your schedule.
+/// ```rust
+/// use bevy::utils::Duration;
+/// use leafwing_abilities::pool::{InterruptiblePool, Pool, RegeneratingPool};
+/// use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
 ///
-/// These types are not automatically registered by [`AbilityPlugin`](crate::plugin::AbilityPlugin).
-/// You must register them manually with [`App::register_type`](bevy::app::App::register_type) if you wish to serialize or inspect them.
-#[derive(Bundle, Reflect)]
-pub struct PoolBundle<A: Abilitylike, P: Pool + Component> {
-    /// The resource pool used to pay for abilities
-    pub pool: P,
-    /// The cost of each ability in terms of this pool
-    pub ability_costs: AbilityCosts<A, P>,
+/// let mana = ManaPool::new(Mana(100.), Mana(100.), Mana(10.));
+/// let mut pool = InterruptiblePool::new(mana, Duration::from_secs(5));
+///
+/// pool.expend(Mana(50.)).unwrap();
+/// pool.regenerate(Duration::from_secs(4));
+/// assert_eq!(pool.current(), Mana(50.));
+///
+/// pool.regenerate(Duration::from_secs(2));
+/// assert_eq!(pool.current(), Mana(60.));
+/// ```
+#[derive(Component, Resource, Debug, Clone, PartialEq, Reflect)]
+pub struct InterruptiblePool<P: RegeneratingPool> {
+    /// The wrapped pool.
+    pool: P,
+    /// How long after an expenditure before regeneration resumes.
+    pub regen_delay: Duration,
+    /// The time elapsed since the pool was last spent, capped at `regen_delay`.
+    since_expended: Duration,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::premade_pools::mana::{Mana, ManaPool};
+impl<P: RegeneratingPool> InterruptiblePool<P> {
+    /// Wraps `pool`, interrupting its regeneration for `regen_delay` after each expenditure.
+    ///
+    /// The pool starts out ready to regenerate, as if the delay had already elapsed.
+    #[must_use]
+    pub fn new(pool: P, regen_delay: Duration) -> Self {
+        InterruptiblePool {
+            pool,
+            regen_delay,
+            since_expended: regen_delay,
+        }
+    }
 
-    #[test]
-    fn set_pool_cannot_exceed_min() {
-        let mut mana_pool = ManaPool::new(Mana(0.), Mana(10.), Mana(0.));
-        mana_pool.set_current(Mana(-3.));
-        assert_eq!(mana_pool.current(), ManaPool::MIN);
+    /// The wrapped pool.
+    #[inline]
+    #[must_use]
+    pub fn inner(&self) -> &P {
+        &self.pool
     }
 
-    #[test]
-    fn set_pool_cannot_exceed_max() {
-        let max_mana = Mana(10.);
-        let mut mana_pool = ManaPool::new(max_mana, max_mana, Mana(0.));
-        mana_pool.set_current(Mana(100.0));
-        assert_eq!(mana_pool.current(), max_mana);
+    /// A mutable reference to the wrapped pool.
+    ///
+    /// Spending from the wrapped pool directly does not interrupt regeneration.
+    #[inline]
+    #[must_use]
+    pub fn inner_mut(&mut self) -> &mut P {
+        &mut self.pool
     }
 
-    #[test]
-    fn reducing_max_decreases_current() {
-        let mut mana_pool = ManaPool::new(Mana(10.), Mana(10.), Mana(0.));
-        assert_eq!(mana_pool.current(), Mana(10.));
-        mana_pool.set_max(Mana(5.)).unwrap();
-        assert_eq!(mana_pool.current(), Mana(5.));
+    /// Unwraps this type, returning the wrapped pool.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> P {
+        self.pool
     }
 
-    #[test]
-    fn setting_max_below_min_fails() {
-        let mut mana_pool = ManaPool::new(Mana(10.), Mana(10.), Mana(0.));
-        let result = mana_pool.set_max(Mana(-7.));
-        assert_eq!(mana_pool.max(), Mana(10.));
-        assert_eq!(result, Err(MaxPoolLessThanMin))
+    /// The time elapsed since the pool was last spent, capped at `regen_delay`.
+    #[inline]
+    #[must_use]
+    pub fn time_since_expended(&self) -> Duration {
+        self.since_expended
+    }
+
+    /// Is regeneration currently interrupted?
+    #[inline]
+    #[must_use]
+    pub fn is_interrupted(&self) -> bool {
+        self.since_expended < self.regen_delay
+    }
+
+    /// Interrupts regeneration, restarting the delay without spending anything.
+    ///
+    /// This is useful for effects like "mana regeneration pauses after taking damage".
+    #[inline]
+    pub fn interrupt(&mut self) {
+        self.since_expended = Duration::ZERO;
+    }
+}
+
+impl<P: RegeneratingPool> Pool for InterruptiblePool<P> {
+    type Quantity = P::Quantity;
+    const MIN: P::Quantity = P::MIN;
+    const ZERO: P::Quantity = P::ZERO;
+
+    fn current(&self) -> Self::Quantity {
+        self.pool.current()
+    }
+
+    fn available(&self, amount: Self::Quantity) -> Result<(), CannotUseAbility> {
+        self.pool.available(amount)
+    }
+
+    fn available_detailed(
+        &self,
+        amount: Self::Quantity,
+    ) -> Result<(), PoolShortfall<Self::Quantity>> {
+        self.pool.available_detailed(amount)
+    }
+
+    fn set_current(&mut self, new_quantity: Self::Quantity) -> Self::Quantity {
+        self.pool.set_current(new_quantity)
+    }
+
+    fn max(&self) -> Self::Quantity {
+        self.pool.max()
+    }
+
+    fn set_max(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
+        self.pool.set_max(new_max)
+    }
+
+    fn debt_floor(&self) -> Option<Self::Quantity> {
+        self.pool.debt_floor()
+    }
+
+    fn reserved(&self) -> Self::Quantity {
+        self.pool.reserved()
+    }
+
+    fn scale_quantity(quantity: Self::Quantity, factor: f32) -> Option<Self::Quantity> {
+        P::scale_quantity(quantity, factor)
+    }
+
+    fn is_valid_quantity(quantity: Self::Quantity) -> bool {
+        P::is_valid_quantity(quantity)
+    }
+
+    /// Spends the specified amount from the wrapped pool, if there is that much available.
+    ///
+    /// This restarts the regeneration delay.
+    fn expend(&mut self, amount: Self::Quantity) -> Result<(), CannotUseAbility> {
+        self.pool.expend(amount)?;
+        self.interrupt();
+        Ok(())
+    }
+
+    /// Spends as much of the specified amount from the wrapped pool as possible.
+    ///
+    /// This restarts the regeneration delay.
+    fn expend_up_to(&mut self, amount: Self::Quantity) -> Self::Quantity {
+        let expended = self.pool.expend_up_to(amount);
+        self.interrupt();
+        expended
+    }
+
+    fn replenish(&mut self, amount: Self::Quantity) -> Self::Quantity {
+        self.pool.replenish(amount)
+    }
+}
+
+impl<P: RegeneratingPool> RegeneratingPool for InterruptiblePool<P> {
+    fn regen_per_second(&self) -> Self::Quantity {
+        self.pool.regen_per_second()
+    }
+
+    fn set_regen_per_second(&mut self, new_regen_per_second: Self::Quantity) {
+        self.pool.set_regen_per_second(new_regen_per_second);
+    }
+
+    fn regenerate(&mut self, delta_time: Duration) {
+        // Negative regeneration drains the pool, and is never held back by the delay
+        let decaying = self.pool.regen_per_second() < P::ZERO;
+
+        // Only the time remaining after the delay has elapsed counts towards regeneration
+        let remaining_delay = self.regen_delay.saturating_sub(self.since_expended);
+        self.since_expended = (self.since_expended + delta_time).min(self.regen_delay);
+
+        if decaying {
+            self.pool.regenerate(delta_time);
+        } else {
+            let regen_time = delta_time.saturating_sub(remaining_delay);
+            if regen_time > Duration::ZERO {
+                self.pool.regenerate(regen_time);
+            }
+        }
+    }
+}
+
+impl<P: RegeneratingPool + Display> Display for InterruptiblePool<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.pool.fmt(f)
+    }
+}
+
+/// Pauses the regeneration of pools of type `P` by [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool).
+///
+/// Insert this as a component to pause the pool on that entity,
+/// or as a resource to pause the pool of type `P` that is stored as a resource.
+/// As it is generic over the pool type, different pools can be paused independently,
+/// such as stopping life regeneration in combat while mana continues to regenerate.
+/// Remove it to resume regeneration: the regeneration rate of the pool is left untouched.
+#[derive(Component, Resource, Debug)]
+pub struct RegenPaused<P: Pool + Send + Sync + 'static> {
+    _phantom: PhantomData<P>,
+}
+
+impl<P: Pool + Send + Sync + 'static> Default for RegenPaused<P> {
+    fn default() -> Self {
+        RegenPaused {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// An event that is sent when a pool of type `P` becomes empty.
+///
+/// Sent by [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool) and [`modify_and_report`],
+/// only on the transition to empty, rather than every frame while the pool remains empty.
+///
+/// This event is not registered automatically: call `app.add_event::<PoolEmptied<P>>()` to receive it.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct PoolEmptied<P: Pool> {
+    /// The entity whose pool was emptied, or [`None`] if it is stored as a resource.
+    pub entity: Option<Entity>,
+    _phantom: PhantomData<P>,
+}
+
+impl<P: Pool> PoolEmptied<P> {
+    /// Creates a new [`PoolEmptied`] event for the pool stored on `entity`.
+    #[must_use]
+    pub fn new(entity: Option<Entity>) -> Self {
+        PoolEmptied {
+            entity,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// An event that is sent when a pool of type `P` becomes full.
+///
+/// Sent by [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool) and [`modify_and_report`],
+/// only on the transition to full, rather than every frame while the pool remains full.
+///
+/// This event is not registered automatically: call `app.add_event::<PoolFilled<P>>()` to receive it.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct PoolFilled<P: Pool> {
+    /// The entity whose pool was filled, or [`None`] if it is stored as a resource.
+    pub entity: Option<Entity>,
+    _phantom: PhantomData<P>,
+}
+
+impl<P: Pool> PoolFilled<P> {
+    /// Creates a new [`PoolFilled`] event for the pool stored on `entity`.
+    #[must_use]
+    pub fn new(entity: Option<Entity>) -> Self {
+        PoolFilled {
+            entity,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// The [fractions](Pool::fraction) of the pool of type `P` on this entity that should be watched,
+/// sending a [`PoolThresholdCrossed`] event whenever the pool crosses one of them.
+///
+/// This is useful for execute-style abilities ("usable when the target is below 30% life") or UI warnings.
+/// The events are sent by [`detect_pool_thresholds`](crate::systems::detect_pool_thresholds),
+/// which compares the fraction of the pool to its fraction the last time that system ran.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct PoolThresholds<P: Pool + Send + Sync + 'static> {
+    /// The watched fractions, in ascending order.
+    thresholds: Vec<f32>,
+    /// The fraction of the pool the last time it was checked.
+    previous_fraction: Option<f32>,
+    _phantom: PhantomData<P>,
+}
+
+impl<P: Pool + Send + Sync + 'static> PoolThresholds<P> {
+    /// Creates a new [`PoolThresholds`], watching each of the provided fractions.
+    ///
+    /// Fractions that are not finite are ignored.
+    #[must_use]
+    pub fn new(thresholds: impl IntoIterator<Item = f32>) -> Self {
+        let mut thresholds: Vec<f32> = thresholds
+            .into_iter()
+            .filter(|threshold| threshold.is_finite())
+            .collect();
+        thresholds.sort_by(f32::total_cmp);
+        thresholds.dedup();
+
+        PoolThresholds {
+            thresholds,
+            previous_fraction: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The watched fractions, in ascending order.
+    #[inline]
+    #[must_use]
+    pub fn thresholds(&self) -> &[f32] {
+        &self.thresholds
+    }
+
+    /// Records the new `fraction` of the pool, returning each `(threshold, rising)` pair that was crossed since the last call.
+    ///
+    /// A threshold is crossed when rising if the fraction reaches it, and when falling if the fraction drops below it.
+    /// Thresholds are returned in the order they were crossed.
+    /// Nothing is returned the first time this is called.
+    pub(crate) fn update(&mut self, fraction: f32) -> Vec<(f32, bool)> {
+        let Some(previous) = self.previous_fraction.replace(fraction) else {
+            return Vec::new();
+        };
+
+        if fraction > previous {
+            self.thresholds
+                .iter()
+                .filter(|&&threshold| previous < threshold && threshold <= fraction)
+                .map(|&threshold| (threshold, true))
+                .collect()
+        } else {
+            self.thresholds
+                .iter()
+                .rev()
+                .filter(|&&threshold| fraction < threshold && threshold <= previous)
+                .map(|&threshold| (threshold, false))
+                .collect()
+        }
+    }
+}
+
+/// An event that is sent when the fraction of a pool of type `P` crosses one of its [`PoolThresholds`].
+///
+/// Sent by [`detect_pool_thresholds`](crate::systems::detect_pool_thresholds).
+/// If several thresholds are crossed at once, one event is sent for each of them, in the order they were crossed.
+///
+/// This event is not registered automatically: call `app.add_event::<PoolThresholdCrossed<P>>()` to receive it.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct PoolThresholdCrossed<P: Pool> {
+    /// The entity whose pool crossed the threshold.
+    pub entity: Entity,
+    /// The fraction that was crossed.
+    pub threshold: f32,
+    /// Was the pool filling up (`true`) or draining (`false`) when it crossed the threshold?
+    pub rising: bool,
+    _phantom: PhantomData<P>,
+}
+
+impl<P: Pool> PoolThresholdCrossed<P> {
+    /// Creates a new [`PoolThresholdCrossed`] event for the pool stored on `entity`.
+    #[must_use]
+    pub fn new(entity: Entity, threshold: f32, rising: bool) -> Self {
+        PoolThresholdCrossed {
+            entity,
+            threshold,
+            rising,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// An event that is sent when the current value of a pool of type `P` changes.
+///
+/// Sent by [`track_pool_deltas`](crate::systems::track_pool_deltas), which compares the value of each pool to its value the last time that system ran.
+/// This captures every kind of change, including those made via operators like `-=`,
+/// but several changes within a single frame are combined into one event.
+///
+/// This event is not registered automatically: call `app.add_event::<PoolDelta<P>>()` to receive it.
+#[derive(Event)]
+pub struct PoolDelta<P: Pool> {
+    /// The entity whose pool changed, or [`None`] if it is stored as a resource.
+    pub entity: Option<Entity>,
+    /// The current value of the pool the last time it was checked.
+    pub previous: P::Quantity,
+    /// The new current value of the pool.
+    pub current: P::Quantity,
+}
+
+impl<P: Pool> PoolDelta<P> {
+    /// The signed change in the current value of the pool.
+    #[inline]
+    #[must_use]
+    pub fn delta(&self) -> P::Quantity {
+        self.current - self.previous
+    }
+}
+
+impl<P: Pool> Clone for PoolDelta<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: Pool> Copy for PoolDelta<P> {}
+
+impl<P: Pool> PartialEq for PoolDelta<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entity == other.entity
+            && self.previous == other.previous
+            && self.current == other.current
+    }
+}
+
+impl<P: Pool> core::fmt::Debug for PoolDelta<P>
+where
+    P::Quantity: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PoolDelta")
+            .field("entity", &self.entity)
+            .field("previous", &self.previous)
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+/// Which transitions a pool went through when it was modified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PoolTransitions {
+    pub(crate) emptied: bool,
+    pub(crate) filled: bool,
+}
+
+impl PoolTransitions {
+    /// Calls `modify` on `pool`, and records whether it became empty or full as a result.
+    pub(crate) fn observe<P: Pool, R>(pool: &mut P, modify: impl FnOnce(&mut P) -> R) -> (R, Self) {
+        let was_empty = pool.is_empty();
+        let was_full = pool.is_full();
+        let output = modify(pool);
+
+        let transitions = PoolTransitions {
+            emptied: !was_empty && pool.is_empty(),
+            filled: !was_full && pool.is_full(),
+        };
+        (output, transitions)
+    }
+}
+
+/// Calls `modify` on `pool`, sending a [`PoolEmptied`] or [`PoolFilled`] event if the pool becomes empty or full as a result.
+///
+/// Use this when calling [`Pool::expend`], [`Pool::replenish`] and so on in your own systems,
+/// so that those changes are reported in the same way as regeneration.
+/// The value returned by `modify` is passed through.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_abilities::pool::{modify_and_report, Pool, PoolEmptied, PoolFilled};
+/// use leafwing_abilities::premade_pools::life::{Life, LifePool};
+///
+/// fn take_damage(
+///     mut query: Query<(Entity, &mut LifePool)>,
+///     mut emptied: EventWriter<PoolEmptied<LifePool>>,
+///     mut filled: EventWriter<PoolFilled<LifePool>>,
+/// ) {
+///     for (entity, mut life) in query.iter_mut() {
+///         modify_and_report(&mut *life, Some(entity), &mut emptied, &mut filled, |life| {
+///             *life -= Life(10.);
+///         });
+///     }
+/// }
+/// ```
+pub fn modify_and_report<P: Pool + Send + Sync + 'static, R>(
+    pool: &mut P,
+    entity: Option<Entity>,
+    emptied: &mut EventWriter<PoolEmptied<P>>,
+    filled: &mut EventWriter<PoolFilled<P>>,
+    modify: impl FnOnce(&mut P) -> R,
+) -> R {
+    let (output, transitions) = PoolTransitions::observe(pool, modify);
+
+    if transitions.emptied {
+        emptied.send(PoolEmptied::new(entity));
+    }
+    if transitions.filled {
+        filled.send(PoolFilled::new(entity));
+    }
+    output
+}
+
+/// The cost of an ability, paid from a [`Pool`] whose [`Pool::Quantity`] is `Q`.
+///
+/// Plain quantities convert into [`AbilityCost::Flat`],
+/// so they can be passed directly to methods like [`AbilityCosts::set`].
+///
+/// This is reflected as an opaque value, as the formulas of [`AbilityCost::Computed`] costs cannot be reflected field by field:
+/// reflected costs are read and replaced whole, which preserves their formulas.
+#[derive(Debug, Clone, Copy, Reflect, Serialize, Deserialize)]
+#[reflect(opaque)]
+#[reflect(where Q: Clone)]
+pub enum AbilityCost<Q> {
+    /// A fixed quantity.
+    Flat(Q),
+    /// A percentage of the [max](Pool::max) of the pool, between `0.0` and `100.0`.
+    ///
+    /// This is resolved each time the cost is checked or paid, so it follows any changes to the max.
+    /// Only pools that implement [`Pool::scale_quantity`] can pay percentage costs.
+    Percent(f32),
+    /// A quantity computed by the provided function, which is passed the [current](Pool::current) and [max](Pool::max) of the pool.
+    ///
+    /// This is evaluated against the live pool each time the cost is checked or paid,
+    /// and only once when the ability is triggered, so the readiness check and the expenditure always agree.
+    /// Formulas are plain function pointers, so that costs remain deterministic.
+    /// Costs that depend on state outside of the pool should be updated with [`AbilityCosts::set`] instead.
+    /// Evaluating the formula again after the cost was paid would give a different quantity,
+    /// so these costs are only refunded if the payment was [recorded](CostPayments::record).
+    ///
+    /// This variant cannot be serialized.
+    #[serde(skip)]
+    Computed(fn(Q, Q) -> Q),
+}
+
+impl<Q: PartialEq> PartialEq for AbilityCost<Q> {
+    /// Computed costs are compared by address, which may consider copies of the same function unequal.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AbilityCost::Flat(quantity), AbilityCost::Flat(other_quantity)) => {
+                quantity == other_quantity
+            }
+            (AbilityCost::Percent(percent), AbilityCost::Percent(other_percent)) => {
+                percent == other_percent
+            }
+            (AbilityCost::Computed(formula), AbilityCost::Computed(other_formula)) => {
+                core::ptr::fn_addr_eq(*formula, *other_formula)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<Q> From<Q> for AbilityCost<Q> {
+    fn from(quantity: Q) -> Self {
+        AbilityCost::Flat(quantity)
+    }
+}
+
+impl<Q: Copy> AbilityCost<Q> {
+    /// The quantity that this cost requires from the `pool`.
+    ///
+    /// Returns [`None`] if this is a [`AbilityCost::Percent`] cost and the `pool` cannot [scale](Pool::scale_quantity) its quantities,
+    /// or if an [`AbilityCost::Computed`] cost is not a [valid](Pool::is_valid_quantity) quantity.
+    #[inline]
+    #[must_use]
+    pub fn resolve<P: Pool<Quantity = Q>>(&self, pool: &P) -> Option<Q> {
+        match self {
+            AbilityCost::Flat(quantity) => Some(*quantity),
+            AbilityCost::Percent(percent) => P::scale_quantity(pool.max(), percent / 100.0),
+            AbilityCost::Computed(formula) => {
+                let quantity = formula(pool.current(), pool.max());
+                P::is_valid_quantity(quantity).then_some(quantity)
+            }
+        }
+    }
+}
+
+/// How [`AbilityCosts`] handles abilities that do not have a cost of their own.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum CostPolicy {
+    /// Abilities without a cost of their own use the [default cost](AbilityCosts::with_default_cost), if one is set.
+    ///
+    /// If no default cost is set, they are free.
+    #[default]
+    UseDefaultCost,
+    /// Abilities without a cost of their own are always free, even if a default cost is set.
+    UnlistedAreFree,
+}
+
+/// Stores the [`AbilityCost`] associated with each ability of type `A`, in terms of the [`Pool::Quantity`] of `P`.
+///
+/// Abilities without a cost of their own fall back to the [default cost](Self::with_default_cost),
+/// according to the [`CostPolicy`].
+///
+/// This can be serialized whenever both `A` and the [`Pool::Quantity`] can be.
+///
+/// As the [`AbilityPlugin`](crate::plugin::AbilityPlugin) does not know which pools you are using,
+/// this type is not registered automatically:
+/// use [`AbilityPlugin::with_ability_costs`](crate::plugin::AbilityPlugin::with_ability_costs) to inspect it.
+///
+/// This only stores configuration: inserting it as a component also inserts the [`CostPayments`]
+/// that record what was actually paid, so costs can be refunded exactly.
+#[derive(Component, Resource, Debug, Reflect, Serialize, Deserialize)]
+#[require(CostPayments<A, P>)]
+#[reflect(Component, Resource)]
+#[serde(bound(
+    serialize = "A: Serialize, P::Quantity: Serialize",
+    deserialize = "A: Deserialize<'de>, P::Quantity: Deserialize<'de>"
+))]
+pub struct AbilityCosts<A: Abilitylike, P: Pool> {
+    /// The underlying cost of each ability.
+    cost_map: HashMap<A, AbilityCost<P::Quantity>>,
+    /// The cost of abilities without an entry in the `cost_map`, if the `policy` allows it.
+    #[serde(default)]
+    default_cost: Option<AbilityCost<P::Quantity>>,
+    /// How abilities without an entry in the `cost_map` are handled.
+    #[serde(default)]
+    policy: CostPolicy,
+    #[serde(skip)]
+    #[reflect(ignore)]
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Abilitylike, P: Pool> Clone for AbilityCosts<A, P> {
+    fn clone(&self) -> Self {
+        AbilityCosts {
+            cost_map: self.cost_map.clone(),
+            default_cost: self.default_cost,
+            policy: self.policy,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Abilitylike, P: Pool> Default for AbilityCosts<A, P> {
+    fn default() -> Self {
+        AbilityCosts {
+            cost_map: HashMap::new(),
+            default_cost: None,
+            policy: CostPolicy::default(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Abilitylike, P: Pool, C: Into<AbilityCost<P::Quantity>>> FromIterator<(A, C)>
+    for AbilityCosts<A, P>
+{
+    /// Creates a new [`AbilityCosts`] from an iterator of `(action, cost)` pairs.
+    fn from_iter<I: IntoIterator<Item = (A, C)>>(iter: I) -> Self {
+        let mut state = Self::default();
+        state.extend(iter);
+        state
+    }
+}
+
+impl<A: Abilitylike, P: Pool, C: Into<AbilityCost<P::Quantity>>> Extend<(A, C)>
+    for AbilityCosts<A, P>
+{
+    /// Adds each `(action, cost)` pair, overwriting any existing entry for that action as [`set`](AbilityCosts::set) does.
+    fn extend<I: IntoIterator<Item = (A, C)>>(&mut self, iter: I) {
+        for (action, value) in iter {
+            self.set(action, value);
+        }
+    }
+}
+
+impl<A: Abilitylike, P: Pool> IntoIterator for AbilityCosts<A, P> {
+    type Item = (A, AbilityCost<P::Quantity>);
+    type IntoIter = std::collections::hash_map::IntoIter<A, AbilityCost<P::Quantity>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cost_map.into_iter()
+    }
+}
+
+impl<'a, A: Abilitylike, P: Pool> IntoIterator for &'a AbilityCosts<A, P> {
+    type Item = (&'a A, &'a AbilityCost<P::Quantity>);
+    type IntoIter = std::collections::hash_map::Iter<'a, A, AbilityCost<P::Quantity>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cost_map.iter()
+    }
+}
+
+impl<A: Abilitylike, P: Pool> AbilityCosts<A, P> {
+    /// Creates a new [`AbilityCosts`] from an iterator of `(charges, action)` pairs
+    ///
+    /// If a cost is not provided for an action, that action will have no cost in terms of the stored resource pool.
+    /// Costs may be plain [`Pool::Quantity`] values, or [`AbilityCost`]s.
+    ///
+    /// To create an empty [`AbilityCosts`] struct, use the [`Default::default`] method instead.
+    #[must_use]
+    pub fn new<C: Into<AbilityCost<P::Quantity>>>(
+        action_cost_pairs: impl IntoIterator<Item = (A, C)>,
+    ) -> Self {
+        let mut ability_costs = AbilityCosts::default();
+        for (action, cost) in action_cost_pairs.into_iter() {
+            ability_costs.set(action, cost);
+        }
+        ability_costs
+    }
+
+    /// Are enough resources available in the `pool` to use the `action`?
+    ///
+    /// Returns `true` if the underlying resource is [`None`].
+    #[inline]
+    #[must_use]
+    pub fn available(&self, action: &A, pool: &P) -> bool {
+        pool_can_pay(Some(pool), self.get(action).copied()).is_ok()
+    }
+
+    /// How much the `pool` is short of paying the cost of `action`, if it cannot pay it.
+    ///
+    /// Returns `Ok(None)` if the `pool` can pay, or if `action` has no cost.
+    /// If the cost cannot be [resolved](AbilityCost::resolve) for this pool, it can never be paid,
+    /// so [`CannotUseAbility::PoolInsufficient`] is returned, just as [`Self::pay_cost`] does.
+    /// See [`Pool::available_detailed`] for more details.
+    #[inline]
+    pub fn shortfall(
+        &self,
+        action: &A,
+        pool: &P,
+    ) -> Result<Option<PoolShortfall<P::Quantity>>, CannotUseAbility> {
+        pool_shortfall(pool, self.get(action).copied())
+    }
+
+    /// Returns every action whose cost can never be paid from the `pool`, along with that cost.
+    ///
+    /// A cost is impossible if it exceeds the most that a full `pool` could spend:
+    /// its [max](Pool::max), less its [debt floor](Pool::debt_floor) (or [`Pool::MIN`]).
+    /// [`AbilityCost::Percent`] costs are resolved against the max of the `pool`.
+    /// [`AbilityCost::Computed`] costs depend on the state of the pool when they are paid, so they are skipped,
+    /// as is the [default cost](Self::with_default_cost).
+    #[must_use]
+    pub fn validate(&self, pool: &P) -> Vec<(A, P::Quantity)> {
+        let limit = pool.max() - pool.debt_floor().unwrap_or(P::MIN);
+
+        self.cost_map
+            .iter()
+            .filter(|(_, cost)| !matches!(cost, AbilityCost::Computed(_)))
+            .filter_map(|(action, cost)| {
+                let quantity = cost.resolve(pool)?;
+                (quantity > limit).then(|| (action.clone(), quantity))
+            })
+            .collect()
+    }
+
+    /// Pay the ability cost for the `action` from the `pool`, if able
+    ///
+    /// The cost of the action is expended from the [`Pool`].
+    ///
+    /// If the underlying pool does not have enough resources to pay the action's cost,
+    /// a [`CannotUseAbility::PoolEmpty`] error is returned and this call has no effect.
+    ///
+    /// Returns [`Ok(())`] if the underlying [`Pool`] can support the cost of the action.
+    /// Use [`CostPayments::pay_cost`] to record the quantity paid, so that it can be refunded exactly.
+    #[inline]
+    pub fn pay_cost(&mut self, action: &A, pool: &mut P) -> Result<(), CannotUseAbility> {
+        self.pay_resolved_cost(action, pool).map(|_| ())
+    }
+
+    /// Pays the cost of `action` from the `pool`, returning the quantity paid, if the action has a cost.
+    fn pay_resolved_cost(
+        &self,
+        action: &A,
+        pool: &mut P,
+    ) -> Result<Option<P::Quantity>, CannotUseAbility> {
+        match self.get(action) {
+            Some(cost) => match cost.resolve(pool) {
+                Some(cost) => {
+                    pool.expend(cost)?;
+                    Ok(Some(cost))
+                }
+                None => Err(CannotUseAbility::PoolInsufficient),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the cost of `action` to the `pool`, such as when a cast is cancelled.
+    ///
+    /// The stored cost is resolved against the `pool` as it is now:
+    /// this ignores any [`CostModifiers`] that applied when the ability was triggered,
+    /// and [`AbilityCost::Percent`] costs may resolve differently than when they were paid.
+    /// [`AbilityCost::Computed`] costs are not refunded,
+    /// as their formula would be evaluated against the pool after the cost was paid.
+    /// Use [`CostPayments::refund`] to restore exactly the quantity that was paid.
+    ///
+    /// Returns the quantity that was returned to the pool, which may be less than the cost if the pool is nearly full.
+    #[inline]
+    pub fn refund(&self, action: &A, pool: &mut P) -> P::Quantity {
+        let quantity = match self.get(action) {
+            Some(AbilityCost::Computed(_)) | None => return P::ZERO,
+            Some(cost) => match cost.resolve(pool) {
+                Some(quantity) => quantity,
+                None => return P::ZERO,
+            },
+        };
+
+        quantity - pool.replenish(quantity)
+    }
+
+    /// Pays as much of the ability cost for the `action` as the `pool` can afford, using [`Pool::expend_up_to`].
+    ///
+    /// Unlike [`Self::pay_cost`], this never fails: if the pool cannot cover the full cost, it is emptied.
+    /// Returns the amount that was actually spent, which is zero if the action has no cost
+    /// or its [`AbilityCost::Percent`] cost cannot be resolved for this pool.
+    #[inline]
+    pub fn pay_cost_partial(&self, action: &A, pool: &mut P) -> P::Quantity {
+        match self.resolve(action, pool) {
+            Some(cost) => pool.expend_up_to(cost),
+            None => P::ZERO,
+        }
+    }
+
+    /// Returns a reference to the [`AbilityCost`] of `action`.
+    ///
+    /// If `action` has no cost of its own, this falls back to the [default cost](Self::with_default_cost),
+    /// unless the [`CostPolicy`] is [`CostPolicy::UnlistedAreFree`].
+    /// This is the cost used when checking or paying for abilities.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, action: &A) -> Option<&AbilityCost<P::Quantity>> {
+        self.cost_map.get(action).or(match self.policy {
+            CostPolicy::UseDefaultCost => self.default_cost.as_ref(),
+            CostPolicy::UnlistedAreFree => None,
+        })
+    }
+
+    /// Returns a reference to the [`AbilityCost`] of `action`, if it has a cost of its own.
+    ///
+    /// Unlike [`Self::get`], this ignores the [default cost](Self::with_default_cost).
+    #[inline]
+    #[must_use]
+    pub fn get_explicit(&self, action: &A) -> Option<&AbilityCost<P::Quantity>> {
+        self.cost_map.get(action)
+    }
+
+    /// The cost of abilities that do not have a cost of their own, if any.
+    #[inline]
+    #[must_use]
+    pub fn default_cost(&self) -> Option<&AbilityCost<P::Quantity>> {
+        self.default_cost.as_ref()
+    }
+
+    /// Sets the cost of abilities that do not have a cost of their own.
+    ///
+    /// Pass [`None`] to remove the default cost, making those abilities free.
+    /// Invalid costs are rejected, as in [`Self::set`].
+    #[inline]
+    pub fn set_default_cost(
+        &mut self,
+        cost: Option<impl Into<AbilityCost<P::Quantity>>>,
+    ) -> &mut Self {
+        match cost.map(Into::into) {
+            Some(cost) if is_valid_cost::<P>(cost) => self.default_cost = Some(cost),
+            Some(_) => (),
+            None => self.default_cost = None,
+        }
+        self
+    }
+
+    /// Sets the cost of abilities that do not have a cost of their own, returning the modified [`AbilityCosts`].
+    #[inline]
+    #[must_use]
+    pub fn with_default_cost(mut self, cost: impl Into<AbilityCost<P::Quantity>>) -> Self {
+        self.set_default_cost(Some(cost));
+        self
+    }
+
+    /// How abilities that do not have a cost of their own are handled.
+    #[inline]
+    #[must_use]
+    pub fn policy(&self) -> CostPolicy {
+        self.policy
+    }
+
+    /// Sets how abilities that do not have a cost of their own are handled.
+    #[inline]
+    pub fn set_policy(&mut self, policy: CostPolicy) -> &mut Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets how abilities that do not have a cost of their own are handled, returning the modified [`AbilityCosts`].
+    #[inline]
+    #[must_use]
+    pub fn with_policy(mut self, policy: CostPolicy) -> Self {
+        self.set_policy(policy);
+        self
+    }
+
+    /// Returns a mutable reference to the underlying [`AbilityCost`] for `action`, if set.
+    ///
+    /// The [default cost](Self::with_default_cost) is never returned: use [`Self::set_default_cost`] to change it.
+    /// Invalid edits are reverted when the returned [`AbilityCostMut`] is dropped: use [`Self::set`] to be warned about them.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, action: &A) -> Option<AbilityCostMut<'_, P>> {
+        self.cost_map.get_mut(action).map(AbilityCostMut::new)
+    }
+
+    /// Returns the quantity that `action` currently costs from the `pool`, if it has a cost.
+    ///
+    /// [`AbilityCost::Percent`] costs are resolved against the max of the `pool`.
+    #[inline]
+    #[must_use]
+    pub fn resolve(&self, action: &A, pool: &P) -> Option<P::Quantity> {
+        self.get(action).and_then(|cost| cost.resolve(pool))
+    }
+
+    /// Sets the underlying cost for `action` to the provided value.
+    ///
+    /// Both plain [`Pool::Quantity`] values and [`AbilityCost`]s are accepted.
+    /// Costs that are not [valid](Pool::is_valid_quantity) panic in debug builds, and are ignored in release builds.
+    /// Unless you're building a new [`AbilityCosts`] struct, you likely want to use [`Self::get_mut`].
+    #[inline]
+    pub fn set(&mut self, action: A, cost: impl Into<AbilityCost<P::Quantity>>) -> &mut Self {
+        let cost = cost.into();
+        if is_valid_cost::<P>(cost) {
+            self.cost_map.insert(action, cost);
+        }
+        self
+    }
+
+    /// Sets the underlying cost for `action`, returning the modified [`AbilityCosts`].
+    ///
+    /// This is the consuming counterpart to [`Self::set`], for building a new [`AbilityCosts`] without a trailing [`Self::build`].
+    #[inline]
+    #[must_use]
+    pub fn with(mut self, action: A, cost: impl Into<AbilityCost<P::Quantity>>) -> Self {
+        self.set(action, cost);
+        self
+    }
+
+    /// Sets the cost of `action`, returning the modified [`AbilityCosts`].
+    ///
+    /// This matches [`PoolBundle::with_cost`], and is equivalent to [`Self::with`].
+    #[inline]
+    #[must_use]
+    pub fn with_cost(self, action: A, cost: impl Into<AbilityCost<P::Quantity>>) -> Self {
+        self.with(action, cost)
+    }
+
+    /// Sets the cost of each action in `action_cost_pairs`, returning the modified [`AbilityCosts`].
+    ///
+    /// This matches [`PoolBundle::with_costs`], and is the consuming counterpart to [`Extend::extend`].
+    #[inline]
+    #[must_use]
+    pub fn with_costs<C: Into<AbilityCost<P::Quantity>>>(
+        mut self,
+        action_cost_pairs: impl IntoIterator<Item = (A, C)>,
+    ) -> Self {
+        self.extend(action_cost_pairs);
+        self
+    }
+
+    /// Removes the cost of `action`, returning it if it existed.
+    ///
+    /// The action will then fall back to the [default cost](Self::with_default_cost), or be free to use if there is none.
+    #[inline]
+    pub fn remove(&mut self, action: &A) -> Option<AbilityCost<P::Quantity>> {
+        self.cost_map.remove(action)
+    }
+
+    /// Removes the cost of every action.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cost_map.clear();
+    }
+
+    /// Retains only the costs for which `keep` returns `true`.
+    ///
+    /// Invalid edits made by `keep` are reverted, as in [`Self::get_mut`].
+    #[inline]
+    pub fn retain(&mut self, mut keep: impl FnMut(&A, &mut AbilityCost<P::Quantity>) -> bool) {
+        self.cost_map
+            .retain(|action, value| keep(action, &mut AbilityCostMut::<P>::new(value)));
+    }
+
+    /// Collects a `&mut Self` into a `Self`.
+    ///
+    /// Used to conclude the builder pattern. Actually just calls `self.clone()`:
+    /// prefer [`Self::with`], which does not need to clone.
+    #[inline]
+    #[must_use]
+    pub fn build(&mut self) -> Self {
+        self.clone()
+    }
+
+    /// Returns an iterator over the actions that have a cost set
+    #[inline]
+    pub fn actions(&self) -> impl Iterator<Item = &A> {
+        self.cost_map.keys()
+    }
+
+    /// Returns an iterator over each action and a reference to its [`AbilityCost`]
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&A, &AbilityCost<P::Quantity>)> {
+        self.cost_map.iter()
+    }
+
+    /// Returns an iterator over each action and a mutable reference to its [`AbilityCost`]
+    ///
+    /// Invalid edits are reverted when each [`AbilityCostMut`] is dropped.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&A, AbilityCostMut<'_, P>)> {
+        self.cost_map
+            .iter_mut()
+            .map(|(action, cost)| (action, AbilityCostMut::new(cost)))
+    }
+}
+
+/// A mutable reference to an [`AbilityCost`] stored in [`AbilityCosts`], returned by [`AbilityCosts::get_mut`] and [`AbilityCosts::iter_mut`].
+///
+/// When dropped, an edited cost that is not [valid](Pool::is_valid_quantity) is silently reverted to the original cost.
+/// Unlike [`AbilityCosts::set`], this never panics, as it may be dropped while unwinding.
+pub struct AbilityCostMut<'a, P: Pool> {
+    cost: &'a mut AbilityCost<P::Quantity>,
+    original: AbilityCost<P::Quantity>,
+}
+
+impl<'a, P: Pool> AbilityCostMut<'a, P> {
+    fn new(cost: &'a mut AbilityCost<P::Quantity>) -> Self {
+        let original = *cost;
+        AbilityCostMut { cost, original }
+    }
+}
+
+impl<P: Pool> core::ops::Deref for AbilityCostMut<'_, P> {
+    type Target = AbilityCost<P::Quantity>;
+
+    fn deref(&self) -> &Self::Target {
+        self.cost
+    }
+}
+
+impl<P: Pool> core::ops::DerefMut for AbilityCostMut<'_, P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.cost
+    }
+}
+
+impl<P: Pool> Drop for AbilityCostMut<'_, P> {
+    fn drop(&mut self) {
+        if !cost_is_valid::<P>(*self.cost) {
+            *self.cost = self.original;
+        }
+    }
+}
+
+/// Checks that `cost` is valid for pools of type `P`, panicking in debug builds if it is not.
+fn is_valid_cost<P: Pool>(cost: AbilityCost<P::Quantity>) -> bool {
+    match cost {
+        AbilityCost::Flat(quantity) => check_quantity::<P>(quantity),
+        AbilityCost::Percent(percent) => {
+            debug_assert!(percent.is_finite(), "percentage costs must be finite");
+            percent.is_finite()
+        }
+        AbilityCost::Computed(_) => true,
+    }
+}
+
+/// Checks that `cost` is valid for pools of type `P`, without panicking.
+fn cost_is_valid<P: Pool>(cost: AbilityCost<P::Quantity>) -> bool {
+    match cost {
+        AbilityCost::Flat(quantity) => P::is_valid_quantity(quantity),
+        AbilityCost::Percent(percent) => percent.is_finite(),
+        AbilityCost::Computed(_) => true,
+    }
+}
+
+/// The quantity most recently paid from pools of type `P` to use each ability of type `A`, so that it can be refunded exactly.
+///
+/// This is runtime state, kept apart from the [`AbilityCosts`] that configure each ability,
+/// and is inserted automatically alongside them.
+/// Payments are recorded by [`AbilityStateItem::trigger`](crate::AbilityStateItem::trigger) and [`Self::pay_cost`],
+/// or manually with [`Self::record`] if you pay costs another way, such as with [`Abilitylike::trigger_modified`].
+#[derive(Component, Resource, Debug)]
+pub struct CostPayments<A: Abilitylike, P: Pool> {
+    paid: HashMap<A, P::Quantity>,
+}
+
+impl<A: Abilitylike, P: Pool> Clone for CostPayments<A, P> {
+    fn clone(&self) -> Self {
+        CostPayments {
+            paid: self.paid.clone(),
+        }
+    }
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Abilitylike, P: Pool> Default for CostPayments<A, P> {
+    fn default() -> Self {
+        CostPayments {
+            paid: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Abilitylike, P: Pool> CostPayments<A, P> {
+    /// Records that `quantity` was paid to use `action`, so that [`Self::refund`] can restore exactly that amount.
+    ///
+    /// This replaces any earlier payment for `action` that has not been refunded.
+    #[inline]
+    pub fn record(&mut self, action: A, quantity: P::Quantity) {
+        self.paid.insert(action, quantity);
+    }
+
+    /// The quantity most recently paid to use `action` that has not yet been refunded, if any.
+    #[inline]
+    #[must_use]
+    pub fn last_paid(&self, action: &A) -> Option<P::Quantity> {
+        self.paid.get(action).copied()
+    }
+
+    /// Pays the cost of `action` from the `pool`, as in [`AbilityCosts::pay_cost`], and records the quantity paid.
+    #[inline]
+    pub fn pay_cost(
+        &mut self,
+        action: &A,
+        pool: &mut P,
+        costs: &AbilityCosts<A, P>,
+    ) -> Result<(), CannotUseAbility> {
+        if let Some(paid) = costs.pay_resolved_cost(action, pool)? {
+            self.record(action.clone(), paid);
+        }
+        Ok(())
+    }
+
+    /// Returns the cost of `action` to the `pool`, such as when a cast is cancelled.
+    ///
+    /// If a payment was [recorded](Self::record), exactly that quantity is restored, and the record is cleared.
+    /// Otherwise, this falls back to [`AbilityCosts::refund`], which resolves the stored cost against the `pool` as it is now.
+    ///
+    /// Returns the quantity that was returned to the pool, which may be less than the cost if the pool is nearly full.
+    #[inline]
+    pub fn refund(&mut self, action: &A, pool: &mut P, costs: &AbilityCosts<A, P>) -> P::Quantity {
+        match self.paid.remove(action) {
+            Some(quantity) => quantity - pool.replenish(quantity),
+            None => costs.refund(action, pool),
+        }
+    }
+
+    /// Forgets every recorded payment.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.paid.clear();
+    }
+}
+
+/// Temporary discounts and surcharges on the [`AbilityCosts`] of abilities of type `A`, paid from pools of type `P`.
+///
+/// Each cost is multiplied by the global multiplier, the multiplier for that action,
+/// and any one-shot multiplier for its next use.
+/// One-shot multipliers are consumed by [`Abilitylike::trigger_modified`], but only when the ability is successfully used.
+///
+/// [`AbilityCost::Flat`] costs are scaled using [`Pool::scale_quantity`]:
+/// setting a multiplier other than `1.0` returns an error if the pool cannot scale its quantities.
+/// [`Abilitylike::refund_modified`] returns the cost after these modifiers,
+/// while [`CostPayments::refund`] returns exactly what was paid.
+#[derive(Component, Resource, Debug)]
+pub struct CostModifiers<A: Abilitylike, P: Pool> {
+    global: f32,
+    per_action: HashMap<A, f32>,
+    next_use: HashMap<A, f32>,
+    _phantom: PhantomData<fn() -> P>,
+}
+
+impl<A: Abilitylike, P: Pool> Clone for CostModifiers<A, P> {
+    fn clone(&self) -> Self {
+        CostModifiers {
+            global: self.global,
+            per_action: self.per_action.clone(),
+            next_use: self.next_use.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Abilitylike, P: Pool> Default for CostModifiers<A, P> {
+    fn default() -> Self {
+        CostModifiers {
+            global: 1.0,
+            per_action: HashMap::new(),
+            next_use: HashMap::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Abilitylike, P: Pool> CostModifiers<A, P> {
+    /// The multiplier applied to the cost of every action.
+    #[inline]
+    #[must_use]
+    pub fn global(&self) -> f32 {
+        self.global
+    }
+
+    /// Sets the multiplier applied to the cost of every action.
+    ///
+    /// Multipliers should be finite and non-negative: `0.5` halves costs, while `2.0` doubles them.
+    ///
+    /// # Errors
+    ///
+    /// Has no effect if `multiplier` is negative or not finite,
+    /// or if it is not `1.0` and the pool cannot [scale its quantities](Pool::scale_quantity),
+    /// returning an [`InvalidCostMultiplier`] error.
+    #[inline]
+    pub fn set_global(&mut self, multiplier: f32) -> Result<(), InvalidCostMultiplier> {
+        check_cost_multiplier::<P>(multiplier)?;
+        self.global = multiplier;
+        Ok(())
+    }
+
+    /// Sets the multiplier applied to every use of `action`, stacking multiplicatively with the global multiplier.
+    ///
+    /// # Errors
+    ///
+    /// Has no effect if `multiplier` is invalid, returning an [`InvalidCostMultiplier`] error.
+    /// See [`CostModifiers::set_global`] for more details.
+    #[inline]
+    pub fn set_action_multiplier(
+        &mut self,
+        action: A,
+        multiplier: f32,
+    ) -> Result<(), InvalidCostMultiplier> {
+        check_cost_multiplier::<P>(multiplier)?;
+        self.per_action.insert(action, multiplier);
+        Ok(())
+    }
+
+    /// Removes the multiplier for every use of `action`, returning it if it existed.
+    #[inline]
+    pub fn remove_action_multiplier(&mut self, action: &A) -> Option<f32> {
+        self.per_action.remove(action)
+    }
+
+    /// Sets a multiplier that only applies to the next successful use of `action`.
+    ///
+    /// Setting this to `0.0` makes the next use free.
+    /// This overwrites any existing one-shot multiplier for `action`.
+    ///
+    /// # Errors
+    ///
+    /// Has no effect if `multiplier` is invalid, returning an [`InvalidCostMultiplier`] error.
+    /// See [`CostModifiers::set_global`] for more details.
+    #[inline]
+    pub fn set_next_use_multiplier(
+        &mut self,
+        action: A,
+        multiplier: f32,
+    ) -> Result<(), InvalidCostMultiplier> {
+        check_cost_multiplier::<P>(multiplier)?;
+        self.next_use.insert(action, multiplier);
+        Ok(())
+    }
+
+    /// The one-shot multiplier waiting for the next use of `action`, if any.
+    #[inline]
+    #[must_use]
+    pub fn next_use_multiplier(&self, action: &A) -> Option<f32> {
+        self.next_use.get(action).copied()
+    }
+
+    /// Removes the one-shot multiplier for `action`, returning it if it existed.
+    ///
+    /// This is called by [`Abilitylike::trigger_modified`] when the ability is successfully used.
+    #[inline]
+    pub fn consume_next_use(&mut self, action: &A) -> Option<f32> {
+        self.next_use.remove(action)
+    }
+
+    /// The combined multiplier applied to the cost of `action`.
+    #[inline]
+    #[must_use]
+    pub fn multiplier(&self, action: &A) -> f32 {
+        self.global
+            * self.per_action.get(action).copied().unwrap_or(1.0)
+            * self.next_use_multiplier(action).unwrap_or(1.0)
+    }
+
+    /// Applies the modifiers for `action` to its `cost`.
+    ///
+    /// [`AbilityCost::Computed`] costs are returned unchanged: [resolve](AbilityCost::resolve) them first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidCostMultiplier::ScaledCostInvalid`] if the scaled cost is not a [valid quantity](Pool::is_valid_quantity),
+    /// such as when the combined multipliers overflow.
+    #[inline]
+    pub fn apply(
+        &self,
+        action: &A,
+        cost: AbilityCost<P::Quantity>,
+    ) -> Result<AbilityCost<P::Quantity>, InvalidCostMultiplier> {
+        scale_cost::<P>(cost, self.multiplier(action))
+    }
+}
+
+/// Checks that `multiplier` can be applied to costs paid from pools of type `P`.
+///
+/// Negative or non-finite multipliers would turn costs into refunds, so they are always rejected.
+pub(crate) fn check_cost_multiplier<P: Pool>(multiplier: f32) -> Result<(), InvalidCostMultiplier> {
+    if !(multiplier.is_finite() && multiplier >= 0.0) {
+        Err(InvalidCostMultiplier::NotFiniteOrNegative)
+    } else if multiplier != 1.0 && P::scale_quantity(P::ZERO, multiplier).is_none() {
+        Err(InvalidCostMultiplier::PoolCannotScale)
+    } else {
+        Ok(())
+    }
+}
+
+/// Multiplies `cost` by `factor`, as done by [`CostModifiers`] and [`AbilityScale`](crate::AbilityScale).
+///
+/// [`AbilityCost::Computed`] costs are left unchanged: [resolve](AbilityCost::resolve) them first.
+///
+/// Returns an error if `factor` is not a valid [cost multiplier](check_cost_multiplier) for pools of type `P`,
+/// or if the scaled cost is not a [valid quantity](Pool::is_valid_quantity).
+pub(crate) fn scale_cost<P: Pool>(
+    cost: AbilityCost<P::Quantity>,
+    factor: f32,
+) -> Result<AbilityCost<P::Quantity>, InvalidCostMultiplier> {
+    if factor == 1.0 {
+        return Ok(cost);
+    }
+    check_cost_multiplier::<P>(factor)?;
+
+    match cost {
+        AbilityCost::Flat(quantity) => P::scale_quantity(quantity, factor)
+            .filter(|&scaled| P::is_valid_quantity(scaled))
+            .map(AbilityCost::Flat)
+            .ok_or(InvalidCostMultiplier::ScaledCostInvalid),
+        AbilityCost::Percent(percent) => {
+            let scaled = percent * factor;
+            if scaled.is_finite() {
+                Ok(AbilityCost::Percent(scaled))
+            } else {
+                Err(InvalidCostMultiplier::ScaledCostInvalid)
+            }
+        }
+        // Formulas cannot be scaled until they are evaluated against a pool
+        AbilityCost::Computed(_) => Ok(cost),
+    }
+}
+
+/// The cost of `action` from `maybe_costs`, after applying any `maybe_modifiers`.
+///
+/// If a pool is provided, the cost is resolved against it first,
+/// so [`AbilityCost::Computed`] costs are evaluated exactly once.
+/// Costs that the modifiers cannot be applied to are reported as [`CannotUseAbility::PoolInsufficient`].
+pub(crate) fn modified_cost<A: Abilitylike, P: Pool>(
+    action: &A,
+    maybe_pool: Option<&P>,
+    maybe_costs: Option<&AbilityCosts<A, P>>,
+    maybe_modifiers: Option<&CostModifiers<A, P>>,
+) -> Result<Option<AbilityCost<P::Quantity>>, CannotUseAbility> {
+    let Some(&cost) = maybe_costs.and_then(|costs| costs.get(action)) else {
+        return Ok(None);
+    };
+    let cost = match maybe_pool.and_then(|pool| cost.resolve(pool)) {
+        Some(quantity) => AbilityCost::Flat(quantity),
+        None => cost,
+    };
+    Ok(Some(match maybe_modifiers {
+        Some(modifiers) => modifiers.apply(action, cost)?,
+        None => cost,
+    }))
+}
+
+/// The outcome of a successful [`transfer`] between two pools.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransferReport<Q> {
+    /// The amount that was added to the destination pool.
+    pub received: Q,
+    /// The amount that was wasted, as it did not fit in the destination pool.
+    pub overflow: Q,
+}
+
+/// Drains `amount` from the `from` pool, converts it with `convert`, and adds the result to the `to` pool.
+///
+/// This is useful for mechanics like life tap (converting life into mana) or leech effects.
+/// If `from` cannot pay `amount`, [`CannotUseAbility::PoolInsufficient`] is returned and nothing is moved.
+/// Otherwise, the full `amount` is drained, even if some of it does not fit in `to`:
+/// the wasted amount is reported in the [`TransferReport`].
+///
+/// ```rust
+/// use leafwing_abilities::pool::{transfer, Pool};
+/// use leafwing_abilities::premade_pools::life::{Life, LifePool};
+/// use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+///
+/// let mut life = LifePool::new(Life(100.), Life(100.), Life(0.));
+/// let mut mana = ManaPool::new(Mana(0.), Mana(100.), Mana(0.));
+///
+/// // Life tap: convert 20 life into 40 mana
+/// let report = transfer(&mut life, &mut mana, Life(20.), |life| Mana(life.0 * 2.)).unwrap();
+/// assert_eq!(report.received, Mana(40.));
+/// assert_eq!(life.current(), Life(80.));
+/// ```
+pub fn transfer<P1: Pool, P2: Pool>(
+    from: &mut P1,
+    to: &mut P2,
+    amount: P1::Quantity,
+    convert: impl FnOnce(P1::Quantity) -> P2::Quantity,
+) -> Result<TransferReport<P2::Quantity>, CannotUseAbility> {
+    from.expend(amount)?;
+
+    let converted = convert(amount);
+    let overflow = to.replenish(converted);
+    Ok(TransferReport {
+        received: converted - overflow,
+        overflow,
+    })
+}
+
+/// Drains `amount` from the `from` pool and adds it to the `to` pool, without any conversion.
+///
+/// See [`transfer`] for more details.
+pub fn transfer_same<P1: Pool, P2: Pool<Quantity = P1::Quantity>>(
+    from: &mut P1,
+    to: &mut P2,
+    amount: P1::Quantity,
+) -> Result<TransferReport<P1::Quantity>, CannotUseAbility> {
+    transfer(from, to, amount, |amount| amount)
+}
+
+/// Checks if an optional `pool` can pay an optional `cost`.
+///
+/// Missing costs are always payable, while costs greater than [`Pool::MIN`] cannot be paid by a missing pool.
+pub(crate) fn pool_can_pay<P: Pool>(
+    pool: Option<&P>,
+    cost: Option<AbilityCost<P::Quantity>>,
+) -> Result<(), CannotUseAbility> {
+    match (pool, cost) {
+        (Some(pool), Some(cost)) => match cost.resolve(pool) {
+            Some(cost) => pool.available(cost),
+            None => Err(CannotUseAbility::PoolInsufficient),
+        },
+        (None, Some(AbilityCost::Flat(cost))) if cost > P::MIN => {
+            Err(CannotUseAbility::PoolInsufficient)
+        }
+        (None, Some(AbilityCost::Percent(percent))) if percent > 0.0 => {
+            Err(CannotUseAbility::PoolInsufficient)
+        }
+        // Without a pool, there is nothing to evaluate the formula against
+        (None, Some(AbilityCost::Computed(_))) => Err(CannotUseAbility::PoolInsufficient),
+        _ => Ok(()),
+    }
+}
+
+/// How much the `pool` is short of paying the `cost`, if it cannot pay it.
+///
+/// Costs that cannot be resolved can never be paid, and return [`CannotUseAbility::PoolInsufficient`].
+pub(crate) fn pool_shortfall<P: Pool>(
+    pool: &P,
+    cost: Option<AbilityCost<P::Quantity>>,
+) -> Result<Option<PoolShortfall<P::Quantity>>, CannotUseAbility> {
+    let Some(cost) = cost else {
+        return Ok(None);
+    };
+    let cost = cost
+        .resolve(pool)
+        .ok_or(CannotUseAbility::PoolInsufficient)?;
+    Ok(pool.available_detailed(cost).err())
+}
+
+/// Several resource pools that can be checked together, for abilities that cost more than one kind of resource.
+///
+/// This is implemented for tuples of two or three optional pool references, such as `(Option<&ManaPool>, Option<&StaminaPool>)`,
+/// where `C` is the matching tuple of optional [`AbilityCosts`] references,
+/// such as `(Option<&AbilityCosts<A, ManaPool>>, Option<&AbilityCosts<A, StaminaPool>>)`.
+///
+/// See [`Abilitylike::ready_multi`](crate::Abilitylike::ready_multi).
+pub trait MultiPool<A: Abilitylike, C> {
+    /// Can every pool pay its cost for `action`?
+    ///
+    /// Returns [`CannotUseAbility::PoolInsufficient`] if any of them cannot.
+    fn available(&self, action: &A, costs: &C) -> Result<(), CannotUseAbility>;
+}
+
+/// Several resource pools that can be spent together, for abilities that cost more than one kind of resource.
+///
+/// This is implemented for tuples of two or three optional mutable pool references, such as `(Option<&mut ManaPool>, Option<&mut StaminaPool>)`,
+/// where `C` is the matching tuple of optional [`AbilityCosts`] references.
+///
+/// See [`Abilitylike::trigger_multi`](crate::Abilitylike::trigger_multi).
+pub trait MultiPoolMut<A: Abilitylike, C>: MultiPool<A, C> {
+    /// Spends the cost of `action` from every pool.
+    ///
+    /// This is atomic: if any pool cannot pay its cost, nothing is spent from the others.
+    fn expend(&mut self, action: &A, costs: &C) -> Result<(), CannotUseAbility>;
+
+    /// Returns the cost of `action` to every pool.
+    fn replenish(&mut self, action: &A, costs: &C);
+}
+
+macro_rules! impl_multi_pool {
+    ($(($P:ident, $index:tt)),*) => {
+        impl<A: Abilitylike, $($P: Pool),*> MultiPool<A, ($(Option<&AbilityCosts<A, $P>>,)*)>
+            for ($(Option<&$P>,)*)
+        {
+            fn available(
+                &self,
+                action: &A,
+                costs: &($(Option<&AbilityCosts<A, $P>>,)*),
+            ) -> Result<(), CannotUseAbility> {
+                $(pool_can_pay(self.$index, costs.$index.and_then(|costs| costs.get(action)).copied())?;)*
+                Ok(())
+            }
+        }
+
+        impl<A: Abilitylike, $($P: Pool),*> MultiPool<A, ($(Option<&AbilityCosts<A, $P>>,)*)>
+            for ($(Option<&mut $P>,)*)
+        {
+            fn available(
+                &self,
+                action: &A,
+                costs: &($(Option<&AbilityCosts<A, $P>>,)*),
+            ) -> Result<(), CannotUseAbility> {
+                $(pool_can_pay(self.$index.as_deref(), costs.$index.and_then(|costs| costs.get(action)).copied())?;)*
+                Ok(())
+            }
+        }
+
+        impl<A: Abilitylike, $($P: Pool),*> MultiPoolMut<A, ($(Option<&AbilityCosts<A, $P>>,)*)>
+            for ($(Option<&mut $P>,)*)
+        {
+            fn expend(
+                &mut self,
+                action: &A,
+                costs: &($(Option<&AbilityCosts<A, $P>>,)*),
+            ) -> Result<(), CannotUseAbility> {
+                // Check everything first, so that nothing is spent unless everything can be paid
+                MultiPool::available(self, action, costs)?;
+
+                $(
+                    if let Some(pool) = self.$index.as_deref_mut() {
+                        if let Some(cost) = costs.$index.and_then(|costs| costs.resolve(action, pool)) {
+                            let _pool_result = pool.expend(cost);
+                            debug_assert!(_pool_result.is_ok());
+                        }
+                    }
+                )*
+                Ok(())
+            }
+
+            fn replenish(&mut self, action: &A, costs: &($(Option<&AbilityCosts<A, $P>>,)*)) {
+                $(
+                    if let Some(pool) = self.$index.as_deref_mut() {
+                        if let Some(cost) = costs.$index.and_then(|costs| costs.resolve(action, pool)) {
+                            pool.replenish(cost);
+                        }
+                    }
+                )*
+            }
+        }
+    };
+}
+
+impl_multi_pool!((P1, 0), (P2, 1));
+impl_multi_pool!((P1, 0), (P2, 1), (P3, 2));
+
+/// Stores a resource pool and the associated costs for each ability.
+///
+/// Note that if your abilities do not cost the given resource,
+/// you can still add your [`Pool`] type as a component.
+///
+/// This is particularly common when working with life totals,
+/// as you want the other functionality of pools (current, max, regen, depletion)
+/// but often cannot spend it on abilities.
+///
+/// # Usage
+///
+/// Note that resource pools are not controlled by [`AbilityPlugin`](crate::plugin::AbilityPlugin) by default.
+/// If you want regeneration to occur automatically, use [`AbilityPlugin::with_regenerating_pool`](crate::plugin::AbilityPlugin::with_regenerating_pool),
+/// or add [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool) to your schedule.
+///
+/// These types are not automatically registered by [`AbilityPlugin`](crate::plugin::AbilityPlugin).
+/// You must register them manually with [`App::register_type`](bevy::app::App::register_type) if you wish to serialize or inspect them.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_abilities::prelude::*;
+/// use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Abilitylike, Clone, Copy, Debug, Hash, PartialEq, Eq, Reflect)]
+/// enum Spell {
+///     Fireball,
+///     Frostbolt,
+///     Blink,
+/// }
+///
+/// let mana_bundle = PoolBundle::new(ManaPool::new(Mana(100.), Mana(100.), Mana(1.)))
+///     .with_cost(Spell::Fireball, Mana(30.))
+///     .with_costs([(Spell::Frostbolt, Mana(20.)), (Spell::Blink, Mana(5.))]);
+///
+/// assert_eq!(mana_bundle.ability_costs.get(&Spell::Blink), Some(&AbilityCost::Flat(Mana(5.))));
+/// ```
+#[derive(Bundle, Reflect)]
+pub struct PoolBundle<A: Abilitylike, P: Pool + Component> {
+    /// The resource pool used to pay for abilities
+    pub pool: P,
+    /// The cost of each ability in terms of this pool
+    pub ability_costs: AbilityCosts<A, P>,
+}
+
+impl<A: Abilitylike, P: Pool + Component> PoolBundle<A, P> {
+    /// Creates a new [`PoolBundle`] from the provided `pool`, where no abilities have a cost.
+    #[must_use]
+    pub fn new(pool: P) -> Self {
+        PoolBundle {
+            pool,
+            ability_costs: AbilityCosts::default(),
+        }
+    }
+
+    /// Sets the cost of `action` to `cost`, overwriting any existing cost.
+    #[must_use]
+    pub fn with_cost(mut self, action: A, cost: impl Into<AbilityCost<P::Quantity>>) -> Self {
+        self.ability_costs.set(action, cost);
+        self
+    }
+
+    /// Sets the cost of each action in `action_cost_pairs`, overwriting any existing costs.
+    ///
+    /// Both plain [`Pool::Quantity`] values and [`AbilityCost`]s are accepted.
+    #[must_use]
+    pub fn with_costs<C: Into<AbilityCost<P::Quantity>>>(
+        mut self,
+        action_cost_pairs: impl IntoIterator<Item = (A, C)>,
+    ) -> Self {
+        for (action, cost) in action_cost_pairs {
+            self.ability_costs.set(action, cost);
+        }
+        self
+    }
+
+    /// Logs a warning for each ability whose cost can never be paid from this pool, returning those abilities and their costs.
+    ///
+    /// See [`AbilityCosts::validate`] for more details.
+    /// To check every pool as it is spawned, use [`AbilityPlugin::with_cost_validation`](crate::plugin::AbilityPlugin::with_cost_validation).
+    pub fn validate(&self) -> Vec<(A, P::Quantity)>
+    where
+        P::Quantity: core::fmt::Debug,
+    {
+        let impossible = self.ability_costs.validate(&self.pool);
+        warn_impossible_costs::<A, P>(&impossible);
+        impossible
+    }
+}
+
+/// Logs a warning for each of the `impossible` costs returned by [`AbilityCosts::validate`].
+pub(crate) fn warn_impossible_costs<A: Abilitylike, P: Pool>(impossible: &[(A, P::Quantity)])
+where
+    P::Quantity: core::fmt::Debug,
+{
+    for (action, cost) in impossible {
+        bevy::log::warn!(
+            "The cost of {action:?} ({cost:?}) can never be paid from a {}, as it exceeds the max of the pool.",
+            core::any::type_name::<P>()
+        );
+    }
+}
+
+impl<A: Abilitylike, P: Pool + Component + Default> Default for PoolBundle<A, P> {
+    fn default() -> Self {
+        PoolBundle::new(P::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_abilities;
+    use crate::premade_pools::energy::EnergyPool;
+    use crate::premade_pools::mana::{Mana, ManaPool};
+    use leafwing_input_manager::Actionlike;
+
+    #[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    enum Spell {
+        Fireball,
+        Frostbolt,
+    }
+
+    #[test]
+    fn set_pool_cannot_exceed_min() {
+        let mut mana_pool = ManaPool::new(Mana(0.), Mana(10.), Mana(0.));
+        mana_pool.set_current(Mana(-3.));
+        assert_eq!(mana_pool.current(), ManaPool::MIN);
+    }
+
+    #[test]
+    fn set_pool_cannot_exceed_max() {
+        let max_mana = Mana(10.);
+        let mut mana_pool = ManaPool::new(max_mana, max_mana, Mana(0.));
+        mana_pool.set_current(Mana(100.0));
+        assert_eq!(mana_pool.current(), max_mana);
+    }
+
+    #[test]
+    fn reducing_max_decreases_current() {
+        let mut mana_pool = ManaPool::new(Mana(10.), Mana(10.), Mana(0.));
+        assert_eq!(mana_pool.current(), Mana(10.));
+        mana_pool.set_max(Mana(5.)).unwrap();
+        assert_eq!(mana_pool.current(), Mana(5.));
+    }
+
+    #[test]
+    fn setting_max_below_min_fails() {
+        let mut mana_pool = ManaPool::new(Mana(10.), Mana(10.), Mana(0.));
+        let result = mana_pool.set_max(Mana(-7.));
+        assert_eq!(mana_pool.max(), Mana(10.));
+        assert_eq!(result, Err(MaxPoolLessThanMin))
     }
 
     #[test]
@@ -339,6 +2353,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shortfall_reports_required_and_available() {
+        let mana_pool = ManaPool::new(Mana(5.), Mana(20.), Mana(0.));
+        let shortfall = mana_pool.available_detailed(Mana(20.)).unwrap_err();
+
+        assert_eq!(shortfall.required, Mana(20.));
+        assert_eq!(shortfall.available, Mana(5.));
+        assert_eq!(shortfall.missing(), Mana(15.));
+        assert_eq!(
+            shortfall.to_string(),
+            "Not enough resources: Mana(20.0) required, but only Mana(5.0) available."
+        );
+        assert_eq!(
+            mana_pool.available(Mana(20.)),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+
+        let in_debt = ManaPool::new(Mana(5.), Mana(20.), Mana(0.)).with_debt_floor(Mana(-10.));
+        let costs = AbilityCosts::<Spell, ManaPool>::new([(Spell::Fireball, Mana(20.))]);
+        let shortfall = costs
+            .shortfall(&Spell::Fireball, &in_debt)
+            .unwrap()
+            .unwrap();
+        assert_eq!(shortfall.available, Mana(15.));
+        assert_eq!(costs.shortfall(&Spell::Frostbolt, &in_debt), Ok(None));
+    }
+
+    #[test]
+    fn unresolvable_costs_have_a_shortfall_error_like_pay_cost() {
+        use crate::premade_pools::combo::{ComboPointPool, ComboPoints};
+
+        let mut combo = ComboPointPool::new(ComboPoints(5), ComboPoints(5));
+        let mut costs = AbilityCosts::<Spell, ComboPointPool>::new([(
+            Spell::Fireball,
+            AbilityCost::Percent(40.),
+        )]);
+
+        assert_eq!(
+            costs.shortfall(&Spell::Fireball, &combo),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert_eq!(
+            costs.pay_cost(&Spell::Fireball, &mut combo),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+    }
+
     #[test]
     fn pool_can_regenerate() {
         let mut mana_pool = ManaPool::new(Mana(0.), Mana(10.), Mana(1.3));
@@ -347,4 +2408,777 @@ mod tests {
 
         assert!((mana_pool.current() - expected).0.abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn removed_costs_are_free() {
+        let pool = ManaPool::new(Mana(5.), Mana(10.), Mana(0.));
+        let mut costs = AbilityCosts::<Spell, ManaPool>::new([
+            (Spell::Fireball, Mana(8.)),
+            (Spell::Frostbolt, Mana(9.)),
+        ]);
+        assert!(!costs.available(&Spell::Fireball, &pool));
+
+        assert_eq!(
+            costs.remove(&Spell::Fireball),
+            Some(AbilityCost::Flat(Mana(8.)))
+        );
+        assert!(costs.available(&Spell::Fireball, &pool));
+
+        costs.retain(|_, cost| matches!(cost, AbilityCost::Flat(mana) if *mana < Mana(5.)));
+        assert_eq!(costs.get(&Spell::Frostbolt), None);
+
+        costs.set(Spell::Fireball, Mana(1.));
+        costs.clear();
+        assert_eq!(costs.get(&Spell::Fireball), None);
+    }
+
+    #[test]
+    fn costs_round_trip_through_iterators() {
+        let costs: AbilityCosts<Spell, ManaPool> =
+            [(Spell::Fireball, Mana(5.)), (Spell::Frostbolt, Mana(3.))]
+                .into_iter()
+                .collect();
+
+        let round_tripped: AbilityCosts<Spell, ManaPool> = costs.clone().into_iter().collect();
+        assert_eq!(
+            round_tripped.get(&Spell::Fireball),
+            Some(&AbilityCost::Flat(Mana(5.)))
+        );
+        assert_eq!(
+            round_tripped.get(&Spell::Frostbolt),
+            Some(&AbilityCost::Flat(Mana(3.)))
+        );
+
+        let mut extended = round_tripped;
+        extended.extend([(Spell::Fireball, Mana(1.))]);
+        assert_eq!(
+            extended.get(&Spell::Fireball),
+            Some(&AbilityCost::Flat(Mana(1.)))
+        );
+        assert_eq!((&extended).into_iter().count(), 2);
+    }
+
+    #[test]
+    fn consuming_builder_matches_mutable_builder() {
+        let built: AbilityCosts<Spell, ManaPool> = AbilityCosts::default()
+            .set(Spell::Fireball, Mana(5.))
+            .set(Spell::Frostbolt, Mana(3.))
+            .build();
+        let consumed: AbilityCosts<Spell, ManaPool> = AbilityCosts::default()
+            .with(Spell::Fireball, Mana(5.))
+            .with(Spell::Frostbolt, Mana(3.));
+
+        for spell in [Spell::Fireball, Spell::Frostbolt] {
+            assert_eq!(built.get(&spell), consumed.get(&spell));
+        }
+    }
+
+    #[test]
+    fn cost_iterators_yield_actions() {
+        let mut costs: AbilityCosts<Spell, ManaPool> =
+            AbilityCosts::new([(Spell::Fireball, Mana(5.)), (Spell::Frostbolt, Mana(3.))]);
+
+        for (action, mut cost) in costs.iter_mut() {
+            if *action == Spell::Fireball {
+                *cost = AbilityCost::Flat(Mana(7.));
+            }
+        }
+
+        let pool = ManaPool::new(Mana(10.), Mana(10.), Mana(0.));
+        let mut seen: Vec<(Spell, Mana)> = costs
+            .iter()
+            .map(|(action, cost)| (*action, cost.resolve(&pool).unwrap()))
+            .collect();
+        seen.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        assert_eq!(
+            seen,
+            vec![(Spell::Frostbolt, Mana(3.)), (Spell::Fireball, Mana(7.))]
+        );
+    }
+
+    #[test]
+    fn interruptible_pool_pauses_and_resumes_regen() {
+        let mana = ManaPool::new(Mana(100.), Mana(100.), Mana(10.));
+        let mut pool = InterruptiblePool::new(mana, Duration::from_secs(5));
+        assert!(!pool.is_interrupted());
+
+        pool.expend(Mana(60.)).unwrap();
+        assert!(pool.is_interrupted());
+
+        pool.regenerate(Duration::from_secs(3));
+        assert_eq!(pool.current(), Mana(40.));
+
+        // Two seconds finish the delay, and one second regenerates
+        pool.regenerate(Duration::from_secs(3));
+        assert_eq!(pool.current(), Mana(50.));
+        assert!(!pool.is_interrupted());
+
+        pool.regenerate(Duration::from_secs(1));
+        assert_eq!(pool.current(), Mana(60.));
+    }
+
+    #[test]
+    fn interruptible_pool_restarts_delay_when_paying_costs() {
+        let mana = ManaPool::new(Mana(100.), Mana(100.), Mana(10.));
+        let mut pool = InterruptiblePool::new(mana, Duration::from_secs(5));
+        let mut costs =
+            AbilityCosts::<Spell, InterruptiblePool<ManaPool>>::new([(Spell::Fireball, Mana(30.))]);
+
+        costs.pay_cost(&Spell::Fireball, &mut pool).unwrap();
+        pool.regenerate(Duration::from_secs(4));
+        costs.pay_cost(&Spell::Fireball, &mut pool).unwrap();
+        pool.regenerate(Duration::from_secs(4));
+        assert_eq!(pool.current(), Mana(40.));
+
+        // Failing to pay does not interrupt regeneration
+        pool.regenerate(Duration::from_secs(1));
+        pool.set_current(Mana(0.));
+        assert!(costs.pay_cost(&Spell::Fireball, &mut pool).is_err());
+        assert!(!pool.is_interrupted());
+    }
+
+    #[test]
+    fn interruptible_pool_does_not_pause_decay() {
+        let mana = ManaPool::new(Mana(100.), Mana(100.), Mana(-10.));
+        let mut pool = InterruptiblePool::new(mana, Duration::from_secs(5));
+
+        pool.expend(Mana(20.)).unwrap();
+        pool.regenerate(Duration::from_secs(2));
+        assert_eq!(pool.current(), Mana(60.));
+    }
+
+    #[test]
+    fn replenish_reports_overflow() {
+        let mut mana_pool = ManaPool::new(Mana(80.), Mana(100.), Mana(0.));
+        assert_eq!(mana_pool.replenish(Mana(20.)), Mana(0.));
+        assert_eq!(mana_pool.current(), Mana(100.));
+
+        mana_pool.set_current(Mana(90.));
+        assert_eq!(mana_pool.replenish(Mana(25.)), Mana(15.));
+        assert_eq!(mana_pool.current(), Mana(100.));
+    }
+
+    #[test]
+    fn expend_up_to_reports_shortfall() {
+        let mut mana_pool = ManaPool::new(Mana(30.), Mana(100.), Mana(0.));
+        assert_eq!(mana_pool.expend_up_to(Mana(30.)), Mana(30.));
+        assert!(mana_pool.is_empty());
+
+        mana_pool.set_current(Mana(10.));
+        assert_eq!(mana_pool.expend_up_to(Mana(25.)), Mana(10.));
+        assert!(mana_pool.is_empty());
+        assert_eq!(mana_pool.expend_up_to(Mana(5.)), Mana(0.));
+    }
+
+    #[test]
+    fn apply_reports_the_applied_change() {
+        let mut mana_pool = ManaPool::new(Mana(30.), Mana(100.), Mana(0.));
+        assert_eq!(mana_pool.apply(Mana(-20.)), Mana(-20.));
+        assert_eq!(mana_pool.apply(Mana(-50.)), Mana(-10.));
+        assert!(mana_pool.is_empty());
+
+        assert_eq!(mana_pool.apply(Mana(60.)), Mana(60.));
+        assert_eq!(mana_pool.apply(Mana(60.)), Mana(40.));
+        assert!(mana_pool.is_full());
+        assert_eq!(mana_pool.apply(Mana(1.)), Mana(0.));
+    }
+
+    #[test]
+    fn transfer_between_pool_types() {
+        use crate::premade_pools::life::{Life, LifePool};
+
+        let mut life = LifePool::new(Life(50.), Life(100.), Life(0.));
+        let mut mana = ManaPool::new(Mana(0.), Mana(100.), Mana(0.));
+        let life_tap = |life: Life| Mana(life.0);
+
+        let report = transfer(&mut life, &mut mana, Life(20.), life_tap).unwrap();
+        assert_eq!(
+            report,
+            TransferReport {
+                received: Mana(20.),
+                overflow: Mana(0.),
+            }
+        );
+        assert_eq!(life.current(), Life(30.));
+        assert_eq!(mana.current(), Mana(20.));
+
+        // Insufficient source: nothing moves
+        assert_eq!(
+            transfer(&mut life, &mut mana, Life(40.), life_tap),
+            Err(CannotUseAbility::PoolInsufficient)
+        );
+        assert_eq!(life.current(), Life(30.));
+        assert_eq!(mana.current(), Mana(20.));
+
+        // Destination overflow is wasted
+        mana.set_current(Mana(95.));
+        let report = transfer(&mut life, &mut mana, Life(20.), life_tap).unwrap();
+        assert_eq!(report.received, Mana(5.));
+        assert_eq!(report.overflow, Mana(15.));
+        assert_eq!(life.current(), Life(10.));
+        assert!(mana.is_full());
+    }
+
+    #[test]
+    fn transfer_between_pools_of_the_same_type() {
+        let mut source = ManaPool::new(Mana(50.), Mana(100.), Mana(0.));
+        let mut target = ManaPool::new(Mana(0.), Mana(100.), Mana(0.));
+
+        let report = transfer_same(&mut source, &mut target, Mana(30.)).unwrap();
+        assert_eq!(report.received, Mana(30.));
+        assert_eq!(source.current(), Mana(20.));
+        assert_eq!(target.current(), Mana(30.));
+    }
+
+    #[test]
+    fn pool_bundle_builder_sets_costs() {
+        let bundle = PoolBundle::new(ManaPool::new(Mana(10.), Mana(10.), Mana(0.)))
+            .with_cost(Spell::Fireball, Mana(10.))
+            .with_costs([(Spell::Frostbolt, Mana(4.)), (Spell::Fireball, Mana(7.))]);
+
+        assert_eq!(
+            bundle.ability_costs.get(&Spell::Fireball),
+            Some(&AbilityCost::Flat(Mana(7.)))
+        );
+        assert_eq!(
+            bundle.ability_costs.get(&Spell::Frostbolt),
+            Some(&AbilityCost::Flat(Mana(4.)))
+        );
+
+        let percent = PoolBundle::new(ManaPool::new(Mana(10.), Mana(10.), Mana(0.)))
+            .with_costs([(Spell::Frostbolt, AbilityCost::Percent(25.))]);
+        assert_eq!(
+            percent.ability_costs.get(&Spell::Frostbolt),
+            Some(&AbilityCost::Percent(25.))
+        );
+
+        let empty = PoolBundle::<Spell, EnergyPool>::default();
+        assert_eq!(empty.pool, EnergyPool::default());
+        assert_eq!(empty.ability_costs.get(&Spell::Fireball), None);
+    }
+
+    #[test]
+    fn impossible_costs_are_reported() {
+        let bundle = PoolBundle::new(ManaPool::new(Mana(100.), Mana(100.), Mana(0.)))
+            .with_cost(Spell::Fireball, Mana(1000.))
+            .with_cost(Spell::Frostbolt, AbilityCost::Percent(100.));
+
+        assert_eq!(bundle.validate(), vec![(Spell::Fireball, Mana(1000.))]);
+
+        // Pools that can go into debt can pay more than their max
+        let mana = ManaPool::new(Mana(100.), Mana(100.), Mana(0.)).with_debt_floor(Mana(-50.));
+        let costs = AbilityCosts::<Spell, ManaPool>::new([(Spell::Fireball, Mana(150.))]);
+        assert!(costs.validate(&mana).is_empty());
+    }
+
+    #[test]
+    fn ability_costs_can_be_paid_into_debt() {
+        let mut costs = AbilityCosts::<Spell, ManaPool>::new([(Spell::Fireball, Mana(30.))]);
+        let mut mana = ManaPool::new(Mana(20.), Mana(100.), Mana(0.));
+        assert!(!costs.available(&Spell::Fireball, &mana));
+
+        mana.set_debt_floor(Some(Mana(-20.)));
+        assert!(costs.available(&Spell::Fireball, &mana));
+        assert!(pool_can_pay(Some(&mana), costs.get(&Spell::Fireball).copied()).is_ok());
+        costs.pay_cost(&Spell::Fireball, &mut mana).unwrap();
+        assert_eq!(mana.current(), Mana(-10.));
+
+        assert!(!costs.available(&Spell::Fireball, &mana));
+    }
+
+    #[test]
+    fn percent_costs_follow_the_max() {
+        let costs =
+            AbilityCosts::<Spell, ManaPool>::new([(Spell::Fireball, AbilityCost::Percent(50.))]);
+        let mut mana = ManaPool::new(Mana(200.), Mana(200.), Mana(0.));
+
+        let mut charges = crate::ChargeState::default();
+        let mut cooldowns = crate::CooldownState::default();
+        Spell::Fireball
+            .trigger(&mut charges, &mut cooldowns, Some(&mut mana), Some(&costs))
+            .unwrap();
+        assert_eq!(mana.current(), Mana(100.));
+
+        mana.set_max(Mana(300.)).unwrap();
+        mana.set_current(Mana(300.));
+        Spell::Fireball
+            .trigger(&mut charges, &mut cooldowns, Some(&mut mana), Some(&costs))
+            .unwrap();
+        assert_eq!(mana.current(), Mana(150.));
+
+        mana.set_current(Mana(100.));
+        assert!(Spell::Fireball
+            .ready(&charges, &cooldowns, Some(&mana), Some(&costs))
+            .is_err());
+    }
+
+    #[test]
+    fn percent_costs_cannot_be_paid_by_unscalable_pools() {
+        use crate::premade_pools::combo::{ComboPointPool, ComboPoints};
+
+        let mut costs = AbilityCosts::<Spell, ComboPointPool>::new([(
+            Spell::Fireball,
+            AbilityCost::Percent(50.),
+        )]);
+        let mut pool = ComboPointPool::new(ComboPoints(5), ComboPoints(5));
+        assert!(!costs.available(&Spell::Fireball, &pool));
+        assert!(costs.pay_cost(&Spell::Fireball, &mut pool).is_err());
+        assert_eq!(pool.current(), ComboPoints(5));
+    }
+
+    #[test]
+    fn reserved_resources_cannot_be_spent() {
+        let costs = AbilityCosts::<Spell, ManaPool>::new([(Spell::Frostbolt, Mana(30.))]);
+        let mut mana = ManaPool::new(Mana(100.), Mana(100.), Mana(0.));
+
+        let reservation = mana.reserve(Mana(80.)).unwrap();
+        assert_eq!(mana.current(), Mana(100.));
+        assert_eq!(mana.reserved(), Mana(80.));
+        assert_eq!(mana.unreserved(), Mana(20.));
+        assert!(!costs.available(&Spell::Frostbolt, &mana));
+        assert!(mana.reserve(Mana(30.)).is_err());
+
+        reservation.release(&mut mana);
+        assert_eq!(mana.current(), Mana(100.));
+        assert_eq!(mana.reserved(), Mana(0.));
+        assert!(costs.available(&Spell::Frostbolt, &mana));
+    }
+
+    #[test]
+    fn committed_reservations_are_spent() {
+        let mut mana = ManaPool::new(Mana(100.), Mana(100.), Mana(0.));
+
+        let reservation = mana.reserve(Mana(40.)).unwrap();
+        reservation.commit(&mut mana);
+        assert_eq!(mana.current(), Mana(60.));
+        assert_eq!(mana.reserved(), Mana(0.));
+
+        let reservation = mana.reserve(Mana(40.)).unwrap();
+        reservation.commit_partial(&mut mana, Mana(10.));
+        assert_eq!(mana.current(), Mana(50.));
+        assert_eq!(mana.unreserved(), Mana(50.));
+    }
+
+    #[test]
+    fn set_max_preserving_fraction_scales_current() {
+        let mut mana = ManaPool::new(Mana(80.), Mana(100.), Mana(0.));
+        mana.set_max_preserving_fraction(Mana(120.)).unwrap();
+        assert_eq!(mana.current(), Mana(96.));
+        assert_eq!(mana.max(), Mana(120.));
+
+        mana.set_max_preserving_fraction(Mana(60.)).unwrap();
+        assert_eq!(mana.current(), Mana(48.));
+
+        let mut empty = ManaPool::new(Mana(0.), Mana(100.), Mana(0.));
+        empty.set_max_preserving_fraction(Mana(200.)).unwrap();
+        assert_eq!(empty.current(), Mana(0.));
+
+        assert!(mana.set_max_preserving_fraction(Mana(-1.)).is_err());
+        assert_eq!(mana.current(), Mana(48.));
+    }
+
+    #[test]
+    fn set_max_adding_delta_shifts_current() {
+        let mut mana = ManaPool::new(Mana(80.), Mana(100.), Mana(0.));
+        mana.set_max_adding_delta(Mana(120.)).unwrap();
+        assert_eq!(mana.current(), Mana(100.));
+
+        mana.set_max_adding_delta(Mana(90.)).unwrap();
+        assert_eq!(mana.current(), Mana(70.));
+
+        // Shrinking never takes the pool below its minimum
+        mana.set_max_adding_delta(Mana(10.)).unwrap();
+        assert_eq!(mana.current(), Mana(0.));
+
+        let mut empty = ManaPool::new(Mana(0.), Mana(100.), Mana(0.));
+        empty.set_max_adding_delta(Mana(120.)).unwrap();
+        assert_eq!(empty.current(), Mana(20.));
+    }
+
+    #[test]
+    fn partial_costs_spend_what_is_available() {
+        let costs = AbilityCosts::<Spell, ManaPool>::new([(Spell::Fireball, Mana(50.))]);
+        let mut mana = ManaPool::new(Mana(30.), Mana(100.), Mana(0.));
+
+        assert_eq!(mana.expend_up_to(Mana(50.)), Mana(30.));
+        assert!(mana.is_empty());
+
+        mana.set_current(Mana(30.));
+        assert_eq!(
+            costs.pay_cost_partial(&Spell::Fireball, &mut mana),
+            Mana(30.)
+        );
+        assert!(mana.is_empty());
+        assert_eq!(
+            costs.pay_cost_partial(&Spell::Fireball, &mut mana),
+            Mana(0.)
+        );
+        assert_eq!(
+            costs.pay_cost_partial(&Spell::Frostbolt, &mut mana),
+            Mana(0.)
+        );
+    }
+
+    #[test]
+    fn invalid_cost_edits_through_get_mut_are_reverted() {
+        let mut costs: AbilityCosts<Spell, ManaPool> =
+            AbilityCosts::new([(Spell::Fireball, Mana(5.)), (Spell::Frostbolt, Mana(3.))]);
+        *costs.get_mut(&Spell::Fireball).unwrap() = AbilityCost::Flat(Mana(f32::NAN));
+        for (_, mut cost) in costs.iter_mut() {
+            *cost = AbilityCost::Percent(f32::INFINITY);
+        }
+
+        let mana = ManaPool::new(Mana(50.), Mana(100.), Mana(0.));
+        assert_eq!(costs.resolve(&Spell::Fireball, &mana), Some(Mana(5.)));
+        assert_eq!(costs.resolve(&Spell::Frostbolt, &mana), Some(Mana(3.)));
+    }
+
+    // Invalid quantities panic in debug builds, and are rejected in release builds
+    mod invalid_quantities {
+        use super::*;
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic(expected = "invalid quantity"))]
+        fn set_current_rejects_nan() {
+            let mut mana = ManaPool::new(Mana(50.), Mana(100.), Mana(0.));
+            assert_eq!(mana.set_current(Mana(f32::NAN)), Mana(50.));
+            assert_eq!(mana.current(), Mana(50.));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic(expected = "invalid quantity"))]
+        fn replenish_rejects_infinity() {
+            let mut mana = ManaPool::new(Mana(50.), Mana(100.), Mana(0.));
+            mana.replenish(Mana(f32::INFINITY));
+            assert_eq!(mana.current(), Mana(50.));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic(expected = "invalid quantity"))]
+        fn expend_rejects_nan() {
+            let mut mana = ManaPool::new(Mana(50.), Mana(100.), Mana(0.));
+            assert!(mana.expend(Mana(f32::NAN)).is_err());
+            assert_eq!(mana.current(), Mana(50.));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic(expected = "invalid quantity"))]
+        fn set_max_rejects_nan() {
+            let mut mana = ManaPool::new(Mana(50.), Mana(100.), Mana(0.));
+            assert!(mana.set_max(Mana(f32::NAN)).is_err());
+            assert_eq!(mana.max(), Mana(100.));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic(expected = "invalid quantity"))]
+        fn set_regen_per_second_rejects_infinity() {
+            let mut mana = ManaPool::new(Mana(50.), Mana(100.), Mana(1.));
+            mana.set_regen_per_second(Mana(f32::NEG_INFINITY));
+            assert_eq!(mana.regen_per_second(), Mana(1.));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic(expected = "invalid quantity"))]
+        fn temporary_max_rejects_nan() {
+            use crate::premade_pools::life::{Life, LifePool};
+
+            let mut life = LifePool::new(Life(50.), Life(100.), Life(0.));
+            life.add_temporary_max(Life(f32::NAN), Duration::from_secs(10));
+            assert_eq!(life.max(), Life(100.));
+            assert_eq!(life.set_current(Life(80.)), Life(80.));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic(expected = "invalid quantity"))]
+        fn costs_reject_nan() {
+            let mut costs = AbilityCosts::<Spell, ManaPool>::default();
+            costs.set(Spell::Fireball, Mana(f32::NAN));
+            assert_eq!(costs.get(&Spell::Fireball), None);
+        }
+
+        #[test]
+        fn finite_quantities_are_valid() {
+            assert!(ManaPool::is_valid_quantity(Mana(-3.)));
+            assert!(!ManaPool::is_valid_quantity(Mana(f32::NAN)));
+            assert!(!ManaPool::is_valid_quantity(Mana(f32::INFINITY)));
+        }
+    }
+
+    #[test]
+    fn cost_builders_are_equivalent() {
+        let pairs = [(Spell::Fireball, Mana(5.)), (Spell::Frostbolt, Mana(3.))];
+        let from_new = AbilityCosts::<Spell, ManaPool>::new(pairs);
+        let chained = AbilityCosts::<Spell, ManaPool>::default()
+            .with(Spell::Fireball, Mana(5.))
+            .with(Spell::Frostbolt, Mana(3.));
+        let mut extended = AbilityCosts::<Spell, ManaPool>::default();
+        extended.extend(pairs);
+        let collected: AbilityCosts<Spell, ManaPool> = pairs.into_iter().collect();
+        let with_cost = AbilityCosts::<Spell, ManaPool>::default()
+            .with_cost(Spell::Fireball, Mana(5.))
+            .with_cost(Spell::Frostbolt, Mana(3.));
+        let with_costs = AbilityCosts::<Spell, ManaPool>::default().with_costs(pairs);
+
+        let expected: HashMap<_, _> = from_new.iter().collect();
+        for costs in [chained, extended, collected, with_cost, with_costs] {
+            assert_eq!(costs.iter().collect::<HashMap<_, _>>(), expected);
+        }
+    }
+
+    #[test]
+    fn unlisted_costs_fall_back_to_default() {
+        let pool = ManaPool::new(Mana(8.), Mana(10.), Mana(0.));
+        let costs = AbilityCosts::<Spell, ManaPool>::new([(Spell::Fireball, Mana(5.))])
+            .with_default_cost(Mana(10.));
+
+        assert_eq!(
+            costs.get(&Spell::Fireball),
+            Some(&AbilityCost::Flat(Mana(5.)))
+        );
+        assert_eq!(
+            costs.get(&Spell::Frostbolt),
+            Some(&AbilityCost::Flat(Mana(10.)))
+        );
+        assert_eq!(costs.get_explicit(&Spell::Frostbolt), None);
+        assert!(costs.available(&Spell::Fireball, &pool));
+        assert!(!costs.available(&Spell::Frostbolt, &pool));
+    }
+
+    #[test]
+    fn unlisted_costs_without_default_are_free() {
+        let pool = ManaPool::new(Mana(0.), Mana(10.), Mana(0.));
+        let costs = AbilityCosts::<Spell, ManaPool>::new([(Spell::Fireball, Mana(5.))]);
+
+        assert_eq!(costs.get(&Spell::Frostbolt), None);
+        assert!(costs.available(&Spell::Frostbolt, &pool));
+
+        let costs = costs
+            .with_default_cost(Mana(10.))
+            .with_policy(CostPolicy::UnlistedAreFree);
+        assert_eq!(costs.get(&Spell::Frostbolt), None);
+        assert_eq!(
+            costs.get(&Spell::Fireball),
+            Some(&AbilityCost::Flat(Mana(5.)))
+        );
+    }
+
+    #[test]
+    fn default_costs_are_paid_when_triggering() {
+        let mut pool = ManaPool::new(Mana(10.), Mana(10.), Mana(0.));
+        let costs = AbilityCosts::<Spell, ManaPool>::default().with_default_cost(Mana(4.));
+
+        Spell::Frostbolt
+            .trigger(
+                &mut crate::charges::ChargeState::default(),
+                &mut crate::cooldown::CooldownState::default(),
+                Some(&mut pool),
+                Some(&costs),
+            )
+            .unwrap();
+        assert_eq!(pool.current(), Mana(6.));
+    }
+
+    #[test]
+    fn refunding_restores_recorded_payment() {
+        let mut pool = ManaPool::new(Mana(10.), Mana(10.), Mana(0.));
+        let mut costs = AbilityCosts::<Spell, ManaPool>::new([(Spell::Fireball, Mana(4.))]);
+        let mut payments = CostPayments::default();
+
+        payments
+            .pay_cost(&Spell::Fireball, &mut pool, &costs)
+            .unwrap();
+        assert_eq!(payments.last_paid(&Spell::Fireball), Some(Mana(4.)));
+
+        // Changing the cost after paying does not change the refund
+        costs.set(Spell::Fireball, Mana(7.));
+        assert_eq!(
+            payments.refund(&Spell::Fireball, &mut pool, &costs),
+            Mana(4.)
+        );
+        assert_eq!(pool.current(), Mana(10.));
+        assert_eq!(payments.last_paid(&Spell::Fireball), None);
+    }
+
+    #[test]
+    fn refunding_without_payment_uses_stored_cost() {
+        let mut pool = ManaPool::new(Mana(2.), Mana(10.), Mana(0.));
+        let costs = AbilityCosts::<Spell, ManaPool>::new([(Spell::Fireball, Mana(4.))]);
+
+        assert_eq!(costs.refund(&Spell::Fireball, &mut pool), Mana(4.));
+        assert_eq!(pool.current(), Mana(6.));
+        assert_eq!(costs.refund(&Spell::Frostbolt, &mut pool), Mana(0.));
+    }
+
+    #[test]
+    fn computed_costs_are_only_refunded_from_recorded_payments() {
+        let mut pool = ManaPool::new(Mana(10.), Mana(10.), Mana(0.));
+        let half_current: fn(Mana, Mana) -> Mana = |current, _max| Mana(current.0 / 2.);
+        let costs = AbilityCosts::<Spell, ManaPool>::new([(
+            Spell::Fireball,
+            AbilityCost::Computed(half_current),
+        )]);
+        let mut payments = CostPayments::default();
+
+        // Evaluating the formula now would refund 2.5 mana, rather than the 5 that was paid
+        pool.expend(Mana(5.)).unwrap();
+        assert_eq!(costs.refund(&Spell::Fireball, &mut pool), Mana(0.));
+        assert_eq!(pool.current(), Mana(5.));
+
+        payments
+            .pay_cost(&Spell::Fireball, &mut pool, &costs)
+            .unwrap();
+        assert_eq!(
+            payments.refund(&Spell::Fireball, &mut pool, &costs),
+            Mana(2.5)
+        );
+        assert_eq!(pool.current(), Mana(5.));
+    }
+
+    mod cost_modifiers {
+        use super::*;
+        use crate::{charges::ChargeState, cooldown::CooldownState};
+
+        fn fireball_costs() -> AbilityCosts<Spell, ManaPool> {
+            AbilityCosts::new([(Spell::Fireball, Mana(10.))])
+        }
+
+        #[test]
+        fn global_and_action_multipliers_stack() {
+            let mut modifiers = CostModifiers::<Spell, ManaPool>::default();
+            modifiers.set_global(0.5).unwrap();
+            modifiers
+                .set_action_multiplier(Spell::Fireball, 0.5)
+                .unwrap();
+
+            assert_eq!(modifiers.multiplier(&Spell::Fireball), 0.25);
+            assert_eq!(modifiers.multiplier(&Spell::Frostbolt), 0.5);
+            assert_eq!(
+                modifiers.apply(&Spell::Fireball, AbilityCost::Flat(Mana(10.))),
+                Ok(AbilityCost::Flat(Mana(2.5)))
+            );
+            assert_eq!(
+                modifiers.apply(&Spell::Fireball, AbilityCost::Percent(20.)),
+                Ok(AbilityCost::Percent(5.))
+            );
+        }
+
+        #[test]
+        fn invalid_multipliers_are_rejected() {
+            let mut modifiers = CostModifiers::<Spell, ManaPool>::default();
+
+            for multiplier in [-0.5, f32::NAN, f32::INFINITY] {
+                assert_eq!(
+                    modifiers.set_global(multiplier),
+                    Err(InvalidCostMultiplier::NotFiniteOrNegative)
+                );
+                assert_eq!(
+                    modifiers.set_action_multiplier(Spell::Fireball, multiplier),
+                    Err(InvalidCostMultiplier::NotFiniteOrNegative)
+                );
+                assert_eq!(
+                    modifiers.set_next_use_multiplier(Spell::Fireball, multiplier),
+                    Err(InvalidCostMultiplier::NotFiniteOrNegative)
+                );
+            }
+
+            assert_eq!(modifiers.global(), 1.0);
+            assert_eq!(modifiers.multiplier(&Spell::Fireball), 1.0);
+            assert_eq!(modifiers.next_use_multiplier(&Spell::Fireball), None);
+        }
+
+        #[test]
+        fn next_use_multiplier_is_consumed_on_success() {
+            let mut pool = ManaPool::new(Mana(20.), Mana(20.), Mana(0.));
+            let costs = fireball_costs();
+            let mut modifiers = CostModifiers::<Spell, ManaPool>::default();
+            modifiers
+                .set_next_use_multiplier(Spell::Fireball, 0.)
+                .unwrap();
+            let mut charges = ChargeState::default();
+            let mut cooldowns = CooldownState::default();
+
+            Spell::Fireball
+                .trigger_modified(
+                    &mut charges,
+                    &mut cooldowns,
+                    Some(&mut pool),
+                    Some(&costs),
+                    Some(&mut modifiers),
+                    None,
+                )
+                .unwrap();
+            assert_eq!(pool.current(), Mana(20.));
+            assert_eq!(modifiers.next_use_multiplier(&Spell::Fireball), None);
+
+            Spell::Fireball
+                .trigger_modified(
+                    &mut charges,
+                    &mut cooldowns,
+                    Some(&mut pool),
+                    Some(&costs),
+                    Some(&mut modifiers),
+                    None,
+                )
+                .unwrap();
+            assert_eq!(pool.current(), Mana(10.));
+        }
+
+        #[test]
+        fn next_use_multiplier_is_kept_on_failure() {
+            let mut pool = ManaPool::new(Mana(4.), Mana(20.), Mana(0.));
+            let costs = fireball_costs();
+            let mut modifiers = CostModifiers::<Spell, ManaPool>::default();
+            modifiers
+                .set_next_use_multiplier(Spell::Fireball, 0.5)
+                .unwrap();
+
+            let outcome = Spell::Fireball.trigger_modified(
+                &mut ChargeState::default(),
+                &mut CooldownState::default(),
+                Some(&mut pool),
+                Some(&costs),
+                Some(&mut modifiers),
+                None,
+            );
+            assert_eq!(outcome, Err(CannotUseAbility::PoolInsufficient));
+            assert_eq!(pool.current(), Mana(4.));
+            assert_eq!(modifiers.next_use_multiplier(&Spell::Fireball), Some(0.5));
+        }
+
+        #[test]
+        fn readiness_reflects_discounted_cost() {
+            let pool = ManaPool::new(Mana(6.), Mana(20.), Mana(0.));
+            let costs = fireball_costs();
+            let mut modifiers = CostModifiers::<Spell, ManaPool>::default();
+            let charges = ChargeState::default();
+            let cooldowns = CooldownState::default();
+
+            assert_eq!(
+                Spell::Fireball.ready_modified(
+                    &charges,
+                    &cooldowns,
+                    Some(&pool),
+                    Some(&costs),
+                    Some(&modifiers),
+                    None
+                ),
+                Err(CannotUseAbility::PoolInsufficient)
+            );
+
+            modifiers
+                .set_action_multiplier(Spell::Fireball, 0.5)
+                .unwrap();
+            assert_eq!(
+                Spell::Fireball.ready_modified(
+                    &charges,
+                    &cooldowns,
+                    Some(&pool),
+                    Some(&costs),
+                    Some(&modifiers),
+                    None
+                ),
+                Ok(())
+            );
+        }
+    }
 }
@@ -2,33 +2,155 @@
 //!
 //! These can be annoying due to orphan rules that prevent you from implementing your own methods,
 //! so feel free to copy-paste them (without attribution) into your own source to make new variants.
+//! The pools backed by an [`f32`] share their arithmetic through the macros at the top of this file,
+//! so copy those along with them.
 
-use crate::pool::{MaxPoolLessThanMin, Pool};
+use crate::pool::{check_quantity, MaxPoolLessThanMin, Pool};
 use bevy::prelude::{Component, Resource};
 use core::fmt::{Display, Formatter};
 use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
 use derive_more::{Add, AddAssign, Sub, SubAssign};
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+
+/// Implements conversion from [`f32`], scaling, ratios and [`Display`] for a quantity that wraps an [`f32`].
+macro_rules! float_quantity {
+    ($quantity:ident) => {
+        impl From<f32> for $quantity {
+            fn from(value: f32) -> $quantity {
+                $quantity(value)
+            }
+        }
+
+        impl Mul<f32> for $quantity {
+            type Output = $quantity;
+
+            fn mul(self, rhs: f32) -> $quantity {
+                $quantity(self.0 * rhs)
+            }
+        }
+
+        impl Mul<$quantity> for f32 {
+            type Output = $quantity;
+
+            fn mul(self, rhs: $quantity) -> $quantity {
+                $quantity(self * rhs.0)
+            }
+        }
+
+        impl Div<f32> for $quantity {
+            type Output = $quantity;
+
+            fn div(self, rhs: f32) -> $quantity {
+                $quantity(self.0 / rhs)
+            }
+        }
+
+        impl Div<$quantity> for $quantity {
+            type Output = f32;
+
+            fn div(self, rhs: $quantity) -> f32 {
+                self.0 / rhs.0
+            }
+        }
+
+        impl Display for $quantity {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+/// Implements [`Pool::scale_quantity`] and [`Pool::is_valid_quantity`] for a pool whose quantity wraps an [`f32`].
+///
+/// Invoke this inside the `impl Pool` block.
+macro_rules! float_quantity_checks {
+    () => {
+        fn scale_quantity(quantity: Self::Quantity, factor: f32) -> Option<Self::Quantity> {
+            Some(quantity * factor)
+        }
+
+        fn is_valid_quantity(quantity: Self::Quantity) -> bool {
+            quantity.0.is_finite()
+        }
+    };
+}
+
+/// Implements adding and subtracting quantities through [`Pool::set_current`], and [`Display`] as `current/max`.
+macro_rules! float_pool_arithmetic {
+    ($pool:ident, $quantity:ident) => {
+        impl Add<$quantity> for $pool {
+            type Output = Self;
+
+            fn add(mut self, rhs: $quantity) -> Self::Output {
+                self.set_current(self.current + rhs);
+                self
+            }
+        }
+
+        impl Sub<$quantity> for $pool {
+            type Output = Self;
+
+            fn sub(mut self, rhs: $quantity) -> Self::Output {
+                self.set_current(self.current - rhs);
+                self
+            }
+        }
+
+        impl AddAssign<$quantity> for $pool {
+            fn add_assign(&mut self, rhs: $quantity) {
+                self.set_current(self.current + rhs);
+            }
+        }
+
+        impl SubAssign<$quantity> for $pool {
+            fn sub_assign(&mut self, rhs: $quantity) {
+                self.set_current(self.current - rhs);
+            }
+        }
+
+        impl Display for $pool {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}/{}", self.current, self.max)
+            }
+        }
+    };
+}
 
 /// A premade resource pool for life (aka health, hit points or HP).
 pub mod life {
+    use bevy::app::{App, Plugin, PostUpdate};
+    use bevy::ecs::prelude::{
+        Changed, Commands, DetectChanges, Entity, Event, Has, Local, Query, Ref, RemovedComponents,
+        Res,
+    };
     use bevy::reflect::Reflect;
+    use bevy::utils::{Duration, HashSet};
 
-    use crate::pool::RegeneratingPool;
+    use crate::pool::{RegeneratingPool, TemporaryMaxPool};
 
     use super::*;
 
     /// The amount of life available to a unit.
     /// If they lose it all, they die or pass out.
     ///
+    /// The max can be temporarily raised by buffs, and life can be overhealed beyond the max:
+    /// see [`TemporaryMaxPool`].
+    ///
     /// This is intended to be stored as a component on each entity.
-    #[derive(Debug, Clone, PartialEq, Component, Resource, Reflect)]
+    /// When deserialized, the bounds of the pool are validated rather than trusted.
+    #[derive(Debug, Clone, PartialEq, Component, Resource, Reflect, Serialize)]
     pub struct LifePool {
         /// The current life.
         current: Life,
-        /// The maximum life that can be stored.
+        /// The maximum life that can be stored, excluding temporary increases.
         max: Life,
         /// The amount of life regenerated per second.
         pub regen_per_second: Life,
+        /// Temporary increases to the max, and the time remaining before each expires.
+        temporary_max: Vec<(Life, Duration)>,
+        /// How far the current life may exceed the max.
+        overfill: Life,
     }
 
     impl LifePool {
@@ -45,70 +167,179 @@ pub mod life {
                 current,
                 max,
                 regen_per_second,
+                temporary_max: Vec::new(),
+                overfill: Life(0.),
             }
         }
-    }
-
-    /// A quantity of life, used to modify a [`LifePool`].
-    ///
-    /// This can be used for damage computations, life regeneration, healing and so on.
-    #[derive(
-        Debug, Clone, Copy, PartialEq, PartialOrd, Default, Add, Sub, AddAssign, SubAssign, Reflect,
-    )]
-    pub struct Life(pub f32);
 
-    impl Mul<f32> for Life {
-        type Output = Life;
+        /// Deals `amount` damage to this pool, reporting how much was dealt and whether it was fatal.
+        ///
+        /// Negative amounts are treated as zero damage: use [`LifePool::heal`] to heal.
+        pub fn apply_damage(&mut self, amount: Life) -> DamageOutcome {
+            let amount = Life(amount.0.max(0.));
+            let was_empty = self.is_empty();
+            let absorbed = Life(0.) - self.apply(Life(0.) - amount);
 
-        fn mul(self, rhs: f32) -> Life {
-            Life(self.0 * rhs)
+            DamageOutcome {
+                absorbed,
+                overkill: amount - absorbed,
+                died: !was_empty && self.is_empty(),
+            }
         }
-    }
 
-    impl Mul<Life> for f32 {
-        type Output = Life;
+        /// Heals this pool by `amount`, reporting how much was healed and how much was wasted.
+        ///
+        /// Negative amounts are treated as zero healing: use [`LifePool::apply_damage`] to deal damage.
+        pub fn heal(&mut self, amount: Life) -> HealOutcome {
+            let amount = Life(amount.0.max(0.));
+            let healed = self.apply(amount);
 
-        fn mul(self, rhs: Life) -> Life {
-            Life(self * rhs.0)
+            HealOutcome {
+                healed,
+                overheal: amount - healed,
+            }
         }
     }
 
-    impl Div<f32> for Life {
-        type Output = Life;
+    /// The result of [`LifePool::apply_damage`].
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+    pub struct DamageOutcome {
+        /// The damage that was actually removed from the pool.
+        pub absorbed: Life,
+        /// The damage that exceeded the remaining life.
+        pub overkill: Life,
+        /// Did this damage empty the pool?
+        ///
+        /// This is `false` when damaging a pool that was already empty.
+        pub died: bool,
+    }
 
-        fn div(self, rhs: f32) -> Life {
-            Life(self.0 / rhs)
-        }
+    /// The result of [`LifePool::heal`].
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+    pub struct HealOutcome {
+        /// The life that was actually restored.
+        pub healed: Life,
+        /// The healing that exceeded the max life.
+        pub overheal: Life,
     }
 
-    impl Div<Life> for Life {
-        type Output = f32;
+    impl<'de> Deserialize<'de> for LifePool {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            /// The serialized form of [`LifePool`], before it has been validated.
+            #[derive(Deserialize)]
+            struct UncheckedLifePool {
+                current: Life,
+                max: Life,
+                regen_per_second: Life,
+                #[serde(default)]
+                temporary_max: Vec<(Life, Duration)>,
+                #[serde(default)]
+                overfill: Life,
+            }
+
+            let unchecked = UncheckedLifePool::deserialize(deserializer)?;
+            let quantities = [
+                unchecked.current,
+                unchecked.max,
+                unchecked.regen_per_second,
+                unchecked.overfill,
+            ];
+            if !quantities
+                .into_iter()
+                .chain(unchecked.temporary_max.iter().map(|(amount, _)| *amount))
+                .all(LifePool::is_valid_quantity)
+            {
+                return Err(D::Error::custom("life quantities must be finite"));
+            }
+            let mut pool = LifePool {
+                current: LifePool::MIN,
+                max: unchecked.max,
+                regen_per_second: unchecked.regen_per_second,
+                temporary_max: unchecked.temporary_max,
+                overfill: unchecked.overfill,
+            };
+
+            if pool.max < LifePool::MIN || pool.overfill < LifePool::MIN {
+                return Err(D::Error::custom(format!(
+                    "max life ({}) and overfill ({}) cannot be negative",
+                    pool.max, pool.overfill
+                )));
+            }
+            if let Some((amount, _)) = pool
+                .temporary_max
+                .iter()
+                .find(|(amount, _)| *amount < LifePool::ZERO)
+            {
+                return Err(D::Error::custom(format!(
+                    "temporary increases to the max life ({amount}) cannot be negative"
+                )));
+            }
+            if unchecked.current < LifePool::MIN || unchecked.current > pool.max() + pool.overfill {
+                return Err(D::Error::custom(format!(
+                    "current life ({}) must be between 0 and the max life ({})",
+                    unchecked.current,
+                    pool.max() + pool.overfill
+                )));
+            }
 
-        fn div(self, rhs: Life) -> f32 {
-            self.0 / rhs.0
+            pool.current = unchecked.current;
+            Ok(pool)
         }
     }
 
+    /// A quantity of life, used to modify a [`LifePool`].
+    ///
+    /// This can be used for damage computations, life regeneration, healing and so on.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        PartialOrd,
+        Default,
+        Add,
+        Sub,
+        AddAssign,
+        SubAssign,
+        Reflect,
+        Serialize,
+        Deserialize,
+    )]
+    pub struct Life(pub f32);
+
+    float_quantity!(Life);
+
     impl Pool for LifePool {
         type Quantity = Life;
         const MIN: Life = Life(0.);
+        const ZERO: Life = Life(0.);
+
+        float_quantity_checks!();
 
         fn current(&self) -> Self::Quantity {
             self.current
         }
 
         fn set_current(&mut self, new_quantity: Self::Quantity) -> Self::Quantity {
-            let actual_value = Life(new_quantity.0.clamp(0., self.max.0));
+            if !check_quantity::<Self>(new_quantity) {
+                return self.current;
+            }
+
+            // Never let the upper bound fall below the lower bound, which would make `clamp` panic
+            let upper_bound = (self.max() + self.overfill).0.max(0.);
+            let actual_value = Life(new_quantity.0.clamp(0., upper_bound));
             self.current = actual_value;
             self.current
         }
 
         fn max(&self) -> Self::Quantity {
-            self.max
+            self.temporary_max
+                .iter()
+                .fold(self.max, |max, (amount, _)| max + *amount)
         }
 
         fn set_max(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
-            if new_max < Self::MIN {
+            if !check_quantity::<Self>(new_max) || new_max < Self::MIN {
                 Err(MaxPoolLessThanMin)
             } else {
                 self.max = new_max;
@@ -124,53 +355,158 @@ pub mod life {
         }
 
         fn set_regen_per_second(&mut self, new_regen_per_second: Self::Quantity) {
-            self.regen_per_second = new_regen_per_second;
+            if check_quantity::<Self>(new_regen_per_second) {
+                self.regen_per_second = new_regen_per_second;
+            }
         }
 
         fn regenerate(&mut self, delta_time: std::time::Duration) {
-            self.set_current(self.current + self.regen_per_second * delta_time.as_secs_f32());
+            let had_temporary_max = !self.temporary_max.is_empty();
+            for (_, remaining) in self.temporary_max.iter_mut() {
+                *remaining = remaining.saturating_sub(delta_time);
+            }
+            self.temporary_max
+                .retain(|(_, remaining)| *remaining > Duration::ZERO);
+            if had_temporary_max {
+                self.set_current(self.current);
+            }
+
+            // Regeneration cannot overfill the pool
+            let regenerated = self.current + self.regen_per_second * delta_time.as_secs_f32();
+            let cap = Life(self.current.0.max(self.max().0));
+            self.set_current(Life(regenerated.0.min(cap.0)));
         }
     }
 
-    impl Add<Life> for LifePool {
-        type Output = Self;
+    impl TemporaryMaxPool for LifePool {
+        fn base_max(&self) -> Self::Quantity {
+            self.max
+        }
 
-        fn add(mut self, rhs: Life) -> Self::Output {
-            self.set_current(self.current + rhs);
-            self
+        fn add_temporary_max(&mut self, amount: Self::Quantity, duration: Duration) {
+            if !check_quantity::<Self>(amount) || amount < Self::ZERO {
+                return;
+            }
+
+            self.temporary_max.push((amount, duration));
+        }
+
+        fn clear_temporary_max(&mut self) {
+            self.temporary_max.clear();
+            self.set_current(self.current);
+        }
+
+        fn overfill(&self) -> Self::Quantity {
+            self.overfill
+        }
+
+        fn set_overfill(&mut self, overfill: Self::Quantity) {
+            self.overfill = Life(overfill.0.max(0.));
+            self.set_current(self.current);
         }
     }
 
-    impl Sub<Life> for LifePool {
-        type Output = Self;
+    float_pool_arithmetic!(LifePool, Life);
+
+    /// An opt-in [`Plugin`] that detects when a [`LifePool`] is depleted.
+    ///
+    /// When the life of an entity reaches [`LifePool::MIN`], a [`Dead`] marker component is inserted
+    /// and a [`LifeDepleted`] observer event is triggered, exactly once.
+    /// If the entity is healed back above zero, the [`Dead`] component is removed,
+    /// so that the event fires again if it is later depleted once more.
+    ///
+    /// By default, entities that are spawned with an empty [`LifePool`] are ignored until they are healed:
+    /// use [`LifePlugin::with_spawned_empty`] to treat them as depleted too.
+    ///
+    /// Depletion is detected by [`detect_life_depletion`], which runs in [`PostUpdate`].
+    #[derive(Debug, Default)]
+    pub struct LifePlugin {
+        include_spawned_empty: bool,
+    }
 
-        fn sub(mut self, rhs: Life) -> Self::Output {
-            self.set_current(self.current - rhs);
+    impl LifePlugin {
+        /// Treats entities that are spawned with an empty [`LifePool`] as depleted,
+        /// inserting [`Dead`] and triggering [`LifeDepleted`] for them.
+        #[must_use]
+        pub fn with_spawned_empty(mut self) -> Self {
+            self.include_spawned_empty = true;
             self
         }
     }
 
-    impl AddAssign<Life> for LifePool {
-        fn add_assign(&mut self, rhs: Life) {
-            self.set_current(self.current + rhs);
+    impl Plugin for LifePlugin {
+        fn build(&self, app: &mut App) {
+            app.insert_resource(LifeDepletionSettings {
+                include_spawned_empty: self.include_spawned_empty,
+            })
+            .add_systems(PostUpdate, detect_life_depletion)
+            .register_type::<LifePool>()
+            .register_type::<Dead>();
         }
     }
 
-    impl SubAssign<Life> for LifePool {
-        fn sub_assign(&mut self, rhs: Life) {
-            self.set_current(self.current - rhs);
-        }
+    /// Configures [`detect_life_depletion`].
+    ///
+    /// Inserted by [`LifePlugin`], and can be changed at runtime.
+    #[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+    pub struct LifeDepletionSettings {
+        /// Should entities that are spawned with an empty [`LifePool`] be treated as depleted?
+        pub include_spawned_empty: bool,
     }
 
-    impl Display for Life {
-        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-            write!(f, "{}", self.0)
-        }
+    /// A marker component for entities whose [`LifePool`] has been depleted.
+    ///
+    /// Inserted and removed by [`detect_life_depletion`].
+    #[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+    pub struct Dead;
+
+    /// An observer event that is triggered on an entity when its [`LifePool`] is depleted.
+    ///
+    /// Triggered by [`detect_life_depletion`], once per depletion.
+    #[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct LifeDepleted {
+        /// The entity whose life was depleted.
+        pub entity: Entity,
     }
 
-    impl Display for LifePool {
-        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-            write!(f, "{}/{}", self.current, self.max)
+    /// Inserts [`Dead`] and triggers [`LifeDepleted`] on entities whose [`LifePool`] has become empty,
+    /// and removes [`Dead`] from entities whose [`LifePool`] is no longer empty.
+    ///
+    /// Added by [`LifePlugin`].
+    pub fn detect_life_depletion(
+        query: Query<(Entity, Ref<LifePool>, Has<Dead>), Changed<LifePool>>,
+        settings: Option<Res<LifeDepletionSettings>>,
+        mut removed: RemovedComponents<LifePool>,
+        // Entities that were spawned empty, and have not yet been healed
+        mut spawned_empty: Local<HashSet<Entity>>,
+        mut commands: Commands,
+    ) {
+        let include_spawned_empty = settings.is_some_and(|settings| settings.include_spawned_empty);
+
+        for entity in removed.read() {
+            spawned_empty.remove(&entity);
+        }
+
+        for (entity, pool, dead) in query.iter() {
+            if pool.is_empty() {
+                if dead || spawned_empty.contains(&entity) {
+                    continue;
+                }
+
+                // Entities that were spawned empty were never alive to begin with
+                if pool.is_added() && !include_spawned_empty {
+                    spawned_empty.insert(entity);
+                    continue;
+                }
+
+                commands.entity(entity).try_insert(Dead);
+                commands.trigger_targets(LifeDepleted { entity }, entity);
+            } else {
+                spawned_empty.remove(&entity);
+                if dead {
+                    commands.entity(entity).remove::<Dead>();
+                }
+            }
         }
     }
 }
@@ -179,7 +515,7 @@ pub mod life {
 pub mod mana {
     use bevy::reflect::Reflect;
 
-    use crate::pool::RegeneratingPool;
+    use crate::pool::{RegenCurve, RegeneratingPool, ReservablePool};
 
     use super::*;
 
@@ -187,7 +523,8 @@ pub mod mana {
     /// Units must spend mana to cast spells according to their [`AbilityCosts<A, Mana>`](crate::pool::AbilityCosts) component.
     ///
     /// This is intended to be stored as a component on each entity.
-    #[derive(Debug, Clone, PartialEq, Component, Resource, Reflect)]
+    /// When deserialized, the bounds of the pool are validated rather than trusted.
+    #[derive(Debug, Clone, PartialEq, Component, Resource, Reflect, Serialize)]
     pub struct ManaPool {
         /// The current mana.
         current: Mana,
@@ -195,6 +532,13 @@ pub mod mana {
         max: Mana,
         /// The amount of mana regenerated per second.
         pub regen_per_second: Mana,
+        /// Scales the regeneration rate based on how full the pool is.
+        #[reflect(ignore)]
+        regen_curve: RegenCurve,
+        /// The mana held by [`PoolReservation`](crate::pool::PoolReservation)s.
+        reserved: Mana,
+        /// How far into debt this pool may go, if at all.
+        debt_floor: Option<Mana>,
     }
 
     impl ManaPool {
@@ -211,60 +555,210 @@ pub mod mana {
                 current,
                 max,
                 regen_per_second,
+                regen_curve: RegenCurve::Constant,
+                reserved: Mana(0.),
+                debt_floor: None,
             }
         }
-    }
 
-    /// A quantity of mana, used to modify a [`ManaPool`].
-    ///
-    /// This can be used for ability costs, mana regeneration and so on.
-    #[derive(
-        Debug, Clone, Copy, PartialEq, PartialOrd, Default, Add, Sub, AddAssign, SubAssign, Reflect,
-    )]
-    pub struct Mana(pub f32);
+        /// Sets the [`RegenCurve`] of this pool, returning the modified pool.
+        #[must_use]
+        pub fn with_regen_curve(mut self, regen_curve: RegenCurve) -> Self {
+            self.regen_curve = regen_curve;
+            self
+        }
 
-    impl Mul<f32> for Mana {
-        type Output = Mana;
+        /// Sets the [`RegenCurve`] of this pool.
+        pub fn set_regen_curve(&mut self, regen_curve: RegenCurve) {
+            self.regen_curve = regen_curve;
+        }
 
-        fn mul(self, rhs: f32) -> Mana {
-            Mana(self.0 * rhs)
+        /// The [`RegenCurve`] that scales the regeneration of this pool based on how full it is.
+        #[inline]
+        #[must_use]
+        pub fn regen_curve(&self) -> RegenCurve {
+            self.regen_curve
+        }
+
+        /// Allows this pool to go into debt, down to `debt_floor`, returning the modified pool.
+        ///
+        /// See [`Pool::debt_floor`] for more details.
+        ///
+        /// # Panics
+        /// Panics if `debt_floor` is greater than [`ManaPool::MIN`].
+        #[must_use]
+        pub fn with_debt_floor(mut self, debt_floor: Mana) -> Self {
+            self.set_debt_floor(Some(debt_floor));
+            self
+        }
+
+        /// Sets how far into debt this pool may go, or prevents it from going into debt if `None`.
+        ///
+        /// If the pool is further in debt than the new floor allows, its current value is raised to the floor.
+        ///
+        /// # Panics
+        /// Panics if `debt_floor` is greater than [`ManaPool::MIN`].
+        pub fn set_debt_floor(&mut self, debt_floor: Option<Mana>) {
+            if let Some(floor) = debt_floor {
+                assert!(floor <= ManaPool::MIN);
+            }
+            self.debt_floor = debt_floor;
+            self.set_current(self.current);
         }
-    }
 
-    impl Mul<Mana> for f32 {
-        type Output = Mana;
+        /// Drains `amount` mana from this pool, reporting how much was drained and whether it was emptied.
+        ///
+        /// Unlike [`Pool::expend`], this never fails.
+        /// Negative amounts are treated as zero: use [`ManaPool::restore`] to restore mana.
+        pub fn drain(&mut self, amount: Mana) -> DrainOutcome {
+            let amount = Mana(amount.0.max(0.));
+            let was_empty = self.is_empty();
+            let drained = Mana(0.) - self.apply(Mana(0.) - amount);
 
-        fn mul(self, rhs: Mana) -> Mana {
-            Mana(self * rhs.0)
+            DrainOutcome {
+                drained,
+                excess: amount - drained,
+                emptied: !was_empty && self.is_empty(),
+            }
         }
-    }
 
-    impl Div<f32> for Mana {
-        type Output = Mana;
+        /// Restores `amount` mana to this pool, reporting how much was restored and how much was wasted.
+        ///
+        /// Negative amounts are treated as zero: use [`ManaPool::drain`] to drain mana.
+        pub fn restore(&mut self, amount: Mana) -> RestoreOutcome {
+            let amount = Mana(amount.0.max(0.));
+            let restored = self.apply(amount);
 
-        fn div(self, rhs: f32) -> Mana {
-            Mana(self.0 / rhs)
+            RestoreOutcome {
+                restored,
+                excess: amount - restored,
+            }
         }
     }
 
-    impl Div<Mana> for Mana {
-        type Output = f32;
+    /// The result of [`ManaPool::drain`].
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+    pub struct DrainOutcome {
+        /// The mana that was actually removed from the pool.
+        pub drained: Mana,
+        /// The part of the drain that exceeded the remaining mana.
+        pub excess: Mana,
+        /// Did this drain empty the pool?
+        ///
+        /// This is `false` when draining a pool that was already empty.
+        pub emptied: bool,
+    }
+
+    /// The result of [`ManaPool::restore`].
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+    pub struct RestoreOutcome {
+        /// The mana that was actually restored.
+        pub restored: Mana,
+        /// The part of the restoration that exceeded the max mana.
+        pub excess: Mana,
+    }
+
+    impl<'de> Deserialize<'de> for ManaPool {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            /// The serialized form of [`ManaPool`], before it has been validated.
+            #[derive(Deserialize)]
+            struct UncheckedManaPool {
+                current: Mana,
+                max: Mana,
+                regen_per_second: Mana,
+                #[serde(default)]
+                regen_curve: RegenCurve,
+                #[serde(default)]
+                debt_floor: Option<Mana>,
+                #[serde(default)]
+                reserved: Mana,
+            }
+
+            let unchecked = UncheckedManaPool::deserialize(deserializer)?;
+            let quantities = [
+                unchecked.current,
+                unchecked.max,
+                unchecked.regen_per_second,
+                unchecked.reserved,
+            ];
+            if !quantities
+                .into_iter()
+                .chain(unchecked.debt_floor)
+                .all(ManaPool::is_valid_quantity)
+            {
+                return Err(D::Error::custom("mana quantities must be finite"));
+            }
+            let floor = unchecked.debt_floor.unwrap_or(ManaPool::MIN);
+            if unchecked.max < ManaPool::MIN || floor > ManaPool::MIN {
+                return Err(D::Error::custom(format!(
+                    "max mana ({}) cannot be negative, and the debt floor ({}) cannot be positive",
+                    unchecked.max, floor
+                )));
+            }
+            if unchecked.current < floor || unchecked.current > unchecked.max {
+                return Err(D::Error::custom(format!(
+                    "current mana ({}) must be between the debt floor ({}) and the max mana ({})",
+                    unchecked.current, floor, unchecked.max
+                )));
+            }
+            if unchecked.reserved < Mana(0.) || unchecked.current - unchecked.reserved < floor {
+                return Err(D::Error::custom(format!(
+                    "reserved mana ({}) cannot be negative, or exceed what current mana ({}) can pay above the debt floor ({})",
+                    unchecked.reserved, unchecked.current, floor
+                )));
+            }
 
-        fn div(self, rhs: Mana) -> f32 {
-            self.0 / rhs.0
+            Ok(ManaPool {
+                current: unchecked.current,
+                max: unchecked.max,
+                regen_per_second: unchecked.regen_per_second,
+                regen_curve: unchecked.regen_curve,
+                debt_floor: unchecked.debt_floor,
+                reserved: unchecked.reserved,
+            })
         }
     }
 
+    /// A quantity of mana, used to modify a [`ManaPool`].
+    ///
+    /// This can be used for ability costs, mana regeneration and so on.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        PartialOrd,
+        Default,
+        Add,
+        Sub,
+        AddAssign,
+        SubAssign,
+        Reflect,
+        Serialize,
+        Deserialize,
+    )]
+    pub struct Mana(pub f32);
+
+    float_quantity!(Mana);
+
     impl Pool for ManaPool {
         type Quantity = Mana;
         const MIN: Mana = Mana(0.);
+        const ZERO: Mana = Mana(0.);
+
+        float_quantity_checks!();
 
         fn current(&self) -> Self::Quantity {
             self.current
         }
 
         fn set_current(&mut self, new_quantity: Self::Quantity) -> Self::Quantity {
-            let actual_value = Mana(new_quantity.0.clamp(0., self.max.0));
+            if !check_quantity::<Self>(new_quantity) {
+                return self.current;
+            }
+
+            let floor = self.debt_floor.unwrap_or(Self::MIN);
+            let actual_value = Mana(new_quantity.0.clamp(floor.0, self.max.0));
             self.current = actual_value;
             self.current
         }
@@ -273,8 +767,16 @@ pub mod mana {
             self.max
         }
 
+        fn reserved(&self) -> Self::Quantity {
+            self.reserved
+        }
+
+        fn debt_floor(&self) -> Option<Self::Quantity> {
+            self.debt_floor
+        }
+
         fn set_max(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
-            if new_max < Self::MIN {
+            if !check_quantity::<Self>(new_max) || new_max < Self::MIN {
                 Err(MaxPoolLessThanMin)
             } else {
                 self.max = new_max;
@@ -284,59 +786,1696 @@ pub mod mana {
         }
     }
 
+    impl ReservablePool for ManaPool {
+        fn set_reserved(&mut self, reserved: Self::Quantity) {
+            self.reserved = Mana(reserved.0.max(0.));
+        }
+    }
+
     impl RegeneratingPool for ManaPool {
         fn regen_per_second(&self) -> Self::Quantity {
             self.regen_per_second
         }
 
         fn set_regen_per_second(&mut self, new_regen_per_second: Self::Quantity) {
-            self.regen_per_second = new_regen_per_second;
+            if check_quantity::<Self>(new_regen_per_second) {
+                self.regen_per_second = new_regen_per_second;
+            }
         }
 
         fn regenerate(&mut self, delta_time: std::time::Duration) {
-            self.set_current(self.current + self.regen_per_second * delta_time.as_secs_f32());
+            let multiplier = self.regen_curve.multiplier(self.fraction());
+            self.set_current(
+                self.current + self.regen_per_second * (multiplier * delta_time.as_secs_f32()),
+            );
         }
     }
 
-    impl Add<Mana> for ManaPool {
-        type Output = Self;
+    float_pool_arithmetic!(ManaPool, Mana);
+}
 
-        fn add(mut self, rhs: Mana) -> Self::Output {
-            self.set_current(self.current + rhs);
-            self
+/// A premade resource pool for energy, which is spent quickly and regenerates quickly, as used by rogues.
+pub mod energy {
+    use bevy::reflect::Reflect;
+
+    use crate::pool::{RegenCurve, RegeneratingPool, ReservablePool};
+
+    use super::*;
+
+    /// The amount of energy available to a unit.
+    /// Units must spend energy to use abilities according to their [`AbilityCosts<A, EnergyPool>`](crate::pool::AbilityCosts) component.
+    ///
+    /// Energy pools are typically small, and regenerate quickly at a constant rate:
+    /// the [`Default`] pool is full, with a max of 100 and 10 energy regenerated per second.
+    ///
+    /// This is intended to be stored as a component on each entity.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_abilities::prelude::*;
+    /// use leafwing_abilities::premade_pools::energy::{Energy, EnergyPool};
+    /// use leafwing_abilities::systems::regenerate_resource_pool;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Abilitylike, Clone, Copy, Debug, Hash, PartialEq, Eq, Reflect)]
+    /// enum RogueAbility {
+    ///     Backstab,
+    ///     Evade,
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, AbilityPlugin::<RogueAbility>::default()))
+    ///     .add_systems(Update, regenerate_resource_pool::<EnergyPool>);
+    ///
+    /// app.world_mut().spawn(
+    ///     PoolBundle::new(EnergyPool::default())
+    ///         .with_cost(RogueAbility::Backstab, Energy(60.))
+    ///         .with_cost(RogueAbility::Evade, Energy(25.)),
+    /// );
+    /// app.update();
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Component, Resource, Reflect)]
+    pub struct EnergyPool {
+        /// The current energy.
+        current: Energy,
+        /// The maximum energy that can be stored.
+        max: Energy,
+        /// The amount of energy regenerated per second.
+        pub regen_per_second: Energy,
+        /// Scales the regeneration rate based on how full the pool is.
+        #[reflect(ignore)]
+        regen_curve: RegenCurve,
+        /// The energy held by [`PoolReservation`](crate::pool::PoolReservation)s.
+        reserved: Energy,
+    }
+
+    impl Default for EnergyPool {
+        /// A full pool with a max of 100 energy, regenerating 10 energy per second.
+        fn default() -> Self {
+            EnergyPool::new(Energy(100.), Energy(100.), Energy(10.))
         }
     }
 
-    impl Sub<Mana> for ManaPool {
-        type Output = Self;
+    impl EnergyPool {
+        /// Creates a new [`EnergyPool`] with the supplied settings.
+        ///
+        /// # Panics
+        /// Panics if `current` is greater than `max`.
+        /// Panics if `current` or `max` is negative.
+        pub fn new(current: Energy, max: Energy, regen_per_second: Energy) -> Self {
+            assert!(current <= max);
+            assert!(current >= EnergyPool::MIN);
+            assert!(max >= EnergyPool::MIN);
+            Self {
+                current,
+                max,
+                regen_per_second,
+                regen_curve: RegenCurve::Constant,
+                reserved: Energy(0.),
+            }
+        }
 
-        fn sub(mut self, rhs: Mana) -> Self::Output {
-            self.set_current(self.current - rhs);
+        /// Sets the [`RegenCurve`] of this pool, returning the modified pool.
+        #[must_use]
+        pub fn with_regen_curve(mut self, regen_curve: RegenCurve) -> Self {
+            self.regen_curve = regen_curve;
             self
         }
-    }
 
-    impl AddAssign<Mana> for ManaPool {
-        fn add_assign(&mut self, rhs: Mana) {
-            self.set_current(self.current + rhs);
+        /// Sets the [`RegenCurve`] of this pool.
+        pub fn set_regen_curve(&mut self, regen_curve: RegenCurve) {
+            self.regen_curve = regen_curve;
+        }
+
+        /// The [`RegenCurve`] that scales the regeneration of this pool based on how full it is.
+        #[inline]
+        #[must_use]
+        pub fn regen_curve(&self) -> RegenCurve {
+            self.regen_curve
         }
     }
 
-    impl SubAssign<Mana> for ManaPool {
-        fn sub_assign(&mut self, rhs: Mana) {
-            self.set_current(self.current - rhs);
+    /// A quantity of energy, used to modify an [`EnergyPool`].
+    ///
+    /// This can be used for ability costs, energy regeneration and so on.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        PartialOrd,
+        Default,
+        Add,
+        Sub,
+        AddAssign,
+        SubAssign,
+        Reflect,
+        Serialize,
+        Deserialize,
+    )]
+    pub struct Energy(pub f32);
+
+    float_quantity!(Energy);
+
+    impl Pool for EnergyPool {
+        type Quantity = Energy;
+        const MIN: Energy = Energy(0.);
+        const ZERO: Energy = Energy(0.);
+
+        float_quantity_checks!();
+
+        fn current(&self) -> Self::Quantity {
+            self.current
+        }
+
+        fn set_current(&mut self, new_quantity: Self::Quantity) -> Self::Quantity {
+            if !check_quantity::<Self>(new_quantity) {
+                return self.current;
+            }
+
+            let actual_value = Energy(new_quantity.0.clamp(0., self.max.0));
+            self.current = actual_value;
+            self.current
+        }
+
+        fn max(&self) -> Self::Quantity {
+            self.max
+        }
+
+        fn reserved(&self) -> Self::Quantity {
+            self.reserved
+        }
+
+        fn set_max(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
+            if !check_quantity::<Self>(new_max) || new_max < Self::MIN {
+                Err(MaxPoolLessThanMin)
+            } else {
+                self.max = new_max;
+                self.set_current(self.current);
+                Ok(())
+            }
         }
     }
 
-    impl Display for Mana {
-        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-            write!(f, "{}", self.0)
+    impl ReservablePool for EnergyPool {
+        fn set_reserved(&mut self, reserved: Self::Quantity) {
+            self.reserved = Energy(reserved.0.max(0.));
         }
     }
 
-    impl Display for ManaPool {
-        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-            write!(f, "{}/{}", self.current, self.max)
+    impl RegeneratingPool for EnergyPool {
+        fn regen_per_second(&self) -> Self::Quantity {
+            self.regen_per_second
+        }
+
+        fn set_regen_per_second(&mut self, new_regen_per_second: Self::Quantity) {
+            if check_quantity::<Self>(new_regen_per_second) {
+                self.regen_per_second = new_regen_per_second;
+            }
+        }
+
+        fn regenerate(&mut self, delta_time: std::time::Duration) {
+            let multiplier = self.regen_curve.multiplier(self.fraction());
+            self.set_current(
+                self.current + self.regen_per_second * (multiplier * delta_time.as_secs_f32()),
+            );
+        }
+    }
+
+    float_pool_arithmetic!(EnergyPool, Energy);
+}
+
+/// A premade resource pool for stamina, which only starts regenerating a short while after it was last spent.
+pub mod stamina {
+    use bevy::reflect::Reflect;
+    use bevy::utils::Duration;
+
+    use crate::pool::RegeneratingPool;
+    use crate::CannotUseAbility;
+
+    use super::*;
+
+    /// The amount of stamina available to a unit.
+    /// Units must spend stamina to use abilities according to their [`AbilityCosts<A, StaminaPool>`](crate::pool::AbilityCosts) component.
+    ///
+    /// Stamina does not regenerate until `regen_delay` has passed since it was last spent with [`Pool::expend`],
+    /// then refills quickly: the [`Default`] pool is full, with a max of 100,
+    /// 50 stamina regenerated per second and a regeneration delay of one second.
+    ///
+    /// As this is tracked by the pool itself, [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool) can be used as normal.
+    /// Note that only [`Pool::expend`] resets the delay: directly lowering the current value (e.g. with `-=`) does not.
+    ///
+    /// This is intended to be stored as a component on each entity.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_abilities::prelude::*;
+    /// use leafwing_abilities::premade_pools::stamina::{Stamina, StaminaPool};
+    /// use leafwing_abilities::systems::regenerate_resource_pool;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Abilitylike, Clone, Copy, Debug, Hash, PartialEq, Eq, Reflect)]
+    /// enum FighterAbility {
+    ///     Roll,
+    ///     HeavyAttack,
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, AbilityPlugin::<FighterAbility>::default()))
+    ///     .add_systems(Update, regenerate_resource_pool::<StaminaPool>);
+    ///
+    /// app.world_mut().spawn(
+    ///     PoolBundle::new(StaminaPool::default())
+    ///         .with_cost(FighterAbility::Roll, Stamina(25.))
+    ///         .with_cost(FighterAbility::HeavyAttack, Stamina(40.)),
+    /// );
+    /// app.update();
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Component, Resource, Reflect)]
+    pub struct StaminaPool {
+        /// The current stamina.
+        current: Stamina,
+        /// The maximum stamina that can be stored.
+        max: Stamina,
+        /// The amount of stamina regenerated per second.
+        pub regen_per_second: Stamina,
+        /// How long after stamina is spent before it begins to regenerate.
+        pub regen_delay: Duration,
+        /// The time elapsed since stamina was last spent.
+        since_expended: Duration,
+    }
+
+    impl Default for StaminaPool {
+        /// A full pool with a max of 100 stamina, regenerating 50 stamina per second after a one second delay.
+        fn default() -> Self {
+            StaminaPool::new(
+                Stamina(100.),
+                Stamina(100.),
+                Stamina(50.),
+                Duration::from_secs(1),
+            )
+        }
+    }
+
+    impl StaminaPool {
+        /// Creates a new [`StaminaPool`] with the supplied settings.
+        ///
+        /// The pool starts out ready to regenerate, as if the delay had already elapsed.
+        ///
+        /// # Panics
+        /// Panics if `current` is greater than `max`.
+        /// Panics if `current` or `max` is negative.
+        pub fn new(
+            current: Stamina,
+            max: Stamina,
+            regen_per_second: Stamina,
+            regen_delay: Duration,
+        ) -> Self {
+            assert!(current <= max);
+            assert!(current >= StaminaPool::MIN);
+            assert!(max >= StaminaPool::MIN);
+            Self {
+                current,
+                max,
+                regen_per_second,
+                regen_delay,
+                since_expended: regen_delay,
+            }
+        }
+
+        /// The time elapsed since stamina was last spent, capped at `regen_delay`.
+        #[inline]
+        #[must_use]
+        pub fn time_since_expended(&self) -> Duration {
+            self.since_expended
+        }
+
+        /// Is this pool still waiting for its `regen_delay` to elapse before regenerating?
+        #[inline]
+        #[must_use]
+        pub fn is_regen_delayed(&self) -> bool {
+            self.since_expended < self.regen_delay
+        }
+    }
+
+    /// A quantity of stamina, used to modify a [`StaminaPool`].
+    ///
+    /// This can be used for ability costs, stamina regeneration and so on.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        PartialOrd,
+        Default,
+        Add,
+        Sub,
+        AddAssign,
+        SubAssign,
+        Reflect,
+        Serialize,
+        Deserialize,
+    )]
+    pub struct Stamina(pub f32);
+
+    float_quantity!(Stamina);
+
+    impl Pool for StaminaPool {
+        type Quantity = Stamina;
+        const MIN: Stamina = Stamina(0.);
+        const ZERO: Stamina = Stamina(0.);
+
+        float_quantity_checks!();
+
+        fn current(&self) -> Self::Quantity {
+            self.current
+        }
+
+        fn set_current(&mut self, new_quantity: Self::Quantity) -> Self::Quantity {
+            if !check_quantity::<Self>(new_quantity) {
+                return self.current;
+            }
+
+            let actual_value = Stamina(new_quantity.0.clamp(0., self.max.0));
+            self.current = actual_value;
+            self.current
+        }
+
+        fn max(&self) -> Self::Quantity {
+            self.max
+        }
+
+        fn set_max(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
+            if !check_quantity::<Self>(new_max) || new_max < Self::MIN {
+                Err(MaxPoolLessThanMin)
+            } else {
+                self.max = new_max;
+                self.set_current(self.current);
+                Ok(())
+            }
+        }
+
+        /// Spends the specified amount from the pool, if there is that much available.
+        ///
+        /// This restarts the regeneration delay.
+        fn expend(&mut self, amount: Self::Quantity) -> Result<(), CannotUseAbility> {
+            self.available(amount)?;
+
+            self.set_current(self.current - amount);
+            self.since_expended = Duration::ZERO;
+            Ok(())
+        }
+
+        /// Spends as much of the specified amount from the pool as possible.
+        ///
+        /// This restarts the regeneration delay.
+        fn expend_up_to(&mut self, amount: Self::Quantity) -> Self::Quantity {
+            let old_current = self.current;
+            self.set_current(old_current - amount);
+            self.since_expended = Duration::ZERO;
+            old_current - self.current
+        }
+    }
+
+    impl RegeneratingPool for StaminaPool {
+        fn regen_per_second(&self) -> Self::Quantity {
+            self.regen_per_second
+        }
+
+        fn set_regen_per_second(&mut self, new_regen_per_second: Self::Quantity) {
+            if check_quantity::<Self>(new_regen_per_second) {
+                self.regen_per_second = new_regen_per_second;
+            }
+        }
+
+        fn regenerate(&mut self, delta_time: Duration) {
+            // Only the time remaining after the delay has elapsed counts towards regeneration
+            let remaining_delay = self.regen_delay.saturating_sub(self.since_expended);
+            let regen_time = delta_time.saturating_sub(remaining_delay);
+            self.since_expended = (self.since_expended + delta_time).min(self.regen_delay);
+
+            self.set_current(self.current + self.regen_per_second * regen_time.as_secs_f32());
+        }
+    }
+
+    float_pool_arithmetic!(StaminaPool, Stamina);
+}
+
+/// A premade resource pool for rage, which is built up by gameplay events and decays over time, as used by warriors.
+pub mod rage {
+    use bevy::ecs::prelude::{Query, Res, Without};
+    use bevy::reflect::Reflect;
+    use bevy::time::Time;
+
+    use crate::pool::RegeneratingPool;
+    use crate::AbilityTimeDilation;
+
+    use super::*;
+
+    /// The amount of rage available to a unit.
+    /// Units must spend rage to use abilities according to their [`AbilityCosts<A, RagePool>`](crate::pool::AbilityCosts) component.
+    ///
+    /// Unlike life or mana, rage usually starts empty, and is gained by dealing or taking damage (see [`RagePool::gain`]).
+    /// It decays towards zero over time, as its `regen_per_second` is negative:
+    /// the [`Default`] pool starts empty, with a max of 100 and 5 rage lost per second.
+    ///
+    /// To stop rage decaying while in combat, add the [`InCombat`] marker component to the entity,
+    /// and use the [`decay_rage_out_of_combat`] system in place of [`regenerate_resource_pool`](crate::systems::regenerate_resource_pool).
+    ///
+    /// This is intended to be stored as a component on each entity.
+    #[derive(Debug, Clone, PartialEq, Component, Resource, Reflect)]
+    pub struct RagePool {
+        /// The current rage.
+        current: Rage,
+        /// The maximum rage that can be stored.
+        max: Rage,
+        /// The amount of rage regenerated per second.
+        pub regen_per_second: Rage,
+    }
+
+    impl Default for RagePool {
+        /// An empty pool with a max of 100 rage, losing 5 rage per second.
+        fn default() -> Self {
+            RagePool::new(Rage(0.), Rage(100.), Rage(-5.))
+        }
+    }
+
+    impl RagePool {
+        /// Gains `amount` rage, up to the max.
+        ///
+        /// Call this when the unit deals or takes damage.
+        /// This is equivalent to [`Pool::replenish`].
+        #[inline]
+        pub fn gain(&mut self, amount: Rage) {
+            self.replenish(amount);
+        }
+
+        /// Creates a new [`RagePool`] with the supplied settings.
+        ///
+        /// `regen_per_second` should usually be negative, so that rage decays over time.
+        ///
+        /// # Panics
+        /// Panics if `current` is greater than `max`.
+        /// Panics if `current` or `max` is negative.
+        pub fn new(current: Rage, max: Rage, regen_per_second: Rage) -> Self {
+            assert!(current <= max);
+            assert!(current >= RagePool::MIN);
+            assert!(max >= RagePool::MIN);
+            Self {
+                current,
+                max,
+                regen_per_second,
+            }
+        }
+    }
+
+    /// A quantity of rage, used to modify a [`RagePool`].
+    ///
+    /// This can be used for ability costs, rage gained from damage, rage decay and so on.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        PartialOrd,
+        Default,
+        Add,
+        Sub,
+        AddAssign,
+        SubAssign,
+        Reflect,
+        Serialize,
+        Deserialize,
+    )]
+    pub struct Rage(pub f32);
+
+    float_quantity!(Rage);
+
+    impl Pool for RagePool {
+        type Quantity = Rage;
+        const MIN: Rage = Rage(0.);
+        const ZERO: Rage = Rage(0.);
+
+        float_quantity_checks!();
+
+        fn current(&self) -> Self::Quantity {
+            self.current
+        }
+
+        fn set_current(&mut self, new_quantity: Self::Quantity) -> Self::Quantity {
+            if !check_quantity::<Self>(new_quantity) {
+                return self.current;
+            }
+
+            let actual_value = Rage(new_quantity.0.clamp(0., self.max.0));
+            self.current = actual_value;
+            self.current
+        }
+
+        fn max(&self) -> Self::Quantity {
+            self.max
+        }
+
+        fn set_max(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
+            if !check_quantity::<Self>(new_max) || new_max < Self::MIN {
+                Err(MaxPoolLessThanMin)
+            } else {
+                self.max = new_max;
+                self.set_current(self.current);
+                Ok(())
+            }
+        }
+    }
+
+    impl RegeneratingPool for RagePool {
+        fn regen_per_second(&self) -> Self::Quantity {
+            self.regen_per_second
+        }
+
+        fn set_regen_per_second(&mut self, new_regen_per_second: Self::Quantity) {
+            if check_quantity::<Self>(new_regen_per_second) {
+                self.regen_per_second = new_regen_per_second;
+            }
+        }
+
+        fn regenerate(&mut self, delta_time: std::time::Duration) {
+            self.set_current(self.current + self.regen_per_second * delta_time.as_secs_f32());
+        }
+    }
+
+    float_pool_arithmetic!(RagePool, Rage);
+
+    /// A marker component for units that are in combat, whose [`RagePool`] should not decay.
+    ///
+    /// Only respected by [`decay_rage_out_of_combat`].
+    #[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+    pub struct InCombat;
+
+    /// Decays (or regenerates) the [`RagePool`] of every entity that is not [`InCombat`], based on the elapsed [`Time`].
+    ///
+    /// Use this in place of [`regenerate_resource_pool::<RagePool>`](crate::systems::regenerate_resource_pool).
+    /// Entities with an [`AbilityTimeDilation`] component have their elapsed time scaled accordingly.
+    pub fn decay_rage_out_of_combat(
+        mut query: Query<(&mut RagePool, Option<&AbilityTimeDilation>), Without<InCombat>>,
+        time: Res<Time>,
+    ) {
+        let delta_time = time.delta();
+
+        for (mut pool, dilation) in query.iter_mut() {
+            pool.regenerate(dilation.map_or(delta_time, |dilation| dilation.dilate(delta_time)));
+        }
+    }
+}
+
+/// A premade resource pool for absorb shields, which soak up damage before it reaches a [`LifePool`](life::LifePool).
+pub mod shield {
+    use bevy::reflect::Reflect;
+
+    use crate::pool::RegeneratingPool;
+
+    use super::life::{Life, LifePool};
+    use super::*;
+
+    /// An absorb shield, which takes damage in place of a unit's [`LifePool`].
+    ///
+    /// Shields are measured in [`Life`], so that damage can be applied to them directly:
+    /// use [`apply_damage`] to deplete the shield first, and let any overflow hit life.
+    ///
+    /// Temporary shields usually decay over time, which can be done by setting a negative `regen_per_second`.
+    /// Temporary shield buffs can raise (and later lower) the cap with [`Pool::set_max`].
+    ///
+    /// This is intended to be stored as a component on each entity.
+    #[derive(Debug, Clone, PartialEq, Component, Resource, Reflect)]
+    pub struct ShieldPool {
+        /// The current shield strength.
+        current: Life,
+        /// The maximum shield strength that can be stored.
+        max: Life,
+        /// The amount of shield regenerated per second.
+        ///
+        /// Set this to a negative value for shields that decay over time.
+        pub regen_per_second: Life,
+    }
+
+    impl ShieldPool {
+        /// Creates a new [`ShieldPool`] with the supplied settings.
+        ///
+        /// # Panics
+        /// Panics if `current` is greater than `max`.
+        /// Panics if `current` or `max` is negative.
+        pub fn new(current: Life, max: Life, regen_per_second: Life) -> Self {
+            assert!(current <= max);
+            assert!(current >= ShieldPool::MIN);
+            assert!(max >= ShieldPool::MIN);
+            Self {
+                current,
+                max,
+                regen_per_second,
+            }
+        }
+    }
+
+    impl Pool for ShieldPool {
+        type Quantity = Life;
+        const MIN: Life = Life(0.);
+        const ZERO: Life = Life(0.);
+
+        float_quantity_checks!();
+
+        fn current(&self) -> Self::Quantity {
+            self.current
+        }
+
+        fn set_current(&mut self, new_quantity: Self::Quantity) -> Self::Quantity {
+            if !check_quantity::<Self>(new_quantity) {
+                return self.current;
+            }
+
+            let actual_value = Life(new_quantity.0.clamp(0., self.max.0));
+            self.current = actual_value;
+            self.current
+        }
+
+        fn max(&self) -> Self::Quantity {
+            self.max
+        }
+
+        fn set_max(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
+            if !check_quantity::<Self>(new_max) || new_max < Self::MIN {
+                Err(MaxPoolLessThanMin)
+            } else {
+                self.max = new_max;
+                self.set_current(self.current);
+                Ok(())
+            }
+        }
+    }
+
+    impl RegeneratingPool for ShieldPool {
+        fn regen_per_second(&self) -> Self::Quantity {
+            self.regen_per_second
+        }
+
+        fn set_regen_per_second(&mut self, new_regen_per_second: Self::Quantity) {
+            if check_quantity::<Self>(new_regen_per_second) {
+                self.regen_per_second = new_regen_per_second;
+            }
+        }
+
+        fn regenerate(&mut self, delta_time: std::time::Duration) {
+            self.set_current(self.current + self.regen_per_second * delta_time.as_secs_f32());
+        }
+    }
+
+    float_pool_arithmetic!(ShieldPool, Life);
+
+    /// How a single instance of damage was split between a [`ShieldPool`] and a [`LifePool`].
+    ///
+    /// Returned by [`apply_damage`].
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+    pub struct DamageReport {
+        /// The damage soaked up by the shield.
+        pub absorbed: Life,
+        /// The damage dealt to the life pool.
+        pub taken: Life,
+        /// The damage that exceeded the remaining life, and was not dealt to either pool.
+        pub overkill: Life,
+    }
+
+    /// Deals `amount` damage, depleting the `shield` (if any) before the `life` pool.
+    ///
+    /// Negative amounts are treated as zero damage: use [`Pool::replenish`] to heal.
+    ///
+    /// ```rust
+    /// use leafwing_abilities::pool::Pool;
+    /// use leafwing_abilities::premade_pools::life::{Life, LifePool};
+    /// use leafwing_abilities::premade_pools::shield::{apply_damage, ShieldPool};
+    ///
+    /// let mut life = LifePool::new(Life(100.), Life(100.), Life(0.));
+    /// let mut shield = ShieldPool::new(Life(30.), Life(50.), Life(-5.));
+    ///
+    /// let report = apply_damage(Some(&mut shield), &mut life, Life(40.));
+    /// assert_eq!(report.absorbed, Life(30.));
+    /// assert_eq!(report.taken, Life(10.));
+    /// assert_eq!(life.current(), Life(90.));
+    /// ```
+    pub fn apply_damage(
+        shield: Option<&mut ShieldPool>,
+        life: &mut LifePool,
+        amount: Life,
+    ) -> DamageReport {
+        let amount = Life(amount.0.max(0.));
+
+        let absorbed = match shield {
+            Some(shield) => {
+                let absorbed = Life(amount.0.min(shield.current().0));
+                shield.set_current(shield.current() - absorbed);
+                absorbed
+            }
+            None => Life(0.),
+        };
+
+        let remaining = amount - absorbed;
+        let taken = Life(remaining.0.min(life.current().0));
+        life.set_current(life.current() - taken);
+
+        DamageReport {
+            absorbed,
+            taken,
+            overkill: remaining - taken,
+        }
+    }
+}
+
+/// A premade resource pool for combo points, which are built up one at a time and spent all at once by finishers.
+pub mod combo {
+    use bevy::reflect::Reflect;
+
+    use super::*;
+
+    /// The number of combo points available to a unit.
+    ///
+    /// Unlike most pools, this uses whole numbers, and does not regenerate over time.
+    /// Builders should add points with [`ComboPointPool::gain`],
+    /// while finishers should require at least one point in their [`AbilityCosts`](crate::pool::AbilityCosts),
+    /// and then consume every point with [`ComboPointPool::spend_all`].
+    ///
+    /// ```rust
+    /// use leafwing_abilities::pool::{AbilityCosts, Pool};
+    /// use leafwing_abilities::premade_pools::combo::{ComboPointPool, ComboPoints};
+    /// # use bevy::prelude::*;
+    /// # use leafwing_abilities::prelude::*;
+    /// # use leafwing_input_manager::prelude::*;
+    /// # #[derive(Actionlike, Abilitylike, Clone, Copy, Debug, Hash, PartialEq, Eq, Reflect)]
+    /// # enum RogueAbility {
+    /// #     SinisterStrike,
+    /// #     Eviscerate,
+    /// # }
+    ///
+    /// let costs = AbilityCosts::<RogueAbility, ComboPointPool>::new([(RogueAbility::Eviscerate, ComboPoints(1))]);
+    /// let mut combo_points = ComboPointPool::new(ComboPoints(0), ComboPoints(5));
+    /// assert!(!costs.available(&RogueAbility::Eviscerate, &combo_points));
+    ///
+    /// // Sinister Strike builds combo points
+    /// combo_points.gain(ComboPoints(1));
+    /// combo_points.gain(ComboPoints(1));
+    /// assert!(costs.available(&RogueAbility::Eviscerate, &combo_points));
+    ///
+    /// // Eviscerate consumes all of them, dealing more damage for each point spent
+    /// let spent = combo_points.spend_all();
+    /// assert_eq!(spent, ComboPoints(2));
+    /// assert!(combo_points.is_empty());
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq, Component, Resource, Reflect)]
+    pub struct ComboPointPool {
+        /// The current combo points.
+        current: ComboPoints,
+        /// The maximum combo points that can be stored.
+        max: ComboPoints,
+    }
+
+    impl ComboPointPool {
+        /// Creates a new [`ComboPointPool`] with the supplied settings.
+        ///
+        /// # Panics
+        /// Panics if `current` is greater than `max`.
+        pub fn new(current: ComboPoints, max: ComboPoints) -> Self {
+            assert!(current <= max);
+            Self { current, max }
+        }
+
+        /// Gains `amount` combo points, up to the max.
+        ///
+        /// This is equivalent to [`Pool::replenish`].
+        #[inline]
+        pub fn gain(&mut self, amount: ComboPoints) {
+            self.replenish(amount);
+        }
+
+        /// Spends every combo point, returning the number that were spent.
+        #[inline]
+        pub fn spend_all(&mut self) -> ComboPoints {
+            let spent = self.current;
+            self.current = Self::MIN;
+            spent
+        }
+    }
+
+    /// A whole number of combo points, used to modify a [`ComboPointPool`].
+    ///
+    /// Addition and subtraction saturate, rather than overflowing.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Default,
+        Reflect,
+        Serialize,
+        Deserialize,
+    )]
+    pub struct ComboPoints(pub u8);
+
+    impl From<u8> for ComboPoints {
+        fn from(value: u8) -> ComboPoints {
+            ComboPoints(value)
+        }
+    }
+
+    impl Add for ComboPoints {
+        type Output = ComboPoints;
+
+        fn add(self, rhs: ComboPoints) -> ComboPoints {
+            ComboPoints(self.0.saturating_add(rhs.0))
+        }
+    }
+
+    impl Sub for ComboPoints {
+        type Output = ComboPoints;
+
+        fn sub(self, rhs: ComboPoints) -> ComboPoints {
+            ComboPoints(self.0.saturating_sub(rhs.0))
+        }
+    }
+
+    impl AddAssign for ComboPoints {
+        fn add_assign(&mut self, rhs: ComboPoints) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl SubAssign for ComboPoints {
+        fn sub_assign(&mut self, rhs: ComboPoints) {
+            *self = *self - rhs;
+        }
+    }
+
+    impl Div<ComboPoints> for ComboPoints {
+        type Output = f32;
+
+        fn div(self, rhs: ComboPoints) -> f32 {
+            self.0 as f32 / rhs.0 as f32
+        }
+    }
+
+    impl Pool for ComboPointPool {
+        type Quantity = ComboPoints;
+        const MIN: ComboPoints = ComboPoints(0);
+        const ZERO: ComboPoints = ComboPoints(0);
+
+        fn current(&self) -> Self::Quantity {
+            self.current
+        }
+
+        fn set_current(&mut self, new_quantity: Self::Quantity) -> Self::Quantity {
+            self.current = new_quantity.min(self.max);
+            self.current
+        }
+
+        fn max(&self) -> Self::Quantity {
+            self.max
+        }
+
+        fn set_max(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
+            self.max = new_max;
+            self.set_current(self.current);
+            Ok(())
+        }
+    }
+
+    impl Display for ComboPoints {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl Display for ComboPointPool {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            write!(f, "{}/{}", self.current, self.max)
+        }
+    }
+}
+
+/// A premade resource pool for heat, which builds up as abilities are used and settles back towards a resting value over time.
+pub mod heat {
+    use bevy::reflect::Reflect;
+
+    use crate::pool::RegeneratingPool;
+
+    use super::*;
+
+    /// The amount of heat built up by a unit.
+    ///
+    /// Rather than regenerating towards the max (or decaying towards zero), heat moves towards its `equilibrium`
+    /// at `regen_per_second`, without ever overshooting it: heat above the equilibrium cools down,
+    /// while heat below it warms up.
+    /// Use [`HeatPool::gain`] to build up heat when abilities are used.
+    ///
+    /// This is intended to be stored as a component on each entity.
+    #[derive(Debug, Clone, PartialEq, Component, Resource, Reflect)]
+    pub struct HeatPool {
+        /// The current heat.
+        current: Heat,
+        /// The maximum heat that can be stored.
+        max: Heat,
+        /// The amount of heat that moves towards the equilibrium per second.
+        ///
+        /// The sign of this value is ignored.
+        pub regen_per_second: Heat,
+        /// The resting value that heat moves towards over time.
+        equilibrium: Heat,
+    }
+
+    impl HeatPool {
+        /// Creates a new [`HeatPool`] with the supplied settings.
+        ///
+        /// # Panics
+        /// Panics if `current` or `equilibrium` is greater than `max`.
+        /// Panics if `current`, `max` or `equilibrium` is negative.
+        pub fn new(current: Heat, max: Heat, regen_per_second: Heat, equilibrium: Heat) -> Self {
+            assert!(current <= max);
+            assert!(current >= HeatPool::MIN);
+            assert!(max >= HeatPool::MIN);
+            assert!(equilibrium <= max);
+            assert!(equilibrium >= HeatPool::MIN);
+            Self {
+                current,
+                max,
+                regen_per_second,
+                equilibrium,
+            }
+        }
+
+        /// Gains `amount` heat, up to the max.
+        ///
+        /// This is equivalent to [`Pool::replenish`].
+        #[inline]
+        pub fn gain(&mut self, amount: Heat) {
+            self.replenish(amount);
+        }
+
+        /// The resting value that heat moves towards over time.
+        #[inline]
+        #[must_use]
+        pub fn equilibrium(&self) -> Heat {
+            self.equilibrium
+        }
+
+        /// Sets the resting value that heat moves towards over time.
+        ///
+        /// This is clamped between [`HeatPool::MIN`] and the max.
+        pub fn set_equilibrium(&mut self, equilibrium: Heat) {
+            self.equilibrium = Heat(equilibrium.0.clamp(0., self.max.0));
+        }
+    }
+
+    /// A quantity of heat, used to modify a [`HeatPool`].
+    ///
+    /// This can be used for ability costs, heat regeneration and so on.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        PartialOrd,
+        Default,
+        Add,
+        Sub,
+        AddAssign,
+        SubAssign,
+        Reflect,
+        Serialize,
+        Deserialize,
+    )]
+    pub struct Heat(pub f32);
+
+    float_quantity!(Heat);
+
+    impl Pool for HeatPool {
+        type Quantity = Heat;
+        const MIN: Heat = Heat(0.);
+        const ZERO: Heat = Heat(0.);
+
+        float_quantity_checks!();
+
+        fn current(&self) -> Self::Quantity {
+            self.current
+        }
+
+        fn set_current(&mut self, new_quantity: Self::Quantity) -> Self::Quantity {
+            if !check_quantity::<Self>(new_quantity) {
+                return self.current;
+            }
+
+            let actual_value = Heat(new_quantity.0.clamp(0., self.max.0));
+            self.current = actual_value;
+            self.current
+        }
+
+        fn max(&self) -> Self::Quantity {
+            self.max
+        }
+
+        fn set_max(&mut self, new_max: Self::Quantity) -> Result<(), MaxPoolLessThanMin> {
+            if !check_quantity::<Self>(new_max) || new_max < Self::MIN {
+                Err(MaxPoolLessThanMin)
+            } else {
+                self.max = new_max;
+                self.set_current(self.current);
+                self.set_equilibrium(self.equilibrium);
+                Ok(())
+            }
+        }
+    }
+
+    impl RegeneratingPool for HeatPool {
+        fn regen_per_second(&self) -> Self::Quantity {
+            self.regen_per_second
+        }
+
+        fn set_regen_per_second(&mut self, new_regen_per_second: Self::Quantity) {
+            if check_quantity::<Self>(new_regen_per_second) {
+                self.regen_per_second = new_regen_per_second;
+            }
+        }
+
+        fn regenerate(&mut self, delta_time: std::time::Duration) {
+            let step = self.regen_per_second.0.abs() * delta_time.as_secs_f32();
+
+            // Move towards the equilibrium, without overshooting it
+            let new_current = if self.current > self.equilibrium {
+                (self.current.0 - step).max(self.equilibrium.0)
+            } else {
+                (self.current.0 + step).min(self.equilibrium.0)
+            };
+            self.set_current(Heat(new_current));
+        }
+    }
+
+    float_pool_arithmetic!(HeatPool, Heat);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pool::{Pool, RegenCurve, RegeneratingPool, TemporaryMaxPool};
+    use bevy::utils::Duration;
+
+    mod life {
+        use super::*;
+        use crate::premade_pools::life::{DamageOutcome, HealOutcome, Life, LifePool};
+
+        #[test]
+        fn damage_reports_overkill_and_death() {
+            let mut pool = LifePool::new(Life(30.), Life(100.), Life(0.));
+            assert_eq!(
+                pool.apply_damage(Life(20.)),
+                DamageOutcome {
+                    absorbed: Life(20.),
+                    overkill: Life(0.),
+                    died: false,
+                }
+            );
+            assert_eq!(
+                pool.apply_damage(Life(25.)),
+                DamageOutcome {
+                    absorbed: Life(10.),
+                    overkill: Life(15.),
+                    died: true,
+                }
+            );
+            assert_eq!(pool.current(), Life(0.));
+        }
+
+        #[test]
+        fn damaging_an_empty_pool_is_not_a_death() {
+            let mut pool = LifePool::new(Life(0.), Life(100.), Life(0.));
+            assert_eq!(
+                pool.apply_damage(Life(10.)),
+                DamageOutcome {
+                    absorbed: Life(0.),
+                    overkill: Life(10.),
+                    died: false,
+                }
+            );
+        }
+
+        #[test]
+        fn heal_reports_overheal() {
+            let mut pool = LifePool::new(Life(80.), Life(100.), Life(0.));
+            assert_eq!(
+                pool.heal(Life(15.)),
+                HealOutcome {
+                    healed: Life(15.),
+                    overheal: Life(0.),
+                }
+            );
+            assert_eq!(
+                pool.heal(Life(15.)),
+                HealOutcome {
+                    healed: Life(5.),
+                    overheal: Life(10.),
+                }
+            );
+            assert_eq!(pool.current(), Life(100.));
+        }
+
+        #[test]
+        fn life_fraction_and_percentage() {
+            let mut pool = LifePool::new(Life(100.), Life(200.), Life(0.));
+            assert_eq!(pool.fraction(), 0.5);
+            assert_eq!(pool.percentage(), 50.);
+
+            pool.set_current(Life(0.));
+            assert_eq!(pool.fraction(), 0.);
+            pool.set_current(Life(200.));
+            assert_eq!(pool.fraction(), 1.);
+        }
+
+        #[test]
+        fn damage_and_healing_report_the_applied_change() {
+            let mut pool = LifePool::new(Life(30.), Life(100.), Life(0.));
+            // Overkill
+            assert_eq!(pool.apply(Life(-50.)), Life(-30.));
+            assert!(pool.is_empty());
+
+            // Overheal
+            pool.set_current(Life(90.));
+            assert_eq!(pool.apply(Life(25.)), Life(10.));
+            assert!(pool.is_full());
+        }
+
+        #[test]
+        fn temporary_max_expires_and_clamps() {
+            let mut pool = LifePool::new(Life(100.), Life(100.), Life(0.));
+            pool.add_temporary_max(Life(20.), Duration::from_secs(60));
+            assert_eq!(pool.max(), Life(120.));
+            assert_eq!(pool.base_max(), Life(100.));
+            assert_eq!(pool.fraction(), 100. / 120.);
+
+            pool.replenish(Life(50.));
+            assert_eq!(pool.current(), Life(120.));
+            assert_eq!(pool.fraction(), 1.);
+
+            pool.regenerate(Duration::from_secs(59));
+            assert_eq!(pool.current(), Life(120.));
+
+            pool.regenerate(Duration::from_secs(1));
+            assert_eq!(pool.max(), Life(100.));
+            assert_eq!(pool.current(), Life(100.));
+        }
+
+        #[test]
+        fn temporary_max_stacks() {
+            let mut pool = LifePool::new(Life(50.), Life(100.), Life(0.));
+            pool.add_temporary_max(Life(20.), Duration::from_secs(10));
+            pool.add_temporary_max(Life(30.), Duration::from_secs(20));
+            assert_eq!(pool.max(), Life(150.));
+
+            pool.regenerate(Duration::from_secs(10));
+            assert_eq!(pool.max(), Life(130.));
+
+            pool.clear_temporary_max();
+            assert_eq!(pool.max(), Life(100.));
+        }
+
+        #[test]
+        fn negative_temporary_max_is_rejected() {
+            let mut pool = LifePool::new(Life(50.), Life(100.), Life(1.));
+            pool.add_temporary_max(Life(-200.), Duration::from_secs(10));
+            assert_eq!(pool.max(), Life(100.));
+
+            pool.set_current(Life(80.));
+            pool.regenerate(Duration::from_secs(1));
+            assert_eq!(pool.current(), Life(81.));
+        }
+
+        #[test]
+        fn overheal_is_capped_by_overfill() {
+            let mut pool = LifePool::new(Life(90.), Life(100.), Life(10.));
+            pool.set_overfill(Life(25.));
+
+            pool.replenish(Life(50.));
+            assert_eq!(pool.current(), Life(125.));
+            assert_eq!(pool.fraction(), 1.);
+
+            // Regeneration neither overfills the pool nor removes overheal
+            pool.regenerate(Duration::from_secs(1));
+            assert_eq!(pool.current(), Life(125.));
+
+            pool.set_current(Life(95.));
+            pool.regenerate(Duration::from_secs(1));
+            assert_eq!(pool.current(), Life(100.));
+
+            pool.replenish(Life(10.));
+            pool.set_overfill(Life(0.));
+            assert_eq!(pool.current(), Life(100.));
+        }
+    }
+
+    mod mana {
+        use super::*;
+        use crate::premade_pools::mana::{Mana, ManaPool};
+
+        #[test]
+        fn mana_fraction_and_percentage() {
+            let pool = ManaPool::new(Mana(10.), Mana(40.), Mana(0.));
+            assert_eq!(pool.fraction(), 0.25);
+            assert_eq!(pool.percentage(), 25.);
+        }
+
+        #[test]
+        fn zero_max_fraction_is_zero() {
+            let pool = ManaPool::new(Mana(0.), Mana(0.), Mana(0.));
+            assert_eq!(pool.fraction(), 0.);
+            assert_eq!(pool.percentage(), 0.);
+        }
+
+        #[test]
+        fn mana_can_go_into_debt_and_regenerate_out() {
+            let mut pool =
+                ManaPool::new(Mana(10.), Mana(100.), Mana(10.)).with_debt_floor(Mana(-50.));
+
+            pool.expend(Mana(40.)).unwrap();
+            assert_eq!(pool.current(), Mana(-30.));
+            assert!(pool.is_in_debt());
+            assert!(pool.is_empty());
+            assert_eq!(pool.fraction(), 0.);
+
+            // Spending beyond the floor is still refused
+            assert!(pool.expend(Mana(30.)).is_err());
+            assert_eq!(pool.current(), Mana(-30.));
+
+            pool.regenerate(Duration::from_secs(2));
+            assert_eq!(pool.current(), Mana(-10.));
+            assert!(pool.is_in_debt());
+
+            pool.regenerate(Duration::from_secs(2));
+            assert_eq!(pool.current(), Mana(10.));
+            assert!(!pool.is_in_debt());
+            assert!(!pool.is_empty());
+        }
+
+        #[test]
+        fn mana_without_debt_floor_cannot_go_negative() {
+            let mut pool = ManaPool::new(Mana(10.), Mana(100.), Mana(10.));
+            assert!(pool.expend(Mana(40.)).is_err());
+            pool.set_current(Mana(-5.));
+            assert_eq!(pool.current(), Mana(0.));
+
+            let mut pool = pool.with_debt_floor(Mana(-20.));
+            pool.set_current(Mana(-15.));
+            pool.set_debt_floor(Some(Mana(-5.)));
+            assert_eq!(pool.current(), Mana(-5.));
+            pool.set_debt_floor(None);
+            assert_eq!(pool.current(), Mana(0.));
+        }
+
+        #[test]
+        fn drain_and_restore_report_excess() {
+            let mut pool = ManaPool::new(Mana(30.), Mana(50.), Mana(0.));
+
+            let drained = pool.drain(Mana(45.));
+            assert_eq!(drained.drained, Mana(30.));
+            assert_eq!(drained.excess, Mana(15.));
+            assert!(drained.emptied);
+            assert!(!pool.drain(Mana(5.)).emptied);
+
+            let restored = pool.restore(Mana(60.));
+            assert_eq!(restored.restored, Mana(50.));
+            assert_eq!(restored.excess, Mana(10.));
+        }
+    }
+
+    mod energy {
+        use super::*;
+        use crate::premade_pools::energy::{Energy, EnergyPool};
+
+        #[test]
+        fn energy_regenerates_linearly() {
+            let mut pool = EnergyPool::new(Energy(0.), Energy(100.), Energy(10.));
+            pool.regenerate(Duration::from_millis(2500));
+            assert_eq!(pool.current(), Energy(25.));
+        }
+
+        #[test]
+        fn energy_is_clamped_to_max() {
+            let mut pool = EnergyPool::default();
+            assert_eq!(pool.current(), Energy(100.));
+            pool.regenerate(Duration::from_secs(5));
+            assert_eq!(pool.current(), Energy(100.));
+
+            pool -= Energy(150.);
+            assert_eq!(pool.current(), Energy(0.));
+        }
+
+        #[test]
+        fn energy_pays_costs() {
+            let mut pool = EnergyPool::default();
+            pool.expend(Energy(60.)).unwrap();
+            assert!(pool.expend(Energy(60.)).is_err());
+            assert_eq!(pool.current(), Energy(40.));
+        }
+
+        #[test]
+        fn linear_regen_curve_is_faster_when_nearly_empty() {
+            let curve = RegenCurve::Linear {
+                at_empty: 2.,
+                at_full: 0.5,
+            };
+            let mut low =
+                EnergyPool::new(Energy(10.), Energy(100.), Energy(10.)).with_regen_curve(curve);
+            let mut high =
+                EnergyPool::new(Energy(90.), Energy(100.), Energy(10.)).with_regen_curve(curve);
+
+            low.regenerate(Duration::from_millis(500));
+            high.regenerate(Duration::from_millis(500));
+
+            let low_gain = low.current() - Energy(10.);
+            let high_gain = high.current() - Energy(90.);
+            assert!(low_gain > high_gain * 2.);
+        }
+
+        #[test]
+        fn custom_regen_curve_is_applied() {
+            let mut pool = EnergyPool::new(Energy(0.), Energy(100.), Energy(10.))
+                .with_regen_curve(RegenCurve::Custom(|fraction| 1. - fraction));
+            assert_eq!(pool.regen_curve().multiplier(0.25), 0.75);
+
+            pool.regenerate(Duration::from_secs(1));
+            assert_eq!(pool.current(), Energy(10.));
+
+            let error = serde_json::to_string(&pool.regen_curve()).unwrap_err();
+            assert!(error.to_string().contains("custom regen curves"));
+        }
+    }
+
+    mod rage {
+        use super::*;
+        use crate::premade_pools::rage::{decay_rage_out_of_combat, InCombat, Rage, RagePool};
+        use crate::systems::regenerate_resource_pool;
+        use bevy::ecs::{system::RunSystemOnce, world::World};
+        use bevy::time::Time;
+
+        fn world_after(delta: Duration) -> World {
+            let mut world = World::new();
+            let mut time = Time::<()>::default();
+            time.advance_by(delta);
+            world.insert_resource(time);
+            world
+        }
+
+        #[test]
+        fn rage_starts_empty_and_can_be_gained() {
+            let mut pool = RagePool::default();
+            assert_eq!(pool.current(), Rage(0.));
+
+            pool.gain(Rage(30.));
+            pool.gain(Rage(90.));
+            assert_eq!(pool.current(), Rage(100.));
+        }
+
+        #[test]
+        fn rage_decays_to_zero() {
+            let mut world = world_after(Duration::from_secs(2));
+            let mut pool = RagePool::default();
+            pool.gain(Rage(15.));
+            let entity = world.spawn(pool).id();
+
+            world
+                .run_system_once(regenerate_resource_pool::<RagePool>)
+                .unwrap();
+            assert_eq!(world.get::<RagePool>(entity).unwrap().current(), Rage(5.));
+
+            world
+                .run_system_once(regenerate_resource_pool::<RagePool>)
+                .unwrap();
+            assert_eq!(world.get::<RagePool>(entity).unwrap().current(), Rage(0.));
+        }
+
+        #[test]
+        fn rage_does_not_decay_in_combat() {
+            let mut world = world_after(Duration::from_secs(1));
+            let mut pool = RagePool::default();
+            pool.gain(Rage(50.));
+            let fighting = world.spawn((pool.clone(), InCombat)).id();
+            let resting = world.spawn(pool).id();
+
+            world.run_system_once(decay_rage_out_of_combat).unwrap();
+            assert_eq!(
+                world.get::<RagePool>(fighting).unwrap().current(),
+                Rage(50.)
+            );
+            assert_eq!(world.get::<RagePool>(resting).unwrap().current(), Rage(45.));
+        }
+
+        #[test]
+        fn rage_pays_costs() {
+            let mut pool = RagePool::default();
+            assert!(pool.expend(Rage(20.)).is_err());
+
+            pool.gain(Rage(25.));
+            pool.expend(Rage(20.)).unwrap();
+            assert_eq!(pool.current(), Rage(5.));
+        }
+    }
+
+    mod stamina {
+        use super::*;
+        use crate::premade_pools::stamina::{Stamina, StaminaPool};
+
+        fn pool() -> StaminaPool {
+            StaminaPool::new(
+                Stamina(100.),
+                Stamina(100.),
+                Stamina(50.),
+                Duration::from_secs(1),
+            )
+        }
+
+        #[test]
+        fn stamina_regenerates_without_delay_initially() {
+            let mut pool = pool();
+            pool.set_current(Stamina(0.));
+            pool.regenerate(Duration::from_millis(500));
+            assert_eq!(pool.current(), Stamina(25.));
+        }
+
+        #[test]
+        fn stamina_regen_is_delayed_after_spending() {
+            let mut pool = pool();
+            pool.expend(Stamina(60.)).unwrap();
+            assert!(pool.is_regen_delayed());
+
+            pool.regenerate(Duration::from_millis(500));
+            assert_eq!(pool.current(), Stamina(40.));
+            assert!(pool.is_regen_delayed());
+
+            // Half a second to finish the delay, and half a second of regeneration
+            pool.regenerate(Duration::from_secs(1));
+            assert_eq!(pool.current(), Stamina(65.));
+            assert!(!pool.is_regen_delayed());
+
+            pool.regenerate(Duration::from_millis(200));
+            assert_eq!(pool.current(), Stamina(75.));
+        }
+
+        #[test]
+        fn spending_restarts_the_delay() {
+            let mut pool = pool();
+            pool.expend(Stamina(50.)).unwrap();
+            pool.regenerate(Duration::from_millis(900));
+            pool.expend(Stamina(10.)).unwrap();
+            assert_eq!(pool.time_since_expended(), Duration::ZERO);
+
+            pool.regenerate(Duration::from_millis(900));
+            assert_eq!(pool.current(), Stamina(40.));
+        }
+
+        #[test]
+        fn failed_expenditure_does_not_restart_the_delay() {
+            let mut pool = pool();
+            pool.set_current(Stamina(5.));
+            assert!(pool.expend(Stamina(10.)).is_err());
+            assert!(!pool.is_regen_delayed());
+        }
+    }
+
+    mod shield {
+        use super::*;
+        use crate::premade_pools::life::{Life, LifePool};
+        use crate::premade_pools::shield::{apply_damage, DamageReport, ShieldPool};
+
+        fn life() -> LifePool {
+            LifePool::new(Life(100.), Life(100.), Life(0.))
+        }
+
+        #[test]
+        fn shield_absorbs_all_damage() {
+            let mut life = life();
+            let mut shield = ShieldPool::new(Life(50.), Life(50.), Life(0.));
+
+            let report = apply_damage(Some(&mut shield), &mut life, Life(20.));
+            assert_eq!(
+                report,
+                DamageReport {
+                    absorbed: Life(20.),
+                    taken: Life(0.),
+                    overkill: Life(0.),
+                }
+            );
+            assert_eq!(shield.current(), Life(30.));
+            assert_eq!(life.current(), Life(100.));
+        }
+
+        #[test]
+        fn damage_spills_over_from_shield_to_life() {
+            let mut life = life();
+            let mut shield = ShieldPool::new(Life(15.), Life(50.), Life(0.));
+
+            let report = apply_damage(Some(&mut shield), &mut life, Life(40.));
+            assert_eq!(report.absorbed, Life(15.));
+            assert_eq!(report.taken, Life(25.));
+            assert_eq!(report.overkill, Life(0.));
+            assert!(shield.is_empty());
+            assert_eq!(life.current(), Life(75.));
+        }
+
+        #[test]
+        fn damage_without_shield_hits_life() {
+            let mut life = life();
+
+            let report = apply_damage(None, &mut life, Life(40.));
+            assert_eq!(report.absorbed, Life(0.));
+            assert_eq!(report.taken, Life(40.));
+            assert_eq!(life.current(), Life(60.));
+        }
+
+        #[test]
+        fn damage_beyond_life_is_overkill() {
+            let mut life = life();
+            let mut shield = ShieldPool::new(Life(10.), Life(10.), Life(0.));
+
+            let report = apply_damage(Some(&mut shield), &mut life, Life(150.));
+            assert_eq!(
+                report,
+                DamageReport {
+                    absorbed: Life(10.),
+                    taken: Life(100.),
+                    overkill: Life(40.),
+                }
+            );
+            assert!(life.is_empty());
+        }
+
+        #[test]
+        fn shields_decay_and_can_be_buffed() {
+            let mut shield = ShieldPool::new(Life(20.), Life(20.), Life(-5.));
+            shield.regenerate(Duration::from_secs(2));
+            assert_eq!(shield.current(), Life(10.));
+
+            shield.set_max(Life(60.)).unwrap();
+            shield.replenish(Life(60.));
+            assert_eq!(shield.current(), Life(60.));
+
+            shield.set_max(Life(20.)).unwrap();
+            assert_eq!(shield.current(), Life(20.));
+            shield.regenerate(Duration::from_secs(10));
+            assert!(shield.is_empty());
+        }
+    }
+
+    mod combo {
+        use crate::pool::{AbilityCosts, Pool};
+        use crate::premade_pools::combo::{ComboPointPool, ComboPoints};
+        use crate::{self as leafwing_abilities, Abilitylike};
+        use bevy::reflect::Reflect;
+        use leafwing_input_manager::Actionlike;
+
+        #[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+        enum RogueAbility {
+            Eviscerate,
+            KidneyShot,
+        }
+
+        #[test]
+        fn combo_points_are_exact_integers() {
+            let mut pool = ComboPointPool::new(ComboPoints(0), ComboPoints(5));
+            for _ in 0..7 {
+                pool.gain(ComboPoints(1));
+            }
+            assert_eq!(pool.current(), ComboPoints(5));
+
+            assert!(pool.available(ComboPoints(5)).is_ok());
+            pool.expend(ComboPoints(2)).unwrap();
+            assert_eq!(pool.current(), ComboPoints(3));
+            assert!(pool.available(ComboPoints(4)).is_err());
+            assert!(pool.expend(ComboPoints(4)).is_err());
+
+            // Subtraction saturates, rather than underflowing
+            assert_eq!(pool.expend_up_to(ComboPoints(200)), ComboPoints(3));
+            assert!(pool.is_empty());
+            assert_eq!(pool.replenish(ComboPoints(255)), ComboPoints(250));
+        }
+
+        #[test]
+        fn finishers_spend_all_points() {
+            let costs = AbilityCosts::<RogueAbility, ComboPointPool>::new([
+                (RogueAbility::Eviscerate, ComboPoints(1)),
+                (RogueAbility::KidneyShot, ComboPoints(3)),
+            ]);
+            let mut pool = ComboPointPool::new(ComboPoints(2), ComboPoints(5));
+
+            assert!(costs.available(&RogueAbility::Eviscerate, &pool));
+            assert!(!costs.available(&RogueAbility::KidneyShot, &pool));
+
+            // The cost is only the minimum: finishers consume every point
+            assert_eq!(pool.spend_all(), ComboPoints(2));
+            assert!(!costs.available(&RogueAbility::Eviscerate, &pool));
+            assert_eq!(pool.fraction(), 0.);
+        }
+    }
+
+    mod heat {
+        use super::*;
+        use crate::premade_pools::heat::{Heat, HeatPool};
+
+        #[test]
+        fn heat_cools_down_to_equilibrium() {
+            let mut pool = HeatPool::new(Heat(80.), Heat(100.), Heat(10.), Heat(30.));
+            pool.regenerate(Duration::from_secs(2));
+            assert_eq!(pool.current(), Heat(60.));
+
+            // No overshoot
+            pool.regenerate(Duration::from_secs(4));
+            assert_eq!(pool.current(), Heat(30.));
+            pool.regenerate(Duration::from_secs(1));
+            assert_eq!(pool.current(), Heat(30.));
+        }
+
+        #[test]
+        fn heat_warms_up_to_equilibrium() {
+            let mut pool = HeatPool::new(Heat(0.), Heat(100.), Heat(10.), Heat(30.));
+            pool.regenerate(Duration::from_secs(2));
+            assert_eq!(pool.current(), Heat(20.));
+
+            pool.regenerate(Duration::from_secs(2));
+            assert_eq!(pool.current(), Heat(30.));
+        }
+
+        #[test]
+        fn huge_ticks_land_on_equilibrium() {
+            let mut pool = HeatPool::new(Heat(100.), Heat(100.), Heat(10.), Heat(30.));
+            pool.regenerate(Duration::from_secs(3600));
+            assert_eq!(pool.current(), Heat(30.));
+
+            pool.gain(Heat(50.));
+            assert_eq!(pool.current(), Heat(80.));
+            pool.regenerate(Duration::from_secs(3600));
+            assert_eq!(pool.current(), pool.equilibrium());
         }
     }
 }
@@ -0,0 +1,234 @@
+//! Ability ranks, which change the cooldown, cost and charges of an ability as it levels up.
+//!
+//! Store the rank of each ability in an [`AbilityRanks`] component,
+//! and describe what each rank does in a [`RankTable`].
+//! Calling [`apply_rank_table`] then patches the [`CooldownState`], [`ChargeState`] and [`AbilityCosts`] in place,
+//! so that cooldowns that are already ticking keep their progress.
+
+use crate::{
+    charges::{ChargeState, Charges},
+    cooldown::CooldownState,
+    pool::{AbilityCost, AbilityCosts, Pool},
+    Abilitylike,
+};
+use bevy::{ecs::prelude::*, reflect::Reflect, utils::Duration};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The rank of each ability of type `A`.
+///
+/// Abilities without a rank are treated as rank `1`.
+#[derive(Component, Resource, Debug, Clone, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Resource)]
+pub struct AbilityRanks<A: Abilitylike> {
+    /// The rank of each ability.
+    rank_map: HashMap<A, u8>,
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Abilitylike> Default for AbilityRanks<A> {
+    fn default() -> Self {
+        AbilityRanks {
+            rank_map: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Abilitylike> FromIterator<(A, u8)> for AbilityRanks<A> {
+    fn from_iter<I: IntoIterator<Item = (A, u8)>>(iter: I) -> Self {
+        AbilityRanks {
+            rank_map: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<A: Abilitylike> AbilityRanks<A> {
+    /// Creates a new [`AbilityRanks`] from an iterator of `(action, rank)` pairs.
+    #[must_use]
+    pub fn new(action_rank_pairs: impl IntoIterator<Item = (A, u8)>) -> Self {
+        action_rank_pairs.into_iter().collect()
+    }
+
+    /// The rank of `action`, which is `1` if it has not been set.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, action: &A) -> u8 {
+        self.rank_map.get(action).copied().unwrap_or(1)
+    }
+
+    /// Sets the rank of `action`.
+    #[inline]
+    pub fn set(&mut self, action: A, rank: u8) -> &mut Self {
+        self.rank_map.insert(action, rank);
+        self
+    }
+
+    /// Increases the rank of `action` by one, returning the new rank.
+    ///
+    /// Ranks saturate at [`u8::MAX`].
+    #[inline]
+    pub fn rank_up(&mut self, action: A) -> u8 {
+        let rank = self.get(&action).saturating_add(1);
+        self.rank_map.insert(action, rank);
+        rank
+    }
+
+    /// Returns an iterator over each action that has a rank set, and its rank.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&A, u8)> {
+        self.rank_map.iter().map(|(action, rank)| (action, *rank))
+    }
+}
+
+/// The values of an ability at a single rank, in terms of the [`Pool::Quantity`] `Q` of its costs.
+///
+/// Fields that are [`None`] are left untouched by [`apply_rank_table`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RankValues<Q> {
+    /// The max time of the [`Cooldown`](crate::cooldown::Cooldown) of the ability.
+    pub cooldown: Option<Duration>,
+    /// The [`AbilityCost`] of the ability.
+    pub cost: Option<AbilityCost<Q>>,
+    /// The max number of [`Charges`] of the ability.
+    pub charges: Option<u8>,
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<Q> Default for RankValues<Q> {
+    fn default() -> Self {
+        RankValues {
+            cooldown: None,
+            cost: None,
+            charges: None,
+        }
+    }
+}
+
+/// The [`RankValues`] of each `(action, rank)` pair.
+pub type RankTable<A, Q> = HashMap<(A, u8), RankValues<Q>>;
+
+/// Patches the `cooldowns`, `charges` and `costs` of each ability to match its rank in `ranks`, as described by the `table`.
+///
+/// This is non-destructive:
+/// - cooldowns keep the fraction of their time that has elapsed, using [`CooldownState::set_max_time_preserving_fraction`]
+/// - charges keep their current count, clamped to the new max, using [`Charges::set_max_charges`]
+///
+/// Abilities that gain a cooldown or charges for the first time get a ready [`Cooldown`](crate::cooldown::Cooldown)
+/// or full [`Charges::replenish_one`] respectively.
+/// Abilities whose rank has no entry in the `table` are left untouched.
+/// Pass [`None`] for `costs` to leave costs alone, such as when using [`NullPool`](crate::NullPool).
+pub fn apply_rank_table<A: Abilitylike, P: Pool>(
+    table: &RankTable<A, P::Quantity>,
+    ranks: &AbilityRanks<A>,
+    cooldowns: &mut CooldownState<A>,
+    charges: &mut ChargeState<A>,
+    mut costs: Option<&mut AbilityCosts<A, P>>,
+) {
+    for ((action, rank), values) in table {
+        if ranks.get(action) != *rank {
+            continue;
+        }
+
+        if let Some(max_time) = values.cooldown {
+            cooldowns.set_max_time_preserving_fraction(action.clone(), max_time);
+        }
+
+        if let Some(max_charges) = values.charges {
+            match charges.get_mut(action) {
+                Some(charges) => charges.set_max_charges(max_charges),
+                None => {
+                    charges.set(action.clone(), Charges::replenish_one(max_charges));
+                }
+            }
+        }
+
+        if let (Some(cost), Some(costs)) = (values.cost, costs.as_deref_mut()) {
+            costs.set(action.clone(), cost);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_abilities;
+    use crate::cooldown::Cooldown;
+    use crate::premade_pools::mana::{Mana, ManaPool};
+    use leafwing_input_manager::Actionlike;
+
+    #[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    enum Action {
+        Fireball,
+        Blink,
+    }
+
+    fn rank_table() -> RankTable<Action, Mana> {
+        RankTable::from([
+            (
+                (Action::Fireball, 1),
+                RankValues {
+                    cooldown: Some(Duration::from_secs(4)),
+                    cost: Some(AbilityCost::Flat(Mana(10.))),
+                    charges: None,
+                },
+            ),
+            (
+                (Action::Fireball, 2),
+                RankValues {
+                    cooldown: Some(Duration::from_secs(2)),
+                    cost: Some(AbilityCost::Flat(Mana(15.))),
+                    charges: Some(2),
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn ranking_up_preserves_cooldown_progress() {
+        let mut ranks = AbilityRanks::default();
+        let mut cooldowns = CooldownState::new([(Action::Fireball, Cooldown::from_secs(4.))]);
+        let mut charges = ChargeState::default();
+        let mut costs = AbilityCosts::<Action, ManaPool>::default();
+
+        cooldowns.trigger(&Action::Fireball).unwrap();
+        cooldowns.tick(Duration::from_secs(1), None);
+        assert_eq!(ranks.rank_up(Action::Fireball), 2);
+        apply_rank_table(
+            &rank_table(),
+            &ranks,
+            &mut cooldowns,
+            &mut charges,
+            Some(&mut costs),
+        );
+
+        let fireball = cooldowns.get(&Action::Fireball).unwrap();
+        assert_eq!(fireball.max_time(), Duration::from_secs(2));
+        assert_eq!(fireball.remaining(), Duration::from_millis(1500));
+        assert_eq!(
+            costs.get(&Action::Fireball),
+            Some(&AbilityCost::Flat(Mana(15.)))
+        );
+        assert_eq!(charges.get(&Action::Fireball).unwrap().max_charges(), 2);
+        assert!(charges.get(&Action::Blink).is_none());
+    }
+
+    #[test]
+    fn unranked_abilities_use_rank_one() {
+        let ranks = AbilityRanks::new([(Action::Blink, 3)]);
+        let mut cooldowns = CooldownState::default();
+        let mut charges = ChargeState::default();
+
+        apply_rank_table::<Action, ManaPool>(
+            &rank_table(),
+            &ranks,
+            &mut cooldowns,
+            &mut charges,
+            None,
+        );
+
+        assert_eq!(ranks.get(&Action::Fireball), 1);
+        let fireball = cooldowns.get(&Action::Fireball).unwrap();
+        assert_eq!(fireball.max_time(), Duration::from_secs(4));
+        assert!(fireball.ready().is_ok());
+    }
+}
@@ -0,0 +1,116 @@
+//! Snapshots of ability state, for rollback netcode.
+//!
+//! Rollback libraries like GGRS need to save the state of each entity every frame,
+//! and restore it when a misprediction is detected.
+//! An [`AbilityStateSnapshot`] captures the [`CooldownState`] and [`ChargeState`] of an entity as plain data,
+//! which can be cloned cheaply, compared, and serialized to detect desyncs.
+
+use crate::{
+    charges::{ChargeSnapshot, ChargeState},
+    cooldown::{CooldownSnapshot, CooldownState},
+    Abilitylike,
+};
+use serde::{Deserialize, Serialize};
+
+/// The combined state of the [`CooldownState`] and [`ChargeState`] of an entity at a single point in time.
+///
+/// Resource pools are not included: as they are ordinary components, they can be saved directly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AbilityStateSnapshot<A: Abilitylike> {
+    /// The snapshot of the [`CooldownState`].
+    pub cooldowns: CooldownSnapshot<A>,
+    /// The snapshot of the [`ChargeState`].
+    pub charges: ChargeSnapshot<A>,
+}
+
+impl<A: Abilitylike> AbilityStateSnapshot<A> {
+    /// Captures the current state of `cooldowns` and `charges`.
+    ///
+    /// See [`CooldownState::snapshot`] and [`ChargeState::snapshot`] for more details.
+    #[must_use]
+    pub fn capture(cooldowns: &CooldownState<A>, charges: &ChargeState<A>) -> Self {
+        AbilityStateSnapshot {
+            cooldowns: cooldowns.snapshot(),
+            charges: charges.snapshot(),
+        }
+    }
+
+    /// Restores `cooldowns` and `charges` to the state captured in this snapshot.
+    ///
+    /// See [`CooldownState::restore`] and [`ChargeState::restore`] for more details.
+    pub fn restore(&self, cooldowns: &mut CooldownState<A>, charges: &mut ChargeState<A>) {
+        cooldowns.restore(&self.cooldowns);
+        charges.restore(&self.charges);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_abilities;
+    use crate::{charges::Charges, cooldown::Cooldown};
+    use bevy::{reflect::Reflect, utils::Duration};
+    use leafwing_input_manager::Actionlike;
+
+    #[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    enum Action {
+        Slash,
+        Parry,
+        Leap,
+    }
+
+    #[test]
+    fn restoring_a_snapshot_rolls_back_state() {
+        let mut cooldowns = CooldownState::new([
+            (Action::Slash, Cooldown::from_secs(1.)),
+            (Action::Parry, Cooldown::from_secs(3.)),
+        ])
+        .with_global_cooldown(Cooldown::from_secs(0.5));
+        let mut charges = ChargeState::default();
+        charges.set(Action::Slash, Charges::replenish_one(2));
+
+        Action::Slash
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        cooldowns.trigger(&Action::Parry).unwrap_err();
+        cooldowns.tick(Duration::from_millis(500), Some(&mut charges));
+        cooldowns.trigger(&Action::Parry).unwrap();
+
+        let snapshot = AbilityStateSnapshot::capture(&cooldowns, &charges);
+        let saved_cooldowns = cooldowns.clone();
+        let saved_charges = charges.clone();
+
+        cooldowns.tick(Duration::from_secs(2), Some(&mut charges));
+        Action::Slash
+            .trigger_no_costs(&mut charges, &mut cooldowns)
+            .unwrap();
+        cooldowns.set(Action::Leap, Cooldown::from_secs(1.));
+        charges.set(Action::Leap, Charges::simple(1));
+        assert_ne!(cooldowns, saved_cooldowns);
+        assert_ne!(charges, saved_charges);
+
+        snapshot.restore(&mut cooldowns, &mut charges);
+        assert_eq!(cooldowns, saved_cooldowns);
+        assert_eq!(charges, saved_charges);
+        assert_eq!(
+            AbilityStateSnapshot::capture(&cooldowns, &charges),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn equal_states_produce_equal_snapshots() {
+        let forwards = CooldownState::new([
+            (Action::Slash, Cooldown::from_secs(1.)),
+            (Action::Parry, Cooldown::from_secs(2.)),
+            (Action::Leap, Cooldown::from_secs(3.)),
+        ]);
+        let backwards = CooldownState::new([
+            (Action::Leap, Cooldown::from_secs(3.)),
+            (Action::Parry, Cooldown::from_secs(2.)),
+            (Action::Slash, Cooldown::from_secs(1.)),
+        ]);
+
+        assert_eq!(forwards.snapshot(), backwards.snapshot());
+    }
+}
@@ -1,53 +1,416 @@
 //! The systems that power each [`InputManagerPlugin`](crate::plugin::InputManagerPlugin).
 
-use crate::pool::RegeneratingPool;
-use crate::{charges::ChargeState, cooldown::CooldownState, Abilitylike};
+use crate::ability_log::{AbilityAttempts, AbilityLog, AbilityLogEntry, AbilityTriggered};
+use crate::charges::ChargeReplenished;
+use crate::cooldown::{CooldownFinished, GlobalCooldownFinished};
+use crate::disabled::DisabledAbilities;
+use crate::hud_summary::{AbilityHudSummary, PlayerId};
+use crate::pool::{
+    warn_impossible_costs, AbilityCosts, CostModifiers, Pool, PoolDelta, PoolEmptied, PoolFilled,
+    PoolThresholdCrossed, PoolThresholds, PoolTransitions, RegenPaused, RegeneratingPool,
+};
+use crate::{
+    charges::ChargeState, cooldown::CooldownState, AbilityState, AbilityTimeDilation, Abilitylike,
+    AutoTrigger,
+};
 
 use bevy::ecs::prelude::*;
 use bevy::time::Time;
+use bevy::utils::Duration;
+use bevy::utils::{HashMap, HashSet};
+use leafwing_input_manager::action_state::ActionState;
 
-/// Advances all [`CooldownState`] components and resources for ability type `A`.
+/// Advances all [`CooldownState`] components and resources for ability type `A`,
+/// as well as the [recharge timers](crate::charges::Charges::with_recharge_time) of all [`ChargeState`] components and resources.
+///
+/// Sends a [`CooldownFinished`] event for each cooldown that becomes ready,
+/// a [`GlobalCooldownFinished`] event for each global cooldown that becomes ready,
+/// and a [`ChargeReplenished`] event for each action that gains charges.
+/// Entities with an [`AbilityTimeDilation`] component have their elapsed time scaled accordingly.
+#[allow(clippy::too_many_arguments)]
 pub fn tick_cooldowns<A: Abilitylike>(
     mut query: Query<
-        (Option<&mut CooldownState<A>>, Option<&mut ChargeState<A>>),
+        (
+            Entity,
+            Option<&mut CooldownState<A>>,
+            Option<&mut ChargeState<A>>,
+            Option<&AbilityTimeDilation>,
+        ),
         Or<(With<CooldownState<A>>, With<ChargeState<A>>)>,
     >,
     cooldowns_res: Option<ResMut<CooldownState<A>>>,
-    charges_res: Option<ResMut<ChargeState<A>>>,
+    mut charges_res: Option<ResMut<ChargeState<A>>>,
     time: Res<Time>,
+    mut cooldown_finished: EventWriter<CooldownFinished<A>>,
+    mut global_cooldown_finished: EventWriter<GlobalCooldownFinished<A>>,
+    mut charge_replenished: EventWriter<ChargeReplenished<A>>,
+    // Reused between frames to avoid allocating
+    mut charges_before: Local<Vec<(A, u8)>>,
 ) {
     let delta_time = time.delta();
 
+    record_charges(charges_res.as_deref(), &mut charges_before);
+
     // Only tick the Cooldowns resource if it exists
     if let Some(mut cooldowns) = cooldowns_res {
-        let charges = charges_res.map(|res| res.into_inner());
+        tick_and_report_finished(
+            &mut cooldowns,
+            delta_time,
+            charges_res.as_deref_mut(),
+            None,
+            &mut cooldown_finished,
+            &mut global_cooldown_finished,
+        );
+    }
 
-        cooldowns.tick(delta_time, charges);
+    if let Some(mut charges) = charges_res {
+        charges.tick(delta_time);
+        report_replenished(&charges, &charges_before, None, &mut charge_replenished);
     }
 
     // Only tick the Cooldowns components if they exist
-    for (cooldowns, charges) in query.iter_mut() {
+    for (entity, cooldowns, mut charges, dilation) in query.iter_mut() {
+        let delta_time = dilation.map_or(delta_time, |dilation| dilation.dilate(delta_time));
+        record_charges(charges.as_deref(), &mut charges_before);
+
         if let Some(mut cooldowns) = cooldowns {
-            let charges = charges.map(|data| data.into_inner());
+            tick_and_report_finished(
+                &mut cooldowns,
+                delta_time,
+                charges.as_deref_mut(),
+                Some(entity),
+                &mut cooldown_finished,
+                &mut global_cooldown_finished,
+            );
+        }
+
+        if let Some(mut charges) = charges {
+            charges.tick(delta_time);
+            report_replenished(
+                &charges,
+                &charges_before,
+                Some(entity),
+                &mut charge_replenished,
+            );
+        }
+    }
+}
+
+/// Stores the number of charges available for each action in `charges`, so they can be compared after ticking.
+fn record_charges<A: Abilitylike>(charges: Option<&ChargeState<A>>, record: &mut Vec<(A, u8)>) {
+    record.clear();
+    if let Some(charges) = charges {
+        record.extend(
+            charges
+                .iter()
+                .map(|(action, charges)| (action.clone(), charges.charges())),
+        );
+    }
+}
+
+/// Sends a [`ChargeReplenished`] event for each action that has more charges than were recorded.
+fn report_replenished<A: Abilitylike>(
+    charges: &ChargeState<A>,
+    record: &[(A, u8)],
+    entity: Option<Entity>,
+    charge_replenished: &mut EventWriter<ChargeReplenished<A>>,
+) {
+    for (action, before) in record {
+        let Some(charges) = charges.get(action) else {
+            continue;
+        };
+
+        if charges.charges() > *before {
+            charge_replenished.send(ChargeReplenished {
+                entity,
+                action: action.clone(),
+                current: charges.charges(),
+                max: charges.max_charges(),
+            });
+        }
+    }
+}
+
+/// Ticks the `cooldowns`, sending events for each cooldown that became ready.
+fn tick_and_report_finished<A: Abilitylike>(
+    cooldowns: &mut CooldownState<A>,
+    delta_time: Duration,
+    charges: Option<&mut ChargeState<A>>,
+    entity: Option<Entity>,
+    cooldown_finished: &mut EventWriter<CooldownFinished<A>>,
+    global_cooldown_finished: &mut EventWriter<GlobalCooldownFinished<A>>,
+) {
+    let gcd_was_ready = cooldowns.gcd_ready().is_ok();
 
-            cooldowns.tick(delta_time, charges);
+    for (action, result) in cooldowns.tick(delta_time, charges) {
+        if result.just_finished {
+            cooldown_finished.send(CooldownFinished { entity, action });
         }
     }
+
+    if !gcd_was_ready && cooldowns.gcd_ready().is_ok() {
+        global_cooldown_finished.send(GlobalCooldownFinished::new(entity));
+    }
 }
 
 /// Regenerates the resource of the [`Pool`] type `P` based on the elapsed [`Time`].
+///
+/// Entities with an [`AbilityTimeDilation`] component have their elapsed time scaled accordingly.
+/// Pools are not regenerated while paused by a [`RegenPaused<P>`] component on the same entity,
+/// or, for pools stored as a resource, by a [`RegenPaused<P>`] resource.
+///
+/// Sends a [`PoolEmptied`] or [`PoolFilled`] event whenever a pool becomes empty or full,
+/// if those events have been registered with [`App::add_event`](bevy::app::App::add_event).
 pub fn regenerate_resource_pool<P: RegeneratingPool + Component + Resource>(
-    mut query: Query<&mut P>,
+    mut query: Query<(Entity, &mut P, Option<&AbilityTimeDilation>), Without<RegenPaused<P>>>,
     pool_res: Option<ResMut<P>>,
+    pool_res_paused: Option<Res<RegenPaused<P>>>,
     time: Res<Time>,
+    mut pool_emptied: Option<ResMut<Events<PoolEmptied<P>>>>,
+    mut pool_filled: Option<ResMut<Events<PoolFilled<P>>>>,
 ) {
     let delta_time = time.delta();
 
-    for mut pool in query.iter_mut() {
-        pool.regenerate(delta_time);
+    let mut report = |entity: Option<Entity>, transitions: PoolTransitions| {
+        if transitions.emptied {
+            if let Some(events) = pool_emptied.as_mut() {
+                events.send(PoolEmptied::new(entity));
+            }
+        }
+        if transitions.filled {
+            if let Some(events) = pool_filled.as_mut() {
+                events.send(PoolFilled::new(entity));
+            }
+        }
+    };
+
+    for (entity, mut pool, dilation) in query.iter_mut() {
+        let delta_time = dilation.map_or(delta_time, |dilation| dilation.dilate(delta_time));
+        let ((), transitions) =
+            PoolTransitions::observe(&mut *pool, |pool| pool.regenerate(delta_time));
+        report(Some(entity), transitions);
     }
 
     if let Some(mut pool) = pool_res {
-        pool.regenerate(delta_time);
+        if pool_res_paused.is_none() {
+            let ((), transitions) =
+                PoolTransitions::observe(&mut *pool, |pool| pool.regenerate(delta_time));
+            report(None, transitions);
+        }
+    }
+}
+
+/// Logs a warning for each ability whose cost can never be paid from its pool of type `P`,
+/// whenever the pool or its [`AbilityCosts`] are added to an entity.
+///
+/// See [`AbilityCosts::validate`] for more details.
+/// Added by [`AbilityPlugin::with_cost_validation`](crate::plugin::AbilityPlugin::with_cost_validation).
+pub fn validate_ability_costs<A: Abilitylike, P: Pool + Component>(
+    query: Query<(&P, &AbilityCosts<A, P>), Or<(Added<P>, Added<AbilityCosts<A, P>>)>>,
+) where
+    P::Quantity: core::fmt::Debug,
+{
+    for (pool, costs) in query.iter() {
+        warn_impossible_costs::<A, P>(&costs.validate(pool));
+    }
+}
+
+/// Sends a [`PoolDelta`] event for each pool of type `P` whose current value has changed since this system last ran.
+///
+/// The first time a pool is seen, its value is recorded without sending an event.
+/// This system is not added automatically: add it to your schedule after any systems that modify pools,
+/// and register the event with [`App::add_event`](bevy::app::App::add_event).
+pub fn track_pool_deltas<P: Pool + Component + Resource>(
+    query: Query<(Entity, &P)>,
+    pool_res: Option<Res<P>>,
+    mut removed: RemovedComponents<P>,
+    mut pool_delta: EventWriter<PoolDelta<P>>,
+    mut previous_values: Local<HashMap<Option<Entity>, P::Quantity>>,
+) {
+    for entity in removed.read() {
+        previous_values.remove(&Some(entity));
+    }
+
+    let pools = query
+        .iter()
+        .map(|(entity, pool)| (Some(entity), pool.current()))
+        .chain(pool_res.map(|pool| (None, pool.current())));
+
+    for (entity, current) in pools {
+        if let Some(previous) = previous_values.insert(entity, current) {
+            if previous != current {
+                pool_delta.send(PoolDelta {
+                    entity,
+                    previous,
+                    current,
+                });
+            }
+        }
     }
 }
+
+/// Sends a [`PoolThresholdCrossed`] event each time the fraction of a pool of type `P` crosses one of the [`PoolThresholds`] on its entity.
+///
+/// Fractions are compared to their value the last time this system ran, so one large change can cross several thresholds.
+/// This system is not added automatically: add it to your schedule after any systems that modify pools,
+/// and register the event with [`App::add_event`](bevy::app::App::add_event).
+pub fn detect_pool_thresholds<P: Pool + Component>(
+    mut query: Query<(Entity, &P, &mut PoolThresholds<P>)>,
+    mut threshold_crossed: EventWriter<PoolThresholdCrossed<P>>,
+) {
+    for (entity, pool, mut thresholds) in query.iter_mut() {
+        for (threshold, rising) in thresholds.update(pool.fraction()) {
+            threshold_crossed.send(PoolThresholdCrossed::new(entity, threshold, rising));
+        }
+    }
+}
+
+/// Records each [`AbilityTriggered`] event for abilities of type `A` in the [`AbilityLog`] resource,
+/// then moves the [`AbilityAttempts`] recorded on each entity into it.
+///
+/// Added by [`AbilityPlugin::with_logging`](crate::plugin::AbilityPlugin::with_logging).
+pub fn log_ability_triggers<A: Abilitylike>(
+    mut events: EventReader<AbilityTriggered<A>>,
+    mut query: Query<(Entity, &mut AbilityAttempts<A>)>,
+    mut log: ResMut<AbilityLog<A>>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+
+    for event in events.read() {
+        log.record(AbilityLogEntry {
+            time: now,
+            entity: event.entity,
+            action: event.action.clone(),
+            outcome: event.outcome,
+        });
+    }
+
+    for (entity, mut attempts) in query.iter_mut() {
+        for (action, outcome) in attempts.drain() {
+            log.record(AbilityLogEntry {
+                time: now,
+                entity: Some(entity),
+                action,
+                outcome,
+            });
+        }
+    }
+}
+
+/// Disables each action in the [`ActionState`] of type `A` that is not [`Abilitylike::ready`],
+/// and re-enables it once it becomes ready again.
+///
+/// Only actions that were disabled by this system are re-enabled:
+/// actions that were disabled for other reasons are left untouched.
+/// Pool costs are not considered.
+///
+/// Added by [`AbilityPlugin::with_action_state_sync`](crate::plugin::AbilityPlugin::with_action_state_sync).
+pub fn sync_action_state<A: Abilitylike>(
+    mut query: Query<(
+        Entity,
+        &mut ActionState<A>,
+        Option<&CooldownState<A>>,
+        Option<&ChargeState<A>>,
+    )>,
+    action_state_res: Option<ResMut<ActionState<A>>>,
+    cooldowns_res: Option<Res<CooldownState<A>>>,
+    charges_res: Option<Res<ChargeState<A>>>,
+    // The actions that were disabled by this system, keyed by entity, or `None` for the resources
+    mut disabled_by_sync: Local<HashMap<Option<Entity>, HashSet<A>>>,
+    // Reused across entities and frames, to avoid allocating while collecting the actions to check
+    mut actions: Local<Vec<A>>,
+) {
+    if let Some(mut action_state) = action_state_res {
+        sync_disabled_actions(
+            &mut action_state,
+            cooldowns_res.as_deref(),
+            charges_res.as_deref(),
+            disabled_by_sync.entry(None).or_default(),
+            &mut actions,
+        );
+    }
+
+    for (entity, mut action_state, cooldowns, charges) in query.iter_mut() {
+        sync_disabled_actions(
+            &mut action_state,
+            cooldowns,
+            charges,
+            disabled_by_sync.entry(Some(entity)).or_default(),
+            &mut actions,
+        );
+    }
+
+    // Forget about entities that have been despawned or lost their `ActionState`
+    disabled_by_sync.retain(|maybe_entity, _| match maybe_entity {
+        Some(entity) => query.contains(*entity),
+        None => true,
+    });
+}
+
+fn sync_disabled_actions<A: Abilitylike>(
+    action_state: &mut ActionState<A>,
+    cooldowns: Option<&CooldownState<A>>,
+    charges: Option<&ChargeState<A>>,
+    disabled_by_sync: &mut HashSet<A>,
+    actions: &mut Vec<A>,
+) {
+    let default_cooldowns = CooldownState::default();
+    let default_charges = ChargeState::default();
+    let cooldowns = cooldowns.unwrap_or(&default_cooldowns);
+    let charges = charges.unwrap_or(&default_charges);
+
+    // Actions may be listed more than once, which is harmless: each check below is idempotent
+    actions.clear();
+    actions.extend(
+        cooldowns
+            .actions()
+            .chain(charges.actions())
+            .chain(disabled_by_sync.iter())
+            .cloned(),
+    );
+
+    for action in actions.drain(..) {
+        let ready = action.ready_no_costs(charges, cooldowns).is_ok();
+
+        if ready {
+            if disabled_by_sync.remove(&action) {
+                action_state.enable_action(&action);
+            }
+        } else if !action_state.action_disabled(&action) {
+            action_state.disable_action(&action);
+            disabled_by_sync.insert(action);
+        }
+    }
+}
+
+/// Triggers the abilities of type `A` that were just pressed on each entity with an [`AutoTrigger`],
+/// resolving abilities pressed on the same frame according to its [`AbilityClashPolicy`](crate::AbilityClashPolicy).
+///
+/// Costs are paid from pools of type `P`.
+/// Added by [`AbilityPlugin::with_auto_trigger`](crate::plugin::AbilityPlugin::with_auto_trigger).
+pub fn auto_trigger_abilities<A: Abilitylike, P: Pool + Component>(
+    mut query: Query<(AbilityState<A, P>, &mut AutoTrigger<A>)>,
+) {
+    for (mut ability_state, mut auto_trigger) in query.iter_mut() {
+        auto_trigger.trigger(&mut ability_state);
+    }
+}
+
+/// Updates the [`AbilityHudSummary`] for abilities of type `A`, paid for from pools of type `P`.
+///
+/// Added by [`AbilityPlugin::with_hud_summary`](crate::plugin::AbilityPlugin::with_hud_summary).
+pub fn update_ability_hud_summary<A: Abilitylike, P: Pool + Component>(
+    query: Query<(
+        &PlayerId,
+        &CooldownState<A>,
+        &ChargeState<A>,
+        Option<&P>,
+        Option<&AbilityCosts<A, P>>,
+        Option<&CostModifiers<A, P>>,
+        Option<&DisabledAbilities<A>>,
+    )>,
+    mut summary: ResMut<AbilityHudSummary<A>>,
+) {
+    summary.update(&query);
+}
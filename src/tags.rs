@@ -0,0 +1,214 @@
+//! User-defined tags that group abilities into categories, such as "Weapon" or "Fire".
+//!
+//! Status effects often operate on a whole category of abilities at once:
+//! "Disarm disables all Weapon abilities", or "refresh the cooldowns of all Fire spells".
+//! Store the tags of each ability in an [`AbilityTags`],
+//! then pass it to bulk operations like [`CooldownState::refresh_tagged`](crate::cooldown::CooldownState::refresh_tagged)
+//! and [`DisabledAbilities::disable_tagged`](crate::disabled::DisabledAbilities::disable_tagged).
+
+use crate::Abilitylike;
+use bevy::ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// A tag that can be attached to abilities by an [`AbilityTags`].
+///
+/// This is implemented for any small, copyable type, and is typically a data-less enum of categories.
+///
+/// # Example
+/// ```rust
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// enum Tag {
+///     Weapon,
+///     Fire,
+/// }
+/// ```
+pub trait AbilityTag: Copy + Eq + Hash + Send + Sync + 'static {}
+
+impl<T: Copy + Eq + Hash + Send + Sync + 'static> AbilityTag for T {}
+
+/// The tags of type `T` attached to each ability of type `A`.
+///
+/// Each ability may have any number of tags, and each tag may be attached to any number of abilities.
+#[derive(Component, Resource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbilityTags<A: Abilitylike, T: AbilityTag> {
+    /// The tags of each ability.
+    tag_map: HashMap<A, HashSet<T>>,
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Abilitylike, T: AbilityTag> Default for AbilityTags<A, T> {
+    fn default() -> Self {
+        AbilityTags {
+            tag_map: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Abilitylike, T: AbilityTag> FromIterator<(A, T)> for AbilityTags<A, T> {
+    fn from_iter<I: IntoIterator<Item = (A, T)>>(iter: I) -> Self {
+        let mut tags = AbilityTags::default();
+        for (action, tag) in iter {
+            tags.tag(action, tag);
+        }
+        tags
+    }
+}
+
+impl<A: Abilitylike, T: AbilityTag> AbilityTags<A, T> {
+    /// Creates a new [`AbilityTags`] from an iterator of `(action, tag)` pairs.
+    ///
+    /// Actions may appear in several pairs, to attach more than one tag to them.
+    #[must_use]
+    pub fn new(action_tag_pairs: impl IntoIterator<Item = (A, T)>) -> Self {
+        action_tag_pairs.into_iter().collect()
+    }
+
+    /// Attaches `tag` to `action`.
+    #[inline]
+    pub fn tag(&mut self, action: A, tag: T) -> &mut Self {
+        self.tag_map.entry(action).or_default().insert(tag);
+        self
+    }
+
+    /// Removes `tag` from `action`.
+    ///
+    /// Returns `true` if `action` had this tag.
+    #[inline]
+    pub fn untag(&mut self, action: &A, tag: T) -> bool {
+        let Some(tags) = self.tag_map.get_mut(action) else {
+            return false;
+        };
+
+        let removed = tags.remove(&tag);
+        if tags.is_empty() {
+            self.tag_map.remove(action);
+        }
+        removed
+    }
+
+    /// Does `action` have `tag`?
+    #[inline]
+    #[must_use]
+    pub fn has_tag(&self, action: &A, tag: T) -> bool {
+        self.tag_map
+            .get(action)
+            .is_some_and(|tags| tags.contains(&tag))
+    }
+
+    /// Returns an iterator over the tags of `action`.
+    #[inline]
+    pub fn tags(&self, action: &A) -> impl Iterator<Item = T> + '_ {
+        self.tag_map.get(action).into_iter().flatten().copied()
+    }
+
+    /// Returns an iterator over the actions that have `tag`.
+    #[inline]
+    pub fn actions_with(&self, tag: T) -> impl Iterator<Item = &A> {
+        self.tag_map
+            .iter()
+            .filter(move |(_, tags)| tags.contains(&tag))
+            .map(|(action, _)| action)
+    }
+
+    /// Returns an iterator over every action with at least one tag.
+    #[inline]
+    pub fn actions(&self) -> impl Iterator<Item = &A> {
+        self.tag_map.keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_abilities;
+    use crate::cooldown::{Cooldown, CooldownState};
+    use crate::disabled::DisabledAbilities;
+    use crate::CannotUseAbility;
+    use bevy::reflect::Reflect;
+    use leafwing_input_manager::Actionlike;
+
+    #[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    enum Action {
+        Slash,
+        Fireball,
+        FlamingSword,
+        Frostbolt,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    enum Tag {
+        Weapon,
+        Fire,
+    }
+
+    fn tags() -> AbilityTags<Action, Tag> {
+        AbilityTags::new([
+            (Action::Slash, Tag::Weapon),
+            (Action::Fireball, Tag::Fire),
+            (Action::FlamingSword, Tag::Weapon),
+            (Action::FlamingSword, Tag::Fire),
+        ])
+    }
+
+    #[test]
+    fn actions_with_a_tag() {
+        let tags = tags();
+
+        let mut fire: Vec<Action> = tags.actions_with(Tag::Fire).copied().collect();
+        fire.sort_by_key(|action| *action as u8);
+        assert_eq!(fire, vec![Action::Fireball, Action::FlamingSword]);
+
+        assert!(tags.has_tag(&Action::FlamingSword, Tag::Weapon));
+        assert_eq!(tags.tags(&Action::Frostbolt).count(), 0);
+    }
+
+    #[test]
+    fn untagging_the_last_tag_forgets_the_action() {
+        let mut tags = tags();
+
+        assert!(tags.untag(&Action::Slash, Tag::Weapon));
+        assert!(!tags.untag(&Action::Slash, Tag::Weapon));
+        assert!(!tags.actions().any(|action| *action == Action::Slash));
+    }
+
+    #[test]
+    fn refreshing_a_tag_only_refreshes_tagged_cooldowns() {
+        let tags = tags();
+        let mut cooldowns = CooldownState::new_triggered([
+            (Action::Slash, Cooldown::from_secs(1.)),
+            (Action::Fireball, Cooldown::from_secs(1.)),
+            (Action::FlamingSword, Cooldown::from_secs(1.)),
+            (Action::Frostbolt, Cooldown::from_secs(1.)),
+        ]);
+
+        cooldowns.refresh_tagged(&tags, Tag::Fire);
+
+        assert!(cooldowns.ready(&Action::Fireball).is_ok());
+        assert!(cooldowns.ready(&Action::FlamingSword).is_ok());
+        assert_eq!(
+            cooldowns.ready(&Action::Slash),
+            Err(CannotUseAbility::OnCooldown)
+        );
+        assert_eq!(
+            cooldowns.ready(&Action::Frostbolt),
+            Err(CannotUseAbility::OnCooldown)
+        );
+    }
+
+    #[test]
+    fn disarming_disables_only_weapons() {
+        let tags = tags();
+        let mut disabled = DisabledAbilities::default();
+
+        disabled.disable_tagged(&tags, Tag::Weapon);
+        assert!(disabled.is_disabled(&Action::Slash));
+        assert!(disabled.is_disabled(&Action::FlamingSword));
+        assert!(!disabled.is_disabled(&Action::Fireball));
+        assert!(!disabled.is_disabled(&Action::Frostbolt));
+
+        disabled.enable_tagged(&tags, Tag::Weapon);
+        assert_eq!(disabled.iter().count(), 0);
+    }
+}
@@ -0,0 +1,192 @@
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use leafwing_abilities::ability_log::{AbilityAttempts, AbilityLog, AbilityTriggered};
+use leafwing_abilities::prelude::*;
+use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Actionlike,
+    Reflect,
+    Abilitylike,
+    Debug,
+    Clone,
+    Copy,
+    Hash,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+enum Action {
+    Fireball,
+    Blink,
+}
+
+fn attempt_abilities(
+    mut query: Query<(Entity, &mut ChargeState<Action>, &mut CooldownState<Action>)>,
+    mut events: EventWriter<AbilityTriggered<Action>>,
+) {
+    for (entity, mut charges, mut cooldowns) in query.iter_mut() {
+        for action in [Action::Fireball, Action::Blink] {
+            let outcome = action.trigger_no_costs(&mut charges, &mut cooldowns);
+            events.send(AbilityTriggered {
+                entity: Some(entity),
+                action,
+                outcome,
+            });
+        }
+    }
+}
+
+fn trigger_abilities(mut query: Query<AbilityState<Action>>) {
+    for mut ability_state in query.iter_mut() {
+        for action in [Action::Fireball, Action::Blink] {
+            let _ = ability_state.trigger(&action);
+        }
+    }
+}
+
+fn spawn(app: &mut App) -> Entity {
+    app.world_mut()
+        .spawn((
+            ActionState::<Action>::default(),
+            AbilitiesBundle::<Action> {
+                cooldowns: CooldownState::new([(Action::Fireball, Cooldown::from_secs(10.))]),
+                charges: ChargeState::new([(Action::Blink, Charges::simple(1))]),
+            },
+        ))
+        .id()
+}
+
+#[test]
+fn ability_state_attempts_are_logged() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        AbilityPlugin::<Action>::default().with_logging(),
+    ))
+    .add_systems(Update, trigger_abilities);
+
+    let entity = spawn(&mut app);
+
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<AbilityLog<Action>>();
+    let outcomes: Vec<(Option<Entity>, Action, Result<(), CannotUseAbility>)> = log
+        .iter()
+        .map(|entry| (entry.entity, entry.action, entry.outcome))
+        .collect();
+
+    assert_eq!(
+        outcomes,
+        vec![
+            (Some(entity), Action::Fireball, Ok(())),
+            (Some(entity), Action::Blink, Ok(())),
+            (
+                Some(entity),
+                Action::Fireball,
+                Err(CannotUseAbility::OnCooldown)
+            ),
+            (
+                Some(entity),
+                Action::Blink,
+                Err(CannotUseAbility::NoCharges)
+            ),
+        ]
+    );
+
+    let attempts = app.world().get::<AbilityAttempts<Action>>(entity).unwrap();
+    assert_eq!(attempts.iter().count(), 0);
+}
+
+#[test]
+fn successful_and_failed_attempts_are_logged() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        AbilityPlugin::<Action>::default().with_logging(),
+    ))
+    .add_systems(Update, attempt_abilities);
+
+    let entity = app
+        .world_mut()
+        .spawn(AbilitiesBundle::<Action> {
+            cooldowns: CooldownState::new([(Action::Fireball, Cooldown::from_secs(10.))]),
+            charges: ChargeState::new([(Action::Blink, Charges::simple(1))]),
+        })
+        .id();
+
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<AbilityLog<Action>>();
+    let outcomes: Vec<(Option<Entity>, Action, Result<(), CannotUseAbility>)> = log
+        .iter()
+        .map(|entry| (entry.entity, entry.action, entry.outcome))
+        .collect();
+
+    assert_eq!(
+        outcomes,
+        vec![
+            (Some(entity), Action::Fireball, Ok(())),
+            (Some(entity), Action::Blink, Ok(())),
+            (
+                Some(entity),
+                Action::Fireball,
+                Err(CannotUseAbility::OnCooldown)
+            ),
+            (
+                Some(entity),
+                Action::Blink,
+                Err(CannotUseAbility::NoCharges)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn logging_is_disabled_by_default() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        AbilityPlugin::<Action>::default(),
+    ))
+    .add_systems(Update, trigger_abilities);
+
+    let entity = spawn(&mut app);
+
+    app.update();
+
+    assert!(app.world().get_resource::<AbilityLog<Action>>().is_none());
+    assert!(app
+        .world()
+        .get_resource::<Events<AbilityTriggered<Action>>>()
+        .is_none());
+    assert!(app.world().get::<AbilityAttempts<Action>>(entity).is_none());
+}
+
+#[test]
+fn preexisting_log_capacity_is_respected() {
+    let mut app = App::new();
+    app.insert_resource(AbilityLog::<Action>::with_capacity(3))
+        .add_plugins((
+            MinimalPlugins,
+            InputPlugin,
+            AbilityPlugin::<Action>::default().with_logging(),
+        ))
+        .add_systems(Update, attempt_abilities);
+
+    app.world_mut().spawn(AbilitiesBundle::<Action>::default());
+
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<AbilityLog<Action>>();
+    assert_eq!(log.capacity(), 3);
+    assert_eq!(log.len(), 3);
+}
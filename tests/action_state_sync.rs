@@ -0,0 +1,108 @@
+mod common;
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use leafwing_abilities::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Action {
+    Fireball,
+    Blink,
+    Walk,
+}
+
+fn app() -> App {
+    common::app(AbilityPlugin::<Action>::default().with_action_state_sync())
+}
+
+fn spawn(app: &mut App, blink_charges: u8) -> Entity {
+    let mut cooldowns = CooldownState::new([(Action::Fireball, Cooldown::from_secs(10.))]);
+    cooldowns.trigger(&Action::Fireball).unwrap();
+
+    let mut blink = Charges::simple(1);
+    blink.set_charges(blink_charges);
+
+    app.world_mut()
+        .spawn((
+            ActionState::<Action>::default(),
+            AbilitiesBundle::<Action> {
+                cooldowns,
+                charges: ChargeState::new([(Action::Blink, blink)]),
+            },
+        ))
+        .id()
+}
+
+fn set_blink_charges(app: &mut App, entity: Entity, charges: u8) {
+    app.world_mut()
+        .get_mut::<ChargeState<Action>>(entity)
+        .unwrap()
+        .get_mut(&Action::Blink)
+        .unwrap()
+        .set_charges(charges);
+}
+
+fn action_state(app: &App, entity: Entity) -> &ActionState<Action> {
+    app.world().get::<ActionState<Action>>(entity).unwrap()
+}
+
+#[test]
+fn blocked_abilities_are_disabled() {
+    let mut app = app();
+    let entity = spawn(&mut app, 0);
+
+    app.update();
+
+    let action_state = action_state(&app, entity);
+    assert!(action_state.action_disabled(&Action::Fireball));
+    assert!(action_state.action_disabled(&Action::Blink));
+    assert!(!action_state.action_disabled(&Action::Walk));
+}
+
+#[test]
+fn abilities_are_reenabled_once_ready() {
+    let mut app = app();
+    let entity = spawn(&mut app, 0);
+
+    app.update();
+    assert!(action_state(&app, entity).action_disabled(&Action::Blink));
+
+    set_blink_charges(&mut app, entity, 1);
+    app.update();
+    assert!(!action_state(&app, entity).action_disabled(&Action::Blink));
+}
+
+#[test]
+fn user_disabled_actions_are_not_reenabled() {
+    let mut app = app();
+    let entity = spawn(&mut app, 0);
+    app.world_mut()
+        .get_mut::<ActionState<Action>>(entity)
+        .unwrap()
+        .disable_action(&Action::Blink);
+
+    app.update();
+    assert!(action_state(&app, entity).action_disabled(&Action::Blink));
+
+    set_blink_charges(&mut app, entity, 1);
+    app.update();
+    assert!(action_state(&app, entity).action_disabled(&Action::Blink));
+}
+
+#[test]
+fn action_state_sync_is_disabled_by_default() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        AbilityPlugin::<Action>::default(),
+    ));
+    let entity = spawn(&mut app, 0);
+
+    app.update();
+
+    let action_state = action_state(&app, entity);
+    assert!(!action_state.action_disabled(&Action::Fireball));
+    assert!(!action_state.action_disabled(&Action::Blink));
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use bevy::prelude::*;
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::NullPool;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Action {
+    Duck,
+    Cover,
+}
+
+fn app() -> App {
+    common::app(AbilityPlugin::<Action>::default().with_auto_trigger::<NullPool>())
+}
+
+/// Spawns an entity that pressed both abilities this frame, with one charge of each.
+fn spawn(app: &mut App) -> Entity {
+    let mut action_state = ActionState::<Action>::default();
+    action_state.press(&Action::Duck);
+    action_state.press(&Action::Cover);
+
+    app.world_mut()
+        .spawn((
+            action_state,
+            AbilitiesBundle::<Action> {
+                charges: ChargeState::new([
+                    (Action::Duck, Charges::simple(1)),
+                    (Action::Cover, Charges::simple(1)),
+                ]),
+                ..default()
+            },
+        ))
+        .id()
+}
+
+fn charges(app: &App, entity: Entity, action: Action) -> u8 {
+    app.world()
+        .get::<ChargeState<Action>>(entity)
+        .unwrap()
+        .get(&action)
+        .unwrap()
+        .charges()
+}
+
+#[test]
+fn same_frame_presses_are_resolved_by_the_policy() {
+    let mut app = app();
+    let entity = spawn(&mut app);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AutoTrigger::new(AbilityClashPolicy::PriorityList(vec![
+            Action::Cover,
+        ])));
+
+    app.update();
+
+    let auto_trigger = app.world().get::<AutoTrigger<Action>>(entity).unwrap();
+    assert_eq!(auto_trigger.outcomes(), &[(Action::Cover, Ok(()))]);
+    assert_eq!(
+        auto_trigger.triggered().collect::<Vec<_>>(),
+        vec![&Action::Cover]
+    );
+    assert_eq!(charges(&app, entity, Action::Cover), 0);
+    assert_eq!(charges(&app, entity, Action::Duck), 1);
+}
+
+#[test]
+fn all_independently_triggers_every_pressed_ability() {
+    let mut app = app();
+    let entity = spawn(&mut app);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AutoTrigger::<Action>::new(
+            AbilityClashPolicy::AllIndependently,
+        ));
+
+    app.update();
+
+    let auto_trigger = app.world().get::<AutoTrigger<Action>>(entity).unwrap();
+    assert_eq!(
+        auto_trigger.outcomes(),
+        &[(Action::Duck, Ok(())), (Action::Cover, Ok(()))]
+    );
+    assert_eq!(charges(&app, entity, Action::Duck), 0);
+    assert_eq!(charges(&app, entity, Action::Cover), 0);
+}
+
+#[test]
+fn entities_without_auto_trigger_are_untouched() {
+    let mut app = app();
+    let entity = spawn(&mut app);
+
+    app.update();
+
+    assert_eq!(charges(&app, entity, Action::Duck), 1);
+    assert_eq!(charges(&app, entity, Action::Cover), 1);
+}
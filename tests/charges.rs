@@ -0,0 +1,170 @@
+mod common;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::utils::Duration;
+use leafwing_abilities::charges::{ChargeReplenished, ChargesExpended};
+use leafwing_abilities::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Action {
+    Blink,
+}
+
+fn app(frame_time: Duration) -> App {
+    common::app_with_frame_time(frame_time, AbilityPlugin::<Action>::default())
+}
+
+fn blink_charges(app: &App, entity: Entity) -> u8 {
+    app.world()
+        .get::<ChargeState<Action>>(entity)
+        .unwrap()
+        .get(&Action::Blink)
+        .unwrap()
+        .charges()
+}
+
+#[test]
+fn charges_recharge_without_cooldowns() {
+    let mut app = app(Duration::from_secs(2));
+
+    let mut charges = ChargeState::default();
+    charges.set(
+        Action::Blink,
+        Charges::simple(2).with_recharge_time(Duration::from_secs(6)),
+    );
+    charges.get_mut(&Action::Blink).unwrap().set_charges(0);
+    // Only a `ChargeState`, without any `CooldownState`
+    let entity = app.world_mut().spawn(charges).id();
+
+    // The first update has a delta time of zero
+    app.update();
+    app.update();
+    app.update();
+    assert_eq!(blink_charges(&app, entity), 0);
+
+    app.update();
+    assert_eq!(blink_charges(&app, entity), 1);
+
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(blink_charges(&app, entity), 2);
+}
+
+fn replenished_events(app: &App) -> Vec<ChargeReplenished<Action>> {
+    let events = app.world().resource::<Events<ChargeReplenished<Action>>>();
+    events.get_cursor().read(events).cloned().collect()
+}
+
+#[test]
+fn completed_cooldowns_report_replenished_charges() {
+    let mut app = app(Duration::from_secs(2));
+
+    let mut cooldowns = CooldownState::new([(Action::Blink, Cooldown::from_secs(3.))]);
+    let mut charges = ChargeState::default();
+    charges.set(Action::Blink, Charges::replenish_one(2));
+    charges.expend(&Action::Blink).unwrap();
+    cooldowns.trigger(&Action::Blink).unwrap();
+    let entity = app.world_mut().spawn((cooldowns, charges)).id();
+
+    // The first update has a delta time of zero
+    app.update();
+    app.update();
+    assert!(replenished_events(&app).is_empty());
+
+    app.update();
+    assert_eq!(blink_charges(&app, entity), 2);
+    assert_eq!(
+        replenished_events(&app),
+        vec![ChargeReplenished {
+            entity: Some(entity),
+            action: Action::Blink,
+            current: 2,
+            max: 2,
+        }]
+    );
+
+    // Full charges are not replenished again
+    app.update();
+    app.update();
+    assert!(replenished_events(&app).is_empty());
+}
+
+#[test]
+fn manual_changes_can_be_reported() {
+    let mut app = app(Duration::from_secs(2));
+
+    let mut charges = ChargeState::default();
+    charges.set(Action::Blink, Charges::replenish_one(1));
+    let entity = app.world_mut().spawn(charges).id();
+
+    app.world_mut()
+        .run_system_once(
+            move |mut query: Query<&mut ChargeState<Action>>,
+                  mut expended: EventWriter<ChargesExpended<Action>>,
+                  mut replenished: EventWriter<ChargeReplenished<Action>>| {
+                let mut charges = query.get_mut(entity).unwrap();
+                charges
+                    .expend_and_report(&Action::Blink, Some(entity), &mut expended)
+                    .unwrap();
+                charges
+                    .expend_and_report(&Action::Blink, Some(entity), &mut expended)
+                    .unwrap_err();
+                charges.replenish_and_report(&Action::Blink, Some(entity), &mut replenished);
+            },
+        )
+        .unwrap();
+
+    let events = app.world().resource::<Events<ChargesExpended<Action>>>();
+    assert_eq!(
+        events
+            .get_cursor()
+            .read(events)
+            .cloned()
+            .collect::<Vec<_>>(),
+        vec![ChargesExpended {
+            entity: Some(entity),
+            action: Action::Blink,
+            remaining: 0,
+        }]
+    );
+    assert_eq!(replenished_events(&app).len(), 1);
+}
+
+#[test]
+fn spending_a_charge_starts_the_cooldown() {
+    let mut app = app(Duration::from_secs(1));
+
+    let cooldowns = CooldownState::new([(Action::Blink, Cooldown::from_secs(5.))]);
+    let charges = ChargeState::new([(Action::Blink, Charges::replenish_one(3))]);
+    let entity = app.world_mut().spawn((cooldowns, charges)).id();
+
+    // The first update has a delta time of zero
+    app.update();
+
+    app.world_mut()
+        .run_system_once(
+            |mut query: Query<(&mut CooldownState<Action>, &mut ChargeState<Action>)>| {
+                let (mut cooldowns, mut charges) = query.single_mut();
+                for _ in 0..3 {
+                    Action::Blink
+                        .trigger_no_costs(&mut charges, &mut cooldowns)
+                        .unwrap();
+                }
+            },
+        )
+        .unwrap();
+    assert_eq!(blink_charges(&app, entity), 0);
+
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(blink_charges(&app, entity), 1);
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(blink_charges(&app, entity), 3);
+}
@@ -0,0 +1,29 @@
+//! Setup shared between the integration test suites.
+
+// Each suite only uses some of these helpers
+#![allow(dead_code)]
+
+use bevy::app::Plugins;
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::utils::Duration;
+
+/// Creates an [`App`] with the minimal plugins needed to run input and time, along with the provided `plugins`.
+pub fn app<M>(plugins: impl Plugins<M>) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin))
+        .add_plugins(plugins);
+    app
+}
+
+/// Creates an [`App`] like [`app`], whose virtual time advances by exactly `frame_time` on each update.
+pub fn app_with_frame_time<M>(frame_time: Duration, plugins: impl Plugins<M>) -> App {
+    let mut app = app(plugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(frame_time));
+    // Long frames would otherwise be clamped to the default maximum delta of 250ms
+    app.world_mut()
+        .resource_mut::<Time<Virtual>>()
+        .set_max_delta(Duration::from_secs(100));
+    app
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::utils::Duration;
+use leafwing_abilities::cooldown::{CooldownFinished, GlobalCooldownFinished};
+use leafwing_abilities::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Action {
+    Fireball,
+    Frostbolt,
+}
+
+fn app(frame_time: Duration) -> App {
+    common::app_with_frame_time(frame_time, AbilityPlugin::<Action>::default())
+}
+
+fn cooldowns() -> CooldownState<Action> {
+    let mut cooldowns = CooldownState::new([
+        (Action::Fireball, Cooldown::from_secs(1.)),
+        (Action::Frostbolt, Cooldown::from_secs(1.)),
+    ]);
+    cooldowns.set_global_cooldown(Some(Cooldown::from_secs(0.5)));
+    cooldowns
+}
+
+fn finished_events(app: &App) -> Vec<CooldownFinished<Action>> {
+    let events = app.world().resource::<Events<CooldownFinished<Action>>>();
+    events.get_cursor().read(events).cloned().collect()
+}
+
+fn global_finished_events(app: &App) -> Vec<GlobalCooldownFinished<Action>> {
+    let events = app
+        .world()
+        .resource::<Events<GlobalCooldownFinished<Action>>>();
+    events.get_cursor().read(events).cloned().collect()
+}
+
+#[test]
+fn one_event_per_finished_cooldown() {
+    let mut app = app(Duration::from_secs(10));
+    let mut cooldowns = cooldowns();
+    cooldowns.trigger(&Action::Fireball).unwrap();
+    let entity = app.world_mut().spawn(cooldowns).id();
+
+    // The first frame has no elapsed time
+    app.update();
+    assert!(finished_events(&app).is_empty());
+
+    // Overshooting the cooldown by a large amount still only sends a single event
+    app.update();
+    assert_eq!(
+        finished_events(&app),
+        vec![CooldownFinished {
+            entity: Some(entity),
+            action: Action::Fireball,
+        }]
+    );
+    assert_eq!(
+        global_finished_events(&app),
+        vec![GlobalCooldownFinished::new(Some(entity))]
+    );
+
+    // Cooldowns that are already ready do not send more events
+    app.update();
+    app.update();
+    assert!(finished_events(&app).is_empty());
+    assert!(global_finished_events(&app).is_empty());
+}
+
+#[test]
+fn resource_cooldowns_send_events_without_entity() {
+    let mut app = app(Duration::from_millis(400));
+    let mut cooldowns = cooldowns();
+    cooldowns.trigger(&Action::Frostbolt).unwrap();
+    app.insert_resource(cooldowns);
+
+    app.update();
+    app.update();
+    // 0.4 seconds have passed: nothing is ready yet
+    assert!(finished_events(&app).is_empty());
+    assert!(global_finished_events(&app).is_empty());
+
+    app.update();
+    // 0.8 seconds: only the global cooldown is ready
+    assert!(finished_events(&app).is_empty());
+    assert_eq!(
+        global_finished_events(&app),
+        vec![GlobalCooldownFinished::new(None)]
+    );
+
+    app.update();
+    // 1.2 seconds: the frostbolt cooldown is ready
+    assert_eq!(
+        finished_events(&app),
+        vec![CooldownFinished {
+            entity: None,
+            action: Action::Frostbolt,
+        }]
+    );
+}
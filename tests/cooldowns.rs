@@ -86,7 +86,7 @@ fn cooldowns_on_entity() {
 
     // Short wait
     let mut query_state = app.world_mut().query::<&CooldownState<Action>>();
-    let cooldowns: &CooldownState<Action> = query_state.single(&app.world());
+    let cooldowns: &CooldownState<Action> = query_state.single(app.world());
     assert!(cooldowns.ready(&NoCooldown).is_ok());
     assert!(cooldowns.ready(&Short).is_ok());
     assert_eq!(cooldowns.ready(&Long), Err(CannotUseAbility::OnCooldown));
@@ -137,14 +137,14 @@ fn global_cooldowns_tick() {
 
     let mut cooldowns: Mut<CooldownState<Action>> = app.world_mut().resource_mut();
     let initial_gcd = Some(Cooldown::new(Duration::from_micros(15)));
-    cooldowns.global_cooldown = initial_gcd.clone();
+    cooldowns.set_global_cooldown(initial_gcd.clone());
     // Trigger the GCD
     let _ = cooldowns.trigger(&Action::Long);
 
     app.update();
 
     let cooldowns: &CooldownState<Action> = app.world().resource();
-    assert_ne!(initial_gcd, cooldowns.global_cooldown);
+    assert_ne!(initial_gcd.as_ref(), cooldowns.global_cooldown());
 }
 
 #[test]
@@ -159,7 +159,7 @@ fn global_cooldown_blocks_cooldownless_actions() {
     app.update();
 
     let mut cooldowns: Mut<CooldownState<Action>> = app.world_mut().resource_mut();
-    cooldowns.global_cooldown = Some(Cooldown::new(Duration::from_micros(15)));
+    cooldowns.set_global_cooldown(Some(Cooldown::new(Duration::from_micros(15))));
 
     assert!(cooldowns.ready(&Action::NoCooldown).is_ok());
 
@@ -190,7 +190,7 @@ fn global_cooldown_affects_other_actions() {
     app.update();
 
     let mut cooldowns: Mut<CooldownState<Action>> = app.world_mut().resource_mut();
-    cooldowns.global_cooldown = Some(Cooldown::new(Duration::from_micros(15)));
+    cooldowns.set_global_cooldown(Some(Cooldown::new(Duration::from_micros(15))));
     let _ = cooldowns.trigger(&Action::Long);
     assert_eq!(
         cooldowns.ready(&Action::Short),
@@ -226,7 +226,7 @@ fn global_cooldown_overrides_short_cooldowns() {
     app.update();
 
     let mut cooldowns: Mut<CooldownState<Action>> = app.world_mut().resource_mut();
-    cooldowns.global_cooldown = Some(Cooldown::from_secs(0.5));
+    cooldowns.set_global_cooldown(Some(Cooldown::from_secs(0.5)));
     let _ = cooldowns.trigger(&Action::Short);
     assert_eq!(
         cooldowns.ready(&Action::Short),
@@ -265,7 +265,7 @@ fn cooldown_not_triggered_on_gcd() {
     app.update();
 
     let mut cooldowns: Mut<CooldownState<Action>> = app.world_mut().resource_mut();
-    cooldowns.global_cooldown = Some(Cooldown::from_secs(0.5));
+    cooldowns.set_global_cooldown(Some(Cooldown::from_secs(0.5)));
     let _ = cooldowns.trigger(&Action::Long);
     assert_eq!(
         cooldowns.ready(&Action::Long),
@@ -0,0 +1,66 @@
+//! Actions that carry data cannot be `Copy`.
+//! These tests ensure that all lookups take actions by reference, so that they never need to be cloned.
+
+use bevy::prelude::*;
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Hash, PartialEq, Eq)]
+enum Spell {
+    Cast(String),
+    Cancel,
+}
+
+#[test]
+fn data_carrying_actions_can_be_used_by_reference() {
+    let fireball = Spell::Cast("fireball".to_string());
+
+    let mut cooldowns = CooldownState::default();
+    cooldowns.set(fireball.clone(), Cooldown::from_secs(1.));
+    let mut charges = ChargeState::default();
+    charges.set(fireball.clone(), Charges::replenish_all(2));
+    let mut costs = AbilityCosts::<Spell, ManaPool>::default();
+    costs.set(fireball.clone(), Mana(10.));
+    let mut mana = ManaPool::new(Mana(15.), Mana(100.), Mana(0.));
+
+    assert!(charges.available(&fireball));
+    assert!(costs.available(&fireball, &mana));
+    assert!(cooldowns.ready(&fireball).is_ok());
+    assert!(charges.get(&fireball).is_some());
+    assert!(costs.get(&fireball).is_some());
+    assert!(cooldowns.get(&fireball).is_some());
+
+    fireball
+        .trigger(&mut charges, &mut cooldowns, Some(&mut mana), Some(&costs))
+        .unwrap();
+    assert_eq!(charges.get(&fireball).unwrap().charges(), 1);
+    assert_eq!(mana.current(), Mana(5.));
+
+    assert!(!costs.available(&fireball, &mana));
+
+    charges.replenish(&fireball);
+    charges.expend(&fireball).unwrap();
+    assert!(cooldowns.remove(&fireball).is_some());
+    assert!(charges.remove(&fireball).is_some());
+    assert!(costs.remove(&fireball).is_some());
+}
+
+#[test]
+fn actions_with_different_data_are_tracked_separately() {
+    let fireball = Spell::Cast("fireball".to_string());
+    let frostbolt = Spell::Cast("frostbolt".to_string());
+
+    let mut cooldowns = CooldownState::new([
+        (fireball.clone(), Cooldown::from_secs(1.)),
+        (frostbolt.clone(), Cooldown::from_secs(1.)),
+    ]);
+    let mut charges = ChargeState::default();
+
+    fireball
+        .trigger_no_costs(&mut charges, &mut cooldowns)
+        .unwrap();
+    assert!(fireball.ready_no_costs(&charges, &cooldowns).is_err());
+    assert!(frostbolt.ready_no_costs(&charges, &cooldowns).is_ok());
+    assert!(Spell::Cancel.ready_no_costs(&charges, &cooldowns).is_ok());
+}
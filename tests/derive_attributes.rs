@@ -0,0 +1,8 @@
+//! Compile-time tests for the `#[ability(...)]` attributes accepted by `#[derive(Abilitylike)]`.
+
+#[test]
+fn ability_attributes() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/pass/*.rs");
+    cases.compile_fail("tests/ui/fail/*.rs");
+}
@@ -0,0 +1,71 @@
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use leafwing_abilities::dynamic_ability::{AbilityDefinition, AbilityRegistry, DynamicAbility};
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_input_manager::prelude::*;
+
+const MODDED_ABILITIES: &str = r#"[
+    (name: "Frost Nova", cooldown: Some(5.0), cost: Some(20.0)),
+    (name: "Shadow Step", charges: Some(2)),
+]"#;
+
+fn registry() -> AbilityRegistry {
+    let definitions: Vec<AbilityDefinition> = ron::from_str(MODDED_ABILITIES).unwrap();
+    let mut registry = AbilityRegistry::default();
+    registry.register_all(definitions).unwrap();
+    registry
+}
+
+#[test]
+fn definitions_load_from_ron() {
+    let registry = registry();
+    let frost_nova = registry.get("Frost Nova").unwrap();
+    let shadow_step = registry.get("Shadow Step").unwrap();
+
+    assert_eq!(registry.definition(frost_nova).unwrap().cooldown, Some(5.0));
+    assert_eq!(registry.definition(shadow_step).unwrap().cooldown, None);
+    assert_eq!(registry.definition(shadow_step).unwrap().charges, Some(2));
+}
+
+#[test]
+fn dynamic_abilities_trigger_through_ability_state() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        AbilityPlugin::<DynamicAbility>::default(),
+    ));
+
+    let registry = registry();
+    let frost_nova = registry.get("Frost Nova").unwrap();
+
+    let mut action_state = ActionState::<DynamicAbility>::default();
+    action_state.press(&frost_nova);
+
+    let entity = app
+        .world_mut()
+        .spawn((
+            action_state,
+            registry.abilities_bundle(),
+            PoolBundle {
+                pool: ManaPool::new(Mana(30.), Mana(30.), Mana(0.)),
+                ability_costs: registry.ability_costs::<ManaPool>(),
+            },
+        ))
+        .id();
+    app.insert_resource(registry);
+
+    let mut query_state = app
+        .world_mut()
+        .query::<AbilityState<DynamicAbility, ManaPool>>();
+    let mut ability_state = query_state.single_mut(app.world_mut());
+    assert_eq!(ability_state.trigger_if_just_pressed(&frost_nova), Ok(()));
+    assert_eq!(
+        ability_state.trigger_if_just_pressed(&frost_nova),
+        Err(CannotUseAbility::OnCooldown)
+    );
+
+    let pool = app.world().get::<ManaPool>(entity).unwrap();
+    assert_eq!(pool.current(), Mana(10.));
+}
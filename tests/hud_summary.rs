@@ -0,0 +1,182 @@
+mod common;
+
+use bevy::prelude::*;
+use leafwing_abilities::hud_summary::{AbilityHudSummary, PlayerId};
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Action {
+    Fireball,
+    Blink,
+    Walk,
+}
+
+fn app() -> App {
+    common::app(AbilityPlugin::<Action>::default().with_hud_summary::<ManaPool>())
+}
+
+fn spawn_player(app: &mut App, id: u32) -> Entity {
+    app.world_mut()
+        .spawn((
+            PlayerId(id),
+            ActionState::<Action>::default(),
+            AbilitiesBundle::<Action> {
+                cooldowns: CooldownState::new([(Action::Fireball, Cooldown::from_secs(10.))]),
+                charges: ChargeState::new([(Action::Blink, Charges::simple(2))]),
+            },
+            PoolBundle::<Action, ManaPool> {
+                pool: ManaPool::new(Mana(15.), Mana(15.), Mana(0.)),
+                ability_costs: AbilityCosts::new([(Action::Fireball, Mana(10.))]),
+            },
+        ))
+        .id()
+}
+
+fn trigger(app: &mut App, player: Entity, action: Action) {
+    let mut query_state = app.world_mut().query::<AbilityState<Action, ManaPool>>();
+    let mut ability_state = query_state.get_mut(app.world_mut(), player).unwrap();
+    ability_state.trigger(&action).unwrap();
+}
+
+#[test]
+fn summary_lists_abilities_in_declaration_order() {
+    let mut app = app();
+    spawn_player(&mut app, 1);
+
+    app.update();
+
+    let summary = app.world().resource::<AbilityHudSummary<Action>>();
+    let actions: Vec<Action> = summary
+        .get(PlayerId(1))
+        .unwrap()
+        .iter()
+        .map(|snapshot| snapshot.action)
+        .collect();
+    assert_eq!(actions, vec![Action::Fireball, Action::Blink]);
+    // Abilities without cooldowns, charges or costs are not summarized
+    assert!(summary.snapshot(PlayerId(1), &Action::Walk).is_none());
+    assert!(summary.get(PlayerId(2)).is_none());
+}
+
+#[test]
+fn summary_matches_components_after_trigger_and_tick() {
+    let mut app = app();
+    let player_one = spawn_player(&mut app, 1);
+    spawn_player(&mut app, 2);
+
+    trigger(&mut app, player_one, Action::Fireball);
+    trigger(&mut app, player_one, Action::Blink);
+    app.update();
+    app.update();
+
+    let world = app.world();
+    let cooldown = world
+        .get::<CooldownState<Action>>(player_one)
+        .unwrap()
+        .get(&Action::Fireball)
+        .unwrap();
+    let charges = world
+        .get::<ChargeState<Action>>(player_one)
+        .unwrap()
+        .get(&Action::Blink)
+        .unwrap();
+
+    let summary = world.resource::<AbilityHudSummary<Action>>();
+
+    let fireball = summary.snapshot(PlayerId(1), &Action::Fireball).unwrap();
+    assert_eq!(fireball.ready, Err(CannotUseAbility::OnCooldown));
+    assert_eq!(
+        fireball.cooldown_remaining,
+        Some(cooldown.fraction_remaining())
+    );
+    // 5 mana remains, which cannot pay for another fireball
+    assert_eq!(fireball.affordable, Some(false));
+    assert_eq!(fireball.charges, None);
+
+    let blink = summary.snapshot(PlayerId(1), &Action::Blink).unwrap();
+    assert_eq!(blink.ready, Ok(()));
+    assert_eq!(blink.charges.as_ref(), Some(charges));
+    assert_eq!(blink.charges.as_ref().unwrap().charges(), 1);
+    assert_eq!(blink.cooldown_remaining, None);
+    assert_eq!(blink.affordable, None);
+
+    // The other player is unaffected
+    let fireball = summary.snapshot(PlayerId(2), &Action::Fireball).unwrap();
+    assert_eq!(fireball.ready, Ok(()));
+    assert_eq!(fireball.cooldown_remaining, Some(0.));
+    assert_eq!(fireball.affordable, Some(true));
+}
+
+#[test]
+fn hud_summary_is_disabled_by_default() {
+    let mut app = common::app(AbilityPlugin::<Action>::default());
+    spawn_player(&mut app, 1);
+
+    app.update();
+
+    assert!(app
+        .world()
+        .get_resource::<AbilityHudSummary<Action>>()
+        .is_none());
+}
+
+#[test]
+fn summary_respects_disabled_abilities_and_cost_modifiers() {
+    let mut app = app();
+    let player = spawn_player(&mut app, 1);
+
+    let mut modifiers = CostModifiers::<Action, ManaPool>::default();
+    modifiers
+        .set_action_multiplier(Action::Fireball, 2.)
+        .unwrap();
+    app.world_mut()
+        .entity_mut(player)
+        .insert((DisabledAbilities::new([Action::Blink]), modifiers));
+    app.update();
+
+    let summary = app.world().resource::<AbilityHudSummary<Action>>();
+
+    // Doubled, the fireball costs 20 mana, more than the 15 available
+    let fireball = summary.snapshot(PlayerId(1), &Action::Fireball).unwrap();
+    assert_eq!(fireball.ready, Err(CannotUseAbility::PoolInsufficient));
+    assert_eq!(fireball.affordable, Some(false));
+
+    let blink = summary.snapshot(PlayerId(1), &Action::Blink).unwrap();
+    assert_eq!(blink.ready, Err(CannotUseAbility::Disabled));
+}
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Spell {
+    Bolt(u8),
+    Nova,
+}
+
+#[test]
+fn abilities_sharing_an_index_are_summarized_in_a_stable_order() {
+    let mut app = common::app(AbilityPlugin::<Spell>::default().with_hud_summary::<ManaPool>());
+    app.world_mut().spawn((
+        PlayerId(1),
+        ActionState::<Spell>::default(),
+        AbilitiesBundle::<Spell> {
+            cooldowns: CooldownState::new([
+                (Spell::Nova, Cooldown::from_secs(1.)),
+                (Spell::Bolt(2), Cooldown::from_secs(1.)),
+                (Spell::Bolt(1), Cooldown::from_secs(1.)),
+            ]),
+            ..default()
+        },
+    ));
+
+    app.update();
+
+    let summary = app.world().resource::<AbilityHudSummary<Spell>>();
+    let spells: Vec<Spell> = summary
+        .get(PlayerId(1))
+        .unwrap()
+        .iter()
+        .map(|snapshot| snapshot.action)
+        .collect();
+    assert_eq!(spells, vec![Spell::Bolt(1), Spell::Bolt(2), Spell::Nova]);
+}
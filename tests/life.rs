@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use leafwing_abilities::pool::Pool;
+use leafwing_abilities::premade_pools::life::{Dead, Life, LifeDepleted, LifePlugin, LifePool};
+
+#[derive(Resource, Default)]
+struct Depletions(Vec<Entity>);
+
+fn app(plugin: LifePlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, plugin))
+        .init_resource::<Depletions>()
+        .add_observer(
+            |trigger: Trigger<LifeDepleted>, mut depletions: ResMut<Depletions>| {
+                assert_eq!(trigger.entity(), trigger.event().entity);
+                depletions.0.push(trigger.event().entity);
+            },
+        );
+    app
+}
+
+fn damage(app: &mut App, entity: Entity, amount: Life) {
+    *app.world_mut().get_mut::<LifePool>(entity).unwrap() -= amount;
+}
+
+fn is_dead(app: &App, entity: Entity) -> bool {
+    app.world().get::<Dead>(entity).is_some()
+}
+
+#[test]
+fn depleting_life_marks_entity_dead_once() {
+    let mut app = app(LifePlugin::default());
+    let entity = app
+        .world_mut()
+        .spawn(LifePool::new(Life(30.), Life(100.), Life(0.)))
+        .id();
+    app.update();
+
+    damage(&mut app, entity, Life(20.));
+    app.update();
+    assert!(!is_dead(&app, entity));
+    assert!(app.world().resource::<Depletions>().0.is_empty());
+
+    damage(&mut app, entity, Life(50.));
+    app.update();
+    assert!(is_dead(&app, entity));
+    assert_eq!(app.world().resource::<Depletions>().0, vec![entity]);
+
+    // Further damage to a dead entity does not fire again
+    damage(&mut app, entity, Life(10.));
+    app.update();
+    app.update();
+    assert!(is_dead(&app, entity));
+    assert_eq!(app.world().resource::<Depletions>().0, vec![entity]);
+}
+
+#[test]
+fn healed_entities_can_die_again() {
+    let mut app = app(LifePlugin::default());
+    let entity = app
+        .world_mut()
+        .spawn(LifePool::new(Life(10.), Life(100.), Life(0.)))
+        .id();
+    app.update();
+
+    damage(&mut app, entity, Life(10.));
+    app.update();
+    assert!(is_dead(&app, entity));
+
+    app.world_mut()
+        .get_mut::<LifePool>(entity)
+        .unwrap()
+        .replenish(Life(50.));
+    app.update();
+    assert!(!is_dead(&app, entity));
+
+    damage(&mut app, entity, Life(60.));
+    app.update();
+    assert!(is_dead(&app, entity));
+    assert_eq!(app.world().resource::<Depletions>().0, vec![entity, entity]);
+}
+
+#[test]
+fn entities_spawned_empty_are_ignored_by_default() {
+    let mut app = app(LifePlugin::default());
+    let entity = app
+        .world_mut()
+        .spawn(LifePool::new(Life(0.), Life(100.), Life(0.)))
+        .id();
+    app.update();
+    damage(&mut app, entity, Life(10.));
+    app.update();
+    assert!(!is_dead(&app, entity));
+    assert!(app.world().resource::<Depletions>().0.is_empty());
+
+    // Once healed, they are treated like any other entity
+    app.world_mut()
+        .get_mut::<LifePool>(entity)
+        .unwrap()
+        .replenish(Life(10.));
+    app.update();
+    damage(&mut app, entity, Life(10.));
+    app.update();
+    assert!(is_dead(&app, entity));
+    assert_eq!(app.world().resource::<Depletions>().0, vec![entity]);
+}
+
+#[test]
+fn entities_spawned_empty_can_be_included() {
+    let mut app = app(LifePlugin::default().with_spawned_empty());
+    let entity = app
+        .world_mut()
+        .spawn(LifePool::new(Life(0.), Life(100.), Life(0.)))
+        .id();
+    app.update();
+    assert!(is_dead(&app, entity));
+    assert_eq!(app.world().resource::<Depletions>().0, vec![entity]);
+}
@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+use bevy::utils::Duration;
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_abilities::premade_pools::stamina::{Stamina, StaminaPool};
+use leafwing_abilities::MultiPoolAbilityState;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Ability {
+    SpellBlade,
+    Sprint,
+}
+
+fn mana_costs() -> AbilityCosts<Ability, ManaPool> {
+    AbilityCosts::new([(Ability::SpellBlade, Mana(20.))])
+}
+
+fn stamina_costs() -> AbilityCosts<Ability, StaminaPool> {
+    AbilityCosts::new([
+        (Ability::SpellBlade, Stamina(10.)),
+        (Ability::Sprint, Stamina(30.)),
+    ])
+}
+
+fn stamina_pool(current: f32) -> StaminaPool {
+    StaminaPool::new(
+        Stamina(current),
+        Stamina(100.),
+        Stamina(0.),
+        Duration::from_secs(1),
+    )
+}
+
+#[test]
+fn costs_are_paid_from_both_pools() {
+    let mut charges = ChargeState::default();
+    let mut cooldowns = CooldownState::new([(Ability::SpellBlade, Cooldown::from_secs(1.))]);
+    let mut mana = ManaPool::new(Mana(50.), Mana(100.), Mana(0.));
+    let mut stamina = stamina_pool(50.);
+    let (mana_costs, stamina_costs) = (mana_costs(), stamina_costs());
+
+    assert!(Ability::SpellBlade
+        .ready_multi(
+            &charges,
+            &cooldowns,
+            (Some(&mana), Some(&stamina)),
+            (Some(&mana_costs), Some(&stamina_costs)),
+        )
+        .is_ok());
+
+    Ability::SpellBlade
+        .trigger_multi(
+            &mut charges,
+            &mut cooldowns,
+            (Some(&mut mana), Some(&mut stamina)),
+            (Some(&mana_costs), Some(&stamina_costs)),
+        )
+        .unwrap();
+    assert_eq!(mana.current(), Mana(30.));
+    assert_eq!(stamina.current(), Stamina(40.));
+    assert!(cooldowns.ready(&Ability::SpellBlade).is_err());
+}
+
+#[test]
+fn nothing_is_spent_if_either_pool_is_insufficient() {
+    let mut charges = ChargeState::default();
+    let mut cooldowns = CooldownState::new([(Ability::SpellBlade, Cooldown::from_secs(1.))]);
+    let mut mana = ManaPool::new(Mana(50.), Mana(100.), Mana(0.));
+    let mut stamina = stamina_pool(5.);
+    let (mana_costs, stamina_costs) = (mana_costs(), stamina_costs());
+
+    assert_eq!(
+        Ability::SpellBlade.trigger_multi(
+            &mut charges,
+            &mut cooldowns,
+            (Some(&mut mana), Some(&mut stamina)),
+            (Some(&mana_costs), Some(&stamina_costs)),
+        ),
+        Err(CannotUseAbility::PoolInsufficient)
+    );
+    assert_eq!(mana.current(), Mana(50.));
+    assert_eq!(stamina.current(), Stamina(5.));
+    assert!(cooldowns.ready(&Ability::SpellBlade).is_ok());
+
+    // The first pool being insufficient leaves the second untouched too
+    let mut mana = ManaPool::new(Mana(5.), Mana(100.), Mana(0.));
+    let mut stamina = stamina_pool(50.);
+    assert!(Ability::SpellBlade
+        .trigger_multi(
+            &mut charges,
+            &mut cooldowns,
+            (Some(&mut mana), Some(&mut stamina)),
+            (Some(&mana_costs), Some(&stamina_costs)),
+        )
+        .is_err());
+    assert_eq!(stamina.current(), Stamina(50.));
+}
+
+#[test]
+fn nothing_is_spent_if_the_ability_is_not_ready() {
+    let mut charges = ChargeState::default();
+    charges.set(Ability::SpellBlade, Charges::simple(0));
+    let mut cooldowns = CooldownState::default();
+    let mut mana = ManaPool::new(Mana(50.), Mana(100.), Mana(0.));
+    let mut stamina = stamina_pool(50.);
+    let (mana_costs, stamina_costs) = (mana_costs(), stamina_costs());
+
+    assert_eq!(
+        Ability::SpellBlade.trigger_multi(
+            &mut charges,
+            &mut cooldowns,
+            (Some(&mut mana), Some(&mut stamina)),
+            (Some(&mana_costs), Some(&stamina_costs)),
+        ),
+        Err(CannotUseAbility::NoCharges)
+    );
+    assert_eq!(mana.current(), Mana(50.));
+    assert_eq!(stamina.current(), Stamina(50.));
+}
+
+#[test]
+fn missing_pools_cannot_pay_costs() {
+    let charges = ChargeState::default();
+    let cooldowns = CooldownState::default();
+    let stamina = stamina_pool(50.);
+    let (mana_costs, stamina_costs) = (mana_costs(), stamina_costs());
+
+    assert_eq!(
+        Ability::SpellBlade.ready_multi(
+            &charges,
+            &cooldowns,
+            (None::<&ManaPool>, Some(&stamina)),
+            (Some(&mana_costs), Some(&stamina_costs)),
+        ),
+        Err(CannotUseAbility::PoolInsufficient)
+    );
+    // Sprint has no mana cost, so no mana pool is needed
+    assert!(Ability::Sprint
+        .ready_multi(
+            &charges,
+            &cooldowns,
+            (None::<&ManaPool>, Some(&stamina)),
+            (Some(&mana_costs), Some(&stamina_costs)),
+        )
+        .is_ok());
+}
+
+#[test]
+fn multi_pool_ability_state_triggers_atomically() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AbilityPlugin::<Ability>::default()));
+
+    let mut action_state = ActionState::<Ability>::default();
+    action_state.press(&Ability::SpellBlade);
+    action_state.press(&Ability::Sprint);
+    let entity = app
+        .world_mut()
+        .spawn((
+            AbilitiesBundle::<Ability>::default(),
+            action_state,
+            ManaPool::new(Mana(50.), Mana(100.), Mana(0.)),
+            stamina_pool(35.),
+            mana_costs(),
+            stamina_costs(),
+        ))
+        .id();
+
+    let mut query_state = app
+        .world_mut()
+        .query::<MultiPoolAbilityState<Ability, ManaPool, StaminaPool>>();
+    let mut ability_state = query_state.single_mut(app.world_mut());
+    assert!(ability_state.ready(&Ability::SpellBlade).is_ok());
+    ability_state
+        .trigger_if_just_pressed(&Ability::SpellBlade)
+        .unwrap();
+    assert_eq!(
+        ability_state.trigger(&Ability::Sprint),
+        Err(CannotUseAbility::PoolInsufficient)
+    );
+
+    ability_state.refund(&Ability::SpellBlade);
+    ability_state.trigger(&Ability::Sprint).unwrap();
+
+    let world = app.world();
+    assert_eq!(world.get::<ManaPool>(entity).unwrap().current(), Mana(50.));
+    assert_eq!(
+        world.get::<StaminaPool>(entity).unwrap().current(),
+        Stamina(5.)
+    );
+}
@@ -0,0 +1,251 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::utils::Duration;
+use leafwing_abilities::pool::{
+    modify_and_report, Pool, PoolDelta, PoolEmptied, PoolFilled, PoolThresholdCrossed,
+    PoolThresholds,
+};
+use leafwing_abilities::premade_pools::life::{Life, LifePool};
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_abilities::systems::{
+    detect_pool_thresholds, regenerate_resource_pool, track_pool_deltas,
+};
+
+fn app() -> App {
+    let mut app = common::app_with_frame_time(Duration::from_secs(1), ());
+    app.add_event::<PoolEmptied<ManaPool>>()
+        .add_event::<PoolFilled<ManaPool>>()
+        .add_systems(Update, regenerate_resource_pool::<ManaPool>);
+    app
+}
+
+fn emptied(app: &App) -> Vec<Option<Entity>> {
+    let events = app.world().resource::<Events<PoolEmptied<ManaPool>>>();
+    events
+        .get_cursor()
+        .read(events)
+        .map(|event| event.entity)
+        .collect()
+}
+
+fn filled(app: &App) -> Vec<Option<Entity>> {
+    let events = app.world().resource::<Events<PoolFilled<ManaPool>>>();
+    events
+        .get_cursor()
+        .read(events)
+        .map(|event| event.entity)
+        .collect()
+}
+
+#[test]
+fn decaying_pool_is_emptied_once() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn(ManaPool::new(Mana(25.), Mana(100.), Mana(-10.)))
+        .id();
+
+    // The first update has a delta time of zero
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(emptied(&app).is_empty());
+
+    // Events are kept for two updates, so this covers the emptying update and the one after
+    app.update();
+    app.update();
+    assert_eq!(emptied(&app), vec![Some(entity)]);
+
+    app.update();
+    app.update();
+    assert!(emptied(&app).is_empty());
+    assert!(filled(&app).is_empty());
+}
+
+#[test]
+fn regenerating_pool_is_filled_once() {
+    let mut app = app();
+    app.insert_resource(ManaPool::new(Mana(75.), Mana(100.), Mana(10.)));
+
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(filled(&app).is_empty());
+
+    app.update();
+    app.update();
+    assert_eq!(filled(&app), vec![None]);
+
+    app.update();
+    app.update();
+    assert!(filled(&app).is_empty());
+    assert!(emptied(&app).is_empty());
+}
+
+#[test]
+fn regeneration_works_without_registered_events() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_systems(Update, regenerate_resource_pool::<ManaPool>);
+    app.world_mut()
+        .resource_mut::<Time<Virtual>>()
+        .set_max_delta(Duration::from_secs(10));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+    let entity = app
+        .world_mut()
+        .spawn(ManaPool::new(Mana(5.), Mana(100.), Mana(-10.)))
+        .id();
+
+    app.update();
+    app.update();
+    assert!(app.world().get::<ManaPool>(entity).unwrap().is_empty());
+}
+
+#[test]
+fn manual_changes_can_be_reported() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn(ManaPool::new(Mana(10.), Mana(100.), Mana(0.)))
+        .id();
+
+    app.add_systems(
+        Update,
+        |mut query: Query<(Entity, &mut ManaPool)>,
+         mut emptied: EventWriter<PoolEmptied<ManaPool>>,
+         mut filled: EventWriter<PoolFilled<ManaPool>>| {
+            for (entity, mut pool) in query.iter_mut() {
+                let spent = modify_and_report(
+                    &mut *pool,
+                    Some(entity),
+                    &mut emptied,
+                    &mut filled,
+                    |pool| pool.expend(Mana(10.)),
+                );
+                assert!(spent.is_ok() || pool.is_empty());
+            }
+        },
+    );
+
+    app.update();
+    assert_eq!(emptied(&app), vec![Some(entity)]);
+
+    // Spending from an empty pool fails, and does not send another event
+    app.update();
+    app.update();
+    let events = app.world().resource::<Events<PoolEmptied<ManaPool>>>();
+    assert_eq!(events.oldest_event_count() + events.len(), 1);
+}
+
+fn deltas(app: &App) -> Vec<PoolDelta<LifePool>> {
+    let events = app.world().resource::<Events<PoolDelta<LifePool>>>();
+    events.get_cursor().read(events).copied().collect()
+}
+
+#[test]
+fn pool_deltas_capture_operator_changes() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<PoolDelta<LifePool>>()
+        .add_systems(PostUpdate, track_pool_deltas::<LifePool>);
+    let entity = app
+        .world_mut()
+        .spawn(LifePool::new(Life(80.), Life(100.), Life(0.)))
+        .id();
+
+    // Pools are recorded without an event the first time they are seen
+    app.update();
+    assert!(deltas(&app).is_empty());
+
+    *app.world_mut().get_mut::<LifePool>(entity).unwrap() -= Life(15.);
+    app.update();
+    let events = deltas(&app);
+    assert_eq!(
+        events,
+        vec![PoolDelta {
+            entity: Some(entity),
+            previous: Life(80.),
+            current: Life(65.),
+        }]
+    );
+    assert_eq!(events[0].delta(), Life(-15.));
+
+    // Unchanged pools send nothing new
+    app.update();
+    app.update();
+    let events = app.world().resource::<Events<PoolDelta<LifePool>>>();
+    assert_eq!(events.oldest_event_count() + events.len(), 1);
+
+    // Overkill is clamped
+    *app.world_mut().get_mut::<LifePool>(entity).unwrap() -= Life(100.);
+    app.update();
+    assert_eq!(deltas(&app).last().unwrap().delta(), Life(-65.));
+}
+
+fn thresholds_crossed(app: &App) -> Vec<(Entity, f32, bool)> {
+    let events = app
+        .world()
+        .resource::<Events<PoolThresholdCrossed<ManaPool>>>();
+    events
+        .get_cursor()
+        .read(events)
+        .map(|event| (event.entity, event.threshold, event.rising))
+        .collect()
+}
+
+#[test]
+fn large_changes_cross_every_threshold() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<PoolThresholdCrossed<ManaPool>>()
+        .add_systems(
+            Update,
+            (
+                regenerate_resource_pool::<ManaPool>,
+                detect_pool_thresholds::<ManaPool>,
+            )
+                .chain(),
+        )
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(4)));
+    app.world_mut()
+        .resource_mut::<Time<Virtual>>()
+        .set_max_delta(Duration::from_secs(10));
+
+    let entity = app
+        .world_mut()
+        .spawn((
+            ManaPool::new(Mana(60.), Mana(100.), Mana(-10.)),
+            PoolThresholds::<ManaPool>::new([0.3, 0.5, 0.9]),
+        ))
+        .id();
+
+    // The first update has a delta time of zero, and records the starting fraction
+    app.update();
+    assert!(thresholds_crossed(&app).is_empty());
+
+    app.update();
+    assert_eq!(
+        app.world().get::<ManaPool>(entity).unwrap().current(),
+        Mana(20.)
+    );
+    assert_eq!(
+        thresholds_crossed(&app),
+        vec![(entity, 0.5, false), (entity, 0.3, false)]
+    );
+
+    // Refilling the pool crosses them again in the opposite direction
+    app.world_mut()
+        .get_mut::<ManaPool>(entity)
+        .unwrap()
+        .set_current(Mana(95.));
+    app.world_mut()
+        .resource_mut::<Events<PoolThresholdCrossed<ManaPool>>>()
+        .clear();
+    app.update();
+    assert_eq!(
+        thresholds_crossed(&app),
+        vec![(entity, 0.3, true), (entity, 0.5, true)]
+    );
+}
@@ -0,0 +1,128 @@
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::reflect::{FromReflect, ReflectRef};
+use bevy::utils::Duration;
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Action {
+    Fireball,
+    Blink,
+}
+
+#[test]
+fn cooldowns_can_be_read_through_reflection() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        AbilityPlugin::<Action>::default(),
+    ));
+
+    let mut cooldowns = CooldownState::new([(Action::Fireball, Cooldown::from_secs(2.))]);
+    cooldowns.trigger(&Action::Fireball).unwrap();
+    cooldowns.tick(Duration::from_millis(500), None);
+    let entity = app
+        .world_mut()
+        .spawn(AbilitiesBundle {
+            cooldowns,
+            charges: ChargeState::new([(Action::Blink, Charges::simple(2))]),
+        })
+        .id();
+
+    let type_registry = app.world().resource::<AppTypeRegistry>().read();
+    let entity_ref = app.world().entity(entity);
+
+    let reflect_cooldowns = type_registry
+        .get_type_data::<ReflectComponent>(std::any::TypeId::of::<CooldownState<Action>>())
+        .unwrap()
+        .reflect(entity_ref)
+        .unwrap();
+    let ReflectRef::Struct(cooldowns) = reflect_cooldowns.reflect_ref() else {
+        panic!("CooldownState should be reflected as a struct");
+    };
+    let ReflectRef::Map(cooldown_map) = cooldowns.field("cooldown_map").unwrap().reflect_ref()
+    else {
+        panic!("The cooldown map should be reflected as a map");
+    };
+    let fireball = cooldown_map
+        .get(&Action::Fireball)
+        .unwrap()
+        .try_downcast_ref::<Cooldown>()
+        .unwrap();
+    assert_eq!(fireball.remaining(), Duration::from_millis(1500));
+
+    let reflect_charges = type_registry
+        .get_type_data::<ReflectComponent>(std::any::TypeId::of::<ChargeState<Action>>())
+        .unwrap()
+        .reflect(entity_ref)
+        .unwrap();
+    let ReflectRef::Struct(charges) = reflect_charges.reflect_ref() else {
+        panic!("ChargeState should be reflected as a struct");
+    };
+    let ReflectRef::Map(charges_map) = charges.field("charges_map").unwrap().reflect_ref() else {
+        panic!("The charges map should be reflected as a map");
+    };
+    let blink = charges_map
+        .get(&Action::Blink)
+        .unwrap()
+        .try_downcast_ref::<Charges>()
+        .unwrap();
+    assert_eq!(blink.charges(), 2);
+}
+
+#[test]
+fn ability_costs_can_be_read_through_reflection() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        AbilityPlugin::<Action>::default().with_ability_costs::<ManaPool>(),
+    ));
+
+    let entity = app
+        .world_mut()
+        .spawn(AbilityCosts::<Action, ManaPool>::new([
+            (Action::Fireball, AbilityCost::Flat(Mana(10.))),
+            (Action::Blink, AbilityCost::Percent(5.)),
+        ]))
+        .id();
+
+    let type_registry = app.world().resource::<AppTypeRegistry>().read();
+    let entity_ref = app.world().entity(entity);
+
+    let reflect_costs = type_registry
+        .get_type_data::<ReflectComponent>(std::any::TypeId::of::<AbilityCosts<Action, ManaPool>>())
+        .unwrap()
+        .reflect(entity_ref)
+        .unwrap();
+    let ReflectRef::Struct(costs) = reflect_costs.reflect_ref() else {
+        panic!("AbilityCosts should be reflected as a struct");
+    };
+    let ReflectRef::Map(cost_map) = costs.field("cost_map").unwrap().reflect_ref() else {
+        panic!("The cost map should be reflected as a map");
+    };
+    assert_eq!(cost_map.len(), 2);
+    let fireball = cost_map
+        .get(&Action::Fireball)
+        .unwrap()
+        .try_downcast_ref::<AbilityCost<Mana>>()
+        .unwrap();
+    assert_eq!(*fireball, AbilityCost::Flat(Mana(10.)));
+}
+
+#[test]
+fn computed_costs_keep_their_formula_through_reflection() {
+    let half_current: fn(Mana, Mana) -> Mana = |current, _max| Mana(current.0 / 2.);
+    let costs = AbilityCosts::<Action, ManaPool>::new([(
+        Action::Fireball,
+        AbilityCost::Computed(half_current),
+    )]);
+
+    let reflected =
+        AbilityCosts::<Action, ManaPool>::from_reflect(costs.as_partial_reflect()).unwrap();
+    let mana = ManaPool::new(Mana(10.), Mana(10.), Mana(0.));
+    assert_eq!(reflected.resolve(&Action::Fireball, &mana), Some(Mana(5.)));
+}
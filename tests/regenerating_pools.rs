@@ -0,0 +1,119 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::utils::Duration;
+use leafwing_abilities::pool::RegenPaused;
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::premade_pools::life::{Life, LifePool};
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Spell {
+    Fireball,
+}
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Item {
+    Potion,
+}
+
+fn app() -> App {
+    common::app_with_frame_time(Duration::from_secs(1), ())
+}
+
+#[test]
+fn registered_pools_regenerate() {
+    let mut app = app();
+    app.add_plugins(
+        AbilityPlugin::<Spell>::default()
+            .with_regenerating_pool::<ManaPool>()
+            .with_regenerating_pool::<LifePool>(),
+    );
+    let entity = app
+        .world_mut()
+        .spawn((
+            ManaPool::new(Mana(0.), Mana(100.), Mana(10.)),
+            LifePool::new(Life(50.), Life(100.), Life(5.)),
+        ))
+        .id();
+
+    // The first update has a delta time of zero
+    app.update();
+    app.update();
+    app.update();
+
+    let world = app.world();
+    assert_eq!(world.get::<ManaPool>(entity).unwrap().current(), Mana(20.));
+    assert_eq!(world.get::<LifePool>(entity).unwrap().current(), Life(60.));
+}
+
+#[test]
+fn shared_pools_regenerate_once_per_frame() {
+    let mut app = app();
+    app.add_plugins((
+        AbilityPlugin::<Spell>::default().with_regenerating_pool::<ManaPool>(),
+        AbilityPlugin::<Item>::default().with_regenerating_pool::<ManaPool>(),
+    ));
+    let entity = app
+        .world_mut()
+        .spawn(ManaPool::new(Mana(0.), Mana(100.), Mana(10.)))
+        .id();
+
+    app.update();
+    for frame in 1..=3 {
+        app.update();
+        assert_eq!(
+            app.world().get::<ManaPool>(entity).unwrap().current(),
+            Mana(10. * frame as f32)
+        );
+    }
+}
+
+#[test]
+fn paused_pools_do_not_regenerate() {
+    let mut app = app();
+    app.add_plugins(
+        AbilityPlugin::<Spell>::default()
+            .with_regenerating_pool::<ManaPool>()
+            .with_regenerating_pool::<LifePool>(),
+    );
+    let entity = app
+        .world_mut()
+        .spawn((
+            ManaPool::new(Mana(0.), Mana(100.), Mana(10.)),
+            LifePool::new(Life(50.), Life(100.), Life(5.)),
+            RegenPaused::<LifePool>::default(),
+        ))
+        .id();
+
+    app.update();
+    app.update();
+    let world = app.world();
+    assert_eq!(world.get::<ManaPool>(entity).unwrap().current(), Mana(10.));
+    assert_eq!(world.get::<LifePool>(entity).unwrap().current(), Life(50.));
+
+    app.world_mut()
+        .entity_mut(entity)
+        .remove::<RegenPaused<LifePool>>();
+    app.update();
+    let world = app.world();
+    assert_eq!(world.get::<ManaPool>(entity).unwrap().current(), Mana(20.));
+    assert_eq!(world.get::<LifePool>(entity).unwrap().current(), Life(55.));
+}
+
+#[test]
+fn paused_resource_pools_do_not_regenerate() {
+    let mut app = app();
+    app.add_plugins(AbilityPlugin::<Spell>::default().with_regenerating_pool::<ManaPool>())
+        .insert_resource(ManaPool::new(Mana(0.), Mana(100.), Mana(10.)))
+        .init_resource::<RegenPaused<ManaPool>>();
+
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<ManaPool>().current(), Mana(0.));
+
+    app.world_mut().remove_resource::<RegenPaused<ManaPool>>();
+    app.update();
+    assert_eq!(app.world().resource::<ManaPool>().current(), Mana(10.));
+}
@@ -0,0 +1,292 @@
+use bevy::prelude::*;
+use bevy::utils::Duration;
+use leafwing_abilities::charges::{CooldownStrategy, ReplenishStrategy};
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Actionlike,
+    Reflect,
+    Abilitylike,
+    Debug,
+    Clone,
+    Copy,
+    Hash,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+enum Action {
+    Fireball,
+    Blink,
+    Heal,
+}
+
+fn in_flight_cooldowns() -> CooldownState<Action> {
+    let mut cooldowns = CooldownState::new([
+        (Action::Fireball, Cooldown::from_secs(3.)),
+        (Action::Heal, Cooldown::from_secs(10.)),
+    ]);
+    cooldowns.set_global_cooldown(Some(Cooldown::from_secs(1.)));
+    cooldowns.trigger(&Action::Fireball).unwrap();
+    cooldowns.tick(Duration::from_millis(250), None);
+    cooldowns
+}
+
+fn charges() -> ChargeState<Action> {
+    let mut charges = ChargeState::new([(Action::Blink, Charges::ammo(3))]);
+    charges.expend(&Action::Blink).unwrap();
+    charges
+}
+
+fn costs() -> AbilityCosts<Action, ManaPool> {
+    AbilityCosts::new([(Action::Fireball, Mana(10.)), (Action::Heal, Mana(25.))])
+}
+
+#[test]
+fn cooldown_state_round_trips_through_json() {
+    let cooldowns = in_flight_cooldowns();
+
+    let serialized = serde_json::to_string(&cooldowns).unwrap();
+    let deserialized: CooldownState<Action> = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, cooldowns);
+    let fireball = deserialized.get(&Action::Fireball).unwrap();
+    assert_eq!(fireball.elapsed(), Duration::from_millis(250));
+    assert_eq!(fireball.remaining(), Duration::from_millis(2750));
+    assert_eq!(
+        deserialized.global_cooldown().unwrap().elapsed(),
+        Duration::from_millis(250)
+    );
+}
+
+#[test]
+fn dense_cooldown_state_round_trips_through_json() {
+    let mut cooldowns = CooldownState::dense().with(Action::Blink, Cooldown::from_secs(2.));
+    cooldowns.trigger(&Action::Blink).unwrap();
+
+    let serialized = serde_json::to_string(&cooldowns).unwrap();
+    let deserialized: CooldownState<Action> = serde_json::from_str(&serialized).unwrap();
+
+    assert!(deserialized.is_dense());
+    assert_eq!(deserialized, cooldowns);
+    assert!(deserialized.ready(&Action::Blink).is_err());
+
+    // Cooldowns in the wrong slot could never be found again
+    let misplaced = serialized.replacen("[null,", "[", 1);
+    assert!(serde_json::from_str::<CooldownState<Action>>(&misplaced).is_err());
+}
+
+#[test]
+fn cooldown_state_round_trips_through_ron() {
+    let cooldowns = in_flight_cooldowns();
+
+    let serialized = ron::to_string(&cooldowns).unwrap();
+    let deserialized: CooldownState<Action> = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, cooldowns);
+    assert_eq!(
+        deserialized.get(&Action::Fireball).unwrap().remaining(),
+        Duration::from_millis(2750)
+    );
+}
+
+#[test]
+fn charge_state_round_trips() {
+    let charges = charges();
+
+    let json: ChargeState<Action> =
+        serde_json::from_str(&serde_json::to_string(&charges).unwrap()).unwrap();
+    let ron: ChargeState<Action> = ron::from_str(&ron::to_string(&charges).unwrap()).unwrap();
+
+    assert_eq!(json, charges);
+    assert_eq!(ron, charges);
+    assert_eq!(json.get(&Action::Blink).unwrap().charges(), 2);
+}
+
+#[test]
+fn ability_costs_round_trip() {
+    let costs = costs();
+
+    let json: AbilityCosts<Action, ManaPool> =
+        serde_json::from_str(&serde_json::to_string(&costs).unwrap()).unwrap();
+    let ron: AbilityCosts<Action, ManaPool> =
+        ron::from_str(&ron::to_string(&costs).unwrap()).unwrap();
+
+    for deserialized in [json, ron] {
+        assert_eq!(
+            deserialized.get(&Action::Fireball),
+            Some(&AbilityCost::Flat(Mana(10.)))
+        );
+        assert_eq!(
+            deserialized.get(&Action::Heal),
+            Some(&AbilityCost::Flat(Mana(25.)))
+        );
+        assert_eq!(deserialized.get(&Action::Blink), None);
+    }
+}
+
+#[test]
+fn edited_ability_costs_are_loaded_from_ron() {
+    let serialized = ron::to_string(&costs()).unwrap();
+    assert_eq!(serialized.matches("10.0").count(), 1);
+    let edited = serialized.replace("10.0", "15.0");
+
+    let deserialized: AbilityCosts<Action, ManaPool> = ron::from_str(&edited).unwrap();
+    assert_eq!(
+        deserialized.get(&Action::Fireball),
+        Some(&AbilityCost::Flat(Mana(15.)))
+    );
+    assert_eq!(
+        deserialized.get(&Action::Heal),
+        Some(&AbilityCost::Flat(Mana(25.)))
+    );
+}
+
+#[test]
+fn partially_expended_charges_round_trip_through_ron() {
+    let mut charges = Charges::new(
+        3,
+        ReplenishStrategy::AllAtOnce,
+        CooldownStrategy::RefreshWhenEmpty,
+    );
+    charges.set_charges(1);
+
+    let deserialized: Charges = ron::from_str(&ron::to_string(&charges).unwrap()).unwrap();
+
+    assert_eq!(deserialized, charges);
+    assert_eq!(deserialized.charges(), 1);
+    assert_eq!(deserialized.max_charges(), 3);
+    assert_eq!(deserialized.replenish_strat, ReplenishStrategy::AllAtOnce);
+    assert_eq!(
+        deserialized.cooldown_strat,
+        CooldownStrategy::RefreshWhenEmpty
+    );
+}
+
+#[test]
+fn charges_with_current_above_max_are_rejected() {
+    let serialized = "(current: 4, max: 3, replenish_strat: OneAtATime, cooldown_strat: Ignore)";
+
+    assert!(ron::from_str::<Charges>(serialized).is_err());
+}
+
+#[test]
+fn pools_round_trip_through_ron() {
+    use leafwing_abilities::premade_pools::life::{Life, LifePool};
+
+    let mut life = LifePool::new(Life(40.), Life(100.), Life(-2.5));
+    life.expend(Life(15.)).unwrap();
+    let serialized = ron::to_string(&life).unwrap();
+    let deserialized: LifePool = ron::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, life);
+    assert_eq!(deserialized.regen_per_second, Life(-2.5));
+
+    let mana = ManaPool::new(Mana(10.), Mana(50.), Mana(-1.));
+    let serialized = ron::to_string(&mana).unwrap();
+    let deserialized: ManaPool = ron::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, mana);
+}
+
+#[test]
+fn pools_with_invalid_bounds_are_rejected() {
+    use leafwing_abilities::premade_pools::life::LifePool;
+
+    for (current, max) in [(120., 100.), (-1., 100.), (0., -5.)] {
+        let mana = format!(
+            "(current: Mana({current:?}), max: Mana({max:?}), regen_per_second: Mana(0.0))"
+        );
+        let life = format!(
+            "(current: Life({current:?}), max: Life({max:?}), regen_per_second: Life(0.0))"
+        );
+        assert!(ron::from_str::<ManaPool>(&mana).is_err());
+        assert!(ron::from_str::<LifePool>(&life).is_err());
+    }
+
+    // Overfilled life is valid, as long as it fits within the overfill
+    let overfilled = "(current: Life(120.0), max: Life(100.0), regen_per_second: Life(0.0), overfill: Life(20.0))";
+    assert!(ron::from_str::<LifePool>(overfilled).is_ok());
+
+    // Reservations must be backed by current mana
+    for reserved in [-5., 30.] {
+        let mana = format!(
+            "(current: Mana(20.0), max: Mana(50.0), regen_per_second: Mana(0.0), reserved: Mana({reserved:?}))"
+        );
+        assert!(ron::from_str::<ManaPool>(&mana).is_err());
+    }
+    let reserved =
+        "(current: Mana(20.0), max: Mana(50.0), regen_per_second: Mana(0.0), reserved: Mana(20.0))";
+    assert!(ron::from_str::<ManaPool>(reserved).is_ok());
+}
+
+#[test]
+fn pools_with_non_finite_quantities_are_rejected() {
+    use leafwing_abilities::premade_pools::life::LifePool;
+
+    let fields = ["current", "max", "regen_per_second", "reserved", "overfill"];
+    for invalid in fields {
+        let value = |field: &str| if field == invalid { "NaN" } else { "5.0" };
+        let [current, max, regen, reserved, overfill] = fields.map(value);
+
+        let mana = format!(
+            "(current: Mana({current}), max: Mana({max}), regen_per_second: Mana({regen}), reserved: Mana({reserved}))"
+        );
+        let life = format!(
+            "(current: Life({current}), max: Life({max}), regen_per_second: Life({regen}), overfill: Life({overfill}))"
+        );
+        assert_eq!(
+            ron::from_str::<ManaPool>(&mana).is_ok(),
+            invalid == "overfill"
+        );
+        assert_eq!(
+            ron::from_str::<LifePool>(&life).is_ok(),
+            invalid == "reserved"
+        );
+    }
+}
+
+#[test]
+fn cannot_use_ability_round_trips_through_json() {
+    let variants = [
+        (
+            CannotUseAbility::NotPressed,
+            0,
+            r#"{"reason":"NotPressed"}"#,
+        ),
+        (CannotUseAbility::Disabled, 1, r#"{"reason":"Disabled"}"#),
+        (CannotUseAbility::NoCharges, 2, r#"{"reason":"NoCharges"}"#),
+        (
+            CannotUseAbility::OnCooldown,
+            3,
+            r#"{"reason":"OnCooldown"}"#,
+        ),
+        (
+            CannotUseAbility::OnSharedCooldown,
+            4,
+            r#"{"reason":"OnSharedCooldown"}"#,
+        ),
+        (
+            CannotUseAbility::OnGlobalCooldown,
+            5,
+            r#"{"reason":"OnGlobalCooldown"}"#,
+        ),
+        (
+            CannotUseAbility::PoolInsufficient,
+            6,
+            r#"{"reason":"PoolInsufficient"}"#,
+        ),
+    ];
+
+    for (variant, discriminant, json) in variants {
+        assert_eq!(variant as u8, discriminant);
+
+        let serialized = serde_json::to_string(&variant).unwrap();
+        assert_eq!(serialized, json);
+
+        let deserialized: CannotUseAbility = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, variant);
+    }
+}
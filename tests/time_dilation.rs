@@ -0,0 +1,87 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::utils::Duration;
+use leafwing_abilities::prelude::*;
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_abilities::systems::regenerate_resource_pool;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Reflect, Abilitylike, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Action {
+    Fireball,
+}
+
+fn app() -> App {
+    let mut app =
+        common::app_with_frame_time(Duration::from_secs(1), AbilityPlugin::<Action>::default());
+    app.add_systems(Update, regenerate_resource_pool::<ManaPool>);
+    app
+}
+
+fn spawn_caster(app: &mut App, dilation: Option<f32>) -> Entity {
+    let mut cooldowns = CooldownState::new([(Action::Fireball, Cooldown::from_secs(10.))]);
+    cooldowns.trigger(&Action::Fireball).unwrap();
+
+    let mut entity = app
+        .world_mut()
+        .spawn((cooldowns, ManaPool::new(Mana(0.), Mana(100.), Mana(10.))));
+    if let Some(dilation) = dilation {
+        entity.insert(AbilityTimeDilation(dilation));
+    }
+    entity.id()
+}
+
+fn remaining(app: &App, entity: Entity) -> Duration {
+    app.world()
+        .get::<CooldownState<Action>>(entity)
+        .unwrap()
+        .get(&Action::Fireball)
+        .unwrap()
+        .remaining()
+}
+
+fn mana(app: &App, entity: Entity) -> Mana {
+    app.world().get::<ManaPool>(entity).unwrap().current()
+}
+
+#[test]
+fn time_dilation_scales_cooldowns_and_regeneration_per_entity() {
+    let mut app = app();
+    let normal = spawn_caster(&mut app, None);
+    let slowed = spawn_caster(&mut app, Some(0.5));
+    let frozen = spawn_caster(&mut app, Some(0.));
+
+    // The first update has no elapsed time
+    app.update();
+    app.update();
+    app.update();
+
+    // Two seconds of wall-clock time have passed
+    assert_eq!(remaining(&app, normal), Duration::from_secs(8));
+    assert_eq!(remaining(&app, slowed), Duration::from_secs(9));
+    assert_eq!(remaining(&app, frozen), Duration::from_secs(10));
+
+    assert_eq!(mana(&app, normal), Mana(20.));
+    assert_eq!(mana(&app, slowed), Mana(10.));
+    assert_eq!(mana(&app, frozen), Mana(0.));
+}
+
+#[test]
+fn resources_are_not_dilated() {
+    let mut app = app();
+    let mut cooldowns = CooldownState::new([(Action::Fireball, Cooldown::from_secs(10.))]);
+    cooldowns.trigger(&Action::Fireball).unwrap();
+    app.insert_resource(cooldowns);
+    // Even an entity with a dilation does not affect the resource
+    app.world_mut().spawn(AbilityTimeDilation(0.));
+
+    app.update();
+    app.update();
+
+    let cooldowns = app.world().resource::<CooldownState<Action>>();
+    assert_eq!(
+        cooldowns.get(&Action::Fireball).unwrap().remaining(),
+        Duration::from_secs(9)
+    );
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::Reflect;
+use leafwing_abilities::prelude::*;
+use leafwing_input_manager::Actionlike;
+
+#[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+enum Action {
+    #[ability(cooldown_secs = "fast")]
+    Fireball,
+    #[ability(charges = 300)]
+    Blink,
+    #[ability(charges = 2, replenish = "sometimes")]
+    Dodge,
+    #[ability(range = 5)]
+    Attack,
+}
+
+fn main() {}
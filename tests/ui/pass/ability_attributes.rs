@@ -0,0 +1,54 @@
+use bevy::prelude::Reflect;
+use bevy::utils::Duration;
+use leafwing_abilities::charges::{CooldownStrategy, ReplenishStrategy};
+use leafwing_abilities::premade_pools::mana::{Mana, ManaPool};
+use leafwing_abilities::prelude::*;
+use leafwing_input_manager::Actionlike;
+
+#[derive(Actionlike, Abilitylike, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+enum Action {
+    #[ability(cooldown_secs = 2.5, charges = 3, replenish = "one_at_a_time")]
+    Fireball,
+    #[ability(cooldown_secs = 10, cost = 40.0)]
+    Blink,
+    #[ability(
+        charges = 2,
+        replenish = "all_at_once",
+        cooldown_strategy = "refresh_when_empty"
+    )]
+    Dodge,
+    Attack,
+}
+
+fn main() {
+    let cooldowns = Action::default_cooldowns();
+    assert_eq!(
+        cooldowns.get(&Action::Fireball).unwrap().max_time(),
+        Duration::from_secs_f32(2.5)
+    );
+    assert_eq!(
+        cooldowns.get(&Action::Blink).unwrap().max_time(),
+        Duration::from_secs(10)
+    );
+    assert!(cooldowns.get(&Action::Dodge).is_none());
+    assert!(cooldowns.get(&Action::Attack).is_none());
+
+    let charges = Action::default_charges();
+    let fireball = charges.get(&Action::Fireball).unwrap();
+    assert_eq!(fireball.max_charges(), 3);
+    assert_eq!(fireball.replenish_strat, ReplenishStrategy::OneAtATime);
+    assert_eq!(fireball.cooldown_strat, CooldownStrategy::default());
+    let dodge = charges.get(&Action::Dodge).unwrap();
+    assert_eq!(dodge.max_charges(), 2);
+    assert_eq!(dodge.replenish_strat, ReplenishStrategy::AllAtOnce);
+    assert_eq!(dodge.cooldown_strat, CooldownStrategy::RefreshWhenEmpty);
+    assert!(charges.get(&Action::Blink).is_none());
+
+    let costs = Action::default_costs::<ManaPool>();
+    assert_eq!(costs.get(&Action::Blink), Some(&AbilityCost::Flat(Mana(40.))));
+    assert_eq!(costs.get(&Action::Attack), None);
+
+    let bundle = Action::default_abilities_bundle();
+    assert_eq!(bundle.cooldowns, cooldowns);
+    assert_eq!(bundle.charges, charges);
+}